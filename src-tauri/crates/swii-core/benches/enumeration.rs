@@ -0,0 +1,84 @@
+//! Benchmarks for the enumeration pipeline, replaying a recorded session.
+//!
+//! `fixtures/session.jsonl` has the format written by swii's snapshot
+//! recording. Each recorded window goes through the steps every provider
+//! takes after reading the raw window list: the editor filter, title parsing
+//! and building the [`WindowInfo`] handed to the frontend. Run with
+//! `cargo bench -p swii-core`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use serde::Deserialize;
+use swii_core::editor_config;
+use swii_core::types::WindowInfo;
+use swii_title_parser::parse_title;
+
+const SESSION: &str = include_str!("fixtures/session.jsonl");
+
+#[derive(Deserialize)]
+struct Snapshot {
+    windows: Vec<WindowInfo>,
+}
+
+fn read_session(contents: &str) -> Vec<Vec<WindowInfo>> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let snapshot: Snapshot = serde_json::from_str(line).expect("valid snapshot");
+            snapshot
+                .windows
+                .into_iter()
+                .map(|window| window.upgrade().expect("supported schema"))
+                .collect()
+        })
+        .collect()
+}
+
+fn enumerate(recorded: &[WindowInfo]) -> Vec<WindowInfo> {
+    recorded
+        .iter()
+        .filter(|window| editor_config::should_inspect_application(&window.app_name))
+        .filter_map(|window| {
+            let parsed = parse_title(
+                Some(&window.app_name),
+                window.window_name.as_deref().unwrap_or_default(),
+            );
+            if editor_config::is_terminal_application(&window.app_name)
+                && !parsed.editor_kind.is_terminal_editor()
+            {
+                return None;
+            }
+            Some(
+                WindowInfo::builder(window.app_name.clone(), window.pid, window.window_number)
+                    .window_name(window.window_name.clone())
+                    .app_icon(window.app_icon.clone())
+                    .parsed(parsed)
+                    .build(),
+            )
+        })
+        .collect()
+}
+
+fn bench_enumeration(c: &mut Criterion) {
+    // The session includes terminal windows running editors
+    editor_config::set_terminal_editors_enabled(true);
+    let snapshots = read_session(SESSION);
+    let window_count: usize = snapshots.iter().map(Vec::len).sum();
+    let mut group = c.benchmark_group("enumeration");
+    group.throughput(Throughput::Elements(window_count as u64));
+
+    group.bench_function("read_session", |b| {
+        b.iter(|| black_box(read_session(black_box(SESSION))))
+    });
+    group.bench_function("enumerate", |b| {
+        b.iter(|| {
+            for windows in &snapshots {
+                black_box(enumerate(black_box(windows)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_enumeration);
+criterion_main!(benches);
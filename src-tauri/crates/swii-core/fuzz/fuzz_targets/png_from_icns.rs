@@ -0,0 +1,14 @@
+//! Feeds arbitrary bytes through the icns reader, as if read from a bundle.
+//!
+//! Run with `cargo fuzz run png_from_icns` from `crates/swii-core`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use swii_core::icns::{constants::PNG_SIGNATURE, png_from_icns};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(png) = png_from_icns(data) {
+        assert!(png.starts_with(PNG_SIGNATURE));
+    }
+});
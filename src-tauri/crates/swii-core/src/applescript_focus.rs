@@ -0,0 +1,128 @@
+//! Selecting windows through AppleScript when Accessibility is unavailable.
+//!
+//! Without Accessibility access swii can't raise a single window through the
+//! AX API, but it can still activate the editor, and most scriptable editors
+//! (Xcode, BBEdit, Nova, TextMate, ...) let AppleScript bring one of their
+//! windows to the front by title. That only needs the Automation permission
+//! for the editor, which macOS asks for on first use. System Events UI
+//! scripting is tried last, for when the AX calls failed for another reason.
+//! Either way the window is matched by the title it had when last listed.
+//! Failed `osascript` runs are mapped to focus errors by [`osascript_error`],
+//! which the app's browser tab focusing shares.
+
+use crate::focus::WindowFocusError;
+
+pub mod constants {
+    /// Apple event error codes reported by `osascript`
+    pub const NOT_AUTHORIZED_ERROR: &str = "-1743";
+    pub const APP_NOT_RUNNING_ERROR: &str = "-600";
+
+    /// Brings the window of application `argv[1]` titled `argv[2]` to the
+    /// front of that application's windows
+    pub const SELECT_WINDOW_SCRIPT: &[&str] = &[
+        "on run argv",
+        "tell application (item 1 of argv) to set index of (first window whose name is (item 2 of argv)) to 1",
+        "end run",
+    ];
+
+    /// Raises the window titled `argv[2]` of the process with id `argv[1]`
+    pub const SYSTEM_EVENTS_RAISE_SCRIPT: &[&str] = &[
+        "on run argv",
+        "tell application \"System Events\"",
+        "tell (first process whose unix id is ((item 1 of argv) as integer))",
+        "perform action \"AXRaise\" of (first window whose name is (item 2 of argv))",
+        "end tell",
+        "end tell",
+        "end run",
+    ];
+}
+
+/// Maps a failed `osascript` run to a focus error
+pub fn osascript_error(stderr: &str) -> WindowFocusError {
+    if stderr.contains(constants::NOT_AUTHORIZED_ERROR) {
+        WindowFocusError::PermissionDenied
+    } else if stderr.contains(constants::APP_NOT_RUNNING_ERROR) {
+        WindowFocusError::ApplicationNotFound
+    } else {
+        WindowFocusError::SystemError(stderr.trim().to_string())
+    }
+}
+
+/// Arguments to `osascript` running `script` with `argv`
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn osascript_args(script: &[&str], argv: &[&str]) -> Vec<String> {
+    script
+        .iter()
+        .flat_map(|line| ["-e", line])
+        .chain(argv.iter().copied())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn run_script(script: &[&str], argv: &[&str]) -> Result<(), WindowFocusError> {
+    let output = std::process::Command::new("osascript")
+        .args(osascript_args(script, argv))
+        .output()
+        .map_err(|e| WindowFocusError::SystemError(e.to_string()))?;
+    if !output.status.success() {
+        return Err(osascript_error(&String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn run_script(_script: &[&str], _argv: &[&str]) -> Result<(), WindowFocusError> {
+    Err(WindowFocusError::SystemError(
+        "AppleScript is only available on macOS".to_string(),
+    ))
+}
+
+/// Brings the window of `app_name` (process `pid`) titled `title` to the
+/// front of that application's windows, without activating the application
+pub fn select_window(pid: i32, app_name: &str, title: &str) -> Result<(), WindowFocusError> {
+    run_script(constants::SELECT_WINDOW_SCRIPT, &[app_name, title]).or_else(|_| {
+        run_script(
+            constants::SYSTEM_EVENTS_RAISE_SCRIPT,
+            &[&pid.to_string(), title],
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osascript_args() {
+        assert_eq!(
+            osascript_args(&["on run argv", "end run"], &["Xcode", "swii — main.rs"]),
+            vec![
+                "-e",
+                "on run argv",
+                "-e",
+                "end run",
+                "Xcode",
+                "swii — main.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_osascript_error() {
+        assert_eq!(
+            osascript_error(
+                "execution error: Not authorized to send Apple events to Safari. (-1743)"
+            ),
+            WindowFocusError::PermissionDenied
+        );
+        assert_eq!(
+            osascript_error("execution error: Application isn't running. (-600)\n"),
+            WindowFocusError::ApplicationNotFound
+        );
+        assert_eq!(
+            osascript_error("syntax error\n"),
+            WindowFocusError::SystemError("syntax error".to_string())
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Editors' command line tools.
+//!
+//! Several editors ship a command line tool inside their application bundle
+//! that opens files in an existing window, which is more precise than
+//! handing the file to the system. [`editor_cli`] knows where each tool lives
+//! and which arguments make it reuse the last active window, and
+//! [`EditorCli::args_for`] adds the `path:line:col` target.
+
+use swii_title_parser::EditorKind;
+
+/// A file to open, optionally at a position
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTarget {
+    pub path: String,
+    /// 1-based
+    pub line: Option<u32>,
+    /// 1-based, only used with a line
+    pub column: Option<u32>,
+}
+
+impl FileTarget {
+    /// `path`, `path:line` or `path:line:column`
+    pub fn with_position(&self) -> String {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => format!("{}:{}:{}", self.path, line, column),
+            (Some(line), None) => format!("{}:{}", self.path, line),
+            _ => self.path.clone(),
+        }
+    }
+}
+
+/// An editor's command line tool inside its application bundle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EditorCli {
+    /// Path of the tool relative to the bundle
+    pub path: &'static str,
+    /// Arguments placed before the file so it opens in the last active
+    /// window
+    pub args: &'static [&'static str],
+    /// Argument placed right before a `path:line:col` target, for tools
+    /// that need to be told the target has a position
+    pub goto_flag: Option<&'static str>,
+}
+
+impl EditorCli {
+    /// Arguments opening `target`
+    pub fn args_for(&self, target: &FileTarget) -> Vec<String> {
+        let mut args: Vec<String> = self.args.iter().map(|arg| arg.to_string()).collect();
+        if target.line.is_some() {
+            args.extend(self.goto_flag.map(str::to_string));
+        }
+        args.push(target.with_position());
+        args
+    }
+}
+
+/// The command line tool of editors that ship one
+pub fn editor_cli(kind: EditorKind) -> Option<EditorCli> {
+    match kind {
+        EditorKind::VsCode => Some(EditorCli {
+            path: "Contents/Resources/app/bin/code",
+            args: &["--reuse-window"],
+            goto_flag: Some("--goto"),
+        }),
+        EditorKind::Cursor => Some(EditorCli {
+            path: "Contents/Resources/app/bin/cursor",
+            args: &["--reuse-window"],
+            goto_flag: Some("--goto"),
+        }),
+        EditorKind::Zed => Some(EditorCli {
+            path: "Contents/MacOS/cli",
+            args: &["--add"],
+            goto_flag: None,
+        }),
+        // Sublime opens files in its last active window by default
+        EditorKind::Sublime => Some(EditorCli {
+            path: "Contents/SharedSupport/bin/subl",
+            args: &[],
+            goto_flag: None,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_cli() {
+        let code = editor_cli(EditorKind::VsCode).unwrap();
+        assert_eq!(code.args, &["--reuse-window"]);
+        assert!(code.path.ends_with("/code"));
+        assert!(editor_cli(EditorKind::Sublime).unwrap().args.is_empty());
+        assert_eq!(editor_cli(EditorKind::Xcode), None);
+    }
+
+    #[test]
+    fn test_editor_cli_positions() {
+        let target = |line, column| FileTarget {
+            path: "/src/api/main.rs".to_string(),
+            line,
+            column,
+        };
+        let code = editor_cli(EditorKind::VsCode).unwrap();
+        assert_eq!(
+            code.args_for(&target(Some(12), Some(5))),
+            vec!["--reuse-window", "--goto", "/src/api/main.rs:12:5"]
+        );
+        assert_eq!(
+            code.args_for(&target(None, None)),
+            vec!["--reuse-window", "/src/api/main.rs"]
+        );
+
+        let zed = editor_cli(EditorKind::Zed).unwrap();
+        assert_eq!(
+            zed.args_for(&target(Some(12), None)),
+            vec!["--add", "/src/api/main.rs:12"]
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Configuration for editor applications and their properties.
+//!
+//! This module centralizes editor-related data that is used across multiple
+//! parts of the application, including window detection and path mapping.
+//! The list of editor names is shared with title parsing and lives in
+//! `swii_title_parser`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+pub use swii_title_parser::editors::EDITOR_APPLICATIONS;
+
+/// Mapping of editor application names to their typical installation paths on macOS.
+/// This is used as a fallback when trying to determine the application bundle path
+/// for a given process ID.
+pub const EDITOR_PATHS: &[(&str, &str)] = &[
+    ("Visual Studio Code", "/Applications/Visual Studio Code.app"),
+    ("Code", "/Applications/Visual Studio Code.app"),
+    ("VSCode", "/Applications/Visual Studio Code.app"),
+    (
+        "Visual Studio Code - Insiders",
+        "/Applications/Visual Studio Code - Insiders.app",
+    ),
+    (
+        "Code - Insiders",
+        "/Applications/Visual Studio Code - Insiders.app",
+    ),
+    ("VSCodium", "/Applications/VSCodium.app"),
+    ("Zed", "/Applications/Zed.app"),
+    ("Zed Preview", "/Applications/Zed Preview.app"),
+    ("Sublime Text", "/Applications/Sublime Text.app"),
+    ("Sublime Text 3", "/Applications/Sublime Text.app"),
+    ("Sublime Text 4", "/Applications/Sublime Text.app"),
+    ("Atom", "/Applications/Atom.app"),
+    ("Cursor", "/Applications/Cursor.app"),
+    ("Cursor Nightly", "/Applications/Cursor Nightly.app"),
+    ("Xcode", "/Applications/Xcode.app"),
+    ("IntelliJ IDEA", "/Applications/IntelliJ IDEA.app"),
+    ("PyCharm", "/Applications/PyCharm.app"),
+    ("WebStorm", "/Applications/WebStorm.app"),
+    ("Lapce", "/Applications/Lapce.app"),
+    ("Pulsar", "/Applications/Pulsar.app"),
+    ("Nova", "/Applications/Nova.app"),
+    ("BBEdit", "/Applications/BBEdit.app"),
+    ("TextMate", "/Applications/TextMate.app"),
+    ("Eclipse", "/Applications/Eclipse.app"),
+    ("Apache NetBeans", "/Applications/Apache NetBeans.app"),
+    ("NetBeans", "/Applications/NetBeans.app"),
+    ("RStudio", "/Applications/RStudio.app"),
+    ("JupyterLab", "/Applications/JupyterLab.app"),
+];
+
+/// Mapping of executable names (without `.exe`) and Wayland app ids to editor application names.
+/// On Windows and Wayland we only learn the executable or app id, so this is used to
+/// translate it into the same app names the macOS backend reports.
+pub const EDITOR_EXECUTABLES: &[(&str, &str)] = &[
+    ("code", "Visual Studio Code"),
+    ("code - insiders", "Visual Studio Code - Insiders"),
+    ("codium", "VSCodium"),
+    ("vscodium", "VSCodium"),
+    ("cursor", "Cursor"),
+    ("zed", "Zed"),
+    ("sublime_text", "Sublime Text"),
+    ("idea64", "IntelliJ IDEA"),
+    ("pycharm64", "PyCharm"),
+    ("webstorm64", "WebStorm"),
+    ("phpstorm64", "PhpStorm"),
+    ("rubymine64", "RubyMine"),
+    ("clion64", "CLion"),
+    ("goland64", "GoLand"),
+    ("datagrip64", "DataGrip"),
+    ("rider64", "Rider"),
+    ("studio64", "Android Studio"),
+    ("gvim", "Vim"),
+    ("nvim-qt", "Neovim"),
+    ("emacs", "Emacs"),
+    ("fleet", "Fleet"),
+    ("hx", "Helix"),
+    ("helix", "Helix"),
+    ("kak", "Kakoune"),
+    ("lapce", "Lapce"),
+    ("pulsar", "Pulsar"),
+    ("eclipse", "Eclipse"),
+    ("netbeans64", "Apache NetBeans"),
+    ("netbeans", "Apache NetBeans"),
+    ("rstudio", "RStudio"),
+    ("matlab", "MATLAB"),
+    ("jupyterlab", "JupyterLab"),
+    // Linux launcher names / Wayland app ids
+    ("code-insiders", "Visual Studio Code - Insiders"),
+    ("idea", "IntelliJ IDEA"),
+    ("pycharm", "PyCharm"),
+    ("webstorm", "WebStorm"),
+    ("phpstorm", "PhpStorm"),
+    ("rubymine", "RubyMine"),
+    ("clion", "CLion"),
+    ("goland", "GoLand"),
+    ("datagrip", "DataGrip"),
+    ("rider", "Rider"),
+    ("android-studio", "Android Studio"),
+    ("zed-editor", "Zed"),
+];
+
+/// Mapping of macOS bundle identifiers to editor application names.
+/// Used to recognize editors started through renamed or symlinked launchers,
+/// whose windows report an owner name that isn't in the editor list.
+pub const EDITOR_BUNDLE_IDS: &[(&str, &str)] = &[
+    ("com.microsoft.VSCode", "Visual Studio Code"),
+    (
+        "com.microsoft.VSCodeInsiders",
+        "Visual Studio Code - Insiders",
+    ),
+    ("com.vscodium", "VSCodium"),
+    ("com.todesktop.230313mzl4w4u92", "Cursor"),
+    ("dev.zed.Zed", "Zed"),
+    ("dev.zed.Zed-Preview", "Zed Preview"),
+    ("com.sublimetext.4", "Sublime Text"),
+    ("com.sublimetext.3", "Sublime Text"),
+    ("com.apple.dt.Xcode", "Xcode"),
+    ("com.jetbrains.intellij", "IntelliJ IDEA"),
+    ("com.jetbrains.intellij.ce", "IntelliJ IDEA"),
+    ("com.jetbrains.pycharm", "PyCharm"),
+    ("com.jetbrains.pycharm.ce", "PyCharm"),
+    ("com.jetbrains.WebStorm", "WebStorm"),
+    ("com.jetbrains.goland", "GoLand"),
+    ("com.google.android.studio", "Android Studio"),
+    ("org.vim.MacVim", "MacVim"),
+    ("com.panic.Nova", "Nova"),
+    ("com.barebones.bbedit", "BBEdit"),
+    ("com.macromates.TextMate", "TextMate"),
+];
+
+/// Returns true if the given application name is a known code editor.
+/// This function performs both exact case-insensitive matches and substring matches
+/// to handle editor variants like "Visual Studio Code - Insiders".
+pub fn is_editor_application(app_name: &str) -> bool {
+    EDITOR_APPLICATIONS
+        .iter()
+        .any(|&editor| app_name.eq_ignore_ascii_case(editor) || app_name.contains(editor))
+}
+
+/// Gets the typical installation path for an editor application.
+pub fn get_editor_path(app_name: &str) -> Option<&'static str> {
+    EDITOR_PATHS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(app_name))
+        .map(|(_, path)| *path)
+}
+
+/// Gets the editor application name for a macOS bundle identifier.
+pub fn get_editor_name_for_bundle_id(bundle_id: &str) -> Option<&'static str> {
+    EDITOR_BUNDLE_IDS
+        .iter()
+        .find(|(id, _)| id.eq_ignore_ascii_case(bundle_id))
+        .map(|(_, name)| *name)
+}
+
+/// Gets the editor application name for an executable name or Wayland app id.
+pub fn get_editor_name_for_executable(executable: &str) -> Option<&'static str> {
+    EDITOR_EXECUTABLES
+        .iter()
+        .find(|(exe, _)| exe.eq_ignore_ascii_case(executable))
+        .map(|(_, name)| *name)
+}
+
+/// Terminal emulators that may host a terminal editor such as Vim.
+/// Their windows are only listed when terminal editor support is enabled
+/// and the title identifies an editor.
+pub const TERMINAL_APPLICATIONS: &[&str] = &[
+    "Terminal",
+    "iTerm2",
+    "iTerm",
+    "Alacritty",
+    "kitty",
+    "WezTerm",
+    "Ghostty",
+    "Warp",
+    "Hyper",
+    "WindowsTerminal",
+    "foot",
+    "konsole",
+    "gnome-terminal",
+    "ptyxis",
+];
+
+static TERMINAL_EDITORS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables listing terminal windows that run an editor.
+pub fn set_terminal_editors_enabled(enabled: bool) {
+    TERMINAL_EDITORS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if the given application name is a known terminal emulator.
+pub fn is_terminal_application(app_name: &str) -> bool {
+    TERMINAL_APPLICATIONS
+        .iter()
+        .any(|&terminal| app_name.eq_ignore_ascii_case(terminal))
+}
+
+/// Returns true if windows of the application should be inspected at all:
+/// editors always, terminals only when terminal editor support is enabled.
+/// Terminal windows must still be confirmed as editors from their title.
+pub fn should_inspect_application(app_name: &str) -> bool {
+    is_editor_application(app_name)
+        || (TERMINAL_EDITORS_ENABLED.load(Ordering::Relaxed) && is_terminal_application(app_name))
+}
+
+/// Application name swii's own windows are reported under
+pub const OWN_APP_NAME: &str = "swii";
+
+/// Labels of swii's webview windows, which double as their titles
+static OWN_WINDOW_LABELS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Records the labels of swii's webview windows so they are never listed.
+pub fn set_own_window_labels(labels: Vec<String>) {
+    *OWN_WINDOW_LABELS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = labels;
+}
+
+/// Returns true if the window belongs to swii itself: any window of this
+/// process, or a window titled like one of swii's webviews in an app named
+/// like swii. The substring matching of [`is_editor_application`] must not
+/// make the switcher list itself.
+pub fn is_own_window(pid: i32, app_name: &str, window_name: Option<&str>) -> bool {
+    if u32::try_from(pid).is_ok_and(|pid| pid == std::process::id()) {
+        return true;
+    }
+    if !app_name.eq_ignore_ascii_case(OWN_APP_NAME) {
+        return false;
+    }
+    let Some(window_name) = window_name else {
+        return false;
+    };
+    OWN_WINDOW_LABELS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .any(|label| label == window_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_own_window() {
+        assert!(is_own_window(std::process::id() as i32, "Zed", None));
+
+        set_own_window_labels(vec!["swii".to_string()]);
+        assert!(is_own_window(1, "swii", Some("swii")));
+        assert!(!is_own_window(1, "Zed", Some("swii")));
+        assert!(!is_own_window(1, "swii", Some("api")));
+        set_own_window_labels(Vec::new());
+    }
+
+    #[test]
+    fn test_terminal_applications_are_gated() {
+        assert!(is_terminal_application("iTerm2"));
+        assert!(!is_editor_application("iTerm2"));
+        assert!(should_inspect_application("Zed"));
+
+        set_terminal_editors_enabled(true);
+        assert!(should_inspect_application("kitty"));
+        set_terminal_editors_enabled(false);
+        assert!(!should_inspect_application("kitty"));
+    }
+
+    #[test]
+    fn test_is_editor_application() {
+        // Test known editors
+        assert!(is_editor_application("Visual Studio Code"));
+        assert!(is_editor_application("Code"));
+        assert!(is_editor_application("Zed"));
+        assert!(is_editor_application("Cursor"));
+        assert!(is_editor_application("Sublime Text"));
+
+        // Test case insensitive
+        assert!(is_editor_application("visual studio code"));
+        assert!(is_editor_application("VISUAL STUDIO CODE"));
+
+        // Test substring matching for variants
+        assert!(is_editor_application("Visual Studio Code - Insiders"));
+        assert!(is_editor_application("Sublime Text 4"));
+        assert!(is_editor_application("Code - OSS"));
+
+        // Test non-editors
+        assert!(!is_editor_application("Safari"));
+        assert!(!is_editor_application("Chrome"));
+        assert!(!is_editor_application("Finder"));
+    }
+
+    #[test]
+    fn test_get_editor_path() {
+        assert_eq!(
+            get_editor_path("Visual Studio Code"),
+            Some("/Applications/Visual Studio Code.app")
+        );
+        assert_eq!(
+            get_editor_path("Code"),
+            Some("/Applications/Visual Studio Code.app")
+        );
+        assert_eq!(get_editor_path("Zed"), Some("/Applications/Zed.app"));
+        assert_eq!(get_editor_path("Unknown Editor"), None);
+
+        // Preview channels keep their own bundles
+        assert_eq!(
+            get_editor_path("Code - Insiders"),
+            Some("/Applications/Visual Studio Code - Insiders.app")
+        );
+        assert_eq!(
+            get_editor_path("Zed Preview"),
+            Some("/Applications/Zed Preview.app")
+        );
+        assert!(is_editor_application("VSCodium"));
+    }
+
+    #[test]
+    fn test_get_editor_name_for_executable() {
+        assert_eq!(
+            get_editor_name_for_executable("Code"),
+            Some("Visual Studio Code")
+        );
+        assert_eq!(
+            get_editor_name_for_executable("sublime_text"),
+            Some("Sublime Text")
+        );
+        assert_eq!(
+            get_editor_name_for_executable("idea64"),
+            Some("IntelliJ IDEA")
+        );
+        assert_eq!(get_editor_name_for_executable("explorer"), None);
+    }
+
+    #[test]
+    fn test_get_editor_name_for_bundle_id() {
+        assert_eq!(
+            get_editor_name_for_bundle_id("com.microsoft.VSCode"),
+            Some("Visual Studio Code")
+        );
+        assert_eq!(
+            get_editor_name_for_bundle_id("dev.zed.Zed-Preview"),
+            Some("Zed Preview")
+        );
+        assert_eq!(get_editor_name_for_bundle_id("com.apple.Safari"), None);
+        // Every mapped name must be listed as an editor
+        assert!(EDITOR_BUNDLE_IDS
+            .iter()
+            .all(|(_, name)| is_editor_application(name)));
+    }
+}
@@ -0,0 +1,127 @@
+//! Wire format of errors, shared by the app's commands and the plugin.
+//!
+//! Failures reach frontends as an [`ErrorInfo`]:
+//! `{ code, user_message, message, details }`. Codes are part of the API
+//! contract: integrators match on them, so existing codes must never be
+//! renamed. They also serve as the keys for translating `user_message`,
+//! which is only the English fallback.
+
+use serde::Serialize;
+
+use crate::focus::WindowFocusError;
+use crate::provider::WindowError;
+
+/// Wire format of an error
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ErrorInfo {
+    /// Stable, snake_case identifier of the failure
+    pub code: &'static str,
+    /// Short description for the UI, in English; translate by `code`
+    pub user_message: &'static str,
+    /// Detailed description for logs and developers
+    pub message: String,
+    /// Structured context for some codes, e.g. the offending parameter
+    pub details: Option<serde_json::Value>,
+}
+
+/// Short description of the failure with `code` that makes sense to users
+pub fn user_message(code: &str) -> &'static str {
+    match code {
+        "window_list_access" => "Couldn't read the list of windows",
+        "window_info" => "Couldn't read a window's details",
+        "icon_extraction" | "base64" => "Couldn't load an app icon",
+        "bundle_access" => "Couldn't read an app's details",
+        "unsupported" => "Not available on this system",
+        "io" => "Couldn't read or write a file",
+        "window_not_found" => "That window is no longer open",
+        "application_not_found" => "That app is no longer running",
+        "permission_denied" => "swii needs Accessibility access",
+        "focus_failed" => "Couldn't switch to that window",
+        "config" => "Your settings file has a problem",
+        "invalid_parameter" => "That request was invalid",
+        _ => "Something went wrong",
+    }
+}
+
+impl WindowError {
+    /// Stable identifier of the error, safe to match on from a frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            WindowError::WindowListAccess { .. } => "window_list_access",
+            WindowError::WindowInfoExtraction { .. } => "window_info",
+            WindowError::IconExtraction { .. } => "icon_extraction",
+            WindowError::BundleAccess { .. } => "bundle_access",
+            WindowError::Unsupported { .. } => "unsupported",
+            WindowError::Io(_) => "io",
+            WindowError::Base64(_) => "base64",
+        }
+    }
+
+    /// Structured context of the error, when it has any
+    pub fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            WindowError::Unsupported {
+                provider,
+                operation,
+            } => Some(serde_json::json!({ "provider": provider, "operation": operation })),
+            _ => None,
+        }
+    }
+}
+
+impl WindowFocusError {
+    /// Stable identifier of the error, safe to match on from a frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            WindowFocusError::WindowNotFound => "window_not_found",
+            WindowFocusError::ApplicationNotFound => "application_not_found",
+            WindowFocusError::PermissionDenied => "permission_denied",
+            WindowFocusError::SystemError(_) => "focus_failed",
+        }
+    }
+}
+
+impl From<WindowError> for ErrorInfo {
+    fn from(error: WindowError) -> Self {
+        ErrorInfo {
+            code: error.code(),
+            user_message: user_message(error.code()),
+            message: error.to_string(),
+            details: error.details(),
+        }
+    }
+}
+
+impl From<WindowFocusError> for ErrorInfo {
+    fn from(error: WindowFocusError) -> Self {
+        ErrorInfo {
+            code: error.code(),
+            user_message: user_message(error.code()),
+            message: error.to_string(),
+            details: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_info() {
+        let info = ErrorInfo::from(WindowFocusError::PermissionDenied);
+        assert_eq!(info.code, "permission_denied");
+        assert_eq!(info.user_message, "swii needs Accessibility access");
+        assert_eq!(info.message, "Accessibility permissions required");
+
+        let info = ErrorInfo::from(WindowError::Unsupported {
+            provider: "wayland",
+            operation: "Observing windows",
+        });
+        let value = serde_json::to_value(&info).unwrap();
+        assert_eq!(value["code"], "unsupported");
+        assert_eq!(value["user_message"], "Not available on this system");
+        assert_eq!(value["details"]["provider"], "wayland");
+    }
+}
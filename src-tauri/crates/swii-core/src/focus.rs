@@ -0,0 +1,71 @@
+//! Errors raised while bringing a window to the front.
+
+#[derive(Debug, PartialEq)]
+pub enum WindowFocusError {
+    WindowNotFound,
+    ApplicationNotFound,
+    PermissionDenied,
+    SystemError(String),
+}
+
+impl std::fmt::Display for WindowFocusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFocusError::WindowNotFound => write!(f, "Window not found"),
+            WindowFocusError::ApplicationNotFound => write!(f, "Application not found"),
+            WindowFocusError::PermissionDenied => write!(f, "Accessibility permissions required"),
+            WindowFocusError::SystemError(msg) => write!(f, "System error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WindowFocusError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_focus_error_display() {
+        assert_eq!(
+            WindowFocusError::WindowNotFound.to_string(),
+            "Window not found"
+        );
+        assert_eq!(
+            WindowFocusError::ApplicationNotFound.to_string(),
+            "Application not found"
+        );
+        assert_eq!(
+            WindowFocusError::PermissionDenied.to_string(),
+            "Accessibility permissions required"
+        );
+        assert_eq!(
+            WindowFocusError::SystemError("test".to_string()).to_string(),
+            "System error: test"
+        );
+    }
+
+    #[test]
+    fn test_window_focus_error_equality() {
+        assert_eq!(
+            WindowFocusError::WindowNotFound,
+            WindowFocusError::WindowNotFound
+        );
+        assert_eq!(
+            WindowFocusError::ApplicationNotFound,
+            WindowFocusError::ApplicationNotFound
+        );
+        assert_eq!(
+            WindowFocusError::PermissionDenied,
+            WindowFocusError::PermissionDenied
+        );
+        assert_eq!(
+            WindowFocusError::SystemError("same".to_string()),
+            WindowFocusError::SystemError("same".to_string())
+        );
+        assert_ne!(
+            WindowFocusError::SystemError("different".to_string()),
+            WindowFocusError::SystemError("other".to_string())
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Reading the PNG images embedded in `.icns` application icons.
+//!
+//! An icns file is the magic `icns`, the file length, then a sequence of
+//! elements: a four-byte type such as `ic09`, a big-endian length that counts
+//! the eight header bytes too, and the element data. The newer types store a
+//! plain PNG. Icons come from arbitrary application bundles, so every length
+//! is checked before it is used to slice.
+
+pub mod constants {
+    /// Magic bytes at the start of every icns file
+    pub const ICNS_MAGIC: &[u8; 4] = b"icns";
+
+    /// Size of the file header and of each element header
+    pub const HEADER_LEN: usize = 8;
+
+    /// Element types whose data may be a PNG
+    pub const PNG_ICON_TYPES: &[&[u8; 4]] = &[
+        b"ic07", b"ic08", b"ic09", b"ic10", b"ic11", b"ic12", b"ic13", b"ic14", b"icp4", b"icp5",
+        b"icp6",
+    ];
+
+    /// Signature at the start of every PNG
+    pub const PNG_SIGNATURE: &[u8; 8] = b"\x89PNG\r\n\x1a\n";
+}
+
+/// Returns true if `data` starts like an icns file
+pub fn is_icns(data: &[u8]) -> bool {
+    data.len() >= constants::HEADER_LEN && data.starts_with(constants::ICNS_MAGIC)
+}
+
+/// Returns the first PNG image stored in an icns file, if any
+///
+/// Stops at the first malformed element instead of guessing where the next
+/// one starts.
+pub fn png_from_icns(data: &[u8]) -> Option<&[u8]> {
+    if !is_icns(data) {
+        return None;
+    }
+
+    let mut offset = constants::HEADER_LEN;
+    while let Some(header) = data.get(offset..offset + constants::HEADER_LEN) {
+        let (icon_type, length) = header.split_at(4);
+        let length = u32::from_be_bytes([length[0], length[1], length[2], length[3]]) as usize;
+        if length < constants::HEADER_LEN {
+            return None;
+        }
+        let end = offset.checked_add(length)?;
+        let icon_data = data.get(offset + constants::HEADER_LEN..end)?;
+
+        if constants::PNG_ICON_TYPES
+            .iter()
+            .any(|png_type| icon_type == png_type.as_slice())
+            && icon_data.starts_with(constants::PNG_SIGNATURE)
+        {
+            return Some(icon_data);
+        }
+        offset = end;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(icon_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut bytes = icon_type.to_vec();
+        bytes.extend_from_slice(&((data.len() + 8) as u32).to_be_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    fn icns(elements: &[Vec<u8>]) -> Vec<u8> {
+        let body: Vec<u8> = elements.concat();
+        let mut bytes = b"icns".to_vec();
+        bytes.extend_from_slice(&((body.len() + 8) as u32).to_be_bytes());
+        bytes.extend(body);
+        bytes
+    }
+
+    #[test]
+    fn test_png_from_icns() {
+        let png = [constants::PNG_SIGNATURE.as_slice(), b"image"].concat();
+        let file = icns(&[
+            element(b"TOC ", b"table of contents"),
+            element(b"is32", b"raw pixels"),
+            element(b"ic09", &png),
+        ]);
+        assert_eq!(png_from_icns(&file), Some(png.as_slice()));
+
+        // A PNG signature under a non-PNG type doesn't count
+        assert_eq!(png_from_icns(&icns(&[element(b"is32", &png)])), None);
+        assert_eq!(png_from_icns(&icns(&[element(b"ic09", b"jpeg2000")])), None);
+    }
+
+    #[test]
+    fn test_png_from_icns_rejects_malformed_files() {
+        assert!(!is_icns(b"icns"));
+        assert_eq!(png_from_icns(b"PNG\x00\x00\x00\x00\x10"), None);
+        assert_eq!(png_from_icns(b""), None);
+
+        // Lengths shorter than the header or past the end of the file
+        let mut file = icns(&[element(b"ic09", b"data")]);
+        file[12..16].copy_from_slice(&4u32.to_be_bytes());
+        assert_eq!(png_from_icns(&file), None);
+        file[12..16].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(png_from_icns(&file), None);
+
+        // Trailing bytes too short for another element
+        let mut file = icns(&[element(b"TOC ", b"")]);
+        file.extend_from_slice(b"ic0");
+        assert_eq!(png_from_icns(&file), None);
+    }
+}
@@ -0,0 +1,232 @@
+//! Working directories of iTerm2 sessions.
+//!
+//! Shell titles are often just "zsh", which says nothing about the project.
+//! iTerm2 tracks the working directory of every session (exactly with shell
+//! integration, by polling otherwise) and exposes it to scripts as the
+//! `session.path` variable, so windows are matched by title to the current
+//! session's directory and listed as shells in that project.
+//!
+//! Disabled unless `iterm2_integration` is set in the settings; iTerm2 windows
+//! are only inspected when `terminal_editors` is on as well. macOS asks for
+//! permission to control iTerm2 on first use.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use swii_title_parser::{
+    find_project_root_from_dir, is_command_like, Confidence, EditorKind, ParsedTitle, ProjectSource,
+};
+use tracing::debug;
+
+pub mod constants {
+    /// Application name iTerm2 windows are reported under
+    pub const APP_NAME: &str = "iTerm2";
+
+    /// How long session paths are reused, so one enumeration runs the script
+    /// once rather than once per window
+    pub const SESSIONS_CACHE_TTL_MS: u64 = 2000;
+
+    /// Prints the title and current session path of every iTerm2 window as
+    /// JSON, without launching iTerm2 if it isn't running
+    pub const SESSIONS_SCRIPT: &str = r#"function run() {
+  const app = Application("iTerm2");
+  if (!app.running()) return "[]";
+  return JSON.stringify(app.windows().map((window) => {
+    const session = window.currentSession;
+    return { name: window.name(), path: session.variable({ named: "session.path" }) };
+  }));
+}"#;
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables asking iTerm2 for session paths
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Title and working directory of an iTerm2 window's current session
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ItermWindow {
+    name: String,
+    #[serde(default)]
+    path: Option<String>,
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_windows(json: &str) -> Vec<ItermWindow> {
+    serde_json::from_str(json.trim()).unwrap_or_else(|e| {
+        debug!("Failed to parse iTerm2 sessions: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn read_windows() -> Vec<ItermWindow> {
+    let output = std::process::Command::new("osascript")
+        .args(["-l", "JavaScript", "-e", constants::SESSIONS_SCRIPT])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            parse_windows(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(output) => {
+            debug!(
+                "iTerm2 session script failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            Vec::new()
+        }
+        Err(e) => {
+            debug!("Failed to run osascript: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn read_windows() -> Vec<ItermWindow> {
+    Vec::new()
+}
+
+/// Windows of the running iTerm2, cached for
+/// [`constants::SESSIONS_CACHE_TTL_MS`]
+fn windows() -> Vec<ItermWindow> {
+    static CACHE: Mutex<Option<(Instant, Vec<ItermWindow>)>> = Mutex::new(None);
+
+    let mut cache = CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let ttl = Duration::from_millis(constants::SESSIONS_CACHE_TTL_MS);
+    if let Some((read_at, windows)) = cache.as_ref() {
+        if read_at.elapsed() < ttl {
+            return windows.clone();
+        }
+    }
+
+    let windows = read_windows();
+    *cache = Some((Instant::now(), windows.clone()));
+    windows
+}
+
+/// Places an iTerm2 window that doesn't run a terminal editor in the project
+/// of its current session's directory
+pub fn resolve_shell_window(
+    app_name: &str,
+    title: Option<&str>,
+    parsed: ParsedTitle,
+) -> ParsedTitle {
+    if !ENABLED.load(Ordering::Relaxed)
+        || app_name != constants::APP_NAME
+        || parsed.editor_kind.is_terminal_editor()
+    {
+        return parsed;
+    }
+    match title {
+        Some(title) => resolve_with(&windows(), title, parsed),
+        None => parsed,
+    }
+}
+
+/// Matches the window by title; titles shared by several windows with
+/// different directories are left alone
+fn resolve_with(windows: &[ItermWindow], title: &str, parsed: ParsedTitle) -> ParsedTitle {
+    let mut paths = windows
+        .iter()
+        .filter(|window| window.name == title)
+        .filter_map(|window| window.path.as_deref())
+        .filter(|path| !path.is_empty());
+    let Some(path) = paths.next() else {
+        return parsed;
+    };
+    if paths.any(|other| other != path) {
+        return parsed;
+    }
+
+    let root = find_project_root_from_dir(path)
+        .map(|root| root.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string());
+    let project = std::path::Path::new(&root)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string());
+    ParsedTitle {
+        editor_kind: EditorKind::Shell,
+        project,
+        // Only a running command says more than the shell's name
+        tab: Some(title.trim().to_string()).filter(|title| is_command_like(title)),
+        path: Some(path.to_string()),
+        project_path: Some(root),
+        project_source: ProjectSource::Terminal,
+        confidence: Confidence::High,
+        ..parsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iterm_window(name: &str, path: &str) -> ItermWindow {
+        ItermWindow {
+            name: name.to_string(),
+            path: Some(path.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_windows() {
+        assert_eq!(
+            parse_windows(r#"[{"name":"zsh","path":"/Users/dev/api"},{"name":"vim"}]"#),
+            vec![
+                iterm_window("zsh", "/Users/dev/api"),
+                ItermWindow {
+                    name: "vim".to_string(),
+                    path: None,
+                },
+            ]
+        );
+        assert!(parse_windows("garbage").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_with() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let src = dir.join("api/src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(dir.join("api/package.json"), "{}").unwrap();
+        let src = src.to_string_lossy().to_string();
+
+        let windows = [
+            iterm_window("zsh", &src),
+            iterm_window("npm run dev", &src),
+            iterm_window("fish", "/tmp/a"),
+            iterm_window("fish", "/tmp/b"),
+        ];
+        let parsed = resolve_with(&windows, "zsh", ParsedTitle::default());
+        let dev = resolve_with(&windows, "npm run dev", ParsedTitle::default());
+
+        assert_eq!(parsed.editor_kind, EditorKind::Shell);
+        assert_eq!(parsed.project.as_deref(), Some("api"));
+        assert_eq!(
+            parsed.project_path,
+            Some(dir.join("api").to_string_lossy().to_string())
+        );
+        assert_eq!(parsed.path.as_deref(), Some(src.as_str()));
+        assert_eq!(parsed.tab, None);
+        assert_eq!(parsed.project_source, ProjectSource::Terminal);
+        assert_eq!(dev.tab.as_deref(), Some("npm run dev"));
+
+        // Ambiguous and unknown titles keep what the title parser found
+        assert_eq!(
+            resolve_with(&windows, "fish", ParsedTitle::default()),
+            ParsedTitle::default()
+        );
+        assert_eq!(
+            resolve_with(&windows, "bash", ParsedTitle::default()),
+            ParsedTitle::default()
+        );
+    }
+}
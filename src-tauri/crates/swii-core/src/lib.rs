@@ -0,0 +1,35 @@
+//! The parts of swii that don't need Tauri.
+//!
+//! [`types::WindowInfo`] is the window model every frontend shows,
+//! [`provider::WindowProvider`] the interface platform backends implement to
+//! list and focus windows, and [`editor_config`] decides which applications
+//! count as editors. Title parsing and project resolution live one level
+//! down in `swii_title_parser`. The platform backends are compiled in for
+//! their target only: `macos_window` (Core Graphics and the Accessibility
+//! API), `windows_window` (Win32 and UI Automation) and `wayland_window`
+//! (wlroots foreign toplevels). The app wraps them in Tauri commands; a CLI
+//! or tests can link this crate directly, with [`mock::MockProvider`]
+//! standing in for a backend.
+
+pub mod applescript_focus;
+pub mod editor_cli;
+pub mod editor_config;
+pub mod error;
+pub mod focus;
+pub mod icns;
+pub mod iterm2;
+#[cfg(target_os = "macos")]
+pub mod macos_accessibility;
+#[cfg(target_os = "macos")]
+mod macos_focus;
+#[cfg(target_os = "macos")]
+mod macos_observer;
+#[cfg(target_os = "macos")]
+pub mod macos_window;
+pub mod mock;
+pub mod provider;
+pub mod types;
+#[cfg(target_os = "linux")]
+pub mod wayland_window;
+#[cfg(target_os = "windows")]
+pub mod windows_window;
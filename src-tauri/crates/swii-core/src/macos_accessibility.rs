@@ -0,0 +1,856 @@
+use std::collections::HashMap;
+use std::ffi::c_void;
+
+#[cfg(target_os = "macos")]
+use core_foundation::{
+    array::CFArray,
+    base::{CFTypeRef, TCFType},
+    dictionary::CFDictionary,
+    number::{CFNumber, CFNumberRef},
+    runloop::{kCFRunLoopDefaultMode, CFRunLoop, CFRunLoopSource, CFRunLoopSourceRef},
+    string::{CFString, CFStringRef},
+};
+
+#[cfg(target_os = "macos")]
+use core_graphics::window::{
+    kCGWindowListExcludeDesktopElements, kCGWindowListOptionOnScreenOnly,
+    CGWindowListCopyWindowInfo,
+};
+
+use swii_title_parser::{
+    extract_project_name_from_path, find_project_root, parse_title, reconcile_with_document,
+    xcode_container_from_path, EditorKind, ParsedTitle, ProjectSource,
+};
+use tracing::debug;
+
+#[cfg(target_os = "macos")]
+use crate::provider::WindowTab;
+
+// Raw FFI declarations for Accessibility API
+#[cfg(target_os = "macos")]
+#[repr(C)]
+pub struct __AXUIElement {
+    _private: [u8; 0],
+}
+
+#[cfg(target_os = "macos")]
+pub type AXUIElementRef = *const __AXUIElement;
+
+#[cfg(target_os = "macos")]
+pub type AXError = i32;
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+pub struct __AXObserver {
+    _private: [u8; 0],
+}
+
+#[cfg(target_os = "macos")]
+pub type AXObserverRef = *const __AXObserver;
+
+#[cfg(target_os = "macos")]
+pub type AXObserverCallback = unsafe extern "C" fn(
+    observer: AXObserverRef,
+    element: AXUIElementRef,
+    notification: CFStringRef,
+    refcon: *mut c_void,
+);
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn AXUIElementCreateApplication(pid: libc::pid_t) -> AXUIElementRef;
+    fn AXUIElementCopyAttributeValue(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        value: *mut CFTypeRef,
+    ) -> AXError;
+    fn AXUIElementGetPid(element: AXUIElementRef, pid: *mut libc::pid_t) -> AXError;
+    fn AXObserverCreate(
+        application: libc::pid_t,
+        callback: AXObserverCallback,
+        observer: *mut AXObserverRef,
+    ) -> AXError;
+    fn AXObserverAddNotification(
+        observer: AXObserverRef,
+        element: AXUIElementRef,
+        notification: CFStringRef,
+        refcon: *mut c_void,
+    ) -> AXError;
+    fn AXObserverGetRunLoopSource(observer: AXObserverRef) -> CFRunLoopSourceRef;
+}
+
+// Constants for macOS accessibility operations
+pub mod constants {
+    // Accessibility API attribute names
+    pub const AX_WINDOWS: &str = "AXWindows";
+    pub const AX_TITLE: &str = "AXTitle";
+    pub const AX_DOCUMENT: &str = "AXDocument";
+    pub const AX_URL: &str = "AXURL";
+    pub const AX_FOCUSED_UI_ELEMENT: &str = "AXFocusedUIElement";
+    pub const AX_FOCUSED_WINDOW: &str = "AXFocusedWindow";
+    pub const AX_CHILDREN: &str = "AXChildren";
+    pub const AX_ROLE: &str = "AXRole";
+    pub const AX_TABS: &str = "AXTabs";
+    pub const AX_VALUE: &str = "AXValue";
+
+    // Accessibility roles
+    pub const AX_TAB_GROUP_ROLE: &str = "AXTabGroup";
+    pub const AX_WINDOW_ROLE: &str = "AXWindow";
+
+    // Accessibility notifications
+    pub const AX_TITLE_CHANGED_NOTIFICATION: &str = "AXTitleChanged";
+    pub const AX_WINDOW_CREATED_NOTIFICATION: &str = "AXWindowCreated";
+    pub const AX_UI_ELEMENT_DESTROYED_NOTIFICATION: &str = "AXUIElementDestroyed";
+    pub const AX_FOCUSED_WINDOW_CHANGED_NOTIFICATION: &str = "AXFocusedWindowChanged";
+    pub const AX_APPLICATION_ACTIVATED_NOTIFICATION: &str = "AXApplicationActivated";
+
+    /// Notifications window observers register for
+    pub const OBSERVED_NOTIFICATIONS: &[&str] = &[
+        AX_TITLE_CHANGED_NOTIFICATION,
+        AX_WINDOW_CREATED_NOTIFICATION,
+        AX_UI_ELEMENT_DESTROYED_NOTIFICATION,
+        AX_FOCUSED_WINDOW_CHANGED_NOTIFICATION,
+        AX_APPLICATION_ACTIVATED_NOTIFICATION,
+    ];
+
+    /// How deep below a window to look for its tab group
+    pub const TAB_GROUP_SEARCH_DEPTH: usize = 8;
+
+    // File path prefixes
+    pub const FILE_URL_PREFIX: &str = "file://";
+}
+
+/// Populates project information for all windows belonging to a specific PID
+#[cfg(target_os = "macos")]
+pub unsafe fn populate_project_info_for_pid(
+    pid: i32,
+    app_name: &str,
+    window_project_map: &mut HashMap<u32, ParsedTitle>,
+) {
+    use std::ptr;
+
+    // Get all AX windows for this PID and extract their project info
+    let app_ref = AXUIElementCreateApplication(pid);
+    if app_ref.is_null() {
+        return;
+    }
+
+    let windows_attr = CFString::new(constants::AX_WINDOWS);
+    let mut windows_ref: CFTypeRef = ptr::null_mut();
+    let result = AXUIElementCopyAttributeValue(
+        app_ref,
+        windows_attr.as_concrete_TypeRef(),
+        &mut windows_ref,
+    );
+
+    if result != 0 || windows_ref.is_null() {
+        return;
+    }
+
+    if let Some(windows_array) = cf_type_to_array(windows_ref) {
+        let mut ax_projects = Vec::new();
+
+        // Get all project info from AX windows
+        for i in 0..windows_array.len() {
+            if let Some(window_ref_ptr) = windows_array.get(i) {
+                let window_ref = *window_ref_ptr as AXUIElementRef;
+
+                if let Some(parsed) = get_parsed_title_from_window(window_ref, app_name) {
+                    ax_projects.push(parsed);
+                }
+            }
+        }
+
+        // Simple 1:1 mapping: match by index order
+        // This assumes windows are returned in a consistent order
+        for (i, window_number) in cg_window_numbers_for_pid(pid).into_iter().enumerate() {
+            if i < ax_projects.len() {
+                window_project_map.insert(window_number, ax_projects[i].clone());
+            }
+        }
+    }
+}
+
+/// Window numbers of the on-screen CG windows owned by a PID, in the order
+/// used for CG/AX correlation
+#[cfg(target_os = "macos")]
+pub unsafe fn cg_window_numbers_for_pid(pid: i32) -> Vec<u32> {
+    let window_list_info = CGWindowListCopyWindowInfo(
+        kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+        0,
+    );
+    if window_list_info.is_null() {
+        return Vec::new();
+    }
+
+    let window_list: CFArray<CFDictionary> = CFArray::wrap_under_create_rule(window_list_info);
+    let mut cg_windows_for_pid = Vec::new();
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            if get_number_value(&window_dict, "kCGWindowOwnerPID") == Some(pid as i64) {
+                if let Some(window_number) = get_number_value(&window_dict, "kCGWindowNumber") {
+                    cg_windows_for_pid.push(window_number as u32);
+                }
+            }
+        }
+    }
+    cg_windows_for_pid
+}
+
+/// Collects the raw AXTitle of every accessibility window owned by a PID
+///
+/// Windows without a readable title are reported as `None` so the index order
+/// matches the order used for CG/AX correlation.
+#[cfg(target_os = "macos")]
+pub unsafe fn get_ax_window_titles_for_pid(pid: i32) -> Vec<Option<String>> {
+    use std::ptr;
+
+    let app_ref = AXUIElementCreateApplication(pid);
+    if app_ref.is_null() {
+        return Vec::new();
+    }
+
+    let windows_attr = CFString::new(constants::AX_WINDOWS);
+    let mut windows_ref: CFTypeRef = ptr::null_mut();
+    let result = AXUIElementCopyAttributeValue(
+        app_ref,
+        windows_attr.as_concrete_TypeRef(),
+        &mut windows_ref,
+    );
+
+    if result != 0 || windows_ref.is_null() {
+        return Vec::new();
+    }
+
+    let mut titles = Vec::new();
+    if let Some(windows_array) = cf_type_to_array(windows_ref) {
+        for i in 0..windows_array.len() {
+            if let Some(window_ref_ptr) = windows_array.get(i) {
+                let window_ref = *window_ref_ptr as AXUIElementRef;
+                let title_attr = CFString::new(constants::AX_TITLE);
+                let mut title_ref: CFTypeRef = ptr::null_mut();
+                let title_result = AXUIElementCopyAttributeValue(
+                    window_ref,
+                    title_attr.as_concrete_TypeRef(),
+                    &mut title_ref,
+                );
+
+                if title_result == 0 {
+                    titles.push(cf_type_to_string(title_ref));
+                } else {
+                    titles.push(None);
+                }
+            }
+        }
+    }
+
+    titles
+}
+
+/// Titles of the tabs shown in one window, with whether each is selected
+///
+/// The AX window is found by its index among the PID's CG windows, like the
+/// rest of the CG/AX correlation. Windows without a native tab group, such as
+/// Electron editors, have no tabs here.
+#[cfg(target_os = "macos")]
+pub unsafe fn get_ax_tabs_for_window(pid: i32, window_number: u32) -> Vec<WindowTab> {
+    with_ax_window(pid, window_number, |window_ref| {
+        Some(tabs_of_window(window_ref))
+    })
+    .unwrap_or_default()
+}
+
+/// The document shown in one window, as its `AXDocument` or `AXURL`, or the
+/// one of its focused element
+///
+/// The value is returned as exposed, usually a `file://` URL.
+#[cfg(target_os = "macos")]
+pub unsafe fn get_ax_document_for_window(pid: i32, window_number: u32) -> Option<String> {
+    with_ax_window(pid, window_number, |window_ref| {
+        try_get_document_from_element(window_ref).or_else(|| {
+            copy_attribute(window_ref, constants::AX_FOCUSED_UI_ELEMENT)
+                .and_then(|focused| try_get_document_from_element(focused as AXUIElementRef))
+        })
+    })
+}
+
+/// Calls `f` with the AX window of `window_number`, found by its index among
+/// the PID's CG windows
+#[cfg(target_os = "macos")]
+unsafe fn with_ax_window<T>(
+    pid: i32,
+    window_number: u32,
+    f: impl FnOnce(AXUIElementRef) -> Option<T>,
+) -> Option<T> {
+    let index = cg_window_numbers_for_pid(pid)
+        .iter()
+        .position(|&number| number == window_number)?;
+
+    let app_ref = AXUIElementCreateApplication(pid);
+    if app_ref.is_null() {
+        return None;
+    }
+    let windows_array =
+        copy_attribute(app_ref, constants::AX_WINDOWS).and_then(|r| cf_type_to_array(r))?;
+    let window_ref = windows_array
+        .get(index as isize)
+        .map(|r| *r as AXUIElementRef)?;
+    f(window_ref)
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn tabs_of_window(window_ref: AXUIElementRef) -> Vec<WindowTab> {
+    let Some(tab_group) = find_tab_group(window_ref, constants::TAB_GROUP_SEARCH_DEPTH) else {
+        return Vec::new();
+    };
+    let Some(tabs) = copy_attribute(tab_group, constants::AX_TABS)
+        .or_else(|| copy_attribute(tab_group, constants::AX_CHILDREN))
+        .and_then(|r| cf_type_to_array(r))
+    else {
+        return Vec::new();
+    };
+
+    (0..tabs.len())
+        .filter_map(|i| tabs.get(i).map(|r| *r as AXUIElementRef))
+        .filter_map(|tab| {
+            let title =
+                copy_attribute(tab, constants::AX_TITLE).and_then(|r| cf_type_to_string(r))?;
+            let selected = copy_attribute(tab, constants::AX_VALUE)
+                .and_then(|r| cf_type_to_i64(r))
+                .is_some_and(|value| value != 0);
+            Some(WindowTab { title, selected })
+        })
+        .filter(|tab| !tab.title.is_empty())
+        .collect()
+}
+
+/// Creates an observer that reports [`constants::OBSERVED_NOTIFICATIONS`] of
+/// any element of a PID to `callback` through the current thread's run loop
+///
+/// The PID is passed to `callback` as its `refcon`, since destroyed elements
+/// can no longer be asked for it.
+#[cfg(target_os = "macos")]
+pub unsafe fn observe_window_changes(
+    pid: i32,
+    callback: AXObserverCallback,
+) -> Option<AXObserverRef> {
+    use core_foundation::base::CFRelease;
+
+    let mut observer: AXObserverRef = std::ptr::null();
+    if AXObserverCreate(pid, callback, &mut observer) != 0 || observer.is_null() {
+        return None;
+    }
+
+    // Registering on the application covers all of its windows, including
+    // ones opened later
+    let app_ref = AXUIElementCreateApplication(pid);
+    let registered = !app_ref.is_null()
+        && constants::OBSERVED_NOTIFICATIONS
+            .iter()
+            .all(|notification| {
+                let notification = CFString::new(notification);
+                AXObserverAddNotification(
+                    observer,
+                    app_ref,
+                    notification.as_concrete_TypeRef(),
+                    pid as isize as *mut c_void,
+                ) == 0
+            });
+    if !registered {
+        CFRelease(observer as CFTypeRef);
+        return None;
+    }
+
+    let source = CFRunLoopSource::wrap_under_get_rule(AXObserverGetRunLoopSource(observer));
+    CFRunLoop::get_current().add_source(&source, kCFRunLoopDefaultMode);
+    Some(observer)
+}
+
+/// Detaches an observer created by [`observe_window_changes`] from the current
+/// thread's run loop and releases it
+#[cfg(target_os = "macos")]
+pub unsafe fn stop_observing(observer: AXObserverRef) {
+    use core_foundation::base::CFRelease;
+
+    let source = CFRunLoopSource::wrap_under_get_rule(AXObserverGetRunLoopSource(observer));
+    CFRunLoop::get_current().remove_source(&source, kCFRunLoopDefaultMode);
+    CFRelease(observer as CFTypeRef);
+}
+
+/// PID, window number and new title of a window whose title changed
+///
+/// Title changes of other elements, such as buttons, are ignored. The window
+/// number comes from the window's index among the PID's AX windows, like the
+/// rest of the CG/AX correlation, and is `None` when it can't be found.
+#[cfg(target_os = "macos")]
+pub unsafe fn changed_window_title(element: AXUIElementRef) -> Option<(i32, Option<u32>, String)> {
+    let role = copy_attribute(element, constants::AX_ROLE).and_then(|r| cf_type_to_string(r));
+    if role.as_deref() != Some(constants::AX_WINDOW_ROLE) {
+        return None;
+    }
+    let title = copy_attribute(element, constants::AX_TITLE).and_then(|r| cf_type_to_string(r))?;
+
+    let mut pid: libc::pid_t = 0;
+    if AXUIElementGetPid(element, &mut pid) != 0 {
+        return None;
+    }
+    Some((pid, window_number_for_element(pid, element), title))
+}
+
+/// Window number of the focused window of a PID, if it has one
+#[cfg(target_os = "macos")]
+pub unsafe fn focused_window_number(pid: i32) -> Option<u32> {
+    let app_ref = AXUIElementCreateApplication(pid);
+    if app_ref.is_null() {
+        return None;
+    }
+    let window = copy_attribute(app_ref, constants::AX_FOCUSED_WINDOW)?;
+    window_number_for_element(pid, window as AXUIElementRef)
+}
+
+/// CG window number of an AX window, from its index among the PID's AX
+/// windows
+#[cfg(target_os = "macos")]
+unsafe fn window_number_for_element(pid: i32, element: AXUIElementRef) -> Option<u32> {
+    use core_foundation::base::CFEqual;
+
+    let app_ref = AXUIElementCreateApplication(pid);
+    let index = (!app_ref.is_null())
+        .then(|| copy_attribute(app_ref, constants::AX_WINDOWS))
+        .flatten()
+        .and_then(|r| cf_type_to_array(r))
+        .and_then(|windows| {
+            (0..windows.len()).position(|i| {
+                windows
+                    .get(i)
+                    .is_some_and(|window| CFEqual(*window, element as CFTypeRef) != 0)
+            })
+        });
+    index.and_then(|index| cg_window_numbers_for_pid(pid).get(index).copied())
+}
+
+/// Depth-first search for the first tab group below an element
+#[cfg(target_os = "macos")]
+unsafe fn find_tab_group(element: AXUIElementRef, depth: usize) -> Option<AXUIElementRef> {
+    let role = copy_attribute(element, constants::AX_ROLE).and_then(|r| cf_type_to_string(r));
+    if role.as_deref() == Some(constants::AX_TAB_GROUP_ROLE) {
+        return Some(element);
+    }
+    if depth == 0 {
+        return None;
+    }
+
+    let children =
+        copy_attribute(element, constants::AX_CHILDREN).and_then(|r| cf_type_to_array(r))?;
+    (0..children.len())
+        .filter_map(|i| children.get(i).map(|r| *r as AXUIElementRef))
+        .find_map(|child| find_tab_group(child, depth - 1))
+}
+
+/// Reads an attribute of an accessibility element
+#[cfg(target_os = "macos")]
+unsafe fn copy_attribute(element: AXUIElementRef, attribute: &str) -> Option<CFTypeRef> {
+    let attr = CFString::new(attribute);
+    let mut value: CFTypeRef = std::ptr::null_mut();
+    let result = AXUIElementCopyAttributeValue(element, attr.as_concrete_TypeRef(), &mut value);
+    (result == 0 && !value.is_null()).then_some(value)
+}
+
+/// Extracts project and tab information from a macOS accessibility window element
+///
+/// Only results that contain a project or identify a scratch window are
+/// returned.
+#[cfg(target_os = "macos")]
+unsafe fn get_parsed_title_from_window(
+    window_ref: AXUIElementRef,
+    app_name: &str,
+) -> Option<ParsedTitle> {
+    use std::ptr;
+
+    // Get the window title first - this is how Mission Control gets project info
+    let title_attr = CFString::new(constants::AX_TITLE);
+    let mut title_ref: CFTypeRef = ptr::null_mut();
+    let title_result =
+        AXUIElementCopyAttributeValue(window_ref, title_attr.as_concrete_TypeRef(), &mut title_ref);
+
+    let mut from_title = ParsedTitle {
+        editor_kind: EditorKind::from_app_name(app_name),
+        ..ParsedTitle::default()
+    };
+    if title_result == 0 && !title_ref.is_null() {
+        if let Some(title) = cf_type_to_string(title_ref) {
+            // Try to extract both project and tab from window title
+            from_title = parse_title(Some(app_name), &title);
+            if from_title.is_scratch {
+                return Some(from_title);
+            }
+            if from_title.project.is_some() && !is_xcode_scheme_title(&from_title) {
+                let Some(file_path) = try_get_document_from_element(window_ref) else {
+                    return Some(from_title);
+                };
+                let parsed = reconcile_with_document(from_title, &file_path);
+                if parsed.project_source == ProjectSource::Document {
+                    debug!(
+                        "Title project of {} window conflicts with its document, using {:?}",
+                        app_name, parsed.project
+                    );
+                }
+                return Some(parsed);
+            }
+        }
+    }
+
+    // Fallback: try to get document path and extract project from it
+    if let Some(file_path) = try_get_document_from_element(window_ref) {
+        if let Some(parsed) = parsed_title_from_document(&from_title, file_path) {
+            return Some(parsed);
+        }
+    }
+
+    // The scheme name is still better than nothing
+    if from_title.project.is_some() {
+        return Some(from_title);
+    }
+
+    // Last resort: try focused element for document path
+    let focused_attr = CFString::new(constants::AX_FOCUSED_UI_ELEMENT);
+    let mut focused_ref: CFTypeRef = ptr::null_mut();
+    let focused_result = AXUIElementCopyAttributeValue(
+        window_ref,
+        focused_attr.as_concrete_TypeRef(),
+        &mut focused_ref,
+    );
+
+    if focused_result == 0 && !focused_ref.is_null() {
+        if let Some(file_path) = try_get_document_from_element(focused_ref as AXUIElementRef) {
+            if let Some(parsed) = parsed_title_from_document(&from_title, file_path) {
+                return Some(parsed);
+            }
+        }
+    }
+
+    None
+}
+
+/// Xcode titles that are just the scheme name, which need not match the
+/// project or workspace that is open
+#[cfg(target_os = "macos")]
+fn is_xcode_scheme_title(parsed: &ParsedTitle) -> bool {
+    parsed.editor_kind == EditorKind::Xcode
+        && parsed.tab.is_none()
+        && parsed.xcode_container.is_none()
+}
+
+/// Project root of a document as (directory name, absolute path)
+#[cfg(target_os = "macos")]
+fn project_path_for(file_path: &str) -> Option<(String, String)> {
+    let root = find_project_root(file_path)?;
+    let name = root.file_name()?.to_string_lossy().to_string();
+    Some((name, root.to_string_lossy().to_string()))
+}
+
+/// Fills in the project from a document path when the title was not usable,
+/// keeping whatever else the title provided
+#[cfg(target_os = "macos")]
+fn parsed_title_from_document(from_title: &ParsedTitle, file_path: String) -> Option<ParsedTitle> {
+    if from_title.editor_kind == EditorKind::Xcode {
+        if let Some((project, container)) = xcode_container_from_path(&file_path) {
+            return Some(ParsedTitle {
+                project: Some(project),
+                xcode_container: Some(container),
+                project_path: project_path_for(&file_path).map(|(_, path)| path),
+                path: Some(file_path),
+                project_source: ProjectSource::Document,
+                ..from_title.clone()
+            });
+        }
+    }
+
+    let project = extract_project_name_from_path(&file_path)?;
+    // The name can also come from the directory fallback, which has no root
+    let project_path = project_path_for(&file_path)
+        .filter(|(name, _)| *name == project)
+        .map(|(_, path)| path);
+    Some(ParsedTitle {
+        project: Some(project),
+        project_path,
+        path: Some(file_path),
+        project_source: ProjectSource::Document,
+        ..from_title.clone()
+    })
+}
+
+/// Attempts to extract document path from an accessibility element
+#[cfg(target_os = "macos")]
+unsafe fn try_get_document_from_element(element_ref: AXUIElementRef) -> Option<String> {
+    use std::ptr;
+
+    // Try to get AXDocument attribute
+    let document_attr = CFString::new(constants::AX_DOCUMENT);
+    let mut document_ref: CFTypeRef = ptr::null_mut();
+    let doc_result = AXUIElementCopyAttributeValue(
+        element_ref,
+        document_attr.as_concrete_TypeRef(),
+        &mut document_ref,
+    );
+
+    if doc_result == 0 && !document_ref.is_null() {
+        if let Some(file_path) = cf_type_to_string(document_ref) {
+            return Some(file_path);
+        }
+    }
+
+    // Fallback: try AXURL attribute
+    let url_attr = CFString::new(constants::AX_URL);
+    let mut url_ref: CFTypeRef = ptr::null_mut();
+    let url_result =
+        AXUIElementCopyAttributeValue(element_ref, url_attr.as_concrete_TypeRef(), &mut url_ref);
+
+    if url_result == 0 && !url_ref.is_null() {
+        if let Some(url) = cf_type_to_string(url_ref) {
+            let file_path = if url.starts_with(constants::FILE_URL_PREFIX) {
+                &url[constants::FILE_URL_PREFIX.len()..]
+            } else {
+                &url
+            };
+            return Some(file_path.to_string());
+        }
+    }
+
+    // Try AXTitle as a last resort
+    let title_attr = CFString::new(constants::AX_TITLE);
+    let mut title_ref: CFTypeRef = ptr::null_mut();
+    let title_result = AXUIElementCopyAttributeValue(
+        element_ref,
+        title_attr.as_concrete_TypeRef(),
+        &mut title_ref,
+    );
+
+    if title_result == 0 && !title_ref.is_null() {
+        if let Some(title) = cf_type_to_string(title_ref) {
+            // Try to extract path from title if it contains file path
+            if title.contains("/") {
+                return Some(title);
+            }
+        }
+    }
+
+    None
+}
+
+/// Converts a Core Foundation type reference to a CFArray if possible
+#[cfg(target_os = "macos")]
+unsafe fn cf_type_to_array(cf_ref: CFTypeRef) -> Option<CFArray<CFTypeRef>> {
+    use core_foundation::array::CFArrayGetTypeID;
+    use core_foundation::base::CFGetTypeID;
+
+    if cf_ref.is_null() {
+        return None;
+    }
+
+    // Check if it's a CFArray
+    if CFGetTypeID(cf_ref) == CFArrayGetTypeID() {
+        Some(CFArray::wrap_under_get_rule(
+            cf_ref as *const core_foundation::array::__CFArray,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Converts a Core Foundation type reference to a String if possible
+#[cfg(target_os = "macos")]
+unsafe fn cf_type_to_string(cf_ref: CFTypeRef) -> Option<String> {
+    use core_foundation::base::CFGetTypeID;
+
+    if cf_ref.is_null() {
+        return None;
+    }
+
+    // Check if it's a CFString
+    if CFGetTypeID(cf_ref) == CFString::type_id() {
+        let cf_string = CFString::wrap_under_get_rule(cf_ref as CFStringRef);
+        Some(cf_string.to_string())
+    } else {
+        None
+    }
+}
+
+/// Converts a Core Foundation number or boolean to an integer
+#[cfg(target_os = "macos")]
+unsafe fn cf_type_to_i64(cf_ref: CFTypeRef) -> Option<i64> {
+    use core_foundation::base::CFGetTypeID;
+    use core_foundation::boolean::{CFBoolean, CFBooleanRef};
+
+    if cf_ref.is_null() {
+        return None;
+    }
+
+    let type_id = CFGetTypeID(cf_ref);
+    if type_id == CFNumber::type_id() {
+        CFNumber::wrap_under_get_rule(cf_ref as CFNumberRef).to_i64()
+    } else if type_id == CFBoolean::type_id() {
+        Some(bool::from(CFBoolean::wrap_under_get_rule(cf_ref as CFBooleanRef)) as i64)
+    } else {
+        None
+    }
+}
+
+/// Extracts a string value from a Core Foundation dictionary
+#[cfg(target_os = "macos")]
+pub unsafe fn get_string_value(dict: &CFDictionary, key: &str) -> Option<String> {
+    let cf_key = CFString::new(key);
+    let key_ptr = cf_key.as_concrete_TypeRef() as *const c_void;
+
+    if let Some(value_ref) = dict.find(key_ptr) {
+        let cf_string = CFString::wrap_under_get_rule(*value_ref as CFStringRef);
+        Some(cf_string.to_string())
+    } else {
+        None
+    }
+}
+
+/// Extracts a numeric value from a Core Foundation dictionary
+#[cfg(target_os = "macos")]
+pub unsafe fn get_number_value(dict: &CFDictionary, key: &str) -> Option<i64> {
+    let cf_key = CFString::new(key);
+    let key_ptr = cf_key.as_concrete_TypeRef() as *const c_void;
+
+    if let Some(value_ref) = dict.find(key_ptr) {
+        let cf_number = CFNumber::wrap_under_get_rule(*value_ref as CFNumberRef);
+        cf_number.to_i64()
+    } else {
+        None
+    }
+}
+
+// Stub implementations for non-macOS platforms
+#[cfg(not(target_os = "macos"))]
+pub unsafe fn populate_project_info_for_pid(
+    _pid: i32,
+    _app_name: &str,
+    _window_project_map: &mut HashMap<u32, ParsedTitle>,
+) {
+    // No-op on non-macOS platforms
+}
+
+#[cfg(not(target_os = "macos"))]
+pub unsafe fn get_ax_window_titles_for_pid(_pid: i32) -> Vec<Option<String>> {
+    Vec::new()
+}
+
+#[cfg(not(target_os = "macos"))]
+pub unsafe fn get_string_value(
+    _dict: &std::collections::HashMap<String, String>,
+    _key: &str,
+) -> Option<String> {
+    None
+}
+
+#[cfg(not(target_os = "macos"))]
+pub unsafe fn get_number_value(
+    _dict: &std::collections::HashMap<String, String>,
+    _key: &str,
+) -> Option<i64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn test_simple_window_mapping() {
+        // Test the simple mapping approach
+        let mut window_project_map = HashMap::new();
+
+        // Simulate the mapping
+        window_project_map.insert(
+            8229,
+            (Some("swii".to_string()), Some("window_list.rs".to_string())),
+        );
+        window_project_map.insert(
+            8512,
+            (Some("switch".to_string()), Some("commands.rs".to_string())),
+        );
+        window_project_map.insert(
+            9062,
+            (
+                Some("promptbook".to_string()),
+                Some("eslint.config.mjs".to_string()),
+            ),
+        );
+
+        // Test lookups
+        assert_eq!(
+            window_project_map.get(&8229),
+            Some(&(Some("swii".to_string()), Some("window_list.rs".to_string())))
+        );
+        assert_eq!(
+            window_project_map.get(&8512),
+            Some(&(Some("switch".to_string()), Some("commands.rs".to_string())))
+        );
+        assert_eq!(
+            window_project_map.get(&9062),
+            Some(&(
+                Some("promptbook".to_string()),
+                Some("eslint.config.mjs".to_string())
+            ))
+        );
+
+        // Test missing window
+        assert_eq!(window_project_map.get(&99999), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn test_window_correlation_real_scenario() {
+        // Test scenario similar to the reported bug - simple direct mapping
+        let mut window_project_map = HashMap::new();
+
+        // The old bug: windows 8229 and 9062 both showed "swii" project
+        // With the new approach, they should show different projects
+        window_project_map.insert(
+            8229,
+            (Some("swii".to_string()), Some("window_list.rs".to_string())),
+        );
+        window_project_map.insert(
+            9062,
+            (
+                Some("promptbook".to_string()),
+                Some("eslint.config.mjs".to_string()),
+            ),
+        );
+        window_project_map.insert(
+            8512,
+            (Some("switch".to_string()), Some("commands.rs".to_string())),
+        );
+
+        // Verify each window shows the correct project
+        assert_eq!(
+            window_project_map.get(&8229),
+            Some(&(Some("swii".to_string()), Some("window_list.rs".to_string())))
+        );
+        assert_eq!(
+            window_project_map.get(&9062),
+            Some(&(
+                Some("promptbook".to_string()),
+                Some("eslint.config.mjs".to_string())
+            ))
+        );
+        assert_eq!(
+            window_project_map.get(&8512),
+            Some(&(Some("switch".to_string()), Some("commands.rs".to_string())))
+        );
+
+        // Verify they're all different (no collision)
+        let result_8229 = window_project_map.get(&8229);
+        let result_9062 = window_project_map.get(&9062);
+        let result_8512 = window_project_map.get(&8512);
+
+        assert_ne!(result_8229, result_9062);
+        assert_ne!(result_8229, result_8512);
+        assert_ne!(result_9062, result_8512);
+    }
+}
@@ -0,0 +1,427 @@
+//! Bringing a single window to the front on macOS.
+//!
+//! The window is first made its application's main and focused window through
+//! the Accessibility API while the application is still in the background,
+//! then the application is activated, so the window that was in front before
+//! doesn't flash up first. Without Accessibility access the window is
+//! selected through AppleScript instead (see `applescript_focus`).
+
+use std::ffi::c_void;
+use std::ptr;
+
+use core_foundation::{
+    array::CFArray,
+    base::{CFTypeRef, TCFType},
+    dictionary::CFDictionary,
+    string::{CFString, CFStringRef},
+};
+
+use core_graphics::window::{
+    kCGWindowListExcludeDesktopElements, kCGWindowListOptionOnScreenOnly,
+    CGWindowListCopyWindowInfo,
+};
+
+use tracing::warn;
+
+use crate::focus::WindowFocusError;
+use crate::macos_accessibility::get_number_value;
+
+type AXUIElementRef = *const crate::macos_accessibility::__AXUIElement;
+
+type AXError = i32;
+
+extern "C" {
+    fn AXUIElementCreateApplication(pid: libc::pid_t) -> AXUIElementRef;
+    fn AXUIElementCopyAttributeValue(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        value: *mut CFTypeRef,
+    ) -> AXError;
+    fn AXUIElementPerformAction(element: AXUIElementRef, action: CFStringRef) -> AXError;
+    fn AXUIElementSetAttributeValue(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        value: CFTypeRef,
+    ) -> AXError;
+    fn AXIsProcessTrusted() -> bool;
+}
+
+extern "C" {
+    fn NSApplicationLoad() -> bool;
+    fn objc_getClass(name: *const std::os::raw::c_char) -> *const c_void;
+    fn sel_registerName(str: *const std::os::raw::c_char) -> *const c_void;
+    fn objc_msgSend(obj: *const c_void, sel: *const c_void, ...) -> *const c_void;
+}
+
+mod constants {
+    pub const AX_WINDOWS: &str = "AXWindows";
+    pub const AX_RAISE: &str = "AXRaise";
+    pub const AX_MAIN: &str = "AXMain";
+    pub const AX_FOCUSED_WINDOW: &str = "AXFocusedWindow";
+    pub const CG_WINDOW_OWNER_PID: &str = "kCGWindowOwnerPID";
+    pub const CG_WINDOW_NUMBER: &str = "kCGWindowNumber";
+}
+
+/// Focuses the window, or only raises it within its application when
+/// `raise_only` is set
+pub(crate) fn bring_window_to_front(
+    pid: i32,
+    window_number: u32,
+    raise_only: bool,
+) -> Result<(), WindowFocusError> {
+    unsafe {
+        // Step 1: Verify the window exists
+        if !window_exists(pid, window_number)? {
+            return Err(WindowFocusError::WindowNotFound);
+        }
+
+        // Step 2: Make the window the app's focused window while the app is
+        // still in the background, so activating it doesn't first show the
+        // window that was in front before. Without Accessibility access the
+        // window is selected through AppleScript instead.
+        if !AXIsProcessTrusted() {
+            return focus_without_accessibility(pid, window_number);
+        }
+        match focus_window_by_number(pid, window_number) {
+            Err(WindowFocusError::PermissionDenied) => {
+                return focus_without_accessibility(pid, window_number)
+            }
+            result => result?,
+        }
+
+        // Step 3: Try to bring application to front (but don't fail if it doesn't work).
+        // Skipped when raising only, since activation can pull the app's
+        // other windows forward too.
+        if !raise_only {
+            let _ = activate_application(pid);
+        }
+
+        Ok(())
+    }
+}
+
+/// Degraded focus for when AX calls are not allowed: the window is selected
+/// through AppleScript if possible, then its application is activated. When
+/// the window can't be selected, the application still comes to the front.
+unsafe fn focus_without_accessibility(
+    pid: i32,
+    window_number: u32,
+) -> Result<(), WindowFocusError> {
+    if let Err(e) = select_listed_window(pid, window_number) {
+        warn!(
+            "Could not select window {} of PID {} without Accessibility access: {}",
+            window_number, pid, e
+        );
+    }
+    activate_application(pid)
+}
+
+/// Selects `window_number` of `pid` through AppleScript by the title it has
+/// in the latest listing
+fn select_listed_window(pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+    let window = crate::macos_window::listed_window(pid, window_number)
+        .ok_or(WindowFocusError::WindowNotFound)?;
+    let Some(title) = window.title.as_deref().filter(|title| !title.is_empty()) else {
+        return Err(WindowFocusError::SystemError(
+            "The window has no title to select it by".to_string(),
+        ));
+    };
+    crate::applescript_focus::select_window(pid, &window.app_name, title)
+}
+
+unsafe fn window_exists(pid: i32, window_number: u32) -> Result<bool, WindowFocusError> {
+    let window_list_info = CGWindowListCopyWindowInfo(
+        kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+        0,
+    );
+
+    if window_list_info.is_null() {
+        return Err(WindowFocusError::SystemError(
+            "Failed to get window list".to_string(),
+        ));
+    }
+
+    let window_list: CFArray<CFDictionary> = CFArray::wrap_under_create_rule(window_list_info);
+
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            if let (Some(dict_pid), Some(dict_window_number)) = (
+                get_number_value(&window_dict, constants::CG_WINDOW_OWNER_PID),
+                get_number_value(&window_dict, constants::CG_WINDOW_NUMBER),
+            ) {
+                if dict_pid as i32 == pid && dict_window_number as u32 == window_number {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+unsafe fn activate_application(pid: i32) -> Result<(), WindowFocusError> {
+    // Load NSApplication framework
+    if !NSApplicationLoad() {
+        return Err(WindowFocusError::SystemError(
+            "Failed to load NSApplication".to_string(),
+        ));
+    }
+
+    // Get NSRunningApplication class
+    let ns_running_app_class =
+        objc_getClass(b"NSRunningApplication\0".as_ptr() as *const std::os::raw::c_char);
+    if ns_running_app_class.is_null() {
+        return Err(WindowFocusError::SystemError(
+            "NSRunningApplication class not found".to_string(),
+        ));
+    }
+
+    // Selector for +runningApplicationWithProcessIdentifier:
+    let app_with_pid_sel = sel_registerName(
+        b"runningApplicationWithProcessIdentifier:\0".as_ptr() as *const std::os::raw::c_char
+    );
+    if app_with_pid_sel.is_null() {
+        return Err(WindowFocusError::SystemError(
+            "Selector runningApplicationWithProcessIdentifier: not found".to_string(),
+        ));
+    }
+
+    // Build a fn pointer for objc_msgSend(id, SEL, pid) -> id
+    let msg_send_app: extern "C" fn(*const c_void, *const c_void, libc::pid_t) -> *const c_void =
+        std::mem::transmute(objc_msgSend as *const c_void);
+
+    // Call +[NSRunningApplication runningApplicationWithProcessIdentifier:]
+    let app = msg_send_app(ns_running_app_class, app_with_pid_sel, pid);
+    if app.is_null() {
+        return Err(WindowFocusError::ApplicationNotFound);
+    }
+
+    // Re-activating the frontmost app makes some apps bounce their Dock icon
+    let is_active_sel = sel_registerName(b"isActive\0".as_ptr() as *const std::os::raw::c_char);
+    if !is_active_sel.is_null() {
+        // Build a fn pointer for objc_msgSend(id, SEL) -> BOOL
+        let is_active_fn: extern "C" fn(*const c_void, *const c_void) -> bool =
+            std::mem::transmute(objc_msgSend as *const c_void);
+        if is_active_fn(app, is_active_sel) {
+            return Ok(());
+        }
+    }
+
+    // Selector for -activateWithOptions:
+    let activate_sel =
+        sel_registerName(b"activateWithOptions:\0".as_ptr() as *const std::os::raw::c_char);
+    if activate_sel.is_null() {
+        return Err(WindowFocusError::SystemError(
+            "Selector activateWithOptions: not found".to_string(),
+        ));
+    }
+
+    // Use only NSApplicationActivateIgnoringOtherApps (2),
+    // so we don't bring *all* windows forward—just let our AXRaise call handle the single window.
+    let options: usize = 1 << 1; // NSApplicationActivateIgnoringOtherApps
+
+    // Build a fn pointer for objc_msgSend(id, SEL, usize) -> id
+    let activate_fn: extern "C" fn(*const c_void, *const c_void, usize) -> *const c_void =
+        std::mem::transmute(objc_msgSend as *const c_void);
+
+    // Call -[NSRunningApplication activateWithOptions:]
+    activate_fn(app, activate_sel, options);
+
+    Ok(())
+}
+
+unsafe fn focus_window_by_number(pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+    let app_ref = AXUIElementCreateApplication(pid);
+    if app_ref.is_null() {
+        return Err(WindowFocusError::ApplicationNotFound);
+    }
+
+    // Get all windows for the application
+    let windows_attr = CFString::new(constants::AX_WINDOWS);
+    let mut windows_ref: CFTypeRef = ptr::null_mut();
+    let result = AXUIElementCopyAttributeValue(
+        app_ref,
+        windows_attr.as_concrete_TypeRef(),
+        &mut windows_ref,
+    );
+
+    if result != 0 {
+        return Err(match result {
+            -25200 => WindowFocusError::PermissionDenied,
+            _ => WindowFocusError::SystemError(format!("AX error: {}", result)),
+        });
+    }
+
+    if windows_ref.is_null() {
+        return Err(WindowFocusError::WindowNotFound);
+    }
+
+    let windows_array = cf_type_to_array(windows_ref);
+    if windows_array.is_none() {
+        return Err(WindowFocusError::SystemError(
+            "Failed to convert windows to array".to_string(),
+        ));
+    }
+
+    let windows = windows_array.unwrap();
+
+    // Find the target window by correlating with Core Graphics windows
+    let target_window_ref = find_ax_window_by_cg_number(pid, window_number, &windows)?;
+
+    // 1) Mark it as the main window
+    let main_attr = CFString::new(constants::AX_MAIN);
+    let main_value = core_foundation::boolean::CFBoolean::true_value();
+    let _ = AXUIElementSetAttributeValue(
+        target_window_ref,
+        main_attr.as_concrete_TypeRef(),
+        main_value.as_CFTypeRef(),
+    );
+
+    // 2) Explicitly set the app’s focused window
+    let focused_attr = CFString::new(constants::AX_FOCUSED_WINDOW);
+    let _ = AXUIElementSetAttributeValue(
+        app_ref,
+        focused_attr.as_concrete_TypeRef(),
+        target_window_ref as CFTypeRef,
+    );
+
+    // 3) Raise the window last. With main and focused already set, the app
+    // has nothing else to bring forward, so no pause is needed before the
+    // activation that follows.
+    let raise_action = CFString::new(constants::AX_RAISE);
+    let raise_result =
+        AXUIElementPerformAction(target_window_ref, raise_action.as_concrete_TypeRef());
+    if raise_result != 0 {
+        return Err(WindowFocusError::SystemError(format!(
+            "Failed to raise window: {}",
+            raise_result
+        )));
+    }
+
+    Ok(())
+}
+
+unsafe fn find_ax_window_by_cg_number(
+    pid: i32,
+    target_window_number: u32,
+    ax_windows: &CFArray<CFTypeRef>,
+) -> Result<AXUIElementRef, WindowFocusError> {
+    // Get Core Graphics windows for this PID to establish correlation
+    let window_list_info = CGWindowListCopyWindowInfo(
+        kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+        0,
+    );
+
+    if window_list_info.is_null() {
+        return Err(WindowFocusError::SystemError(
+            "Failed to get CG window list".to_string(),
+        ));
+    }
+
+    let window_list: CFArray<CFDictionary> = CFArray::wrap_under_create_rule(window_list_info);
+    let mut cg_windows_for_pid = Vec::new();
+
+    // Collect CG windows for this PID in order
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            if let Some(dict_pid) = get_number_value(&window_dict, constants::CG_WINDOW_OWNER_PID) {
+                if dict_pid as i32 == pid {
+                    if let Some(window_num) =
+                        get_number_value(&window_dict, constants::CG_WINDOW_NUMBER)
+                    {
+                        cg_windows_for_pid.push(window_num as u32);
+                    }
+                }
+            }
+        }
+    }
+
+    // Find the index of our target window number
+    let target_index = cg_windows_for_pid
+        .iter()
+        .position(|&num| num == target_window_number)
+        .ok_or(WindowFocusError::WindowNotFound)?;
+
+    // Return the corresponding AX window (assuming same order)
+    let ax_len = ax_windows.len();
+    if (target_index as isize) < ax_len {
+        if let Some(window_ref_ptr) = ax_windows.get(target_index as isize) {
+            return Ok(*window_ref_ptr as AXUIElementRef);
+        }
+    }
+
+    Err(WindowFocusError::WindowNotFound)
+}
+
+unsafe fn cf_type_to_array(cf_ref: CFTypeRef) -> Option<CFArray<CFTypeRef>> {
+    use core_foundation::array::CFArrayGetTypeID;
+    use core_foundation::base::CFGetTypeID;
+
+    if cf_ref.is_null() {
+        return None;
+    }
+
+    if CFGetTypeID(cf_ref) == CFArrayGetTypeID() {
+        Some(CFArray::wrap_under_get_rule(
+            cf_ref as *const core_foundation::array::__CFArray,
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bring_window_to_front_signature() {
+        // Should return an error for an invalid PID/window
+        let result = bring_window_to_front(0, 0, false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bring_window_to_front_invalid_inputs() {
+        // Test with clearly invalid inputs
+        let result = bring_window_to_front(-1, 0, false);
+        assert!(result.is_err());
+
+        let result = bring_window_to_front(0, 0, false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_window_exists_with_invalid_inputs() {
+        unsafe {
+            // Test with invalid PID
+            let result = window_exists(-1, 12345);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), false);
+
+            // Test with valid PID but non-existent window
+            let result = window_exists(1, 999999999);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), false);
+        }
+    }
+
+    #[test]
+    fn test_activate_application_invalid_pid() {
+        unsafe {
+            // Test with invalid PID
+            let result = activate_application(-1);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), WindowFocusError::ApplicationNotFound);
+        }
+    }
+
+    #[test]
+    fn test_constants_not_empty() {
+        assert!(!constants::AX_WINDOWS.is_empty());
+        assert!(!constants::AX_RAISE.is_empty());
+        assert!(!constants::AX_MAIN.is_empty());
+        assert!(!constants::CG_WINDOW_OWNER_PID.is_empty());
+        assert!(!constants::CG_WINDOW_NUMBER.is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+//! Watching editor windows for changes on macOS.
+//!
+//! An accessibility observer is attached to every application with a listed
+//! window. Editors retitle their window whenever the user switches files, so
+//! each `AXTitleChanged` of a window is reported as
+//! [`WindowEvent::TitleChanged`]; `AXWindowCreated` and `AXUIElementDestroyed`
+//! are reported as [`WindowEvent::Opened`] and [`WindowEvent::Closed`], and
+//! `AXFocusedWindowChanged` and `AXApplicationActivated` as
+//! [`WindowEvent::Focused`].
+//! Observers live on a dedicated run loop thread that re-syncs them with the
+//! latest listing every few seconds. While observation is paused (the app
+//! pauses it while the user is idle) they are all detached and the thread
+//! only checks back every few seconds, so nothing wakes swii up until the
+//! user returns.
+
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use core_foundation::base::TCFType;
+use core_foundation::runloop::{kCFRunLoopDefaultMode, CFRunLoop, CFRunLoopRunResult};
+use core_foundation::string::{CFString, CFStringRef};
+use tracing::debug;
+
+use crate::macos_accessibility::constants::{
+    AX_APPLICATION_ACTIVATED_NOTIFICATION, AX_FOCUSED_WINDOW_CHANGED_NOTIFICATION,
+    AX_TITLE_CHANGED_NOTIFICATION, AX_UI_ELEMENT_DESTROYED_NOTIFICATION,
+    AX_WINDOW_CREATED_NOTIFICATION,
+};
+use crate::macos_accessibility::{
+    cg_window_numbers_for_pid, changed_window_title, focused_window_number, observe_window_changes,
+    stop_observing, AXObserverRef, AXUIElementRef,
+};
+use crate::provider::{WindowError, WindowEvent, WindowEventCallback};
+
+pub mod constants {
+    /// How often observers are attached to newly listed applications and
+    /// dropped for applications that are gone
+    pub const OBSERVER_SYNC_INTERVAL_SECS: u64 = 5;
+}
+
+static CALLBACK: OnceLock<WindowEventCallback> = OnceLock::new();
+
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// Pauses or resumes observation; observers are detached while paused
+pub fn set_paused(paused: bool) {
+    PAUSED.store(paused, Ordering::Relaxed);
+}
+
+/// Starts the observer thread. Calling this more than once is a no-op.
+pub fn start(callback: WindowEventCallback) -> Result<(), WindowError> {
+    if CALLBACK.set(callback).is_err() {
+        return Ok(());
+    }
+
+    std::thread::Builder::new()
+        .name("swii-window-observer".to_string())
+        .spawn(run)?;
+    Ok(())
+}
+
+fn run() {
+    let interval = Duration::from_secs(constants::OBSERVER_SYNC_INTERVAL_SECS);
+    let mut observers: HashMap<i32, AXObserverRef> = HashMap::new();
+    loop {
+        if PAUSED.load(Ordering::Relaxed) {
+            stop_all(&mut observers);
+            std::thread::sleep(interval);
+            continue;
+        }
+        sync_observers(&mut observers);
+
+        // SAFETY: kCFRunLoopDefaultMode is a constant CFString provided by
+        // Core Foundation
+        let result = CFRunLoop::run_in_mode(unsafe { kCFRunLoopDefaultMode }, interval, false);
+        // A run loop without sources returns at once
+        if matches!(result, CFRunLoopRunResult::Finished) {
+            std::thread::sleep(interval);
+        }
+    }
+}
+
+/// Stops observing every application
+fn stop_all(observers: &mut HashMap<i32, AXObserverRef>) {
+    if !observers.is_empty() {
+        debug!(
+            "Detaching {} window observers while paused",
+            observers.len()
+        );
+    }
+    for (_, observer) in observers.drain() {
+        // SAFETY: the observer was created on this thread by
+        // observe_window_changes and is released exactly once
+        unsafe { stop_observing(observer) };
+    }
+}
+
+/// Observes every application of the latest listing and stops observing
+/// applications no longer in it
+fn sync_observers(observers: &mut HashMap<i32, AXObserverRef>) {
+    let pids = crate::macos_window::listed_pids();
+
+    observers.retain(|pid, observer| {
+        let keep = pids.contains(pid);
+        if !keep {
+            // SAFETY: the observer was created on this thread by
+            // observe_window_changes and is released exactly once
+            unsafe { stop_observing(*observer) };
+        }
+        keep
+    });
+
+    for pid in pids {
+        if observers.contains_key(&pid) {
+            continue;
+        }
+        // SAFETY: observe_window_changes handles invalid PIDs and missing
+        // accessibility permission by returning None
+        match unsafe { observe_window_changes(pid, on_notification) } {
+            Some(observer) => {
+                debug!("Observing windows of PID {}", pid);
+                observers.insert(pid, observer);
+            }
+            None => debug!("Failed to observe windows of PID {}", pid),
+        }
+    }
+}
+
+/// Whether a listed window of `pid` is no longer on screen
+///
+/// Every element of an application posts `AXUIElementDestroyed`, so this
+/// keeps menus and tooltips from being reported as closed windows.
+fn listed_window_gone(pid: i32) -> bool {
+    // SAFETY: cg_window_numbers_for_pid only reads the CG window list
+    let on_screen = unsafe { cg_window_numbers_for_pid(pid) };
+    crate::macos_window::listed_window_numbers(pid)
+        .iter()
+        .any(|number| !on_screen.contains(number))
+}
+
+unsafe extern "C" fn on_notification(
+    _observer: AXObserverRef,
+    element: AXUIElementRef,
+    notification: CFStringRef,
+    refcon: *mut c_void,
+) {
+    let pid = refcon as isize as i32;
+    let notification = CFString::wrap_under_get_rule(notification).to_string();
+    let event = match notification.as_str() {
+        AX_TITLE_CHANGED_NOTIFICATION => {
+            changed_window_title(element).map(|(pid, window_number, title)| {
+                if let Some(window_number) = window_number {
+                    crate::macos_window::retitle_listed(pid, window_number, &title);
+                }
+                WindowEvent::TitleChanged {
+                    pid,
+                    window_number,
+                    title,
+                }
+            })
+        }
+        AX_WINDOW_CREATED_NOTIFICATION => Some(WindowEvent::Opened { pid }),
+        AX_UI_ELEMENT_DESTROYED_NOTIFICATION => {
+            listed_window_gone(pid).then_some(WindowEvent::Closed { pid })
+        }
+        AX_FOCUSED_WINDOW_CHANGED_NOTIFICATION | AX_APPLICATION_ACTIVATED_NOTIFICATION => {
+            Some(WindowEvent::Focused {
+                pid,
+                window_number: focused_window_number(pid),
+            })
+        }
+        _ => None,
+    };
+
+    if let (Some(event), Some(callback)) = (event, CALLBACK.get()) {
+        callback(event);
+    }
+}
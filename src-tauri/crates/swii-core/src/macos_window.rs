@@ -0,0 +1,1114 @@
+//! macOS window management functionality including window enumeration,
+//! icon extraction, and editor detection.
+//!
+//! ## Examples
+//!
+//! ```rust,ignore
+//! use swii_core::macos_window::get_editor_windows;
+//!
+//! let windows = get_editor_windows();
+//! for window in windows {
+//!     println!("Found editor: {} - {}", window.app_name, window.window_name.unwrap_or_default());
+//! }
+//! ```
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+use swii_title_parser::{EditorKind, ParsedTitle};
+use tracing::{debug, warn};
+
+use base64::Engine;
+use core_foundation::{array::CFArray, base::TCFType, dictionary::CFDictionary};
+use core_graphics::window::{
+    kCGWindowListExcludeDesktopElements, kCGWindowListOptionOnScreenOnly,
+    CGWindowListCopyWindowInfo,
+};
+
+use crate::editor_cli::{EditorCli, FileTarget};
+use crate::editor_config;
+use crate::focus::WindowFocusError;
+use crate::icns;
+use crate::macos_accessibility::{
+    get_number_value, get_string_value, populate_project_info_for_pid,
+};
+use crate::provider::{
+    AppExtractionTiming, EnumerationTimings, ProviderCapabilities, RunningApp, WindowEventCallback,
+    WindowProvider, WindowTab,
+};
+use crate::types::WindowInfo;
+
+pub use crate::provider::WindowError;
+
+// Core Graphics window dictionary keys
+pub const CG_WINDOW_OWNER_NAME: &str = "kCGWindowOwnerName";
+pub const CG_WINDOW_OWNER_PID: &str = "kCGWindowOwnerPID";
+pub const CG_WINDOW_NUMBER: &str = "kCGWindowNumber";
+pub const CG_WINDOW_NAME: &str = "kCGWindowName";
+pub const CG_WINDOW_LAYER: &str = "kCGWindowLayer";
+
+/// A window of the latest listing, as the observer and the AppleScript
+/// focus fallback need it
+#[derive(Debug, Clone)]
+pub(crate) struct ListedWindow {
+    pub pid: i32,
+    pub window_number: u32,
+    pub app_name: String,
+    /// Title the window was listed with, or last retitled to
+    pub title: Option<String>,
+}
+
+/// Windows of the latest listing
+static LISTED_WINDOWS: Mutex<Vec<ListedWindow>> = Mutex::new(Vec::new());
+
+/// PIDs of the latest running editors, including ones without windows
+static RUNNING_PIDS: Mutex<Vec<i32>> = Mutex::new(Vec::new());
+
+fn lock_listed() -> std::sync::MutexGuard<'static, Vec<ListedWindow>> {
+    LISTED_WINDOWS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Window `window_number` of `pid` in the latest listing
+pub(crate) fn listed_window(pid: i32, window_number: u32) -> Option<ListedWindow> {
+    lock_listed()
+        .iter()
+        .find(|window| window.pid == pid && window.window_number == window_number)
+        .cloned()
+}
+
+/// PIDs of the latest listing and of the latest running editors
+pub(crate) fn listed_pids() -> Vec<i32> {
+    let mut pids: Vec<i32> = lock_listed().iter().map(|window| window.pid).collect();
+    pids.extend(
+        RUNNING_PIDS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter(),
+    );
+    pids.sort_unstable();
+    pids.dedup();
+    pids
+}
+
+/// Window numbers of the windows of `pid` in the latest listing
+pub(crate) fn listed_window_numbers(pid: i32) -> Vec<u32> {
+    lock_listed()
+        .iter()
+        .filter(|window| window.pid == pid)
+        .map(|window| window.window_number)
+        .collect()
+}
+
+/// Records the title a listed window changed to
+pub(crate) fn retitle_listed(pid: i32, window_number: u32, title: &str) {
+    if let Some(window) = lock_listed()
+        .iter_mut()
+        .find(|window| window.pid == pid && window.window_number == window_number)
+    {
+        window.title = Some(title.to_string());
+    }
+}
+
+/// Window provider backed by Core Graphics and the Accessibility API
+pub struct MacOsProvider;
+
+impl WindowProvider for MacOsProvider {
+    fn name(&self) -> &'static str {
+        "macos"
+    }
+
+    fn list_windows(&self) -> Result<Vec<WindowInfo>, WindowError> {
+        let windows = get_editor_windows()?;
+        *lock_listed() = windows
+            .iter()
+            .map(|window| ListedWindow {
+                pid: window.pid,
+                window_number: window.window_number,
+                app_name: window.app_name.clone(),
+                title: window.window_name.clone(),
+            })
+            .collect();
+        Ok(windows)
+    }
+
+    fn focus_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+        crate::macos_focus::bring_window_to_front(pid, window_number, false)
+    }
+
+    fn raise_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+        crate::macos_focus::bring_window_to_front(pid, window_number, true)
+    }
+
+    fn app_icon(&self, pid: i32) -> Result<Option<String>, WindowError> {
+        get_app_icon_for_pid(pid)
+    }
+
+    fn window_tabs(&self, pid: i32, window_number: u32) -> Result<Vec<WindowTab>, WindowError> {
+        // SAFETY: get_ax_tabs_for_window handles invalid PIDs and windows gracefully
+        Ok(unsafe { crate::macos_accessibility::get_ax_tabs_for_window(pid, window_number) })
+    }
+
+    fn window_document(&self, pid: i32, window_number: u32) -> Result<Option<String>, WindowError> {
+        // SAFETY: get_ax_document_for_window handles invalid PIDs and windows gracefully
+        Ok(unsafe { crate::macos_accessibility::get_ax_document_for_window(pid, window_number) })
+    }
+
+    fn open_window(&self, pid: i32, path: Option<&str>) -> Result<(), WindowFocusError> {
+        open_window_for_pid(pid, path)
+    }
+
+    fn open_file(
+        &self,
+        pid: i32,
+        target: &FileTarget,
+        cli: Option<EditorCli>,
+    ) -> Result<(), WindowFocusError> {
+        open_file_for_pid(pid, target, cli)
+    }
+
+    fn running_editors(&self) -> Result<Vec<RunningApp>, WindowError> {
+        let apps = get_running_editors()?;
+        *RUNNING_PIDS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+            apps.iter().map(|app| app.pid).collect();
+        Ok(apps)
+    }
+
+    fn observe(&self, callback: WindowEventCallback) -> Result<(), WindowError> {
+        crate::macos_observer::start(callback)
+    }
+
+    fn pause_observation(&self, paused: bool) {
+        crate::macos_observer::set_paused(paused);
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            provider: self.name(),
+            list_windows: true,
+            focus_windows: true,
+            observe_windows: true,
+            detail: None,
+        }
+    }
+}
+
+/// Milliseconds elapsed since `start`, with sub-millisecond precision
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Gets all editor windows on macOS
+///
+/// This function enumerates all visible windows on macOS and filters them
+/// to return only windows belonging to known code editors.
+///
+/// # Returns
+///
+/// A vector of `WindowInfo` structs containing information about editor windows,
+/// one per window number. Windows are in Core Graphics z-order, front-most
+/// first, with the window number breaking ties, so listing the same windows
+/// twice gives the same order.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use swii_core::macos_window::get_editor_windows;
+///
+/// let editor_windows = get_editor_windows();
+/// println!("Found {} editor windows", editor_windows.len());
+/// ```
+pub fn get_editor_windows() -> Result<Vec<WindowInfo>, WindowError> {
+    get_editor_windows_timed(&mut EnumerationTimings::default())
+}
+
+/// Gets all editor windows on macOS, recording how long each phase took
+///
+/// Behaves exactly like [`get_editor_windows`] but fills `timings` with the
+/// duration of the Core Graphics enumeration, the per-application
+/// Accessibility project extraction and the icon extraction.
+pub fn get_editor_windows_timed(
+    timings: &mut EnumerationTimings,
+) -> Result<Vec<WindowInfo>, WindowError> {
+    debug!("Starting enumeration of macOS editor windows");
+
+    let cg_start = Instant::now();
+
+    // SAFETY: This function is safe to call as it only reads window information
+    // and doesn't modify any system state. The Core Graphics API is thread-safe
+    // for read operations.
+    let window_list_info = unsafe {
+        CGWindowListCopyWindowInfo(
+            kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+            0, // kCGNullWindowID - get all windows
+        )
+    };
+
+    if window_list_info.is_null() {
+        warn!("Failed to retrieve window list from Core Graphics");
+        return Err(WindowError::WindowListAccess {
+            message: "Core Graphics returned null window list".to_string(),
+        });
+    }
+
+    // SAFETY: window_list_info is guaranteed to be non-null at this point
+    let window_list: CFArray<CFDictionary> =
+        unsafe { CFArray::wrap_under_create_rule(window_list_info) };
+
+    timings.cg_enumeration_ms = elapsed_ms(cg_start);
+
+    let mut windows = Vec::new();
+    let mut window_project_map = HashMap::new();
+    let mut processed_pids = std::collections::HashSet::new();
+
+    let ax_start = Instant::now();
+
+    // First pass: collect all editor app PIDs and their project info
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            // SAFETY: get_string_value and get_number_value are safe to call with valid
+            // CFDictionary references obtained from Core Graphics APIs. The window_dict
+            // comes from CGWindowListCopyWindowInfo which returns valid dictionaries.
+            let app_name = unsafe { get_string_value(&window_dict, CG_WINDOW_OWNER_NAME) };
+            let pid = unsafe { get_number_value(&window_dict, CG_WINDOW_OWNER_PID) };
+            if let (Some(app_name), Some(pid)) = (app_name, pid) {
+                let pid = pid as i32;
+                if let Some(app_name) = editor_app_name(pid, app_name) {
+                    // Get all project info for this PID once
+                    if !processed_pids.contains(&pid) {
+                        let app_start = Instant::now();
+                        // SAFETY: populate_project_info_for_pid is safe to call with valid PIDs
+                        // obtained from Core Graphics. The function handles invalid PIDs gracefully.
+                        unsafe {
+                            populate_project_info_for_pid(pid, &app_name, &mut window_project_map)
+                        };
+                        timings.ax_extraction_per_app.push(AppExtractionTiming {
+                            app_name,
+                            pid,
+                            elapsed_ms: elapsed_ms(app_start),
+                        });
+                        processed_pids.insert(pid);
+                    }
+                }
+            }
+        }
+    }
+
+    timings.ax_extraction_ms = elapsed_ms(ax_start);
+
+    let info_start = Instant::now();
+
+    // Second pass: create WindowInfo objects using the map
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            match extract_editor_window_info(&window_dict, &window_project_map, timings) {
+                Ok(Some(info)) => {
+                    windows.push((i, info));
+                }
+                Ok(None) => {
+                    // Not an editor window, skip silently
+                }
+                Err(e) => {
+                    warn!("Failed to extract window info: {}", e);
+                    // Continue processing other windows
+                }
+            }
+        }
+    }
+
+    timings.window_info_ms = elapsed_ms(info_start);
+
+    let windows = stable_window_order(windows);
+    debug!("Found {} editor windows", windows.len());
+    Ok(windows)
+}
+
+/// Orders windows by their z-order index, then window number, and drops
+/// repeated window numbers
+///
+/// Core Graphics occasionally reports the same window twice while it is being
+/// moved between spaces; the front-most entry is kept.
+fn stable_window_order(mut windows: Vec<(usize, WindowInfo)>) -> Vec<WindowInfo> {
+    windows.sort_by_key(|(z_index, window)| (*z_index, window.window_number));
+    let mut seen = std::collections::HashSet::new();
+    windows
+        .into_iter()
+        .filter(|(_, window)| seen.insert(window.window_number))
+        .map(|(_, window)| window)
+        .collect()
+}
+
+/// Subset of a Core Graphics window dictionary captured for bug reports
+#[derive(Debug, Clone, Serialize)]
+pub struct RawCgWindow {
+    pub owner_name: Option<String>,
+    pub owner_pid: Option<i64>,
+    pub window_number: Option<i64>,
+    pub window_name: Option<String>,
+    pub layer: Option<i64>,
+    pub is_editor: bool,
+}
+
+/// Reads the raw Core Graphics window list without any project processing
+///
+/// Only the keys swii relies on are copied out of each dictionary. When
+/// `include_all_apps` is false, windows of non-editor applications are skipped
+/// so unrelated window titles don't end up in bug reports.
+pub fn get_raw_window_list(include_all_apps: bool) -> Result<Vec<RawCgWindow>, WindowError> {
+    // SAFETY: This function is safe to call as it only reads window information
+    // and doesn't modify any system state.
+    let window_list_info = unsafe {
+        CGWindowListCopyWindowInfo(
+            kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+            0,
+        )
+    };
+
+    if window_list_info.is_null() {
+        return Err(WindowError::WindowListAccess {
+            message: "Core Graphics returned null window list".to_string(),
+        });
+    }
+
+    // SAFETY: window_list_info is guaranteed to be non-null at this point
+    let window_list: CFArray<CFDictionary> =
+        unsafe { CFArray::wrap_under_create_rule(window_list_info) };
+
+    let mut raw_windows = Vec::new();
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            // SAFETY: window_dict comes from CGWindowListCopyWindowInfo and is valid
+            let owner_name = unsafe { get_string_value(&window_dict, CG_WINDOW_OWNER_NAME) };
+            let is_editor = owner_name.as_deref().map(is_editor_window).unwrap_or(false);
+            if !include_all_apps && !is_editor {
+                continue;
+            }
+
+            raw_windows.push(RawCgWindow {
+                owner_name,
+                owner_pid: unsafe { get_number_value(&window_dict, CG_WINDOW_OWNER_PID) },
+                window_number: unsafe { get_number_value(&window_dict, CG_WINDOW_NUMBER) },
+                window_name: unsafe { get_string_value(&window_dict, CG_WINDOW_NAME) },
+                layer: unsafe { get_number_value(&window_dict, CG_WINDOW_LAYER) },
+                is_editor,
+            });
+        }
+    }
+
+    Ok(raw_windows)
+}
+
+/// Extracts window information from a macOS window dictionary
+///
+/// # Arguments
+///
+/// * `window_dict` - Core Foundation dictionary containing window information
+/// * `window_project_map` - Map of window numbers to project information
+/// * `timings` - Accumulates the time spent extracting app icons
+///
+/// # Returns
+///
+/// Returns `Some(WindowInfo)` if the window is an editor window and information
+/// was successfully extracted, `None` if it's not an editor window, or an error
+/// if extraction failed.
+///
+/// # Safety
+///
+/// This function is safe to call as it only reads from the provided dictionary
+/// and doesn't perform any unsafe operations itself.
+fn extract_editor_window_info(
+    window_dict: &CFDictionary,
+    window_project_map: &HashMap<u32, ParsedTitle>,
+    timings: &mut EnumerationTimings,
+) -> Result<Option<WindowInfo>, WindowError> {
+    // Extract basic window info
+    // SAFETY: get_string_value and get_number_value are safe to call with valid
+    // CFDictionary references obtained from Core Graphics APIs. The window_dict
+    // comes from CGWindowListCopyWindowInfo which returns valid dictionaries.
+    let app_name =
+        unsafe { get_string_value(window_dict, CG_WINDOW_OWNER_NAME) }.ok_or_else(|| {
+            WindowError::WindowInfoExtraction {
+                message: "Failed to extract app name".to_string(),
+            }
+        })?;
+
+    let window_name = unsafe { get_string_value(window_dict, CG_WINDOW_NAME) };
+    let pid = unsafe { get_number_value(window_dict, CG_WINDOW_OWNER_PID) }.ok_or_else(|| {
+        WindowError::WindowInfoExtraction {
+            message: "Failed to extract PID".to_string(),
+        }
+    })? as i32;
+
+    let window_number =
+        unsafe { get_number_value(window_dict, CG_WINDOW_NUMBER) }.ok_or_else(|| {
+            WindowError::WindowInfoExtraction {
+                message: "Failed to extract window number".to_string(),
+            }
+        })? as u32;
+
+    // Filter out windows that are not editor windows
+    let Some(app_name) = editor_app_name(pid, app_name) else {
+        return Ok(None);
+    };
+
+    // Never list the switcher itself, whatever its name matches
+    if editor_config::is_own_window(pid, &app_name, window_name.as_deref()) {
+        return Ok(None);
+    }
+
+    // Look up project info from our simple map
+    let parsed = window_project_map
+        .get(&window_number)
+        .cloned()
+        .unwrap_or_else(|| ParsedTitle {
+            editor_kind: EditorKind::from_app_name(&app_name),
+            ..ParsedTitle::default()
+        });
+
+    let parsed = crate::iterm2::resolve_shell_window(&app_name, window_name.as_deref(), parsed);
+
+    // Terminal windows are only listed when they run an editor
+    if editor_config::is_terminal_application(&app_name) && !parsed.editor_kind.is_terminal_editor()
+    {
+        return Ok(None);
+    }
+
+    // Get app icon - log errors but don't fail the entire operation
+    let icon_start = Instant::now();
+    let app_icon = match get_app_icon_for_pid(pid) {
+        Ok(icon) => {
+            if let Some(ref icon_data) = icon {
+                debug!(
+                    "Found app icon for {} (PID: {}), length: {}",
+                    app_name,
+                    pid,
+                    icon_data.len()
+                );
+            } else {
+                debug!("No app icon found for {} (PID: {})", app_name, pid);
+            }
+            icon
+        }
+        Err(e) => {
+            warn!(
+                "Failed to extract app icon for {} (PID: {}): {}",
+                app_name, pid, e
+            );
+            None
+        }
+    };
+    timings.icon_extraction_ms += elapsed_ms(icon_start);
+
+    Ok(Some(
+        WindowInfo::builder(app_name, pid, window_number)
+            .window_name(window_name)
+            .app_icon(app_icon)
+            .parsed(parsed)
+            .build(),
+    ))
+}
+
+/// Checks if an application's windows should be inspected: code editors,
+/// plus terminals when terminal editor support is enabled
+pub fn is_editor_window(app_name: &str) -> bool {
+    editor_config::should_inspect_application(app_name)
+}
+
+/// The name to list an application's windows under, or `None` when they
+/// shouldn't be inspected
+///
+/// Editors started through a renamed or symlinked launcher report an owner
+/// name that isn't in the editor list. Those are recognized by the bundle
+/// identifier of the executable the process runs and listed under the
+/// editor's usual name.
+fn editor_app_name(pid: i32, owner_name: String) -> Option<String> {
+    if is_editor_window(&owner_name) {
+        return Some(owner_name);
+    }
+    let executable = executable_path_for_pid(pid)?;
+    let name = editor_name_for_bundle(bundle_path_for_executable(&executable)?)?;
+    debug!("Listing {} (PID: {}) as {}", owner_name, pid, name);
+    Some(name.to_string())
+}
+
+/// The editor an app bundle belongs to, by its bundle identifier
+///
+/// Results are cached per bundle path, as most bundles checked here aren't
+/// editors and would otherwise have their Info.plist read on every listing.
+fn editor_name_for_bundle(bundle_path: &str) -> Option<&'static str> {
+    static BUNDLE_EDITORS: OnceLock<Mutex<HashMap<String, Option<&'static str>>>> = OnceLock::new();
+    let mut cache = BUNDLE_EDITORS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *cache.entry(bundle_path.to_string()).or_insert_with(|| {
+        let plist = std::fs::read_to_string(format!("{}/Contents/Info.plist", bundle_path)).ok()?;
+        let bundle_id = extract_plist_string(&plist, "CFBundleIdentifier")?;
+        editor_config::get_editor_name_for_bundle_id(&bundle_id)
+    })
+}
+
+/// Gets the app icon for a given PID
+///
+/// This function attempts to extract the application icon for a process
+/// by finding its bundle path and extracting the icon from the bundle.
+///
+/// # Arguments
+///
+/// * `pid` - Process ID of the application
+///
+/// # Returns
+///
+/// Returns the base64-encoded icon data if successful, None if the icon
+/// could not be extracted.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use swii_core::macos_window::get_app_icon_for_pid;
+///
+/// let icon = get_app_icon_for_pid(12345);
+/// match icon {
+///     Ok(Some(icon_data)) => println!("Found icon with {} bytes", icon_data.len()),
+///     Ok(None) => println!("No icon found"),
+///     Err(e) => println!("Error extracting icon: {}", e),
+/// }
+/// ```
+pub fn get_app_icon_for_pid(pid: i32) -> Result<Option<String>, WindowError> {
+    debug!("Getting app icon for PID: {}", pid);
+
+    // SAFETY: This function is safe to call as it only reads window information
+    // and doesn't modify any system state. The Core Graphics API is thread-safe
+    // for read operations.
+    let window_list_info = unsafe {
+        CGWindowListCopyWindowInfo(
+            kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+            0,
+        )
+    };
+
+    if window_list_info.is_null() {
+        warn!("Window list info is null for PID {}", pid);
+        return Err(WindowError::WindowListAccess {
+            message: format!("Failed to get window list for PID {}", pid),
+        });
+    }
+
+    // SAFETY: window_list_info is guaranteed to be non-null at this point
+    let window_list: CFArray<CFDictionary> =
+        unsafe { CFArray::wrap_under_create_rule(window_list_info) };
+
+    // Find a window for this PID to get the app bundle path
+    for i in 0..window_list.len() {
+        if let Some(window_dict) = window_list.get(i) {
+            // SAFETY: get_number_value is safe to call with valid CFDictionary references
+            // obtained from Core Graphics APIs.
+            if let Some(dict_pid) = unsafe { get_number_value(&window_dict, "kCGWindowOwnerPID") } {
+                if dict_pid as i32 == pid {
+                    // Try to get the app bundle path from the window info
+                    match get_app_bundle_path_for_pid(pid) {
+                        Ok(Some(bundle_path)) => {
+                            debug!("Found bundle path: {}", bundle_path);
+                            return get_icon_from_bundle_path(&bundle_path);
+                        }
+                        Ok(None) => {
+                            debug!("No bundle path found for PID: {}", pid);
+                        }
+                        Err(e) => {
+                            warn!("Failed to get bundle path for PID {}: {}", pid, e);
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    debug!("No app icon found for PID: {}", pid);
+    Ok(None)
+}
+
+/// Gets the app bundle path for a given PID
+///
+/// This function attempts to determine the bundle path of an application
+/// given its process ID by looking up the application name and mapping
+/// it to known bundle locations.
+///
+/// # Arguments
+///
+/// * `pid` - Process ID of the application
+///
+/// # Returns
+///
+/// Returns the bundle path as a string if found and the bundle exists,
+/// None if the bundle path could not be determined.
+///
+/// # Safety
+///
+/// This function is safe to call as it only reads window information
+/// and performs filesystem existence checks.
+fn get_app_bundle_path_for_pid(pid: i32) -> Result<Option<String>, WindowError> {
+    // SAFETY: This function is safe to call as it only reads window information
+    // and doesn't modify any system state. The Core Graphics API is thread-safe
+    // for read operations.
+    let window_list_info = unsafe {
+        CGWindowListCopyWindowInfo(
+            kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+            0,
+        )
+    };
+
+    if !window_list_info.is_null() {
+        // SAFETY: window_list_info is guaranteed to be non-null at this point
+        let window_list: CFArray<CFDictionary> =
+            unsafe { CFArray::wrap_under_create_rule(window_list_info) };
+
+        for i in 0..window_list.len() {
+            if let Some(window_dict) = window_list.get(i) {
+                // SAFETY: get_number_value and get_string_value are safe to call with valid
+                // CFDictionary references obtained from Core Graphics APIs.
+                if let Some(dict_pid) =
+                    unsafe { get_number_value(&window_dict, "kCGWindowOwnerPID") }
+                {
+                    if dict_pid as i32 == pid {
+                        if let Some(app_name) =
+                            unsafe { get_string_value(&window_dict, "kCGWindowOwnerName") }
+                        {
+                            // Look for a matching editor path using the centralized config
+                            if let Some(bundle_path) = editor_config::get_editor_path(&app_name) {
+                                // Check if the bundle exists
+                                if std::path::Path::new(bundle_path).exists() {
+                                    return Ok(Some(bundle_path.to_string()));
+                                }
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Path of the executable a process was started from
+fn executable_path_for_pid(pid: i32) -> Option<String> {
+    let mut buffer = vec![0u8; libc::PROC_PIDPATHINFO_MAXSIZE as usize];
+    // SAFETY: the buffer is writable and its size is passed along
+    let length = unsafe {
+        libc::proc_pidpath(
+            pid,
+            buffer.as_mut_ptr() as *mut std::ffi::c_void,
+            buffer.len() as u32,
+        )
+    };
+    if length <= 0 {
+        return None;
+    }
+    buffer.truncate(length as usize);
+    String::from_utf8(buffer).ok()
+}
+
+/// The `.app` bundle whose main executable is `executable`
+///
+/// Helpers nested inside another bundle, such as "Code Helper.app", are not
+/// applications of their own and yield `None`.
+fn bundle_path_for_executable(executable: &str) -> Option<&str> {
+    let end = executable.find(".app/")? + ".app".len();
+    let (bundle, rest) = executable.split_at(end);
+    let name = rest.strip_prefix("/Contents/MacOS/")?;
+    (!name.is_empty() && !name.contains('/')).then_some(bundle)
+}
+
+/// Opens `path` in the application owning `pid`, or a new window
+///
+/// `open -a` reopens a running application, which editors answer with an
+/// empty window when they have none.
+pub fn open_window_for_pid(pid: i32, path: Option<&str>) -> Result<(), WindowFocusError> {
+    let bundle_path = executable_path_for_pid(pid)
+        .and_then(|executable| bundle_path_for_executable(&executable).map(str::to_string))
+        .ok_or(WindowFocusError::ApplicationNotFound)?;
+
+    let mut command = std::process::Command::new("open");
+    command.arg("-a").arg(&bundle_path);
+    if let Some(path) = path {
+        command.arg(path);
+    }
+    let output = command
+        .output()
+        .map_err(|e| WindowFocusError::SystemError(e.to_string()))?;
+    if !output.status.success() {
+        return Err(WindowFocusError::SystemError(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Opens `target` with the editor CLI inside the bundle of `pid`, falling
+/// back to `open -a`, which can't place the cursor, when the bundle has no
+/// such tool
+pub fn open_file_for_pid(
+    pid: i32,
+    target: &FileTarget,
+    cli: Option<EditorCli>,
+) -> Result<(), WindowFocusError> {
+    let bundle_path = executable_path_for_pid(pid)
+        .and_then(|executable| bundle_path_for_executable(&executable).map(str::to_string))
+        .ok_or(WindowFocusError::ApplicationNotFound)?;
+    let tool = cli
+        .map(|cli| {
+            (
+                format!("{}/{}", bundle_path, cli.path),
+                cli.args_for(target),
+            )
+        })
+        .filter(|(tool, _)| std::path::Path::new(tool).exists());
+    let Some((tool, args)) = tool else {
+        return open_window_for_pid(pid, Some(&target.path));
+    };
+
+    let output = std::process::Command::new(tool)
+        .args(args)
+        .output()
+        .map_err(|e| WindowFocusError::SystemError(e.to_string()))?;
+    if !output.status.success() {
+        return Err(WindowFocusError::SystemError(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Lists running editor applications, including ones without windows
+///
+/// Every process whose executable is the main executable of an app bundle is
+/// looked up by the bundle's name, so no windows are needed.
+pub fn get_running_editors() -> Result<Vec<RunningApp>, WindowError> {
+    // SAFETY: a null buffer only asks for the number of processes
+    let count = unsafe { libc::proc_listallpids(std::ptr::null_mut(), 0) };
+    if count < 0 {
+        return Err(WindowError::WindowListAccess {
+            message: "Failed to count running processes".to_string(),
+        });
+    }
+
+    // Leave room for processes started in between
+    let mut pids = vec![0 as libc::pid_t; count as usize + 32];
+    // SAFETY: the buffer is writable and its size in bytes is passed along
+    let count = unsafe {
+        libc::proc_listallpids(
+            pids.as_mut_ptr() as *mut std::ffi::c_void,
+            (pids.len() * std::mem::size_of::<libc::pid_t>()) as libc::c_int,
+        )
+    };
+    if count < 0 {
+        return Err(WindowError::WindowListAccess {
+            message: "Failed to list running processes".to_string(),
+        });
+    }
+    pids.truncate(count as usize);
+
+    let mut apps = Vec::new();
+    for pid in pids {
+        let Some(executable) = executable_path_for_pid(pid) else {
+            continue;
+        };
+        let Some(bundle_path) = bundle_path_for_executable(&executable) else {
+            continue;
+        };
+
+        let plist = std::fs::read_to_string(format!("{}/Contents/Info.plist", bundle_path))
+            .unwrap_or_default();
+        let name = extract_plist_string(&plist, "CFBundleName").unwrap_or_else(|| {
+            std::path::Path::new(bundle_path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default()
+        });
+        if !editor_config::is_editor_application(&name) {
+            continue;
+        }
+
+        let icon = get_icon_from_bundle_path(bundle_path).unwrap_or_else(|e| {
+            warn!("Failed to extract app icon for {}: {}", name, e);
+            None
+        });
+        apps.push(RunningApp {
+            pid,
+            bundle_id: extract_plist_string(&plist, "CFBundleIdentifier"),
+            name,
+            icon,
+        });
+    }
+    Ok(apps)
+}
+
+/// Gets the app icon from a bundle path
+///
+/// This function extracts the application icon from a macOS application bundle
+/// by parsing the Info.plist file and locating the icon file.
+///
+/// # Arguments
+///
+/// * `bundle_path` - Path to the application bundle (.app directory)
+///
+/// # Returns
+///
+/// Returns the base64-encoded icon data if successful, None if extraction fails.
+///
+/// # Safety
+///
+/// This function is safe to call as it only performs file system operations
+/// and doesn't use any unsafe code internally.
+fn get_icon_from_bundle_path(bundle_path: &str) -> Result<Option<String>, WindowError> {
+    // Construct the Info.plist path
+    let info_plist_path = format!("{}/Contents/Info.plist", bundle_path);
+
+    // Try to get the icon file name from Info.plist
+    let plist_data =
+        std::fs::read_to_string(&info_plist_path).map_err(|e| WindowError::BundleAccess {
+            message: format!("Failed to read Info.plist from {}: {}", info_plist_path, e),
+        })?;
+
+    // Simple parsing to find CFBundleIconFile
+    if let Some(icon_file) = extract_plist_string(&plist_data, "CFBundleIconFile") {
+        let icon_path = if icon_file.ends_with(".icns") {
+            format!("{}/Contents/Resources/{}", bundle_path, icon_file)
+        } else {
+            format!("{}/Contents/Resources/{}.icns", bundle_path, icon_file)
+        };
+
+        // Convert icon to base64
+        return convert_icon_to_base64(&icon_path);
+    }
+
+    // Fallback: try to find any .icns file in Resources
+    let resources_path = format!("{}/Contents/Resources", bundle_path);
+    if let Ok(entries) = std::fs::read_dir(&resources_path) {
+        for entry in entries {
+            if let Ok(entry) = entry {
+                let path = entry.path();
+                if let Some(extension) = path.extension() {
+                    if extension == "icns" {
+                        if let Some(base64) = convert_icon_to_base64(&path.to_string_lossy())? {
+                            return Ok(Some(base64));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Extracts the string value of `key` from XML Info.plist content
+fn extract_plist_string(plist_content: &str, key: &str) -> Option<String> {
+    if let Some(start) = plist_content.find(&format!("<key>{}</key>", key)) {
+        if let Some(value_start) = plist_content[start..].find("<string>") {
+            let value_start = start + value_start + 8;
+            if let Some(value_end) = plist_content[value_start..].find("</string>") {
+                return Some(plist_content[value_start..value_start + value_end].to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Converts an icon file to base64 string
+///
+/// This function reads an icon file and converts its contents to a base64-encoded string.
+/// If the file is an icns file, it attempts to extract PNG data from it.
+///
+/// # Arguments
+///
+/// * `icon_path` - Path to the icon file
+///
+/// # Returns
+///
+/// Returns the base64-encoded icon data if successful, None if conversion fails.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use swii_core::macos_window::convert_icon_to_base64;
+///
+/// let base64_data = convert_icon_to_base64("/path/to/icon.icns");
+/// match base64_data {
+///     Ok(Some(data)) => println!("Converted icon: {}", data.len()),
+///     Ok(None) => println!("Conversion failed"),
+///     Err(e) => println!("Error: {}", e),
+/// }
+/// ```
+fn convert_icon_to_base64(icon_path: &str) -> Result<Option<String>, WindowError> {
+    use std::fs::File;
+    use std::io::Read;
+
+    let mut file = File::open(icon_path).map_err(|e| WindowError::IconExtraction {
+        message: format!("Failed to open icon file {}: {}", icon_path, e),
+    })?;
+
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)
+        .map_err(|e| WindowError::IconExtraction {
+            message: format!("Failed to read icon file {}: {}", icon_path, e),
+        })?;
+
+    // If it's an icns file, we need to convert it to PNG
+    if icon_path.ends_with(".icns") {
+        // For now, let's try to extract a PNG from the icns file
+        if let Some(png_data) = extract_png_from_icns(&buffer)? {
+            return Ok(Some(
+                base64::engine::general_purpose::STANDARD.encode(&png_data),
+            ));
+        }
+    }
+
+    // Convert to base64 using the new API
+    Ok(Some(
+        base64::engine::general_purpose::STANDARD.encode(&buffer),
+    ))
+}
+
+/// Attempts to extract PNG data from an icns file
+///
+/// This function parses an icns (Apple Icon Image) file and attempts to extract
+/// PNG data from it. It looks for various icon types that contain PNG data.
+///
+/// # Arguments
+///
+/// * `icns_data` - Raw bytes of the icns file
+///
+/// # Returns
+///
+/// Returns PNG data if found, or creates a fallback icon if no PNG is found.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use swii_core::macos_window::extract_png_from_icns;
+///
+/// let icns_bytes = std::fs::read("icon.icns").unwrap();
+/// let png_data = extract_png_from_icns(&icns_bytes);
+/// ```
+fn extract_png_from_icns(icns_data: &[u8]) -> Result<Option<Vec<u8>>, WindowError> {
+    debug!("Extracting PNG from icns file, size: {}", icns_data.len());
+
+    if !icns::is_icns(icns_data) {
+        warn!("Not a valid icns file - missing or invalid header");
+        return Ok(None);
+    }
+
+    if let Some(png_data) = icns::png_from_icns(icns_data) {
+        debug!("Found PNG icon, size: {}", png_data.len());
+        return Ok(Some(png_data.to_vec()));
+    }
+
+    debug!("No PNG icon found in icns file, creating fallback");
+    // If no PNG found, create a fallback icon
+    create_fallback_icon().map(Some)
+}
+
+/// Creates a simple fallback icon as PNG
+///
+/// This function generates a simple 32x32 gradient icon as a fallback
+/// when no icon can be extracted from an application bundle.
+///
+/// # Returns
+///
+/// Returns PNG data for a simple gradient icon.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use swii_core::macos_window::create_fallback_icon;
+///
+/// let fallback_png = create_fallback_icon();
+/// match fallback_png {
+///     Ok(png_data) => println!("Created fallback icon with {} bytes", png_data.len()),
+///     Err(e) => println!("Failed to create fallback icon: {}", e),
+/// }
+/// ```
+fn create_fallback_icon() -> Result<Vec<u8>, WindowError> {
+    use image::{ImageBuffer, Rgba};
+    use std::io::Cursor;
+
+    debug!("Creating fallback icon");
+
+    // Create a simple 32x32 icon with a gradient
+    let width = 32;
+    let height = 32;
+    let mut img = ImageBuffer::new(width, height);
+
+    for (x, y, pixel) in img.enumerate_pixels_mut() {
+        let r = (x as f32 / width as f32 * 255.0) as u8;
+        let g = (y as f32 / height as f32 * 255.0) as u8;
+        let b = 128;
+        let a = 255;
+        *pixel = Rgba([r, g, b, a]);
+    }
+
+    // Convert to PNG
+    let mut png_data = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png_data), image::ImageFormat::Png)
+        .map_err(|e| WindowError::IconExtraction {
+            message: format!("Failed to create fallback PNG: {}", e),
+        })?;
+
+    debug!("Created fallback icon with {} bytes", png_data.len());
+    Ok(png_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_path_for_executable() {
+        assert_eq!(
+            bundle_path_for_executable("/Applications/Zed.app/Contents/MacOS/zed"),
+            Some("/Applications/Zed.app")
+        );
+        assert_eq!(
+            bundle_path_for_executable(
+                "/Applications/Visual Studio Code.app/Contents/Frameworks/Code Helper.app/Contents/MacOS/Code Helper"
+            ),
+            None
+        );
+        assert_eq!(bundle_path_for_executable("/usr/bin/vim"), None);
+    }
+
+    #[test]
+    fn test_extract_plist_string() {
+        let plist = "<dict><key>CFBundleIdentifier</key><string>dev.zed.Zed</string>\
+                     <key>CFBundleName</key><string>Zed</string></dict>";
+        assert_eq!(
+            extract_plist_string(plist, "CFBundleIdentifier").as_deref(),
+            Some("dev.zed.Zed")
+        );
+        assert_eq!(
+            extract_plist_string(plist, "CFBundleName").as_deref(),
+            Some("Zed")
+        );
+        assert_eq!(extract_plist_string(plist, "CFBundleIconFile"), None);
+    }
+
+    #[test]
+    fn test_stable_window_order() {
+        let windows = crate::mock::mock_editor_windows();
+        let (first, second) = (windows[0].clone(), windows[1].clone());
+        let ordered = stable_window_order(vec![
+            (3, first.clone()),
+            (1, second.clone()),
+            (5, first.clone()),
+        ]);
+        let numbers: Vec<u32> = ordered.iter().map(|w| w.window_number).collect();
+        assert_eq!(numbers, vec![second.window_number, first.window_number]);
+    }
+}
@@ -0,0 +1,499 @@
+//! Synthetic editor windows for development without the macOS backend.
+//!
+//! Setting `SWII_MOCK_WINDOWS=1` or passing `--mock-windows` swaps in
+//! [`MockProvider`], which serves a realistic set of windows so the frontend
+//! can be developed and tested on Linux, Windows or CI where the CG/AX code
+//! paths are unavailable. Pointing either at a JSON file
+//! (`SWII_MOCK_WINDOWS=windows.json`, `--mock-windows=windows.json`) serves
+//! the windows listed there instead, each an object with `app_name`, `pid`,
+//! `window_number`, `title` and an optional `color`. The file holds either an
+//! array of windows or an object with `windows` and `windowless_editors`, the
+//! latter listing editors that run without windows (`app_name`, `pid` and an
+//! optional `bundle_id` and `color`). Titles go through the real title parser
+//! so project/tab values stay representative.
+
+use base64::Engine;
+use image::{ImageBuffer, Rgba};
+use serde::Deserialize;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use swii_title_parser::parse_title;
+
+use crate::editor_cli::{EditorCli, FileTarget};
+use crate::focus::WindowFocusError;
+use crate::provider::{RunningApp, WindowError, WindowProvider};
+use crate::types::WindowInfo;
+
+pub mod constants {
+    /// Environment variable that enables mock mode
+    pub const MOCK_ENV_VAR: &str = "SWII_MOCK_WINDOWS";
+
+    /// Command line flag that enables mock mode, optionally as
+    /// `--mock-windows=<file>`
+    pub const MOCK_FLAG: &str = "--mock-windows";
+
+    /// Size of the generated placeholder icons
+    pub const MOCK_ICON_SIZE: u32 = 32;
+
+    /// Icon colour of windows read from a file that don't set one
+    pub const MOCK_DEFAULT_COLOR: [u8; 3] = [128, 128, 128];
+}
+
+/// A synthetic window definition: app name, pid, window number, title, icon colour
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct MockWindowSpec {
+    app_name: String,
+    pid: i32,
+    window_number: u32,
+    title: String,
+    #[serde(default = "default_color")]
+    color: [u8; 3],
+}
+
+/// A synthetic editor running without windows: app name, pid, bundle id, icon colour
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct MockEditorSpec {
+    app_name: String,
+    pid: i32,
+    #[serde(default)]
+    bundle_id: Option<String>,
+    #[serde(default = "default_color")]
+    color: [u8; 3],
+}
+
+/// The windows of a mock source and the editors running without any
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+struct MockData {
+    windows: Vec<MockWindowSpec>,
+    #[serde(default)]
+    windowless_editors: Vec<MockEditorSpec>,
+}
+
+/// A mock window file: a bare array of windows, or windows together with
+/// windowless editors
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MockFile {
+    Windows(Vec<MockWindowSpec>),
+    Data(MockData),
+}
+
+/// The windows served when no file is given
+const MOCK_WINDOWS: &[(&str, i32, u32, &str, [u8; 3])] = &[
+    ("Cursor", 41001, 5101, "main.rs — swii", [30, 30, 30]),
+    (
+        "Cursor",
+        41001,
+        5102,
+        "bun run tauri dev — promptbook",
+        [30, 30, 30],
+    ),
+    (
+        "Visual Studio Code",
+        41002,
+        5201,
+        "App.tsx - dashboard - Visual Studio Code",
+        [0, 122, 204],
+    ),
+    ("Zed", 41003, 5301, "api — handlers.rs", [8, 66, 160]),
+    (
+        "IntelliJ IDEA",
+        41004,
+        5401,
+        "billing-service [~/work/billing-service] - IntelliJ IDEA",
+        [254, 49, 93],
+    ),
+    ("Xcode", 41005, 5501, "MyiOSApp", [20, 126, 251]),
+];
+
+/// The editors running without windows when no file is given
+const MOCK_WINDOWLESS_EDITORS: &[(&str, i32, &str, [u8; 3])] =
+    &[("Sublime Text", 41006, "com.sublimetext.4", [255, 152, 0])];
+
+fn default_color() -> [u8; 3] {
+    constants::MOCK_DEFAULT_COLOR
+}
+
+fn builtin_data() -> MockData {
+    MockData {
+        windows: MOCK_WINDOWS
+            .iter()
+            .map(
+                |&(app_name, pid, window_number, title, color)| MockWindowSpec {
+                    app_name: app_name.to_string(),
+                    pid,
+                    window_number,
+                    title: title.to_string(),
+                    color,
+                },
+            )
+            .collect(),
+        windowless_editors: MOCK_WINDOWLESS_EDITORS
+            .iter()
+            .map(|&(app_name, pid, bundle_id, color)| MockEditorSpec {
+                app_name: app_name.to_string(),
+                pid,
+                bundle_id: Some(bundle_id.to_string()),
+                color,
+            })
+            .collect(),
+    }
+}
+
+/// Where the synthetic windows come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockSource {
+    /// The built-in set of windows
+    BuiltIn,
+    /// A JSON file with an array of windows
+    File(PathBuf),
+}
+
+/// Returns the mock source requested on the command line or through the
+/// environment, the flag taking precedence
+pub fn source() -> Option<&'static MockSource> {
+    static SOURCE: OnceLock<Option<MockSource>> = OnceLock::new();
+    SOURCE
+        .get_or_init(|| {
+            source_from_args(std::env::args().skip(1)).or_else(|| {
+                std::env::var(constants::MOCK_ENV_VAR)
+                    .ok()
+                    .and_then(|value| source_from_env(&value))
+            })
+        })
+        .as_ref()
+}
+
+/// Returns true when mock mode has been requested through the command line
+/// or the environment
+pub fn is_enabled() -> bool {
+    source().is_some()
+}
+
+fn source_from_args(args: impl IntoIterator<Item = String>) -> Option<MockSource> {
+    args.into_iter().find_map(|arg| {
+        let rest = arg.strip_prefix(constants::MOCK_FLAG)?;
+        if rest.is_empty() {
+            return Some(MockSource::BuiltIn);
+        }
+        let path = rest.strip_prefix('=')?;
+        Some(if path.is_empty() {
+            MockSource::BuiltIn
+        } else {
+            MockSource::File(PathBuf::from(path))
+        })
+    })
+}
+
+fn source_from_env(value: &str) -> Option<MockSource> {
+    if is_truthy(value) {
+        Some(MockSource::BuiltIn)
+    } else if is_falsy(value) {
+        None
+    } else {
+        Some(MockSource::File(PathBuf::from(value.trim())))
+    }
+}
+
+fn is_truthy(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+fn is_falsy(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "" | "0" | "false" | "no" | "off"
+    )
+}
+
+/// Reads a window set from a JSON file
+fn load_data(path: &Path) -> Result<MockData, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let file = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+    Ok(match file {
+        MockFile::Windows(windows) => MockData {
+            windows,
+            ..MockData::default()
+        },
+        MockFile::Data(data) => data,
+    })
+}
+
+/// The data of the requested source, read once; a file that can't be read
+/// is reported on every listing rather than silently replaced
+fn data() -> Result<&'static MockData, WindowError> {
+    static DATA: OnceLock<Result<MockData, String>> = OnceLock::new();
+    DATA.get_or_init(|| match source() {
+        Some(MockSource::File(path)) => load_data(path),
+        _ => Ok(builtin_data()),
+    })
+    .as_ref()
+    .map_err(|message| WindowError::WindowListAccess {
+        message: message.clone(),
+    })
+}
+
+/// The windows of the requested source
+fn specs() -> Result<&'static [MockWindowSpec], WindowError> {
+    data().map(|data| data.windows.as_slice())
+}
+
+fn window_info(spec: &MockWindowSpec) -> WindowInfo {
+    let parsed = parse_title(Some(&spec.app_name), &spec.title);
+    WindowInfo::builder(spec.app_name.as_str(), spec.pid, spec.window_number)
+        .window_name(Some(spec.title.as_str()))
+        .app_icon(mock_icon(spec.color))
+        .parsed(parsed)
+        .build()
+}
+
+/// Builds the built-in synthetic editor window list
+pub fn mock_editor_windows() -> Vec<WindowInfo> {
+    builtin_data().windows.iter().map(window_info).collect()
+}
+
+/// Window provider serving the synthetic windows of the requested source
+pub struct MockProvider;
+
+impl WindowProvider for MockProvider {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn list_windows(&self) -> Result<Vec<WindowInfo>, WindowError> {
+        Ok(specs()?.iter().map(window_info).collect())
+    }
+
+    fn focus_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+        specs()
+            .unwrap_or_default()
+            .iter()
+            .find(|spec| spec.pid == pid && spec.window_number == window_number)
+            .map(|_| ())
+            .ok_or(WindowFocusError::WindowNotFound)
+    }
+
+    fn app_icon(&self, pid: i32) -> Result<Option<String>, WindowError> {
+        Ok(specs()?
+            .iter()
+            .find(|spec| spec.pid == pid)
+            .and_then(|spec| mock_icon(spec.color)))
+    }
+
+    fn open_window(&self, pid: i32, _path: Option<&str>) -> Result<(), WindowFocusError> {
+        let running = data().is_ok_and(|data| {
+            data.windows.iter().any(|spec| spec.pid == pid)
+                || data.windowless_editors.iter().any(|spec| spec.pid == pid)
+        });
+        running
+            .then_some(())
+            .ok_or(WindowFocusError::ApplicationNotFound)
+    }
+
+    fn open_file(
+        &self,
+        pid: i32,
+        _target: &FileTarget,
+        _cli: Option<EditorCli>,
+    ) -> Result<(), WindowFocusError> {
+        specs()
+            .unwrap_or_default()
+            .iter()
+            .any(|spec| spec.pid == pid)
+            .then_some(())
+            .ok_or(WindowFocusError::ApplicationNotFound)
+    }
+
+    fn running_editors(&self) -> Result<Vec<RunningApp>, WindowError> {
+        let data = data()?;
+        let mut apps: Vec<RunningApp> = Vec::new();
+        for spec in &data.windows {
+            if apps.iter().any(|app| app.pid == spec.pid) {
+                continue;
+            }
+            apps.push(RunningApp {
+                pid: spec.pid,
+                name: spec.app_name.clone(),
+                bundle_id: None,
+                icon: mock_icon(spec.color),
+            });
+        }
+
+        for spec in &data.windowless_editors {
+            if apps.iter().any(|app| app.pid == spec.pid) {
+                continue;
+            }
+            apps.push(RunningApp {
+                pid: spec.pid,
+                name: spec.app_name.clone(),
+                bundle_id: spec.bundle_id.clone(),
+                icon: mock_icon(spec.color),
+            });
+        }
+        Ok(apps)
+    }
+}
+
+/// Renders a solid-colour PNG icon and returns it base64-encoded
+fn mock_icon(color: [u8; 3]) -> Option<String> {
+    let size = constants::MOCK_ICON_SIZE;
+    let img = ImageBuffer::from_pixel(size, size, Rgba([color[0], color[1], color[2], 255]));
+
+    let mut png_data = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png_data), image::ImageFormat::Png)
+        .ok()?;
+
+    Some(base64::engine::general_purpose::STANDARD.encode(&png_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_is_truthy() {
+        assert!(is_truthy("1"));
+        assert!(is_truthy("true"));
+        assert!(is_truthy(" YES "));
+        assert!(!is_truthy("0"));
+        assert!(!is_truthy("false"));
+        assert!(!is_truthy(""));
+    }
+
+    #[test]
+    fn test_source_from_args() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            source_from_args(args(&["--verbose", "--mock-windows"])),
+            Some(MockSource::BuiltIn)
+        );
+        assert_eq!(
+            source_from_args(args(&["--mock-windows=/tmp/windows.json"])),
+            Some(MockSource::File(PathBuf::from("/tmp/windows.json")))
+        );
+        assert_eq!(source_from_args(args(&["--mock-windowsx"])), None);
+        assert_eq!(source_from_args(args(&[])), None);
+    }
+
+    #[test]
+    fn test_source_from_env() {
+        assert_eq!(source_from_env("1"), Some(MockSource::BuiltIn));
+        assert_eq!(source_from_env("off"), None);
+        assert_eq!(source_from_env(""), None);
+        assert_eq!(
+            source_from_env(" windows.json "),
+            Some(MockSource::File(PathBuf::from("windows.json")))
+        );
+    }
+
+    #[test]
+    fn test_load_data() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("windows.json");
+        std::fs::write(
+            &path,
+            r#"[
+                {"app_name": "Zed", "pid": 7, "window_number": 70, "title": "web — app.ts"},
+                {"app_name": "Cursor", "pid": 8, "window_number": 80, "title": "lib.rs — core", "color": [1, 2, 3]}
+            ]"#,
+        )
+        .unwrap();
+        let data = load_data(&path).unwrap();
+        assert_eq!(data.windows.len(), 2);
+        assert!(data.windowless_editors.is_empty());
+        assert_eq!(data.windows[0].color, constants::MOCK_DEFAULT_COLOR);
+        assert_eq!(data.windows[1].color, [1, 2, 3]);
+        assert_eq!(
+            window_info(&data.windows[0]).project.as_deref(),
+            Some("web")
+        );
+
+        std::fs::write(
+            &path,
+            r#"{
+                "windows": [{"app_name": "Zed", "pid": 7, "window_number": 70, "title": "web — app.ts"}],
+                "windowless_editors": [{"app_name": "Sublime Text", "pid": 9}]
+            }"#,
+        )
+        .unwrap();
+        let data = load_data(&path).unwrap();
+        assert_eq!(data.windows.len(), 1);
+        assert_eq!(data.windowless_editors[0].pid, 9);
+        assert_eq!(data.windowless_editors[0].bundle_id, None);
+        assert_eq!(
+            data.windowless_editors[0].color,
+            constants::MOCK_DEFAULT_COLOR
+        );
+
+        std::fs::write(&path, r#"[{"app_name": "Zed"}]"#).unwrap();
+        assert!(load_data(&path).unwrap_err().contains("Failed to parse"));
+        std::fs::remove_file(&path).unwrap();
+        assert!(load_data(&path).unwrap_err().contains("Failed to read"));
+    }
+
+    #[test]
+    fn test_mock_windows_are_well_formed() {
+        let windows = mock_editor_windows();
+        assert!(!windows.is_empty());
+
+        let numbers: HashSet<u32> = windows.iter().map(|w| w.window_number).collect();
+        assert_eq!(
+            numbers.len(),
+            windows.len(),
+            "window numbers must be unique"
+        );
+
+        for window in windows.iter() {
+            assert!(!window.app_name.is_empty());
+            assert!(window.pid > 0);
+            assert!(
+                window.project.is_some(),
+                "{:?} should parse",
+                window.window_name
+            );
+            assert!(window.app_icon.is_some());
+        }
+    }
+
+    #[test]
+    fn test_mock_provider_focus() {
+        let provider = MockProvider;
+        assert_eq!(provider.focus_window(41001, 5101), Ok(()));
+        assert_eq!(
+            provider.focus_window(41001, 9999),
+            Err(WindowFocusError::WindowNotFound)
+        );
+        assert!(provider.app_icon(41002).unwrap().is_some());
+        assert!(provider.app_icon(1).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_mock_provider_running_editors() {
+        let apps = MockProvider.running_editors().unwrap();
+        let pids: HashSet<i32> = apps.iter().map(|app| app.pid).collect();
+        assert_eq!(pids.len(), apps.len(), "each app is listed once");
+
+        let windowless = apps
+            .iter()
+            .find(|app| app.pid == MOCK_WINDOWLESS_EDITORS[0].1)
+            .unwrap();
+        assert!(!MOCK_WINDOWS.iter().any(|spec| spec.1 == windowless.pid));
+        assert!(windowless.icon.is_some());
+    }
+
+    #[test]
+    fn test_mock_icon_is_png() {
+        let encoded = mock_icon([1, 2, 3]).unwrap();
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        assert_eq!(&decoded[0..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
@@ -0,0 +1,313 @@
+//! Platform abstraction for window enumeration and focusing.
+//!
+//! Every backend (macOS, Windows, Wayland, the mock provider) implements
+//! [`WindowProvider`]. [`platform_provider`] returns the backend compiled in
+//! for this platform; the app and the plugin use it unless the mock provider
+//! is enabled at runtime through `SWII_MOCK_WINDOWS`.
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::editor_cli::{EditorCli, FileTarget};
+use crate::focus::WindowFocusError;
+use crate::types::WindowInfo;
+
+/// Errors that can occur during window management operations
+#[derive(Debug, Error)]
+pub enum WindowError {
+    #[error("Failed to access window list: {message}")]
+    WindowListAccess { message: String },
+
+    #[error("Failed to read window information: {message}")]
+    WindowInfoExtraction { message: String },
+
+    #[error("Failed to extract app icon: {message}")]
+    IconExtraction { message: String },
+
+    #[error("Failed to read bundle information: {message}")]
+    BundleAccess { message: String },
+
+    #[error("{operation} is not supported by the {provider} window provider")]
+    Unsupported {
+        provider: &'static str,
+        operation: &'static str,
+    },
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Base64 encoding error: {0}")]
+    Base64(#[from] base64::DecodeError),
+}
+
+/// Window changes reported by providers that support observation
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WindowEvent {
+    TitleChanged {
+        pid: i32,
+        window_number: Option<u32>,
+        title: String,
+    },
+    Opened {
+        pid: i32,
+    },
+    Closed {
+        pid: i32,
+    },
+    /// An application came to the front or focused another of its windows
+    Focused {
+        pid: i32,
+        window_number: Option<u32>,
+    },
+}
+
+/// What the active provider can do on this system
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ProviderCapabilities {
+    pub provider: &'static str,
+    pub list_windows: bool,
+    pub focus_windows: bool,
+    pub observe_windows: bool,
+    /// Why a capability is missing, when known
+    pub detail: Option<String>,
+}
+
+/// Timing for project extraction of a single editor application
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct AppExtractionTiming {
+    pub app_name: String,
+    pub pid: i32,
+    pub elapsed_ms: f64,
+}
+
+/// Per-phase timing breakdown collected during window enumeration; only the
+/// macOS provider fills it in
+#[derive(Debug, Default, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct EnumerationTimings {
+    pub cg_enumeration_ms: f64,
+    pub ax_extraction_ms: f64,
+    pub ax_extraction_per_app: Vec<AppExtractionTiming>,
+    pub icon_extraction_ms: f64,
+    pub window_info_ms: f64,
+}
+
+/// A tab of an editor window, as exposed by the platform
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowTab {
+    pub title: String,
+    /// Whether the tab is the one currently shown
+    pub selected: bool,
+}
+
+/// A running editor application, whether or not it has windows
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningApp {
+    pub pid: i32,
+    pub name: String,
+    /// Platform identifier of the application, e.g. "dev.zed.Zed"
+    pub bundle_id: Option<String>,
+    /// Base64-encoded application icon
+    pub icon: Option<String>,
+}
+
+/// Callback invoked by a provider for every observed window event
+pub type WindowEventCallback = Box<dyn Fn(WindowEvent) + Send + Sync + 'static>;
+
+/// A source of editor windows that can also focus them
+pub trait WindowProvider: Send + Sync {
+    /// Short identifier used in logs and diagnostics
+    fn name(&self) -> &'static str;
+
+    /// Lists all editor windows currently known to the provider
+    fn list_windows(&self) -> Result<Vec<WindowInfo>, WindowError>;
+
+    /// Brings the given window to the front
+    fn focus_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError>;
+
+    /// Brings the given window to the front without bringing the rest of its
+    /// application's windows along. Platforms that focus single windows
+    /// anyway fall back to `focus_window`.
+    fn raise_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+        self.focus_window(pid, window_number)
+    }
+
+    /// Returns the base64-encoded icon of the application owning `pid`
+    fn app_icon(&self, pid: i32) -> Result<Option<String>, WindowError>;
+
+    /// Lists the tabs of a window, when the platform exposes them
+    fn window_tabs(&self, _pid: i32, _window_number: u32) -> Result<Vec<WindowTab>, WindowError> {
+        Ok(Vec::new())
+    }
+
+    /// The document a window shows, as exposed by the platform (a path or a
+    /// `file://` URL), when it exposes one
+    fn window_document(
+        &self,
+        _pid: i32,
+        _window_number: u32,
+    ) -> Result<Option<String>, WindowError> {
+        Ok(None)
+    }
+
+    /// Opens `path` in the application owning `pid`, or a new window when no
+    /// path is given
+    fn open_window(&self, _pid: i32, _path: Option<&str>) -> Result<(), WindowFocusError> {
+        Err(WindowFocusError::SystemError(format!(
+            "Opening windows is not supported by the {} window provider",
+            self.name()
+        )))
+    }
+
+    /// Opens a file in the front window of the application owning `pid`,
+    /// through the editor's command line tool when it has one
+    fn open_file(
+        &self,
+        _pid: i32,
+        _target: &FileTarget,
+        _cli: Option<EditorCli>,
+    ) -> Result<(), WindowFocusError> {
+        Err(WindowFocusError::SystemError(format!(
+            "Opening files is not supported by the {} window provider",
+            self.name()
+        )))
+    }
+
+    /// Lists running editor applications, including ones without windows
+    fn running_editors(&self) -> Result<Vec<RunningApp>, WindowError> {
+        Err(WindowError::Unsupported {
+            provider: self.name(),
+            operation: "Listing running applications",
+        })
+    }
+
+    /// Starts delivering window events to `callback`
+    fn observe(&self, _callback: WindowEventCallback) -> Result<(), WindowError> {
+        Err(WindowError::Unsupported {
+            provider: self.name(),
+            operation: "Observing windows",
+        })
+    }
+
+    /// Detaches from the observed applications while `paused`, so nothing
+    /// wakes the app while the user is away. Providers that don't observe
+    /// ignore it.
+    fn pause_observation(&self, _paused: bool) {}
+
+    /// Reports which operations work on this system
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            provider: self.name(),
+            list_windows: true,
+            focus_windows: true,
+            observe_windows: false,
+            detail: None,
+        }
+    }
+}
+
+/// The native backend of the platform this crate was compiled for
+#[cfg(target_os = "macos")]
+pub fn platform_provider() -> &'static dyn WindowProvider {
+    &crate::macos_window::MacOsProvider
+}
+
+/// The native backend of the platform this crate was compiled for
+#[cfg(target_os = "windows")]
+pub fn platform_provider() -> &'static dyn WindowProvider {
+    &crate::windows_window::WindowsProvider
+}
+
+/// The native backend of the platform this crate was compiled for: the
+/// Wayland backend inside a Wayland session, none otherwise
+#[cfg(target_os = "linux")]
+pub fn platform_provider() -> &'static dyn WindowProvider {
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        &crate::wayland_window::WaylandProvider
+    } else {
+        &UnsupportedProvider
+    }
+}
+
+/// The native backend of the platform this crate was compiled for; there is
+/// none on this platform
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+pub fn platform_provider() -> &'static dyn WindowProvider {
+    &UnsupportedProvider
+}
+
+/// Fallback provider for platforms without a native backend
+pub struct UnsupportedProvider;
+
+impl WindowProvider for UnsupportedProvider {
+    fn name(&self) -> &'static str {
+        "unsupported"
+    }
+
+    fn list_windows(&self) -> Result<Vec<WindowInfo>, WindowError> {
+        Ok(Vec::new())
+    }
+
+    fn focus_window(&self, _pid: i32, _window_number: u32) -> Result<(), WindowFocusError> {
+        Err(WindowFocusError::SystemError(
+            "Window focusing is not supported on this platform".to_string(),
+        ))
+    }
+
+    fn app_icon(&self, _pid: i32) -> Result<Option<String>, WindowError> {
+        Ok(None)
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            provider: self.name(),
+            list_windows: false,
+            focus_windows: false,
+            observe_windows: false,
+            detail: Some("No window backend is available for this platform".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_provider_behaviour() {
+        let provider = UnsupportedProvider;
+        assert_eq!(provider.list_windows().unwrap().len(), 0);
+        assert!(provider.focus_window(1, 1).is_err());
+        assert_eq!(provider.app_icon(1).unwrap(), None);
+
+        let capabilities = provider.capabilities();
+        assert!(!capabilities.list_windows);
+        assert!(!capabilities.focus_windows);
+        assert!(capabilities.detail.is_some());
+    }
+
+    #[test]
+    fn test_observe_defaults_to_unsupported() {
+        let err = UnsupportedProvider.observe(Box::new(|_| {})).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Observing windows is not supported by the unsupported window provider"
+        );
+    }
+
+    #[test]
+    fn test_window_event_serialization() {
+        let event = WindowEvent::TitleChanged {
+            pid: 42,
+            window_number: Some(7),
+            title: "main.rs — swii".to_string(),
+        };
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["kind"], "title_changed");
+        assert_eq!(value["pid"], 42);
+        assert_eq!(value["window_number"], 7);
+    }
+}
@@ -0,0 +1,278 @@
+//! Common types used across the application.
+//!
+//! [`WindowInfo`] is also read by external consumers such as CLIs, and
+//! recorded snapshots are read back by later builds, so its serialized form
+//! carries a version. Bump [`constants::WINDOW_SCHEMA_VERSION`] when a field is
+//! renamed or removed or its meaning changes, and convert older payloads in
+//! [`WindowInfo::upgrade`]. New fields don't need a bump as long as they have a
+//! serde default.
+
+use serde::{Deserialize, Serialize};
+use swii_title_parser::{DevEnvironment, EditorKind, ParsedTitle, ProjectSource};
+
+pub mod constants {
+    /// Version of the serialized `WindowInfo` layout
+    pub const WINDOW_SCHEMA_VERSION: u32 = 1;
+
+    /// `WindowInfo::pid` of a window whose process the backend couldn't
+    /// find; it's never a real process, so per-process lookups skip it
+    pub const UNKNOWN_PID: i32 = 0;
+}
+
+/// Window information structure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct WindowInfo {
+    pub app_name: String,
+    pub window_name: Option<String>,
+    /// Owning process, [`constants::UNKNOWN_PID`] when it couldn't be found
+    pub pid: i32,
+    pub window_number: u32,
+    pub project: Option<String>,
+    pub active_editor_tab: Option<String>,
+    pub app_icon: Option<String>,
+    #[serde(default)]
+    pub editor_kind: EditorKind,
+    /// Whether the window title marks unsaved changes
+    #[serde(default)]
+    pub is_modified: bool,
+    /// Host of a remote SSH window
+    #[serde(default)]
+    pub remote_host: Option<String>,
+    /// Dev container, WSL distribution or codespace of a remote window
+    #[serde(default)]
+    pub environment: Option<DevEnvironment>,
+    /// Whether the project is a VS Code multi-root workspace
+    #[serde(default)]
+    pub workspace: bool,
+    /// All worktrees of a Zed window when more than one is open, primary first
+    #[serde(default)]
+    pub worktrees: Vec<String>,
+    /// Dialog, diff viewer or welcome screen rather than a project window
+    #[serde(default)]
+    pub auxiliary: bool,
+    /// Window shows only an unnamed buffer such as "Untitled-1"
+    #[serde(default)]
+    pub is_scratch: bool,
+    /// Project name with a disambiguator when several windows share it,
+    /// e.g. "api (prod-box)" or "api (2)"
+    #[serde(default)]
+    pub project_label: Option<String>,
+    /// Absolute root directory of the project, when it could be resolved
+    /// from the open document
+    #[serde(default)]
+    pub project_path: Option<String>,
+    /// Whether `project` came from the title or from the open document
+    #[serde(default)]
+    pub project_source: ProjectSource,
+    /// Whether the project runs in a dev container or Docker container,
+    /// from the title or a dev container config in the project root
+    #[serde(default)]
+    pub containerized: bool,
+    /// False for an entry standing in for a running editor without windows;
+    /// `project` and `project_path` then name the project it showed last
+    #[serde(default = "default_has_windows")]
+    pub has_windows: bool,
+    /// Name the user gave the window with `set_window_label`
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Layout version of this payload; 0 for payloads written before
+    /// versioning was added
+    #[serde(default)]
+    pub schema_version: u32,
+}
+
+fn default_has_windows() -> bool {
+    true
+}
+
+impl WindowInfo {
+    /// Starts a window of `app_name` with no title, project or icon; the
+    /// editor kind is guessed from the app name until set otherwise
+    pub fn builder(app_name: impl Into<String>, pid: i32, window_number: u32) -> WindowInfoBuilder {
+        let app_name = app_name.into();
+        WindowInfoBuilder {
+            window: WindowInfo {
+                editor_kind: EditorKind::from_app_name(&app_name),
+                app_name,
+                window_name: None,
+                pid,
+                window_number,
+                project: None,
+                active_editor_tab: None,
+                app_icon: None,
+                is_modified: false,
+                remote_host: None,
+                environment: None,
+                workspace: false,
+                worktrees: Vec::new(),
+                auxiliary: false,
+                is_scratch: false,
+                project_label: None,
+                project_path: None,
+                project_source: ProjectSource::Title,
+                containerized: false,
+                has_windows: true,
+                label: None,
+                schema_version: constants::WINDOW_SCHEMA_VERSION,
+            },
+        }
+    }
+
+    /// Converts a deserialized window to the current layout
+    ///
+    /// Fails for payloads written by a newer build, whose fields may mean
+    /// something this build doesn't know about.
+    pub fn upgrade(mut self) -> Result<Self, String> {
+        if self.schema_version > constants::WINDOW_SCHEMA_VERSION {
+            return Err(format!(
+                "Window schema version {} is newer than the supported version {}",
+                self.schema_version,
+                constants::WINDOW_SCHEMA_VERSION
+            ));
+        }
+        // Version 0 payloads only lack fields that have defaults
+        self.schema_version = constants::WINDOW_SCHEMA_VERSION;
+        Ok(self)
+    }
+}
+
+/// Builds a [`WindowInfo`]; start one with [`WindowInfo::builder`]
+#[derive(Debug, Clone)]
+pub struct WindowInfoBuilder {
+    window: WindowInfo,
+}
+
+impl WindowInfoBuilder {
+    pub fn window_name<S: Into<String>>(mut self, window_name: Option<S>) -> Self {
+        self.window.window_name = window_name.map(Into::into);
+        self
+    }
+
+    pub fn project<S: Into<String>>(mut self, project: Option<S>) -> Self {
+        self.window.project = project.map(Into::into);
+        self
+    }
+
+    pub fn active_editor_tab<S: Into<String>>(mut self, tab: Option<S>) -> Self {
+        self.window.active_editor_tab = tab.map(Into::into);
+        self
+    }
+
+    pub fn app_icon(mut self, app_icon: Option<String>) -> Self {
+        self.window.app_icon = app_icon;
+        self
+    }
+
+    pub fn editor_kind(mut self, editor_kind: EditorKind) -> Self {
+        self.window.editor_kind = editor_kind;
+        self
+    }
+
+    pub fn remote_host<S: Into<String>>(mut self, remote_host: Option<S>) -> Self {
+        self.window.remote_host = remote_host.map(Into::into);
+        self
+    }
+
+    pub fn environment(mut self, environment: Option<DevEnvironment>) -> Self {
+        self.window.environment = environment;
+        self
+    }
+
+    pub fn project_label<S: Into<String>>(mut self, project_label: Option<S>) -> Self {
+        self.window.project_label = project_label.map(Into::into);
+        self
+    }
+
+    pub fn project_path<S: Into<String>>(mut self, project_path: Option<S>) -> Self {
+        self.window.project_path = project_path.map(Into::into);
+        self
+    }
+
+    pub fn project_source(mut self, project_source: ProjectSource) -> Self {
+        self.window.project_source = project_source;
+        self
+    }
+
+    /// Marks an entry standing in for a running editor without windows
+    pub fn has_windows(mut self, has_windows: bool) -> Self {
+        self.window.has_windows = has_windows;
+        self
+    }
+
+    /// Takes every field the title parser fills in from `parsed`
+    pub fn parsed(mut self, parsed: ParsedTitle) -> Self {
+        self.window.project = parsed.project;
+        self.window.active_editor_tab = parsed.tab;
+        self.window.editor_kind = parsed.editor_kind;
+        self.window.is_modified = parsed.is_modified;
+        self.window.remote_host = parsed.remote_host;
+        self.window.environment = parsed.environment;
+        self.window.workspace = parsed.workspace;
+        self.window.worktrees = parsed.worktrees;
+        self.window.auxiliary = parsed.auxiliary;
+        self.window.is_scratch = parsed.is_scratch;
+        self.window.project_path = parsed.project_path;
+        self.window.project_source = parsed.project_source;
+        self
+    }
+
+    pub fn build(self) -> WindowInfo {
+        self.window
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_unversioned_window() {
+        let window: WindowInfo = serde_json::from_str(
+            r#"{"app_name":"Zed","window_name":"api","pid":7,"window_number":3,
+                "project":"api","active_editor_tab":null,"app_icon":null}"#,
+        )
+        .unwrap();
+        assert_eq!(window.schema_version, 0);
+
+        let window = window.upgrade().unwrap();
+        assert_eq!(window.schema_version, constants::WINDOW_SCHEMA_VERSION);
+        assert!(window.has_windows);
+
+        let newer = WindowInfo {
+            schema_version: constants::WINDOW_SCHEMA_VERSION + 1,
+            ..window
+        };
+        assert!(newer.upgrade().is_err());
+    }
+    #[test]
+    fn test_builder() {
+        let parsed = swii_title_parser::parse_title(Some("Zed"), "api — handlers.rs");
+        let window = WindowInfo::builder("Zed", 7, 3)
+            .window_name(Some("api — handlers.rs"))
+            .parsed(parsed)
+            .build();
+        assert_eq!(window.editor_kind, EditorKind::Zed);
+        assert_eq!(window.project.as_deref(), Some("api"));
+        assert_eq!(window.active_editor_tab.as_deref(), Some("handlers.rs"));
+        assert!(window.has_windows);
+        assert_eq!(window.schema_version, constants::WINDOW_SCHEMA_VERSION);
+
+        let windowless = WindowInfo::builder("Zed", 7, 0).has_windows(false).build();
+        assert!(!windowless.has_windows);
+        assert_eq!(windowless.project, None);
+    }
+
+    #[test]
+    fn test_window_round_trip() {
+        let window = WindowInfo::builder("Visual Studio Code", 41002, 5201)
+            .window_name(Some("App.tsx - dashboard - Visual Studio Code"))
+            .project(Some("dashboard"))
+            .active_editor_tab(Some("App.tsx"))
+            .remote_host(Some("devbox"))
+            .project_path(Some("/work/dashboard"))
+            .build();
+        let json = serde_json::to_string(&window).unwrap();
+        assert_eq!(serde_json::from_str::<WindowInfo>(&json).unwrap(), window);
+    }
+}
@@ -0,0 +1,472 @@
+//! Linux Wayland window management through `wlr-foreign-toplevel-management`.
+//!
+//! Wayland deliberately hides other clients' windows, so listing and
+//! activating them is only possible on compositors that expose the
+//! `zwlr_foreign_toplevel_manager_v1` global (sway, Hyprland, river, labwc,
+//! Wayfire, ...). On compositors without it the provider reports the missing
+//! capability instead of failing.
+//!
+//! The protocol carries no PIDs, so the `pid` of a window is looked up in
+//! `/proc`: the oldest process whose executable maps to the same editor as
+//! the window's `app_id`, or [`UNKNOWN_PID`] when there is none, e.g. for
+//! Flatpak apps whose processes are hidden from swii. Window numbers are the protocol ids
+//! of the toplevel handles, which stay valid for as long as the shared
+//! connection below is alive. When that connection breaks, e.g. because the
+//! compositor restarted, it's dropped and the next call connects again; a
+//! failed connection is retried after a short backoff.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use swii_title_parser::parse_title;
+use tracing::{debug, warn};
+use wayland_client::globals::{registry_queue_init, GlobalListContents};
+use wayland_client::protocol::{wl_registry, wl_seat};
+use wayland_client::{event_created_child, Connection, Dispatch, EventQueue, Proxy, QueueHandle};
+use wayland_protocols_wlr::foreign_toplevel::v1::client::{
+    zwlr_foreign_toplevel_handle_v1::{self, ZwlrForeignToplevelHandleV1},
+    zwlr_foreign_toplevel_manager_v1::{self, ZwlrForeignToplevelManagerV1},
+};
+
+use crate::editor_config;
+use crate::focus::WindowFocusError;
+use crate::provider::{ProviderCapabilities, WindowError, WindowProvider};
+use crate::types::constants::UNKNOWN_PID;
+use crate::types::WindowInfo;
+
+pub mod constants {
+    use std::time::Duration;
+
+    /// Name of the protocol global the backend depends on
+    pub const FOREIGN_TOPLEVEL_GLOBAL: &str = "zwlr_foreign_toplevel_manager_v1";
+
+    /// Highest protocol version the backend understands
+    pub const FOREIGN_TOPLEVEL_MAX_VERSION: u32 = 3;
+
+    /// Time before connecting again after connecting failed
+    pub const RECONNECT_BACKOFF: Duration = Duration::from_secs(5);
+}
+
+/// A toplevel as last described by the compositor
+struct Toplevel {
+    handle: ZwlrForeignToplevelHandleV1,
+    title: Option<String>,
+    app_id: Option<String>,
+    closed: bool,
+}
+
+#[derive(Default)]
+struct ToplevelState {
+    toplevels: Vec<Toplevel>,
+    /// Set once the compositor stops sending toplevel events
+    finished: bool,
+}
+
+impl ToplevelState {
+    fn toplevel_mut(&mut self, handle: &ZwlrForeignToplevelHandleV1) -> Option<&mut Toplevel> {
+        self.toplevels.iter_mut().find(|t| &t.handle == handle)
+    }
+}
+
+/// Long-lived connection so toplevel handles (and window numbers) stay stable
+struct WaylandSession {
+    _connection: Connection,
+    queue: EventQueue<ToplevelState>,
+    state: ToplevelState,
+    seat: Option<wl_seat::WlSeat>,
+    /// Set when the connection can't be used anymore
+    lost: bool,
+}
+
+/// Outcome of the last attempt to connect to the compositor
+enum Session {
+    Connected(WaylandSession),
+    /// Why connecting failed, and when
+    Unavailable(String, Instant),
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for ToplevelState {
+    fn event(
+        _state: &mut Self,
+        _registry: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<wl_seat::WlSeat, ()> for ToplevelState {
+    fn event(
+        _state: &mut Self,
+        _seat: &wl_seat::WlSeat,
+        _event: wl_seat::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwlrForeignToplevelManagerV1, ()> for ToplevelState {
+    fn event(
+        state: &mut Self,
+        _manager: &ZwlrForeignToplevelManagerV1,
+        event: zwlr_foreign_toplevel_manager_v1::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        match event {
+            zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } => {
+                state.toplevels.push(Toplevel {
+                    handle: toplevel,
+                    title: None,
+                    app_id: None,
+                    closed: false,
+                });
+            }
+            zwlr_foreign_toplevel_manager_v1::Event::Finished => {
+                warn!("Compositor stopped sending foreign toplevel events");
+                state.finished = true;
+            }
+            _ => {}
+        }
+    }
+
+    event_created_child!(ToplevelState, ZwlrForeignToplevelManagerV1, [
+        zwlr_foreign_toplevel_manager_v1::EVT_TOPLEVEL_OPCODE => (ZwlrForeignToplevelHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<ZwlrForeignToplevelHandleV1, ()> for ToplevelState {
+    fn event(
+        state: &mut Self,
+        handle: &ZwlrForeignToplevelHandleV1,
+        event: zwlr_foreign_toplevel_handle_v1::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        let Some(toplevel) = state.toplevel_mut(handle) else {
+            return;
+        };
+
+        match event {
+            zwlr_foreign_toplevel_handle_v1::Event::Title { title } => {
+                toplevel.title = Some(title);
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::AppId { app_id } => {
+                toplevel.app_id = Some(app_id);
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::Closed => {
+                toplevel.closed = true;
+                handle.destroy();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Window provider backed by the wlr foreign toplevel protocol
+pub struct WaylandProvider;
+
+impl WindowProvider for WaylandProvider {
+    fn name(&self) -> &'static str {
+        "wayland"
+    }
+
+    fn list_windows(&self) -> Result<Vec<WindowInfo>, WindowError> {
+        with_session(|session| {
+            refresh(session)?;
+            Ok(editor_windows(
+                &session.state,
+                &app_pids(&running_processes()),
+            ))
+        })
+        .unwrap_or_else(|reason| {
+            debug!("Wayland window listing unavailable: {}", reason);
+            Ok(Vec::new())
+        })
+    }
+
+    fn focus_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+        with_session(|session| {
+            refresh(session).map_err(|e| WindowFocusError::SystemError(e.to_string()))?;
+
+            let seat = session.seat.as_ref().ok_or_else(|| {
+                WindowFocusError::SystemError("No Wayland seat available".to_string())
+            })?;
+            let pids = app_pids(&running_processes());
+            let toplevel = session
+                .state
+                .toplevels
+                .iter()
+                .find(|t| {
+                    !t.closed
+                        && t.handle.id().protocol_id() == window_number
+                        && t.app_id.as_deref().map(|app_id| app_pid(&pids, app_id)) == Some(pid)
+                })
+                .ok_or(WindowFocusError::WindowNotFound)?;
+
+            toplevel.handle.activate(seat);
+            session
+                .queue
+                .roundtrip(&mut session.state)
+                .map_err(|e| WindowFocusError::SystemError(e.to_string()))?;
+            Ok(())
+        })
+        .unwrap_or_else(|reason| Err(WindowFocusError::SystemError(reason)))
+    }
+
+    fn app_icon(&self, _pid: i32) -> Result<Option<String>, WindowError> {
+        // Icons would have to be resolved through desktop entries; not implemented yet
+        Ok(None)
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        match with_session(|_| ()) {
+            Ok(()) => ProviderCapabilities {
+                provider: self.name(),
+                list_windows: true,
+                focus_windows: true,
+                observe_windows: false,
+                detail: None,
+            },
+            Err(reason) => ProviderCapabilities {
+                provider: self.name(),
+                list_windows: false,
+                focus_windows: false,
+                observe_windows: false,
+                detail: Some(reason),
+            },
+        }
+    }
+}
+
+/// Runs `f` against the shared session, connecting on first use and again
+/// after the session was lost
+///
+/// Returns the reason the backend is unavailable if the compositor can't be
+/// reached or doesn't implement the protocol. A failed connection is retried
+/// once `RECONNECT_BACKOFF` has passed, e.g. when swii started before the
+/// compositor was ready.
+fn with_session<T>(f: impl FnOnce(&mut WaylandSession) -> T) -> Result<T, String> {
+    let mut guard = SESSION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut session = match guard.take() {
+        Some(Session::Connected(session)) => session,
+        Some(Session::Unavailable(reason, at)) if at.elapsed() < constants::RECONNECT_BACKOFF => {
+            *guard = Some(Session::Unavailable(reason.clone(), at));
+            return Err(reason);
+        }
+        previous => match connect() {
+            Ok(session) => session,
+            Err(reason) => {
+                if matches!(&previous, Some(Session::Unavailable(before, _)) if *before == reason) {
+                    debug!("Wayland window backend still unavailable: {}", reason);
+                } else {
+                    warn!("Wayland window backend unavailable: {}", reason);
+                }
+                *guard = Some(Session::Unavailable(reason.clone(), Instant::now()));
+                return Err(reason);
+            }
+        },
+    };
+
+    let result = f(&mut session);
+    if session.lost {
+        warn!("Lost the Wayland connection, reconnecting on next use");
+    } else {
+        *guard = Some(Session::Connected(session));
+    }
+    Ok(result)
+}
+
+fn connect() -> Result<WaylandSession, String> {
+    let connection = Connection::connect_to_env()
+        .map_err(|e| format!("Failed to connect to the Wayland compositor: {}", e))?;
+    let (globals, mut queue) = registry_queue_init::<ToplevelState>(&connection)
+        .map_err(|e| format!("Failed to read Wayland globals: {}", e))?;
+    let qh = queue.handle();
+
+    globals
+        .bind::<ZwlrForeignToplevelManagerV1, _, _>(
+            &qh,
+            1..=constants::FOREIGN_TOPLEVEL_MAX_VERSION,
+            (),
+        )
+        .map_err(|_| {
+            format!(
+                "The compositor does not support {}",
+                constants::FOREIGN_TOPLEVEL_GLOBAL
+            )
+        })?;
+    let seat = globals.bind::<wl_seat::WlSeat, _, _>(&qh, 1..=1, ()).ok();
+
+    let mut state = ToplevelState::default();
+    queue
+        .roundtrip(&mut state)
+        .map_err(|e| format!("Wayland roundtrip failed: {}", e))?;
+
+    debug!(
+        "Connected to Wayland compositor, {} toplevels announced",
+        state.toplevels.len()
+    );
+
+    Ok(WaylandSession {
+        _connection: connection,
+        queue,
+        state,
+        seat,
+        lost: false,
+    })
+}
+
+/// Processes pending compositor events and drops closed toplevels; marks
+/// the session lost when the compositor can't be talked to anymore
+fn refresh(session: &mut WaylandSession) -> Result<(), WindowError> {
+    let failure = match session.queue.roundtrip(&mut session.state) {
+        Err(e) => Some(format!("Wayland roundtrip failed: {}", e)),
+        Ok(_) if session.state.finished => {
+            Some("The compositor stopped reporting toplevels".to_string())
+        }
+        Ok(_) => None,
+    };
+    if let Some(message) = failure {
+        session.lost = true;
+        return Err(WindowError::WindowListAccess { message });
+    }
+    session.state.toplevels.retain(|t| !t.closed);
+    Ok(())
+}
+
+fn editor_windows(state: &ToplevelState, pids: &HashMap<String, i32>) -> Vec<WindowInfo> {
+    state
+        .toplevels
+        .iter()
+        .filter_map(|toplevel| {
+            let app_id = toplevel.app_id.as_deref()?;
+            let app_name = app_name_for_app_id(app_id);
+            if !editor_config::should_inspect_application(&app_name) {
+                return None;
+            }
+
+            let parsed = parse_title(
+                Some(&app_name),
+                toplevel.title.as_deref().unwrap_or_default(),
+            );
+            if editor_config::is_terminal_application(&app_name)
+                && !parsed.editor_kind.is_terminal_editor()
+            {
+                return None;
+            }
+
+            Some(
+                WindowInfo::builder(
+                    app_name,
+                    app_pid(pids, app_id),
+                    toplevel.handle.id().protocol_id(),
+                )
+                .window_name(toplevel.title.clone())
+                .parsed(parsed)
+                .build(),
+            )
+        })
+        .collect()
+}
+
+/// Maps app ids such as `code-url-handler` or `dev.zed.Zed` to editor names
+fn app_name_for_app_id(app_id: &str) -> String {
+    let base = app_id.rsplit('.').next().unwrap_or(app_id);
+    let base = base.strip_suffix("-url-handler").unwrap_or(base);
+    let base = base.strip_prefix("jetbrains-").unwrap_or(base);
+
+    editor_config::get_editor_name_for_executable(base)
+        .map(str::to_string)
+        .unwrap_or_else(|| base.to_string())
+}
+
+/// Pids and executable names of the processes visible in `/proc`
+fn running_processes() -> Vec<(i32, String)> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse::<i32>().ok()?;
+            // `exe` is only readable for our own processes, `comm` for all
+            // but cut to 15 bytes
+            let executable = std::fs::read_link(entry.path().join("exe"))
+                .ok()
+                .and_then(|path| {
+                    let name = path.file_name()?.to_string_lossy();
+                    Some(name.trim_end_matches(" (deleted)").to_string())
+                })
+                .or_else(|| {
+                    let comm = std::fs::read_to_string(entry.path().join("comm")).ok()?;
+                    Some(comm.trim_end().to_string())
+                })?;
+            Some((pid, executable))
+        })
+        .collect()
+}
+
+/// Maps editor names to the oldest process running one of their
+/// executables; Electron editors run one process per window and helper, and
+/// the main one is started first
+fn app_pids(processes: &[(i32, String)]) -> HashMap<String, i32> {
+    let mut pids = HashMap::new();
+    for (pid, executable) in processes {
+        let oldest = pids.entry(app_name_for_app_id(executable)).or_insert(*pid);
+        *oldest = (*oldest).min(*pid);
+    }
+    pids
+}
+
+/// The pid of the process behind `app_id`, [`UNKNOWN_PID`] if none was found
+fn app_pid(pids: &HashMap<String, i32>, app_id: &str) -> i32 {
+    pids.get(&app_name_for_app_id(app_id))
+        .copied()
+        .unwrap_or(UNKNOWN_PID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_name_for_app_id() {
+        assert_eq!(app_name_for_app_id("code"), "Visual Studio Code");
+        assert_eq!(
+            app_name_for_app_id("code-url-handler"),
+            "Visual Studio Code"
+        );
+        assert_eq!(app_name_for_app_id("dev.zed.Zed"), "Zed");
+        assert_eq!(app_name_for_app_id("jetbrains-idea"), "IntelliJ IDEA");
+        assert_eq!(app_name_for_app_id("firefox"), "firefox");
+    }
+
+    #[test]
+    fn test_app_pid() {
+        let processes = [
+            (812, "code".to_string()),
+            (640, "code".to_string()),
+            (901, "zed-editor".to_string()),
+            (77, "bash".to_string()),
+        ];
+        let pids = app_pids(&processes);
+        assert_eq!(app_pid(&pids, "code-url-handler"), 640);
+        assert_eq!(app_pid(&pids, "dev.zed.Zed"), 901);
+        assert_eq!(app_pid(&pids, "cursor"), UNKNOWN_PID);
+
+        let own = app_pids(&running_processes());
+        assert!(own.values().any(|&pid| pid == std::process::id() as i32));
+    }
+}
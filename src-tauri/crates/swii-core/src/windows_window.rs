@@ -0,0 +1,394 @@
+//! Windows window management: enumeration via `EnumWindows`, titles via
+//! `GetWindowTextW` with a UI Automation fallback, icons from the first icon
+//! resource of each executable, and focusing via `SetForegroundWindow`.
+//!
+//! Window numbers are derived from the `HWND` value. Window handles are 32-bit
+//! values, sign-extended on 64-bit Windows, so `window_number` keeps the low
+//! 32 bits and converting back sign-extends them again.
+
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::io::Cursor;
+use std::path::Path;
+
+use base64::Engine;
+use swii_title_parser::parse_title;
+use tracing::{debug, warn};
+use windows::core::{BOOL, HSTRING};
+use windows::Win32::Foundation::{CloseHandle, HWND, LPARAM};
+use windows::Win32::Graphics::Gdi::{
+    DeleteObject, GetDC, GetDIBits, GetObjectW, ReleaseDC, BITMAP, BITMAPINFO, BITMAPINFOHEADER,
+    BI_RGB, DIB_RGB_COLORS,
+};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+};
+use windows::Win32::System::Threading::{
+    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+};
+use windows::Win32::UI::Accessibility::{CUIAutomation, IUIAutomation};
+use windows::Win32::UI::Shell::ExtractIconExW;
+use windows::Win32::UI::WindowsAndMessaging::{
+    DestroyIcon, EnumWindows, GetIconInfo, GetWindow, GetWindowTextLengthW, GetWindowTextW,
+    GetWindowThreadProcessId, IsIconic, IsWindow, IsWindowVisible, SetForegroundWindow, ShowWindow,
+    GW_OWNER, HICON, ICONINFO, SW_RESTORE,
+};
+
+use crate::editor_config;
+use crate::focus::WindowFocusError;
+use crate::provider::{WindowError, WindowProvider};
+use crate::types::WindowInfo;
+
+/// Window provider backed by the Win32 windowing APIs
+pub struct WindowsProvider;
+
+impl WindowProvider for WindowsProvider {
+    fn name(&self) -> &'static str {
+        "windows"
+    }
+
+    fn list_windows(&self) -> Result<Vec<WindowInfo>, WindowError> {
+        get_editor_windows()
+    }
+
+    fn focus_window(&self, pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+        bring_window_to_front(pid, window_number)
+    }
+
+    fn app_icon(&self, pid: i32) -> Result<Option<String>, WindowError> {
+        Ok(process_image_path(pid as u32).and_then(|path| executable_icon(&path)))
+    }
+}
+
+fn hwnd_to_window_number(hwnd: HWND) -> u32 {
+    hwnd.0 as usize as u32
+}
+
+fn window_number_to_hwnd(window_number: u32) -> HWND {
+    HWND(window_number as i32 as isize as *mut c_void)
+}
+
+/// EnumWindows callback collecting every top-level window handle
+unsafe extern "system" fn collect_window(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let handles = &mut *(lparam.0 as *mut Vec<HWND>);
+    handles.push(hwnd);
+    BOOL(1)
+}
+
+/// Gets all editor windows on Windows
+pub fn get_editor_windows() -> Result<Vec<WindowInfo>, WindowError> {
+    debug!("Starting enumeration of Windows editor windows");
+
+    let mut handles: Vec<HWND> = Vec::new();
+    // SAFETY: the callback only pushes into the Vec passed through lparam,
+    // which outlives the EnumWindows call.
+    unsafe {
+        EnumWindows(
+            Some(collect_window),
+            LPARAM(&mut handles as *mut Vec<HWND> as isize),
+        )
+    }
+    .map_err(|e| WindowError::WindowListAccess {
+        message: format!("EnumWindows failed: {}", e),
+    })?;
+
+    let automation = create_automation();
+    let mut icons: HashMap<u32, Option<String>> = HashMap::new();
+    let mut windows = Vec::new();
+
+    for hwnd in handles {
+        // SAFETY: all handles come straight from EnumWindows
+        if !unsafe { is_switchable_window(hwnd) } {
+            continue;
+        }
+
+        let pid = unsafe { window_pid(hwnd) };
+        let Some(image_path) = process_image_path(pid) else {
+            continue;
+        };
+        let Some(app_name) = app_name_for_image(&image_path) else {
+            continue;
+        };
+
+        if !editor_config::should_inspect_application(&app_name) {
+            continue;
+        }
+
+        let window_name = unsafe { window_title(hwnd, automation.as_ref()) };
+        let parsed = parse_title(Some(&app_name), window_name.as_deref().unwrap_or_default());
+        if editor_config::is_terminal_application(&app_name)
+            && !parsed.editor_kind.is_terminal_editor()
+        {
+            continue;
+        }
+
+        // Editors usually have several windows; extract each icon once
+        let app_icon = icons
+            .entry(pid)
+            .or_insert_with(|| executable_icon(&image_path))
+            .clone();
+        windows.push(
+            WindowInfo::builder(app_name, pid as i32, hwnd_to_window_number(hwnd))
+                .window_name(window_name)
+                .app_icon(app_icon)
+                .parsed(parsed)
+                .build(),
+        );
+    }
+
+    debug!("Found {} editor windows", windows.len());
+    Ok(windows)
+}
+
+/// Visible, unowned top-level windows are the ones Alt+Tab would show
+unsafe fn is_switchable_window(hwnd: HWND) -> bool {
+    IsWindowVisible(hwnd).as_bool() && GetWindow(hwnd, GW_OWNER).is_err()
+}
+
+unsafe fn window_pid(hwnd: HWND) -> u32 {
+    let mut pid: u32 = 0;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid as *mut u32));
+    pid
+}
+
+/// Reads the window title, falling back to UI Automation for windows that
+/// don't answer WM_GETTEXT (some Electron and custom-drawn title bars)
+unsafe fn window_title(hwnd: HWND, automation: Option<&IUIAutomation>) -> Option<String> {
+    let length = GetWindowTextLengthW(hwnd);
+    if length > 0 {
+        let mut buffer = vec![0u16; length as usize + 1];
+        let copied = GetWindowTextW(hwnd, &mut buffer);
+        if copied > 0 {
+            return Some(String::from_utf16_lossy(&buffer[..copied as usize]));
+        }
+    }
+
+    let name = automation?
+        .ElementFromHandle(hwnd)
+        .and_then(|element| element.CurrentName())
+        .ok()?
+        .to_string();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+fn create_automation() -> Option<IUIAutomation> {
+    // SAFETY: COM is initialised for this thread before creating the
+    // automation object; repeated initialisation is harmless (S_FALSE).
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        match CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER) {
+            Ok(automation) => Some(automation),
+            Err(e) => {
+                warn!("UI Automation unavailable, using window text only: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Full path of the executable backing a process
+fn process_image_path(pid: u32) -> Option<String> {
+    // SAFETY: the handle is closed before returning and the buffer size is
+    // passed alongside the buffer pointer.
+    unsafe {
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+
+        let mut buffer = vec![0u16; 1024];
+        let mut size = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            windows::core::PWSTR(buffer.as_mut_ptr()),
+            &mut size,
+        );
+        let _ = CloseHandle(process);
+
+        result.ok()?;
+        Some(String::from_utf16_lossy(&buffer[..size as usize]))
+    }
+}
+
+/// Maps an executable path such as `C:\...\Code.exe` to an editor app name
+fn app_name_for_image(image_path: &str) -> Option<String> {
+    let stem = Path::new(&image_path.replace('\\', "/"))
+        .file_stem()?
+        .to_string_lossy()
+        .to_string();
+
+    Some(
+        editor_config::get_editor_name_for_executable(&stem)
+            .map(str::to_string)
+            .unwrap_or(stem),
+    )
+}
+
+/// Base64 PNG of the first large icon in an executable
+fn executable_icon(image_path: &str) -> Option<String> {
+    let mut icon = HICON::default();
+    // SAFETY: ExtractIconExW writes at most one handle into `icon`, which is
+    // destroyed once its pixels are copied.
+    unsafe {
+        let extracted = ExtractIconExW(&HSTRING::from(image_path), 0, Some(&mut icon), None, 1);
+        if extracted == 0 || icon.is_invalid() {
+            debug!("No icon found in {}", image_path);
+            return None;
+        }
+        let pixels = icon_pixels(icon);
+        let _ = DestroyIcon(icon);
+        let (width, height, bgra) = pixels?;
+        encode_png(width, height, bgra_to_rgba(bgra))
+    }
+}
+
+/// Width, height and top-down BGRA pixels of an icon's color bitmap
+unsafe fn icon_pixels(icon: HICON) -> Option<(u32, u32, Vec<u8>)> {
+    let mut info = ICONINFO::default();
+    GetIconInfo(icon, &mut info).ok()?;
+
+    let mut bitmap = BITMAP::default();
+    let copied = GetObjectW(
+        info.hbmColor.into(),
+        std::mem::size_of::<BITMAP>() as i32,
+        Some(&mut bitmap as *mut BITMAP as *mut c_void),
+    );
+    let (width, height) = (bitmap.bmWidth, bitmap.bmHeight);
+
+    let mut pixels = None;
+    if copied != 0 && width > 0 && height > 0 {
+        let mut header = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: width,
+                // Negative for rows top to bottom
+                biHeight: -height,
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut buffer = vec![0u8; width as usize * height as usize * 4];
+        let dc = GetDC(None);
+        let lines = GetDIBits(
+            dc,
+            info.hbmColor,
+            0,
+            height as u32,
+            Some(buffer.as_mut_ptr() as *mut c_void),
+            &mut header,
+            DIB_RGB_COLORS,
+        );
+        ReleaseDC(None, dc);
+        if lines == height {
+            pixels = Some((width as u32, height as u32, buffer));
+        }
+    }
+
+    // GetIconInfo hands over copies of both bitmaps
+    let _ = DeleteObject(info.hbmColor.into());
+    let _ = DeleteObject(info.hbmMask.into());
+    pixels
+}
+
+/// Converts BGRA pixels to RGBA. Icons without an alpha channel have it all
+/// zero, so they're made opaque instead of invisible
+fn bgra_to_rgba(mut pixels: Vec<u8>) -> Vec<u8> {
+    let has_alpha = pixels.chunks_exact(4).any(|pixel| pixel[3] != 0);
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+        if !has_alpha {
+            pixel[3] = 255;
+        }
+    }
+    pixels
+}
+
+/// Base64 PNG of RGBA pixels
+fn encode_png(width: u32, height: u32, rgba: Vec<u8>) -> Option<String> {
+    let image = image::RgbaImage::from_raw(width, height, rgba)?;
+    let mut png = Vec::new();
+    if let Err(e) = image.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png) {
+        warn!("Failed to encode icon as PNG: {}", e);
+        return None;
+    }
+    Some(base64::engine::general_purpose::STANDARD.encode(&png))
+}
+
+fn bring_window_to_front(pid: i32, window_number: u32) -> Result<(), WindowFocusError> {
+    let hwnd = window_number_to_hwnd(window_number);
+
+    // SAFETY: IsWindow validates the handle before any other call uses it
+    unsafe {
+        if !IsWindow(Some(hwnd)).as_bool() || window_pid(hwnd) as i32 != pid {
+            return Err(WindowFocusError::WindowNotFound);
+        }
+
+        if IsIconic(hwnd).as_bool() {
+            let _ = ShowWindow(hwnd, SW_RESTORE);
+        }
+
+        if !SetForegroundWindow(hwnd).as_bool() {
+            return Err(WindowFocusError::SystemError(
+                "SetForegroundWindow was refused".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_number_round_trip() {
+        let hwnd = window_number_to_hwnd(0x0012_04a8);
+        assert_eq!(hwnd_to_window_number(hwnd), 0x0012_04a8);
+    }
+
+    #[test]
+    fn test_bgra_to_rgba() {
+        assert_eq!(
+            bgra_to_rgba(vec![1, 2, 3, 0, 4, 5, 6, 128]),
+            vec![3, 2, 1, 0, 6, 5, 4, 128]
+        );
+        // Without any alpha the icon is opaque
+        assert_eq!(
+            bgra_to_rgba(vec![1, 2, 3, 0, 4, 5, 6, 0]),
+            vec![3, 2, 1, 255, 6, 5, 4, 255]
+        );
+    }
+
+    #[test]
+    fn test_encode_png() {
+        let encoded = encode_png(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 255]).unwrap();
+        let png = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        assert_eq!(encode_png(2, 2, vec![0; 4]), None);
+    }
+
+    #[test]
+    fn test_app_name_for_image() {
+        assert_eq!(
+            app_name_for_image(r"C:\Users\dev\AppData\Local\Programs\Microsoft VS Code\Code.exe"),
+            Some("Visual Studio Code".to_string())
+        );
+        assert_eq!(
+            app_name_for_image(r"C:\Program Files\Sublime Text\sublime_text.exe"),
+            Some("Sublime Text".to_string())
+        );
+        assert_eq!(
+            app_name_for_image(r"C:\Windows\explorer.exe"),
+            Some("explorer".to_string())
+        );
+    }
+}
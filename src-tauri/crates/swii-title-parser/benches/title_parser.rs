@@ -0,0 +1,49 @@
+//! Benchmarks for title parsing over a corpus of real editor window titles.
+//!
+//! Run with `cargo bench -p swii-title-parser`; criterion compares each run
+//! with the previous one and reports regressions.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use swii_title_parser::{extract_project_and_tab_from_title, parse_title};
+
+/// One title per line: the application name, a tab, then the window title
+const CORPUS: &str = include_str!("titles.tsv");
+
+fn corpus() -> Vec<(&'static str, &'static str)> {
+    CORPUS
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .collect()
+}
+
+fn bench_parse_title(c: &mut Criterion) {
+    let titles = corpus();
+    let mut group = c.benchmark_group("title_parser");
+    group.throughput(Throughput::Elements(titles.len() as u64));
+
+    group.bench_function("parse_title", |b| {
+        b.iter(|| {
+            for (app_name, title) in &titles {
+                black_box(parse_title(Some(app_name), black_box(title)));
+            }
+        })
+    });
+    group.bench_function("parse_title_without_app", |b| {
+        b.iter(|| {
+            for (_, title) in &titles {
+                black_box(parse_title(None, black_box(title)));
+            }
+        })
+    });
+    group.bench_function("extract_project_and_tab", |b| {
+        b.iter(|| {
+            for (_, title) in &titles {
+                black_box(extract_project_and_tab_from_title(black_box(title)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_title);
+criterion_main!(benches);
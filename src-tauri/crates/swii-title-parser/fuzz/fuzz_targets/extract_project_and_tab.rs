@@ -0,0 +1,18 @@
+//! Feeds arbitrary titles through the separator-based project/tab split.
+//!
+//! Run with `cargo fuzz run extract_project_and_tab` from
+//! `crates/swii-title-parser`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use swii_title_parser::extract_project_and_tab_from_title;
+
+fuzz_target!(|title: &str| {
+    let (project, tab) = extract_project_and_tab_from_title(title);
+
+    for part in [project, tab].into_iter().flatten() {
+        assert!(!part.is_empty());
+        assert_eq!(part.trim(), part.as_str());
+    }
+});
@@ -0,0 +1,17 @@
+//! Feeds arbitrary titles and app names through the parser.
+//!
+//! Run with `cargo fuzz run parse_title` from `crates/swii-title-parser`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use swii_title_parser::parse_title;
+
+fuzz_target!(|input: (Option<&str>, &str)| {
+    let (app_name, title) = input;
+    let parsed = parse_title(app_name, title);
+
+    if parsed.is_scratch {
+        assert!(parsed.project.is_none());
+    }
+});
@@ -0,0 +1,83 @@
+//! Editor names the title parser needs to recognise.
+//!
+//! The app re-exports these so window detection and title parsing agree on
+//! which applications count as editors.
+
+/// List of supported code editor applications.
+/// This list is used for identifying editor windows and filtering them from
+/// other application windows.
+pub const EDITOR_APPLICATIONS: &[&str] = &[
+    "Visual Studio Code",
+    "Visual Studio Code - Insiders",
+    "Code",
+    "Code - Insiders",
+    "VSCode",
+    "VSCodium",
+    "Zed",
+    "Zed Preview",
+    "Sublime Text",
+    "Sublime Text 3",
+    "Sublime Text 4",
+    "Atom",
+    "Vim",
+    "MacVim",
+    "Neovim",
+    "Emacs",
+    "GNU Emacs",
+    "IntelliJ IDEA",
+    "PyCharm",
+    "WebStorm",
+    "PhpStorm",
+    "RubyMine",
+    "CLion",
+    "GoLand",
+    "DataGrip",
+    "Rider",
+    "Android Studio",
+    "Xcode",
+    "TextEdit",
+    "TextMate",
+    "Brackets",
+    "Nova",
+    "CotEditor",
+    "BBEdit",
+    "Nano",
+    "Cursor",
+    "Cursor Nightly",
+    "Fleet",
+    "Helix",
+    "Kakoune",
+    "Lapce",
+    "Pulsar",
+    "Eclipse",
+    "NetBeans",
+    "RStudio",
+    "MATLAB",
+    "JupyterLab",
+];
+
+/// Checks if a title contains any known editor name.
+/// This is useful for title parsing logic to avoid false positives.
+pub fn title_contains_editor(title: &str) -> bool {
+    EDITOR_APPLICATIONS
+        .iter()
+        .any(|&editor| title.contains(editor))
+}
+
+/// Checks if a title contains any IntelliJ family editor.
+pub fn title_contains_intellij_family(title: &str) -> bool {
+    title.contains("IntelliJ")
+        || title.contains("PyCharm")
+        || title.contains("WebStorm")
+        || title.contains("PhpStorm")
+        || title.contains("RubyMine")
+        || title.contains("CLion")
+        || title.contains("GoLand")
+        || title.contains("DataGrip")
+        || title.contains("Rider")
+}
+
+/// Checks if a title contains "Visual Studio Code" or "Cursor".
+pub fn title_contains_vscode_or_cursor(title: &str) -> bool {
+    title.contains("Visual Studio Code") || title.contains("Cursor")
+}
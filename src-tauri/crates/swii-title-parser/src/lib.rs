@@ -0,0 +1,2869 @@
+//! Window title parsing for swii.
+//!
+//! Turns editor window titles such as `"main.rs - swii - Visual Studio Code"`
+//! into a [`ParsedTitle`] with the project, active tab and whatever else the
+//! editor puts in its title. The parser is pure apart from an optional
+//! filesystem walk for project roots, so it can be fuzzed and reused outside
+//! the app.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, RwLock, RwLockReadGuard};
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+pub mod editors;
+
+// Constants for title parsing
+pub mod constants {
+    // Title separators
+    pub const EM_DASH_SEPARATOR: &str = " — ";
+    pub const EN_DASH_SEPARATOR: &str = " – ";
+    pub const REGULAR_DASH_SEPARATOR: &str = " - ";
+
+    /// Separators between the project and tab segments, tried in order.
+    /// The regular dash is not included because editors that use it
+    /// (VS Code, Xcode) are handled by their own formats.
+    pub const DEFAULT_SEGMENT_SEPARATORS: &[&str] = &[EM_DASH_SEPARATOR, EN_DASH_SEPARATOR];
+
+    // File path prefixes
+    pub const FILE_URL_PREFIX: &str = "file://";
+
+    // Files and directories that mark a project root
+    pub const PROJECT_ROOT_MARKERS: &[&str] = &[
+        ".git",
+        ".hg",
+        ".svn",
+        "Cargo.toml",
+        "package.json",
+        "deno.json",
+        "deno.jsonc",
+        "Gemfile",
+        "requirements.txt",
+        "Pipfile",
+        "pyproject.toml",
+        "pom.xml",
+        "build.gradle",
+        "build.gradle.kts",
+        "composer.json",
+        "go.mod",
+        "flake.nix",
+        "pubspec.yaml",
+        "CMakeLists.txt",
+        "Package.swift",
+        "mix.exs",
+        "stack.yaml",
+        "build.zig",
+    ];
+
+    // How long a project root lookup is trusted before the filesystem is
+    // checked again, and how many directories are remembered
+    pub const ROOT_CACHE_TTL_SECS: u64 = 30;
+    pub const ROOT_CACHE_CAPACITY: usize = 512;
+
+    // Separators of Unix and Windows paths
+    pub const PATH_SEPARATORS: [char; 2] = ['/', '\\'];
+
+    // Unsaved-changes markers added by editors, e.g. "● main.rs" or "notes.txt — Edited"
+    pub const MODIFIED_PREFIXES: &[&str] = &["●", "•"];
+    pub const MODIFIED_SUFFIXES: &[&str] = &[" — Edited", " – Edited", " - Edited"];
+
+    // VS Code/Cursor multi-root workspaces: "my.code-workspace (Workspace)"
+    pub const WORKSPACE_MARKER: &str = " (Workspace)";
+    pub const WORKSPACE_EXTENSION: &str = ".code-workspace";
+
+    // Separator between Zed worktree names: "folder1, folder2"
+    pub const WORKTREE_SEPARATOR: &str = ",";
+
+    // Titles of IntelliJ-family windows that don't belong to a project
+    pub const JETBRAINS_AUXILIARY_TITLES: &[&str] = &[
+        "Settings",
+        "Preferences",
+        "Project Structure",
+        "Run/Debug Configurations",
+        "Plugins",
+        "Tip of the Day",
+        "Open File or Project",
+        "New Project",
+        "Find in Files",
+        "Replace in Files",
+        "Commit Changes",
+        "Push Commits",
+        "Update Project",
+    ];
+    pub const JETBRAINS_AUXILIARY_PREFIXES: &[&str] =
+        &["Welcome to ", "Diff", "Compare ", "Differences between "];
+    pub const DIFF_MARKERS: &[&str] = &[" vs ", " vs. "];
+
+    // Editors whose titles end in " - <name>", " — <name>" or " – <name>"
+    pub const APP_NAME_SUFFIXES: &[(&str, super::EditorKind)] = &[
+        ("Helix", super::EditorKind::Helix),
+        ("hx", super::EditorKind::Helix),
+        ("Kakoune", super::EditorKind::Kakoune),
+        ("Lapce", super::EditorKind::Lapce),
+        ("Pulsar", super::EditorKind::Pulsar),
+        ("BBEdit", super::EditorKind::BbEdit),
+        ("JupyterLab", super::EditorKind::Jupyter),
+    ];
+
+    // Names editors give unsaved buffers, besides "Untitled-<n>"
+    pub const SCRATCH_BUFFER_NAMES: &[&str] = &["untitled", "[no name]", "*scratch*"];
+
+    // Segments that may accompany an unnamed buffer without naming a
+    // project: Vim's title suffix and Zed's placeholder for folderless windows
+    pub const SCRATCH_COMPANION_SEGMENTS: &[&str] = &["VIM", "NVIM", "empty project"];
+
+    // Bundle extensions of the documents Xcode opens as a whole
+    pub const XCODE_CONTAINER_EXTENSIONS: &[(&str, super::XcodeContainer)] = &[
+        (".xcworkspace", super::XcodeContainer::Workspace),
+        (".xcodeproj", super::XcodeContainer::Project),
+        (".playground", super::XcodeContainer::Playground),
+    ];
+
+    // Product names in the last " - " segment of Eclipse ("Eclipse IDE")
+    // and NetBeans ("Apache NetBeans IDE 21") titles
+    pub const ECLIPSE_PRODUCT: &str = "Eclipse";
+    pub const NETBEANS_PRODUCT: &str = "NetBeans";
+    pub const RSTUDIO_PRODUCT: &str = "RStudio";
+
+    // Extension of RStudio project files, shown in some titles
+    pub const RSTUDIO_PROJECT_EXTENSION: &str = ".Rproj";
+
+    // Prefix of MATLAB editor window titles: "Editor - /path/to/script.m"
+    pub const MATLAB_EDITOR_PREFIX: &str = "Editor - ";
+
+    // Modified marker Helix and Kakoune append to the buffer name
+    pub const BUFFER_MODIFIED_MARKER: &str = "[+]";
+
+    // Label of VS Code/Cursor remote SSH markers
+    pub const SSH_LABEL: &str = "SSH";
+
+    // Labels of containerized/virtualized environment markers, e.g. "[WSL: Ubuntu]"
+    pub const ENVIRONMENT_LABELS: &[(&str, super::DevEnvironmentKind)] = &[
+        ("Dev Container", super::DevEnvironmentKind::DevContainer),
+        ("WSL", super::DevEnvironmentKind::Wsl),
+        ("Codespaces", super::DevEnvironmentKind::Codespaces),
+    ];
+
+    // Prefixes of remote/environment segments, e.g. "SSH: host"
+    pub const REMOTE_PREFIXES: &[&str] =
+        &["SSH:", "WSL:", "Dev Container:", "Codespaces:", "Tunnel:"];
+
+    // Leading words of commands shown in terminal/build output tabs: tools
+    // and the task names editors run without one ("build", "watch")
+    pub const COMMAND_PATTERNS: &[&str] = &[
+        "npm", "npx", "yarn", "pnpm", "bun", "bunx", "deno", "node", "cargo", "go", "make", "just",
+        "python", "python3", "pip", "uv", "poetry", "pytest", "docker", "kubectl", "git", "mvn",
+        "gradle", "dotnet", "swift", "tsc", "vite", "bundle", "rake", "mix", "zig", "run", "dev",
+        "build", "test", "start", "watch", "serve",
+    ];
+
+    // Shell prompt characters that may precede a command in a tab title
+    pub const PROMPT_PREFIXES: &[&str] = &["$ ", "> ", "% "];
+
+    // Default file extension length range, wide enough for ".c" and ".dockerfile"
+    pub const MIN_EXTENSION_LENGTH: usize = 1;
+    pub const MAX_EXTENSION_LENGTH: usize = 10;
+
+    // Minimum meaningful project name length
+    pub const MIN_PROJECT_NAME_LENGTH: usize = 1;
+
+    // Maximum length difference for heuristic detection
+    pub const HEURISTIC_LENGTH_THRESHOLD: usize = 5;
+}
+
+/// Editor family a window title belongs to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum EditorKind {
+    VsCode,
+    Cursor,
+    Zed,
+    JetBrains,
+    Xcode,
+    Sublime,
+    Vim,
+    Helix,
+    Kakoune,
+    /// tmux with `set-titles` on, in any terminal emulator
+    Tmux,
+    /// A plain shell, placed by the working directory its terminal reports
+    Shell,
+    Lapce,
+    Pulsar,
+    Nova,
+    BbEdit,
+    TextMate,
+    Eclipse,
+    NetBeans,
+    RStudio,
+    Matlab,
+    Jupyter,
+    #[default]
+    Unknown,
+}
+
+impl EditorKind {
+    /// Classifies an application name as reported by the window provider
+    pub fn from_app_name(app_name: &str) -> Self {
+        if app_name.contains("Cursor") {
+            EditorKind::Cursor
+        } else if app_name.contains("Visual Studio Code")
+            || app_name.eq_ignore_ascii_case("Code")
+            || app_name.eq_ignore_ascii_case("VSCode")
+            || app_name.starts_with("Code - ")
+            || app_name.eq_ignore_ascii_case("VSCodium")
+        {
+            EditorKind::VsCode
+        } else if app_name.eq_ignore_ascii_case("Zed")
+            || app_name.eq_ignore_ascii_case("Zed Preview")
+        {
+            EditorKind::Zed
+        } else if editors::title_contains_intellij_family(app_name)
+            || app_name.contains("Android Studio")
+        {
+            EditorKind::JetBrains
+        } else if app_name.eq_ignore_ascii_case("Xcode") {
+            EditorKind::Xcode
+        } else if app_name.contains("Sublime Text") {
+            EditorKind::Sublime
+        } else if app_name.to_ascii_lowercase().contains("vim") {
+            EditorKind::Vim
+        } else if app_name.eq_ignore_ascii_case("Helix") || app_name.eq_ignore_ascii_case("hx") {
+            EditorKind::Helix
+        } else if app_name.eq_ignore_ascii_case("Kakoune") || app_name.eq_ignore_ascii_case("kak") {
+            EditorKind::Kakoune
+        } else if app_name.eq_ignore_ascii_case("Lapce") {
+            EditorKind::Lapce
+        } else if app_name.eq_ignore_ascii_case("Pulsar") {
+            EditorKind::Pulsar
+        } else if app_name.eq_ignore_ascii_case("Nova") {
+            EditorKind::Nova
+        } else if app_name.eq_ignore_ascii_case("BBEdit") {
+            EditorKind::BbEdit
+        } else if app_name.eq_ignore_ascii_case("TextMate") {
+            EditorKind::TextMate
+        } else if app_name.contains(constants::ECLIPSE_PRODUCT) {
+            EditorKind::Eclipse
+        } else if app_name.contains(constants::NETBEANS_PRODUCT) {
+            EditorKind::NetBeans
+        } else if app_name.contains(constants::RSTUDIO_PRODUCT) {
+            EditorKind::RStudio
+        } else if app_name.starts_with("MATLAB") {
+            EditorKind::Matlab
+        } else if app_name.contains("JupyterLab") {
+            EditorKind::Jupyter
+        } else {
+            EditorKind::Unknown
+        }
+    }
+
+    /// Guesses the editor from the decorations it adds to window titles
+    fn from_title(title: &str) -> Self {
+        if title.contains("Visual Studio Code") {
+            EditorKind::VsCode
+        } else if title.ends_with("Cursor") {
+            EditorKind::Cursor
+        } else if editors::title_contains_intellij_family(title) {
+            EditorKind::JetBrains
+        } else if title.contains("Xcode") {
+            EditorKind::Xcode
+        } else if title.contains("Sublime Text") {
+            EditorKind::Sublime
+        } else if vim_title_regex().is_match(title) {
+            EditorKind::Vim
+        } else if tmux_title_regex().is_match(title) {
+            EditorKind::Tmux
+        } else if let Some(kind) = constants::APP_NAME_SUFFIXES
+            .iter()
+            .find(|(name, _)| strip_app_name_suffix(title, name).is_some())
+            .map(|(_, kind)| *kind)
+        {
+            kind
+        } else if strip_product_suffix(title, constants::ECLIPSE_PRODUCT).is_some() {
+            EditorKind::Eclipse
+        } else if strip_product_suffix(title, constants::NETBEANS_PRODUCT).is_some() {
+            EditorKind::NetBeans
+        } else if strip_product_suffix(title, constants::RSTUDIO_PRODUCT).is_some() {
+            EditorKind::RStudio
+        } else {
+            EditorKind::Unknown
+        }
+    }
+
+    /// Editors that usually run inside a terminal emulator, plus tmux and
+    /// shells whose project is known, which stand in for editors there
+    pub fn is_terminal_editor(self) -> bool {
+        matches!(
+            self,
+            EditorKind::Vim
+                | EditorKind::Helix
+                | EditorKind::Kakoune
+                | EditorKind::Tmux
+                | EditorKind::Shell
+        )
+    }
+
+    /// Prefers the application name and falls back to the title
+    pub fn detect(app_name: Option<&str>, title: &str) -> Self {
+        app_name
+            .map(EditorKind::from_app_name)
+            .filter(|kind| *kind != EditorKind::Unknown)
+            .unwrap_or_else(|| EditorKind::from_title(title))
+    }
+}
+
+/// How much the parser trusts its result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum Confidence {
+    /// Generic fallback, or nothing could be extracted
+    #[default]
+    Low,
+    /// Heuristic split of an em-dash title
+    Medium,
+    /// A user rule or an editor-specific title format matched
+    High,
+}
+
+/// Where the project name of a window came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectSource {
+    /// Parsed from the window title
+    #[default]
+    Title,
+    /// Resolved from the document the window has open
+    Document,
+    /// Reported by the editor's companion extension
+    Extension,
+    /// Reported by a running JetBrains IDE
+    Ide,
+    /// Read from the editor's own workspace database
+    EditorDatabase,
+    /// Read from the editor's saved session
+    EditorSession,
+    /// Reported by the terminal multiplexer, e.g. tmux
+    Multiplexer,
+    /// Reported by the terminal emulator, e.g. iTerm2's session path
+    Terminal,
+}
+
+/// Everything the parser extracted from a window title
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ParsedTitle {
+    pub editor_kind: EditorKind,
+    pub project: Option<String>,
+    pub tab: Option<String>,
+    /// File or folder path shown in the title, if any
+    pub path: Option<String>,
+    /// Whether the title marks unsaved changes
+    pub is_modified: bool,
+    /// Host of a remote SSH window, from "[SSH: host]" or an "SSH: host" segment
+    pub remote_host: Option<String>,
+    /// Container or WSL distribution the window is attached to
+    pub environment: Option<DevEnvironment>,
+    /// Whether the project is a VS Code multi-root workspace
+    pub workspace: bool,
+    /// Non-default VS Code profile shown in the title
+    pub profile: Option<String>,
+    /// All worktrees of a Zed window when more than one is open, primary first
+    pub worktrees: Vec<String>,
+    /// Dialogs, diff viewers and welcome screens that don't show a project
+    pub auxiliary: bool,
+    /// Kind of Xcode container the project was resolved from
+    pub xcode_container: Option<XcodeContainer>,
+    /// Window shows only an unnamed buffer such as "Untitled-1"
+    pub is_scratch: bool,
+    /// Root directory of the project, when it could be resolved on disk
+    pub project_path: Option<String>,
+    /// Whether `project` came from the title or from the open document
+    pub project_source: ProjectSource,
+    pub confidence: Confidence,
+}
+
+/// What an Xcode window has open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum XcodeContainer {
+    /// A `.xcodeproj` project
+    Project,
+    /// A `.xcworkspace` grouping one or more projects
+    Workspace,
+    /// A `.playground`
+    Playground,
+}
+
+/// Kind of environment a remote window runs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum DevEnvironmentKind {
+    DevContainer,
+    Wsl,
+    Codespaces,
+}
+
+/// Environment marker such as "[Dev Container: api]" or "[WSL: Ubuntu]"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct DevEnvironment {
+    pub kind: DevEnvironmentKind,
+    /// Container, distribution or codespace name
+    pub name: String,
+}
+
+/// A user-defined title rule as written in the settings file
+///
+/// `pattern` is a regex with any of the named groups `project`, `tab` and
+/// `path`; when only `path` is captured the project is derived from it.
+/// Rules without `editor` apply to every editor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TitleRule {
+    pub editor: Option<String>,
+    pub pattern: String,
+}
+
+struct CompiledRule {
+    editor: Option<String>,
+    regex: Regex,
+}
+
+/// Runtime configuration of the title parser
+pub struct ParserConfig {
+    rules: Vec<CompiledRule>,
+    /// Segment separators; empty means [`constants::DEFAULT_SEGMENT_SEPARATORS`]
+    separators: Vec<String>,
+    /// Names of VS Code profiles, stripped from titles wherever they appear
+    profiles: Vec<String>,
+    /// Accepted file extension lengths, inclusive
+    extension_lengths: (usize, usize),
+    /// Lowercase extensions accepted regardless of length or characters
+    extension_allowlist: Vec<String>,
+    /// User command patterns, tried alongside [`constants::COMMAND_PATTERNS`]
+    command_patterns: Vec<String>,
+    /// User project root markers, checked after [`constants::PROJECT_ROOT_MARKERS`]
+    root_markers: Vec<String>,
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl ParserConfig {
+    /// Built-in behaviour without user rules
+    const fn empty() -> Self {
+        ParserConfig {
+            rules: Vec::new(),
+            separators: Vec::new(),
+            profiles: Vec::new(),
+            extension_lengths: (
+                constants::MIN_EXTENSION_LENGTH,
+                constants::MAX_EXTENSION_LENGTH,
+            ),
+            extension_allowlist: Vec::new(),
+            command_patterns: Vec::new(),
+            root_markers: Vec::new(),
+        }
+    }
+
+    /// Compiles user rules, skipping (and reporting) the invalid ones
+    pub fn from_rules(rules: &[TitleRule]) -> (Self, Vec<String>) {
+        let mut compiled = Vec::new();
+        let mut errors = Vec::new();
+
+        for rule in rules {
+            match Regex::new(&rule.pattern) {
+                Ok(regex) => compiled.push(CompiledRule {
+                    editor: rule.editor.clone(),
+                    regex,
+                }),
+                Err(e) => errors.push(format!("Invalid title rule '{}': {}", rule.pattern, e)),
+            }
+        }
+
+        (
+            ParserConfig {
+                rules: compiled,
+                ..ParserConfig::empty()
+            },
+            errors,
+        )
+    }
+
+    /// Replaces the segment separators; whitespace-only entries are ignored
+    /// since they would split every title
+    pub fn with_separators(mut self, separators: &[String]) -> Self {
+        self.separators = separators
+            .iter()
+            .filter(|separator| !separator.trim().is_empty())
+            .cloned()
+            .collect();
+        self
+    }
+
+    /// Replaces the known VS Code profile names
+    pub fn with_profiles(mut self, profiles: &[String]) -> Self {
+        self.profiles = profiles
+            .iter()
+            .map(|profile| profile.trim().to_string())
+            .filter(|profile| !profile.is_empty())
+            .collect();
+        self
+    }
+
+    /// Replaces the accepted extension length range and adds extensions
+    /// (with or without the leading dot) that are always accepted
+    pub fn with_extensions(
+        mut self,
+        lengths: Option<(usize, usize)>,
+        allowlist: &[String],
+    ) -> Self {
+        if let Some(lengths) = lengths {
+            self.extension_lengths = lengths;
+        }
+        self.extension_allowlist = allowlist
+            .iter()
+            .map(|extension| extension.trim().trim_start_matches('.').to_lowercase())
+            .filter(|extension| !extension.is_empty())
+            .collect();
+        self
+    }
+
+    /// Whether the text after the last dot of a name is a file extension
+    fn accepts_extension(&self, extension: &str) -> bool {
+        let (min, max) = self.extension_lengths;
+        if self
+            .extension_allowlist
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+        {
+            return true;
+        }
+        // Requiring a letter keeps version numbers like "v1.2" out
+        (min..=max).contains(&extension.len())
+            && extension.chars().all(|c| c.is_alphanumeric())
+            && extension.chars().any(|c| c.is_alphabetic())
+    }
+
+    /// Adds leading words (or phrases, like `"docker compose"`) of commands
+    /// to recognize in titles
+    pub fn with_command_patterns(mut self, patterns: &[String]) -> Self {
+        self.command_patterns = patterns
+            .iter()
+            .map(|pattern| pattern.trim().to_string())
+            .filter(|pattern| !pattern.is_empty())
+            .collect();
+        self
+    }
+
+    /// Adds file or directory names that mark a project root, e.g. `"WORKSPACE"`
+    pub fn with_root_markers(mut self, markers: &[String]) -> Self {
+        self.root_markers = markers
+            .iter()
+            .map(|marker| marker.trim().to_string())
+            .filter(|marker| !marker.is_empty())
+            .collect();
+        self
+    }
+
+    /// Whether a directory contains any built-in or configured root marker
+    fn is_project_root(&self, dir: &Path) -> bool {
+        constants::PROJECT_ROOT_MARKERS
+            .iter()
+            .copied()
+            .chain(self.root_markers.iter().map(String::as_str))
+            .any(|marker| dir.join(marker).exists())
+    }
+
+    /// Separators between title segments, in the order they are tried
+    fn segment_separators(&self) -> Vec<&str> {
+        if self.separators.is_empty() {
+            constants::DEFAULT_SEGMENT_SEPARATORS.to_vec()
+        } else {
+            self.separators.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Number of active user rules
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+}
+
+static CONFIG: RwLock<ParserConfig> = RwLock::new(ParserConfig::empty());
+
+/// Replaces the parser configuration used by all subsequent parses
+pub fn set_config(config: ParserConfig) {
+    *CONFIG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+    // Root markers may have changed
+    clear_root_cache();
+}
+
+fn config() -> RwLockReadGuard<'static, ParserConfig> {
+    CONFIG
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Splits a title on the first configured separator it contains
+///
+/// Returns `None` when the title contains none of the separators.
+fn split_segments(title: &str) -> Option<Vec<&str>> {
+    config()
+        .segment_separators()
+        .into_iter()
+        .find(|separator| title.contains(separator))
+        .map(|separator| title.split(separator).collect())
+}
+
+/// Applies the first matching user rule for the given editor
+fn apply_user_rules(app_name: Option<&str>, title: &str) -> Option<ParsedTitle> {
+    let config = config();
+
+    config
+        .rules
+        .iter()
+        .filter(|rule| match (&rule.editor, app_name) {
+            (None, _) => true,
+            (Some(editor), Some(app_name)) => editor.eq_ignore_ascii_case(app_name),
+            (Some(_), None) => false,
+        })
+        .find_map(|rule| {
+            let captures = rule.regex.captures(title)?;
+            let group = |name: &str| {
+                captures
+                    .name(name)
+                    .map(|m| m.as_str().trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+
+            let tab = group("tab");
+            let path = group("path");
+            let project = group("project")
+                .or_else(|| path.as_deref().and_then(extract_project_from_file_path));
+
+            if project.is_none() && tab.is_none() {
+                return None;
+            }
+            Some(ParsedTitle {
+                project,
+                tab,
+                path,
+                confidence: Confidence::High,
+                ..ParsedTitle::default()
+            })
+        })
+}
+
+/// Extracts project name from window title using various editor-specific patterns
+pub fn extract_project_from_title(title: &str) -> Option<String> {
+    if title.is_empty() {
+        return None;
+    }
+
+    // Em dash formats: Handle different patterns
+    if let Some(parts) = split_segments(title) {
+        if parts.len() == 2 {
+            let first_part = parts[0].trim();
+            let second_part = parts[1].trim();
+
+            // Case 1: "ProjectName — filename.ext" (Zed style)
+            if second_part.contains('.') && !first_part.contains('/') && !first_part.is_empty() {
+                return Some(first_part.to_string());
+            }
+
+            // Case 2: "command — ProjectName" (Cursor/VS Code style)
+            if !second_part.contains('/') && !second_part.contains('.') && !second_part.is_empty() {
+                return Some(second_part.to_string());
+            }
+        }
+    }
+
+    // VS Code / Cursor format with regular dash: "filename.ext - ProjectName - Visual Studio Code"
+    if editors::title_contains_vscode_or_cursor(title) {
+        let parts: Vec<&str> = title.split(constants::REGULAR_DASH_SEPARATOR).collect();
+        if parts.len() >= 3 {
+            // Second part should be the project name
+            let project_name = parts[1].trim();
+            if !project_name.is_empty() && !project_name.contains('/') {
+                return Some(project_name.to_string());
+            }
+        }
+    }
+
+    // Xcode format: "ProjectName" or "ProjectName - filename.ext"
+    // Only apply Xcode logic if we detect this is actually from Xcode or has Xcode-like pattern
+    if title.contains("Xcode") {
+        let project_name = if let Some(dash_pos) = title.find(constants::REGULAR_DASH_SEPARATOR) {
+            &title[..dash_pos]
+        } else {
+            title
+        };
+
+        let cleaned = project_name.trim();
+        if !cleaned.is_empty() && !cleaned.contains("Xcode") && !cleaned.contains('/') {
+            return Some(cleaned.to_string());
+        }
+    }
+
+    // Handle potential Xcode-style titles (ProjectName - filename) without "Xcode" in title
+    if title.contains(constants::REGULAR_DASH_SEPARATOR) && !editors::title_contains_editor(title) {
+        let parts: Vec<&str> = title.split(constants::REGULAR_DASH_SEPARATOR).collect();
+        if parts.len() == 2 {
+            let potential_project = parts[0].trim();
+            let potential_file = parts[1].trim();
+
+            // If second part looks like a filename, first part might be project
+            if potential_file.contains('.') && !potential_project.contains('/') {
+                return Some(potential_project.to_string());
+            }
+        }
+    }
+
+    // Zed format: Often just "ProjectName" (single word/phrase without common editor names)
+    if !title.contains(constants::REGULAR_DASH_SEPARATOR)
+        && !title.contains('/')
+        && !title.contains('.')
+        && !editors::title_contains_editor(title)
+        && !title.contains("Chrome")
+        && !title.contains("Safari")
+        && !title.contains("Firefox")
+    {
+        let cleaned = title.trim();
+        if !cleaned.is_empty() && cleaned.len() > 1 {
+            return Some(cleaned.to_string());
+        }
+    }
+
+    // IntelliJ family format: "ProjectName [path] - IntelliJ IDEA"
+    if editors::title_contains_intellij_family(title) {
+        if let Some(bracket_pos) = title.find('[') {
+            let project_name = title[..bracket_pos].trim();
+            if !project_name.is_empty() {
+                return Some(project_name.to_string());
+            }
+        }
+    }
+
+    // Sublime Text format: "filename.ext - Sublime Text"
+    if title.contains("Sublime Text") {
+        let parts: Vec<&str> = title.split(constants::REGULAR_DASH_SEPARATOR).collect();
+        if parts.len() >= 2 {
+            let file_part = parts[0].trim();
+            // Try to extract project from filename path
+            if file_part.contains(constants::PATH_SEPARATORS) {
+                return extract_project_from_file_path(file_part);
+            }
+        }
+    }
+
+    // Generic fallback: if title contains a path, try to extract project from it
+    if title.contains(constants::PATH_SEPARATORS) {
+        return extract_project_from_file_path(title);
+    }
+
+    None
+}
+
+/// Determines which part is the project name and which is the tab name based on heuristics
+pub fn determine_project_and_tab_from_parts<'a>(
+    first_part: &'a str,
+    second_part: &'a str,
+) -> (&'a str, &'a str) {
+    // Heuristic 0: A multi-root workspace is always the project
+    let first_is_workspace = first_part.ends_with(constants::WORKSPACE_MARKER);
+    let second_is_workspace = second_part.ends_with(constants::WORKSPACE_MARKER);
+
+    if first_is_workspace && !second_is_workspace {
+        return (first_part, second_part);
+    }
+
+    if second_is_workspace && !first_is_workspace {
+        return (second_part, first_part);
+    }
+
+    // Heuristic 1: Check for file extensions (tab names often have file extensions)
+    let first_has_extension = has_file_extension(first_part);
+    let second_has_extension = has_file_extension(second_part);
+
+    if first_has_extension && !second_has_extension {
+        // First part is likely a filename (tab), second part is likely project
+        return (second_part, first_part);
+    }
+
+    if second_has_extension && !first_has_extension {
+        // Second part is likely a filename (tab), first part is likely project
+        return (first_part, second_part);
+    }
+
+    // Heuristic 2: Check for command-like patterns (tab names for terminal/command output)
+    let first_is_command = is_command_like(first_part);
+    let second_is_command = is_command_like(second_part);
+
+    if first_is_command && !second_is_command {
+        // First part is likely a command (tab), second part is likely project
+        return (second_part, first_part);
+    }
+
+    if second_is_command && !first_is_command {
+        // Second part is likely a command (tab), first part is likely project
+        return (first_part, second_part);
+    }
+
+    // Heuristic 3: Length and complexity (project names tend to be shorter and simpler)
+    // Only apply this heuristic if there's a significant length difference (at least 5 characters)
+    if first_part.len() + constants::HEURISTIC_LENGTH_THRESHOLD <= second_part.len()
+        && !first_part.contains('/')
+        && !first_part.contains(' ')
+    {
+        // First part is likely the project name (much shorter, no path separators)
+        return (first_part, second_part);
+    }
+
+    if second_part.len() + constants::HEURISTIC_LENGTH_THRESHOLD <= first_part.len()
+        && !second_part.contains('/')
+        && !second_part.contains(' ')
+    {
+        // Second part is likely the project name (much shorter, no path separators)
+        return (second_part, first_part);
+    }
+
+    // Default fallback: assume first part is project, second part is tab
+    // This handles the common Zed pattern: "project — filename"
+    (first_part, second_part)
+}
+
+/// Removes a "[label: value]" marker such as "[SSH: devbox]" from a title,
+/// returning the marker's value
+fn strip_bracket_marker<'a>(title: &'a str, label: &str) -> (Cow<'a, str>, Option<String>) {
+    let opening = format!("[{}:", label);
+    let Some(start) = title.find(&opening) else {
+        return (Cow::Borrowed(title), None);
+    };
+    let Some(length) = title[start..].find(']') else {
+        return (Cow::Borrowed(title), None);
+    };
+
+    let end = start + length;
+    let value = title[start + opening.len()..end].trim();
+    let before = title[..start].trim_end();
+    let after = &title[end + 1..];
+    let stripped = if before.is_empty() {
+        after.trim_start().to_string()
+    } else {
+        format!("{}{}", before, after)
+    };
+
+    (
+        Cow::Owned(stripped),
+        (!value.is_empty()).then(|| value.to_string()),
+    )
+}
+
+/// Removes the first environment marker such as "[WSL: Ubuntu]"
+fn strip_environment_marker(title: &str) -> (Cow<'_, str>, Option<DevEnvironment>) {
+    for (label, kind) in constants::ENVIRONMENT_LABELS {
+        if let (stripped, Some(name)) = strip_bracket_marker(title, label) {
+            return (stripped, Some(DevEnvironment { kind: *kind, name }));
+        }
+    }
+    (Cow::Borrowed(title), None)
+}
+
+/// Returns `value` when a segment reads "label: value"
+fn labelled_value<'a>(segment: &'a str, label: &str) -> Option<&'a str> {
+    let value = segment
+        .trim()
+        .strip_prefix(label)?
+        .strip_prefix(':')?
+        .trim();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Turns "my.code-workspace (Workspace)" into "my", reporting whether the
+/// project was a workspace
+fn strip_workspace_marker(project: &str) -> (&str, bool) {
+    match project.strip_suffix(constants::WORKSPACE_MARKER) {
+        Some(name) => {
+            let name = name.trim();
+            let name = name
+                .strip_suffix(constants::WORKSPACE_EXTENSION)
+                .unwrap_or(name);
+            (name, true)
+        }
+        None => (project, false),
+    }
+}
+
+/// Removes unsaved-changes markers, reporting whether any were present
+fn strip_modified_markers(title: &str) -> (&str, bool) {
+    let mut stripped = title;
+    let mut is_modified = false;
+
+    for prefix in constants::MODIFIED_PREFIXES {
+        if let Some(rest) = stripped.strip_prefix(prefix) {
+            stripped = rest.trim_start();
+            is_modified = true;
+        }
+    }
+    for suffix in constants::MODIFIED_SUFFIXES {
+        if let Some(rest) = stripped.strip_suffix(suffix) {
+            stripped = rest.trim_end();
+            is_modified = true;
+        }
+    }
+
+    (stripped, is_modified)
+}
+
+/// Matches Vim's and Neovim's default window titles
+fn vim_title_regex() -> &'static Regex {
+    static VIM_TITLE: OnceLock<Regex> = OnceLock::new();
+    VIM_TITLE.get_or_init(|| {
+        Regex::new(
+            r"^(?P<file>[^()]+?)(?: (?P<flag>[-+=]{1,2}))?(?: \((?P<dir>[^()]*)\))?(?: \(\d+ of \d+\))? - (?:VIM\d*|NVIM|Nvim)$",
+        )
+        .expect("valid Vim title regex")
+    })
+}
+
+/// tmux's default `set-titles-string`, `#S:#I:#W - "#T" #{session_alerts}`:
+/// session, window index, window name and pane title
+fn tmux_title_regex() -> &'static Regex {
+    static TMUX_TITLE: OnceLock<Regex> = OnceLock::new();
+    TMUX_TITLE.get_or_init(|| {
+        Regex::new(
+            r#"^(?P<session>[^:\s][^:]*):(?P<index>\d+):(?P<window>[^"]*?) - "(?P<pane>.*)"(?:\s+\S+)?\s*$"#,
+        )
+        .expect("valid tmux title regex")
+    })
+}
+
+/// Session name of a window titled by tmux's default `set-titles-string`
+pub fn tmux_session_from_title(title: &str) -> Option<&str> {
+    tmux_title_regex()
+        .captures(title)
+        .and_then(|captures| captures.name("session"))
+        .map(|session| session.as_str())
+}
+
+/// Whether a tmux session still has the numeric name tmux gives by default
+pub fn is_default_tmux_session_name(session: &str) -> bool {
+    !session.is_empty() && session.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Removes a trailing " - <app>", " — <app>" or " – <app>" and splits the
+/// rest on the same separator
+fn strip_app_name_suffix<'a>(title: &'a str, app_name: &str) -> Option<Vec<&'a str>> {
+    [
+        constants::REGULAR_DASH_SEPARATOR,
+        constants::EM_DASH_SEPARATOR,
+        constants::EN_DASH_SEPARATOR,
+    ]
+    .into_iter()
+    .find_map(|separator| {
+        let rest = title.strip_suffix(app_name)?.strip_suffix(separator)?;
+        Some(rest.split(separator).map(str::trim).collect())
+    })
+}
+
+/// Splits a " - " separated title whose last segment names the product,
+/// returning the segments before it
+fn strip_product_suffix<'a>(title: &'a str, product: &str) -> Option<Vec<&'a str>> {
+    let (rest, last) = title.rsplit_once(constants::REGULAR_DASH_SEPARATOR)?;
+    last.contains(product).then(|| {
+        rest.split(constants::REGULAR_DASH_SEPARATOR)
+            .map(str::trim)
+            .collect()
+    })
+}
+
+/// Parses "workspace - project/src/Main.java - Eclipse IDE"; the resource
+/// segment starts with the project, and without one the workspace is shown
+fn parse_eclipse_segments(segments: &[&str], parsed: &mut ParsedTitle) {
+    match segments {
+        [] => {}
+        [workspace] => parsed.project = Some(workspace.to_string()),
+        [_, resource, ..] => {
+            let resource = resource.trim_start_matches('/');
+            let components: Vec<&str> = resource.split('/').filter(|c| !c.is_empty()).collect();
+            parsed.project = components.first().map(|c| c.to_string());
+            if components.len() > 1 {
+                parsed.path = Some(resource.to_string());
+                parsed.tab = components
+                    .last()
+                    .filter(|c| has_file_extension(c))
+                    .map(|c| c.to_string());
+            }
+        }
+    }
+    parsed.confidence = Confidence::High;
+}
+
+/// Splits "MyApp.xcworkspace" into the name and container kind
+fn xcode_container_name(name: &str) -> Option<(String, XcodeContainer)> {
+    constants::XCODE_CONTAINER_EXTENSIONS
+        .iter()
+        .find_map(|(extension, container)| {
+            let stem = name.strip_suffix(extension)?;
+            (!stem.is_empty()).then(|| (stem.to_string(), *container))
+        })
+}
+
+/// Finds the Xcode project, workspace or playground a document path belongs
+/// to, e.g. from the `AXDocument` of an Xcode window
+pub fn xcode_container_from_path(path: &str) -> Option<(String, XcodeContainer)> {
+    let path = path
+        .strip_prefix(constants::FILE_URL_PREFIX)
+        .unwrap_or(path);
+    path.split('/').rev().find_map(xcode_container_name)
+}
+
+/// Parses "project - RStudio" and "~/code/project - main - RStudio"; the
+/// first segment names the project, possibly as its directory or .Rproj file
+fn parse_rstudio_segments(segments: &[&str], parsed: &mut ParsedTitle) {
+    let Some(project) = segments.first().filter(|s| !s.is_empty()) else {
+        return;
+    };
+    if project.contains(constants::PATH_SEPARATORS) {
+        parsed.path = Some(project.to_string());
+    }
+    let name = project
+        .trim_end_matches(constants::PATH_SEPARATORS)
+        .rsplit(constants::PATH_SEPARATORS)
+        .next()
+        .unwrap_or(project);
+    let name = name
+        .strip_suffix(constants::RSTUDIO_PROJECT_EXTENSION)
+        .unwrap_or(name);
+    parsed.project = Some(name.to_string());
+    parsed.confidence = Confidence::High;
+}
+
+/// Parses "Editor - /Users/dev/model/src/fit.m"; the main MATLAB window
+/// only shows the release and yields nothing
+fn parse_matlab_title(title: &str, parsed: &mut ParsedTitle) {
+    let Some(path) = title.strip_prefix(constants::MATLAB_EDITOR_PREFIX) else {
+        return;
+    };
+    let path = path.trim();
+    match path.rsplit_once(constants::PATH_SEPARATORS) {
+        Some((dir, file)) => {
+            parsed.path = Some(path.to_string());
+            parsed.tab = Some(file.to_string());
+            parsed.project = extract_project_from_file_path(dir);
+        }
+        None => parsed.tab = Some(path.to_string()),
+    }
+    parsed.confidence = Confidence::High;
+}
+
+/// Fills tab and project from "[tab, project]" title segments; a lone
+/// segment is a tab if it looks like a file
+fn parse_tab_project_segments(segments: &[&str], parsed: &mut ParsedTitle) {
+    let (tab, project) = match segments {
+        [] => (None, None),
+        [only] if has_file_extension(only) => (Some(*only), None),
+        [only] => (None, Some(*only)),
+        [tab, project, ..] => (Some(*tab), Some(*project)),
+    };
+
+    if let Some(tab) = tab.filter(|tab| !tab.is_empty()) {
+        let tab = match tab.strip_suffix(constants::BUFFER_MODIFIED_MARKER) {
+            Some(rest) => {
+                parsed.is_modified = true;
+                rest.trim_end()
+            }
+            None => tab,
+        };
+        if is_absolute_path(tab) {
+            parsed.path = Some(tab.to_string());
+            parsed.project = tab
+                .rsplit_once(constants::PATH_SEPARATORS)
+                .and_then(|(dir, _)| extract_project_from_file_path(dir));
+        }
+        let name = tab.rsplit(constants::PATH_SEPARATORS).next().unwrap_or(tab);
+        parsed.tab = Some(name.to_string());
+    }
+
+    if let Some(project) = project.filter(|project| !project.is_empty()) {
+        if project.contains(constants::PATH_SEPARATORS) {
+            parsed.path.get_or_insert_with(|| project.to_string());
+            parsed.project = project
+                .rsplit(constants::PATH_SEPARATORS)
+                .find(|part| !part.is_empty())
+                .map(str::to_string);
+        } else {
+            parsed.project = Some(project.to_string());
+        }
+    }
+
+    parsed.confidence = Confidence::High;
+}
+
+/// Recognizes "Untitled", "Untitled-1", "untitled 2", "[No Name]" and "*scratch*"
+fn is_scratch_buffer_name(segment: &str) -> bool {
+    let lower = segment.to_lowercase();
+    constants::SCRATCH_BUFFER_NAMES.contains(&lower.as_str())
+        || lower
+            .strip_prefix("untitled")
+            .and_then(|rest| rest.strip_prefix(['-', ' ']))
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns the buffer name of a window that shows nothing but an unnamed
+/// buffer, e.g. "Untitled-1 - Visual Studio Code" or "[No Name] - NVIM"
+fn scratch_buffer_name(title: &str) -> Option<&str> {
+    let mut segments = vec![title];
+    for separator in [
+        constants::EM_DASH_SEPARATOR,
+        constants::EN_DASH_SEPARATOR,
+        constants::REGULAR_DASH_SEPARATOR,
+    ] {
+        segments = segments
+            .into_iter()
+            .flat_map(|segment| segment.split(separator))
+            .collect();
+    }
+
+    let mut name = None;
+    for segment in segments.into_iter().map(str::trim) {
+        if name.is_none() && is_scratch_buffer_name(segment) {
+            name = Some(segment);
+        } else if !is_editor_name(segment)
+            && !constants::SCRATCH_COMPANION_SEGMENTS
+                .iter()
+                .any(|companion| segment.eq_ignore_ascii_case(companion))
+        {
+            return None;
+        }
+    }
+    name
+}
+
+/// Recognizes IntelliJ-family dialogs, diff viewers and the Welcome screen,
+/// whose titles the bracket and single-name rules would turn into projects
+fn is_jetbrains_auxiliary_title(title: &str) -> bool {
+    let title = title.trim();
+    constants::JETBRAINS_AUXILIARY_TITLES
+        .iter()
+        .any(|dialog| title.eq_ignore_ascii_case(dialog))
+        || constants::JETBRAINS_AUXILIARY_PREFIXES
+            .iter()
+            .any(|prefix| title.starts_with(prefix))
+        || constants::DIFF_MARKERS
+            .iter()
+            .any(|marker| title.contains(marker))
+}
+
+/// Checks if a title segment is exactly an editor's application name
+fn is_editor_name(segment: &str) -> bool {
+    editors::EDITOR_APPLICATIONS
+        .iter()
+        .any(|editor| segment.trim().eq_ignore_ascii_case(editor))
+}
+
+/// Removes a configured VS Code profile name from the end of the title (or
+/// just before the trailing application name), returning the profile
+fn strip_profile(title: &str) -> (Cow<'_, str>, Option<String>) {
+    let config = config();
+    if config.profiles.is_empty() {
+        return (Cow::Borrowed(title), None);
+    }
+
+    let mut separators = vec![constants::REGULAR_DASH_SEPARATOR];
+    separators.extend(config.segment_separators());
+
+    for separator in separators {
+        let mut parts: Vec<&str> = title.split(separator).collect();
+        if parts.len() < 2 {
+            continue;
+        }
+
+        let last = parts.len() - 1;
+        let index = if is_editor_name(parts[last]) {
+            last - 1
+        } else {
+            last
+        };
+        if index == 0 {
+            continue;
+        }
+
+        let candidate = parts[index].trim();
+        if let Some(profile) = config
+            .profiles
+            .iter()
+            .find(|profile| profile.eq_ignore_ascii_case(candidate))
+        {
+            let profile = profile.clone();
+            parts.remove(index);
+            return (Cow::Owned(parts.join(separator)), Some(profile));
+        }
+    }
+
+    (Cow::Borrowed(title), None)
+}
+
+/// Checks if a title segment only names the editor or the remote it runs on
+fn is_trailing_decoration(segment: &str) -> bool {
+    let segment = segment.trim();
+    is_editor_name(segment)
+        || constants::REMOTE_PREFIXES
+            .iter()
+            .any(|prefix| segment.starts_with(prefix))
+}
+
+/// Checks if a text string has a valid file extension, as configured with
+/// [`ParserConfig::with_extensions`]
+pub fn has_file_extension(text: &str) -> bool {
+    match text.rsplit_once('.') {
+        Some((_, extension)) => config().accepts_extension(extension),
+        None => false,
+    }
+}
+
+/// Checks if a text string looks like a command or terminal output: at least
+/// two words, the first of which are a known command pattern
+pub fn is_command_like(text: &str) -> bool {
+    let text = constants::PROMPT_PREFIXES
+        .iter()
+        .find_map(|prompt| text.strip_prefix(prompt))
+        .unwrap_or(text);
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    if tokens.len() < 2 {
+        return false;
+    }
+
+    let starts_with = |pattern: &str| {
+        let words: Vec<&str> = pattern.split_whitespace().collect();
+        words.len() <= tokens.len()
+            && words
+                .iter()
+                .zip(&tokens)
+                .all(|(word, token)| word.eq_ignore_ascii_case(token))
+    };
+
+    constants::COMMAND_PATTERNS
+        .iter()
+        .any(|pattern| starts_with(pattern))
+        || config()
+            .command_patterns
+            .iter()
+            .any(|pattern| starts_with(pattern))
+}
+
+/// Trims a parsed name, dropping it when nothing is left
+fn trimmed_name(name: Option<String>) -> Option<String> {
+    let name = name?;
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        None
+    } else if trimmed.len() == name.len() {
+        Some(name)
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Extracts both project name and tab name from a window title
+///
+/// Shorthand for [`parse_title`] when only the project and tab are needed.
+pub fn extract_project_and_tab_from_title(title: &str) -> (Option<String>, Option<String>) {
+    let parsed = parse_title(None, title);
+    (parsed.project, parsed.tab)
+}
+
+/// Parses a window title into a [`ParsedTitle`]
+///
+/// User rules scoped to `app_name` (or to every editor) are tried before the
+/// built-in heuristics.
+pub fn parse_title(app_name: Option<&str>, title: &str) -> ParsedTitle {
+    let mut parsed = parse_title_parts(app_name, title);
+    parsed.project = trimmed_name(parsed.project.take());
+    parsed.tab = trimmed_name(parsed.tab.take());
+
+    if let Some(project) = parsed.project.take() {
+        let (project, workspace) = strip_workspace_marker(&project);
+        parsed.project = Some(project.to_string());
+        parsed.workspace = workspace;
+    }
+
+    // Zed lists every worktree: "folder1, folder2 — file.rs"
+    if parsed.editor_kind == EditorKind::Zed {
+        if let Some(project) = parsed.project.as_deref() {
+            let worktrees: Vec<String> = project
+                .split(constants::WORKTREE_SEPARATOR)
+                .map(str::trim)
+                .filter(|worktree| !worktree.is_empty())
+                .map(str::to_string)
+                .collect();
+            if worktrees.len() > 1 {
+                parsed.project = Some(worktrees[0].clone());
+                parsed.worktrees = worktrees;
+            }
+        }
+    }
+
+    parsed
+}
+
+/// Splits the title into its parts; [`parse_title`] cleans up the result
+fn parse_title_parts(app_name: Option<&str>, title: &str) -> ParsedTitle {
+    let editor_kind = EditorKind::detect(app_name, title);
+    let mut parsed = ParsedTitle {
+        editor_kind,
+        ..ParsedTitle::default()
+    };
+
+    let (title, is_modified) = strip_modified_markers(title);
+    parsed.is_modified = is_modified;
+
+    let (title, environment) = strip_environment_marker(title);
+    parsed.environment = environment;
+
+    let (title, remote_host) = strip_bracket_marker(&title, constants::SSH_LABEL);
+    parsed.remote_host = remote_host;
+
+    let (title, profile) = strip_profile(&title);
+    parsed.profile = profile;
+    let title = title.as_ref();
+
+    if title.is_empty() {
+        return parsed;
+    }
+
+    if let Some(rule_match) = apply_user_rules(app_name, title) {
+        return ParsedTitle {
+            project: rule_match.project,
+            tab: rule_match.tab,
+            path: rule_match.path,
+            confidence: rule_match.confidence,
+            ..parsed
+        };
+    }
+
+    if let Some(name) = scratch_buffer_name(title) {
+        parsed.tab = Some(name.to_string());
+        parsed.is_scratch = true;
+        parsed.confidence = Confidence::High;
+        return parsed;
+    }
+
+    if editor_kind == EditorKind::JetBrains && is_jetbrains_auxiliary_title(title) {
+        parsed.auxiliary = true;
+        parsed.confidence = Confidence::High;
+        return parsed;
+    }
+
+    // Vim/Neovim titlestring: "main.rs + (~/code/swii/src) - NVIM"
+    if editor_kind == EditorKind::Vim {
+        if let Some(captures) = vim_title_regex().captures(title) {
+            let file = captures["file"].trim();
+            let dir = captures.name("dir").map(|m| m.as_str().trim());
+
+            parsed.tab = Some(file.to_string());
+            parsed.is_modified |= captures
+                .name("flag")
+                .is_some_and(|flag| flag.as_str().contains('+'));
+            // Vim shows the directory relative to the working directory when
+            // it can, which says nothing about the project name
+            if let Some(dir) = dir.filter(|dir| is_absolute_path(dir)) {
+                parsed.path = Some(join_path(dir, file));
+                parsed.project = extract_project_from_file_path(dir);
+            }
+            parsed.confidence = Confidence::High;
+            return parsed;
+        }
+    }
+
+    // tmux: "swii:1:nvim - \"dev-mbp.local\"", session as the project
+    if editor_kind == EditorKind::Tmux {
+        if let Some(captures) = tmux_title_regex().captures(title) {
+            let session = &captures["session"];
+            if !is_default_tmux_session_name(session) {
+                parsed.project = Some(session.to_string());
+            }
+            parsed.tab = Some(captures["window"].trim().to_string()).filter(|w| !w.is_empty());
+            parsed.confidence = Confidence::Medium;
+            return parsed;
+        }
+    }
+
+    // "buffer [+] - project - Kakoune", "file – project – BBEdit"
+    if let Some(segments) = constants::APP_NAME_SUFFIXES
+        .iter()
+        .filter(|(_, kind)| *kind == editor_kind)
+        .find_map(|(name, _)| strip_app_name_suffix(title, name))
+    {
+        parse_tab_project_segments(&segments, &mut parsed);
+        return parsed;
+    }
+
+    if editor_kind == EditorKind::Eclipse {
+        if let Some(segments) = strip_product_suffix(title, constants::ECLIPSE_PRODUCT) {
+            parse_eclipse_segments(&segments, &mut parsed);
+            return parsed;
+        }
+    }
+
+    // "project - Apache NetBeans IDE 21"
+    if editor_kind == EditorKind::NetBeans {
+        if let Some(segments) = strip_product_suffix(title, constants::NETBEANS_PRODUCT) {
+            parsed.project = segments.first().map(|s| s.to_string());
+            parsed.confidence = Confidence::High;
+            return parsed;
+        }
+    }
+
+    if editor_kind == EditorKind::RStudio {
+        if let Some(segments) = strip_product_suffix(title, constants::RSTUDIO_PRODUCT) {
+            parse_rstudio_segments(&segments, &mut parsed);
+            return parsed;
+        }
+    }
+
+    // "MyApp.xcworkspace", "MyApp — MyApp.xcodeproj", "Sandbox.playground"
+    if editor_kind == EditorKind::Xcode {
+        let segments: Vec<&str> = title
+            .split(constants::EM_DASH_SEPARATOR)
+            .map(str::trim)
+            .collect();
+        if let Some((project, container)) = segments
+            .iter()
+            .rev()
+            .find_map(|segment| xcode_container_name(segment))
+        {
+            parsed.project = Some(project);
+            parsed.xcode_container = Some(container);
+            parsed.confidence = Confidence::High;
+            return parsed;
+        }
+        // A lone file name ("ContentView.swift") is the open editor, not the
+        // project; the window's document has to supply that
+        if let [file] = segments.as_slice() {
+            if Path::new(file).extension().is_some() {
+                parsed.tab = Some(file.to_string());
+                return parsed;
+            }
+        }
+    }
+
+    if editor_kind == EditorKind::Matlab {
+        parse_matlab_title(title, &mut parsed);
+        return parsed;
+    }
+
+    // Nova and TextMate leave their name out: "file.swift — project"
+    if matches!(editor_kind, EditorKind::Nova | EditorKind::TextMate) {
+        let segments: Vec<&str> = title
+            .split(constants::EM_DASH_SEPARATOR)
+            .map(str::trim)
+            .collect();
+        parse_tab_project_segments(&segments, &mut parsed);
+        return parsed;
+    }
+
+    parsed.path = extract_path_from_title(title);
+
+    // Em dash formats: Handle different patterns
+    if let Some(mut parts) = split_segments(title) {
+        // "file — project — SSH: host": drop trailing editor names and
+        // remote markers, then treat the first two segments as the pair
+        while parts.len() > 2 && is_trailing_decoration(parts[parts.len() - 1]) {
+            let segment = parts.pop().unwrap_or_default();
+            if let Some(host) = labelled_value(segment, constants::SSH_LABEL) {
+                parsed.remote_host.get_or_insert_with(|| host.to_string());
+            }
+            for (label, kind) in constants::ENVIRONMENT_LABELS {
+                if let Some(name) = labelled_value(segment, label) {
+                    parsed.environment.get_or_insert_with(|| DevEnvironment {
+                        kind: *kind,
+                        name: name.to_string(),
+                    });
+                }
+            }
+        }
+
+        if parts.len() >= 2 {
+            let first_part = parts[0].trim();
+            let second_part = parts[1].trim();
+
+            // Validate that both parts are meaningful (not just symbols or empty)
+            if !first_part.is_empty()
+                && !second_part.is_empty()
+                && first_part.len() > constants::MIN_PROJECT_NAME_LENGTH
+                && second_part.len() > constants::MIN_PROJECT_NAME_LENGTH
+                && !first_part
+                    .chars()
+                    .all(|c| c.is_ascii_punctuation() || c == '—' || c == '–')
+                && !second_part
+                    .chars()
+                    .all(|c| c.is_ascii_punctuation() || c == '—' || c == '–')
+                && first_part != "—"
+                && first_part != "–"
+                && first_part != "-"
+                && second_part != "—"
+                && second_part != "–"
+                && second_part != "-"
+            {
+                // Smart detection: determine which part is project vs tab based on content
+                let (project, tab) = determine_project_and_tab_from_parts(first_part, second_part);
+                parsed.project = Some(project.to_string());
+                parsed.tab = Some(tab.to_string());
+                parsed.confidence = Confidence::Medium;
+                return parsed;
+            }
+        }
+    }
+
+    // VS Code / Cursor format with regular dash: "filename.ext - ProjectName - Visual Studio Code"
+    if editors::title_contains_vscode_or_cursor(title) {
+        let parts: Vec<&str> = title.split(constants::REGULAR_DASH_SEPARATOR).collect();
+        if parts.len() >= 3 {
+            let tab_name = parts[0].trim();
+            let project_name = parts[1].trim();
+            if !tab_name.is_empty() && !project_name.is_empty() && !project_name.contains('/') {
+                // "file - project - profile - Visual Studio Code"
+                if parts.len() >= 4 && is_editor_name(parts[parts.len() - 1]) {
+                    parsed
+                        .profile
+                        .get_or_insert_with(|| parts[2].trim().to_string());
+                }
+                parsed.project = Some(project_name.to_string());
+                parsed.tab = Some(tab_name.to_string());
+                parsed.confidence = Confidence::High;
+                return parsed;
+            }
+        }
+    }
+
+    // Fallback to just project extraction (no tab info available)
+    if let Some(project) = extract_project_from_title(title) {
+        // Validate that the extracted project is meaningful
+        // Exclude single characters, punctuation, and known invalid patterns
+        if project.len() > constants::MIN_PROJECT_NAME_LENGTH
+            && !project
+                .chars()
+                .all(|c| c.is_ascii_punctuation() || c == '—' || c == '–')
+            && !project.contains('—')
+            && !project.contains('–')
+            && !project.ends_with(' ')
+            && !project.starts_with(' ')
+            && project != "—"
+            && project != "–"
+            && project != "-"
+        {
+            parsed.project = Some(project);
+        }
+    }
+
+    parsed
+}
+
+/// Finds a file or folder path shown in the title, e.g. the bracketed path of
+/// IntelliJ-family titles or the leading path of Sublime Text titles
+fn extract_path_from_title(title: &str) -> Option<String> {
+    if let Some(open) = title.find('[') {
+        let inner = title[open + 1..]
+            .split(']')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        if is_absolute_path(inner) {
+            return Some(inner.to_string());
+        }
+    }
+
+    title
+        .split(constants::REGULAR_DASH_SEPARATOR)
+        .map(str::trim)
+        .find(|part| is_absolute_path(part))
+        .map(str::to_string)
+}
+
+/// Whether a title segment is an absolute path: "/usr", "~/code",
+/// "C:\Users" or a UNC path like "\\server\share"
+fn is_absolute_path(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    text.starts_with(['/', '~'])
+        || text.starts_with("\\\\")
+        || (bytes.len() >= 3
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && constants::PATH_SEPARATORS.contains(&(bytes[2] as char)))
+}
+
+/// Joins a file name to a directory with the separator the directory uses
+fn join_path(dir: &str, file: &str) -> String {
+    let separator = if dir.contains('/') || !dir.contains('\\') {
+        '/'
+    } else {
+        '\\'
+    };
+    format!(
+        "{}{}{}",
+        dir.trim_end_matches(constants::PATH_SEPARATORS),
+        separator,
+        file
+    )
+}
+
+/// Splits a Unix or Windows path into its named components, leaving out the
+/// drive letter
+fn path_components(path: &str) -> Vec<&str> {
+    path.split(constants::PATH_SEPARATORS)
+        .filter(|component| !component.is_empty())
+        .filter(|component| {
+            !(component.len() == 2
+                && component.ends_with(':')
+                && component.as_bytes()[0].is_ascii_alphabetic())
+        })
+        .collect()
+}
+
+/// Extracts project name from a file path by looking for meaningful directory names
+pub fn extract_project_from_file_path(path: &str) -> Option<String> {
+    let path_parts = path_components(path);
+
+    // Look for common project indicators in the path
+    for (i, part) in path_parts.iter().enumerate() {
+        if *part == "src" || *part == "lib" || *part == "app" {
+            // The directory before src/lib/app might be the project
+            if i > 0 {
+                let potential_project = path_parts[i - 1];
+                if !potential_project.is_empty() && potential_project.len() > 1 {
+                    return Some(potential_project.to_string());
+                }
+            }
+        }
+    }
+
+    // Fallback: take the last meaningful directory name
+    for part in path_parts.iter().rev() {
+        if !part.is_empty()
+            && !part.starts_with('.')
+            && part.len() > 1
+            && !part.chars().all(|c| c.is_numeric())
+        {
+            return Some(part.to_string());
+        }
+    }
+
+    None
+}
+
+/// A project root lookup for one directory, including failed ones
+struct CachedRoot {
+    root: Option<PathBuf>,
+    looked_up: Instant,
+}
+
+fn root_cache() -> &'static Mutex<HashMap<PathBuf, CachedRoot>> {
+    static ROOT_CACHE: OnceLock<Mutex<HashMap<PathBuf, CachedRoot>>> = OnceLock::new();
+    ROOT_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Forgets all project root lookups, e.g. after the root markers change
+pub fn clear_root_cache() {
+    root_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+/// Closest directory at or above `dir` that contains a root marker,
+/// remembered for [`constants::ROOT_CACHE_TTL_SECS`] so repeated window
+/// enumerations don't stat the same directories over and over
+fn cached_project_root(dir: &Path) -> Option<PathBuf> {
+    let ttl = Duration::from_secs(constants::ROOT_CACHE_TTL_SECS);
+    if let Some(cached) = root_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(dir)
+        .filter(|cached| cached.looked_up.elapsed() < ttl)
+    {
+        return cached.root.clone();
+    }
+
+    let root = {
+        let config = config();
+        dir.ancestors()
+            .find(|ancestor| ancestor.file_name().is_some() && config.is_project_root(ancestor))
+            .map(Path::to_path_buf)
+    };
+
+    let mut cache = root_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if cache.len() >= constants::ROOT_CACHE_CAPACITY {
+        cache.retain(|_, cached| cached.looked_up.elapsed() < ttl);
+        if cache.len() >= constants::ROOT_CACHE_CAPACITY {
+            cache.clear();
+        }
+    }
+    cache.insert(
+        dir.to_path_buf(),
+        CachedRoot {
+            root: root.clone(),
+            looked_up: Instant::now(),
+        },
+    );
+    root
+}
+
+/// Strips a `file://` prefix from a document path or URL
+fn strip_file_url(file_path: &str) -> &str {
+    file_path
+        .strip_prefix(constants::FILE_URL_PREFIX)
+        .unwrap_or(file_path)
+}
+
+/// Root directory of the project containing `file_path`: the closest
+/// ancestor holding one of the configured root markers
+pub fn find_project_root(file_path: &str) -> Option<PathBuf> {
+    Path::new(strip_file_url(file_path))
+        .parent()
+        .and_then(cached_project_root)
+}
+
+/// Root directory of the project containing the directory `dir`, which may
+/// be the root itself
+pub fn find_project_root_from_dir(dir: &str) -> Option<PathBuf> {
+    cached_project_root(Path::new(strip_file_url(dir)))
+}
+
+/// Reconciles a title-derived project with the document the window has open
+///
+/// Title heuristics can't tell a tab named like a project from the project
+/// itself, so when a project root found on disk disagrees with the title,
+/// the root wins and `project_source` records that. Multi-root workspaces and
+/// Zed windows listing the root as a worktree are left alone.
+pub fn reconcile_with_document(parsed: ParsedTitle, file_path: &str) -> ParsedTitle {
+    if parsed.editor_kind == EditorKind::Xcode {
+        return match xcode_container_from_path(file_path) {
+            Some((project, container)) if parsed.project.as_deref() != Some(project.as_str()) => {
+                ParsedTitle {
+                    project: Some(project),
+                    xcode_container: Some(container),
+                    path: Some(file_path.to_string()),
+                    project_source: ProjectSource::Document,
+                    ..parsed
+                }
+            }
+            _ => parsed,
+        };
+    }
+
+    let Some(root) = find_project_root(file_path) else {
+        return parsed;
+    };
+    let Some(name) = root
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+    else {
+        return parsed;
+    };
+    let project_path = Some(root.to_string_lossy().to_string());
+
+    let agrees =
+        parsed.project.as_deref() == Some(name.as_str()) || parsed.worktrees.contains(&name);
+    if agrees {
+        return ParsedTitle {
+            project_path,
+            ..parsed
+        };
+    }
+    if parsed.workspace {
+        return parsed;
+    }
+
+    ParsedTitle {
+        project: Some(name),
+        project_path,
+        path: Some(file_path.to_string()),
+        worktrees: Vec::new(),
+        project_source: ProjectSource::Document,
+        ..parsed
+    }
+}
+
+/// Extracts project name from a file path by looking for project root indicators
+pub fn extract_project_name_from_path(file_path: &str) -> Option<String> {
+    let clean_path = strip_file_url(file_path);
+
+    // Go up the directory tree looking for project root indicators
+    if let Some(project) = find_project_root(clean_path)
+        .as_deref()
+        .and_then(Path::file_name)
+    {
+        return Some(project.to_string_lossy().to_string());
+    }
+
+    // Fallback: if no project root found, extract meaningful directory name
+    // Go up a few levels and take a reasonable directory name
+    let path_components = path_components(clean_path);
+
+    // Look for meaningful project-like directory names
+    for (i, component) in path_components.iter().enumerate().rev() {
+        // Skip common non-project directories
+        if *component == "src"
+            || *component == "lib"
+            || *component == "bin"
+            || *component == "target"
+            || *component == "node_modules"
+            || component.starts_with('.')
+        {
+            continue;
+        }
+
+        // If we're in a src directory, the parent might be the project
+        if i > 0 && (*component == "src" || *component == "lib") {
+            if let Some(project_name) = path_components.get(i - 1) {
+                return Some(project_name.to_string());
+            }
+        }
+
+        // Take the first meaningful directory name
+        if component.len() > 1 && !component.chars().all(|c| c.is_numeric()) {
+            return Some(component.to_string());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{RwLock, RwLockWriteGuard};
+
+    /// Held for writing by tests that replace the global parser config and
+    /// for reading by every other test, since they all parse with it
+    static CONFIG_LOCK: RwLock<()> = RwLock::new(());
+
+    fn reading_config() -> RwLockReadGuard<'static, ()> {
+        CONFIG_LOCK.read().unwrap_or_else(|p| p.into_inner())
+    }
+
+    fn replacing_config() -> RwLockWriteGuard<'static, ()> {
+        CONFIG_LOCK.write().unwrap_or_else(|p| p.into_inner())
+    }
+
+    #[test]
+    fn test_extract_project_from_title_vscode() {
+        let _config = reading_config();
+        // VS Code format: "filename.ext - ProjectName - Visual Studio Code"
+        assert_eq!(
+            extract_project_from_title("main.rs - swii - Visual Studio Code"),
+            Some("swii".to_string())
+        );
+
+        assert_eq!(
+            extract_project_from_title("App.tsx - my-project - Visual Studio Code"),
+            Some("my-project".to_string())
+        );
+
+        // Cursor format (similar to VS Code)
+        assert_eq!(
+            extract_project_from_title("index.js - awesome-app - Cursor"),
+            Some("awesome-app".to_string())
+        );
+
+        // Cursor em dash format: "command — ProjectName"
+        assert_eq!(
+            extract_project_from_title("bun run tauri dev — swii"),
+            Some("swii".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_project_from_title_xcode() {
+        let _config = reading_config();
+        // Xcode format: "ProjectName" or "ProjectName - filename.ext"
+        assert_eq!(
+            extract_project_from_title("MyiOSApp"),
+            Some("MyiOSApp".to_string())
+        );
+
+        assert_eq!(
+            extract_project_from_title("SwiftUIDemo - ContentView.swift"),
+            Some("SwiftUIDemo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_project_from_title_zed() {
+        let _config = reading_config();
+        // Zed format: Often just "ProjectName"
+        assert_eq!(
+            extract_project_from_title("rust-analyzer"),
+            Some("rust-analyzer".to_string())
+        );
+
+        assert_eq!(
+            extract_project_from_title("webserver"),
+            Some("webserver".to_string())
+        );
+
+        // Zed em dash format: "ProjectName — filename.ext"
+        assert_eq!(
+            extract_project_from_title("switch — ARCHITECTURE.md"),
+            Some("switch".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_project_from_title_intellij() {
+        let _config = reading_config();
+        // IntelliJ family format: "ProjectName [path] - IntelliJ IDEA"
+        assert_eq!(
+            extract_project_from_title(
+                "my-spring-boot [~/projects/my-spring-boot] - IntelliJ IDEA"
+            ),
+            Some("my-spring-boot".to_string())
+        );
+
+        assert_eq!(
+            extract_project_from_title("django-webapp [/Users/dev/django-webapp] - PyCharm"),
+            Some("django-webapp".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_project_from_title_sublime() {
+        let _config = reading_config();
+        // Sublime Text with path in filename
+        assert_eq!(
+            extract_project_from_title("/Users/dev/my-project/src/main.py - Sublime Text"),
+            Some("my-project".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_project_from_title_invalid_cases() {
+        let _config = reading_config();
+        // Empty title
+        assert_eq!(extract_project_from_title(""), None);
+
+        // Just application name
+        assert_eq!(extract_project_from_title("Visual Studio Code"), None);
+
+        // No meaningful project name
+        assert_eq!(
+            extract_project_from_title("Untitled - Visual Studio Code"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_project_from_file_path() {
+        let _config = reading_config();
+        // Path with src directory
+        assert_eq!(
+            extract_project_from_file_path("/Users/dev/my-project/src/main.rs"),
+            Some("my-project".to_string())
+        );
+
+        // Path with lib directory
+        assert_eq!(
+            extract_project_from_file_path("/home/user/awesome-lib/lib/index.js"),
+            Some("awesome-lib".to_string())
+        );
+
+        // Path with app directory
+        assert_eq!(
+            extract_project_from_file_path("/projects/web-app/app/controllers/home.py"),
+            Some("web-app".to_string())
+        );
+
+        // Simple path without common indicators
+        assert_eq!(
+            extract_project_from_file_path("/Users/dev/simple-project/file.txt"),
+            Some("file.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_project_and_tab_from_title_em_dash() {
+        let _config = reading_config();
+        // Em dash format: "tab_name — project_name"
+        assert_eq!(
+            extract_project_and_tab_from_title("bun run tauri dev — swii"),
+            (
+                Some("swii".to_string()),
+                Some("bun run tauri dev".to_string())
+            )
+        );
+
+        assert_eq!(
+            extract_project_and_tab_from_title("eslint.config.mjs — promptbook"),
+            (
+                Some("promptbook".to_string()),
+                Some("eslint.config.mjs".to_string())
+            )
+        );
+
+        assert_eq!(
+            extract_project_and_tab_from_title("commands.rs — switch"),
+            (Some("switch".to_string()), Some("commands.rs".to_string()))
+        );
+
+        assert_eq!(
+            extract_project_and_tab_from_title("switch — ARCHITECTURE.md"),
+            (
+                Some("switch".to_string()),
+                Some("ARCHITECTURE.md".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_extract_project_and_tab_from_title_vscode_cursor() {
+        let _config = reading_config();
+        // VS Code/Cursor format: "filename.ext - ProjectName - Visual Studio Code"
+        assert_eq!(
+            extract_project_and_tab_from_title("main.rs - swii - Visual Studio Code"),
+            (Some("swii".to_string()), Some("main.rs".to_string()))
+        );
+
+        assert_eq!(
+            extract_project_and_tab_from_title("App.tsx - my-project - Cursor"),
+            (Some("my-project".to_string()), Some("App.tsx".to_string()))
+        );
+
+        assert_eq!(
+            extract_project_and_tab_from_title("index.js - awesome-app - Visual Studio Code"),
+            (
+                Some("awesome-app".to_string()),
+                Some("index.js".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_extract_project_and_tab_from_title_fallback() {
+        let _config = reading_config();
+        // Fallback to just project extraction when tab parsing fails
+        assert_eq!(
+            extract_project_and_tab_from_title("MyiOSApp"),
+            (Some("MyiOSApp".to_string()), None)
+        );
+
+        // Should return None for both when nothing can be extracted
+        assert_eq!(extract_project_and_tab_from_title(""), (None, None));
+
+        assert_eq!(
+            extract_project_and_tab_from_title("Visual Studio Code"),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_extract_project_and_tab_from_title_edge_cases() {
+        let _config = reading_config();
+        // Empty parts should be handled gracefully
+        assert_eq!(extract_project_and_tab_from_title(" — "), (None, None));
+
+        assert_eq!(
+            extract_project_and_tab_from_title("something — "),
+            (None, None)
+        );
+
+        // Malformed but recoverable: " — project" can extract project from fallback
+        assert_eq!(
+            extract_project_and_tab_from_title(" — project"),
+            (Some("project".to_string()), None)
+        );
+
+        // Single em dash without spaces
+        assert_eq!(
+            extract_project_and_tab_from_title("file—project"),
+            (None, None)
+        );
+
+        // Completely empty
+        assert_eq!(extract_project_and_tab_from_title(""), (None, None));
+
+        // Only punctuation
+        assert_eq!(extract_project_and_tab_from_title("—"), (None, None));
+    }
+
+    #[test]
+    fn test_determine_project_and_tab_from_parts() {
+        let _config = reading_config();
+        // Test file extension detection
+        assert_eq!(
+            determine_project_and_tab_from_parts("switch", "ARCHITECTURE.md"),
+            ("switch", "ARCHITECTURE.md")
+        );
+
+        assert_eq!(
+            determine_project_and_tab_from_parts("commands.rs", "switch"),
+            ("switch", "commands.rs")
+        );
+
+        assert_eq!(
+            determine_project_and_tab_from_parts("promptbook", "eslint.config.mjs"),
+            ("promptbook", "eslint.config.mjs")
+        );
+
+        // Test command detection
+        assert_eq!(
+            determine_project_and_tab_from_parts("bun run tauri dev", "swii"),
+            ("swii", "bun run tauri dev")
+        );
+
+        assert_eq!(
+            determine_project_and_tab_from_parts("npm start", "my-project"),
+            ("my-project", "npm start")
+        );
+
+        // Test length heuristic (requires significant length difference)
+        assert_eq!(
+            determine_project_and_tab_from_parts("app", "some-really-long-filename-or-command"),
+            ("app", "some-really-long-filename-or-command")
+        );
+
+        // Test default fallback
+        assert_eq!(
+            determine_project_and_tab_from_parts("project", "tab"),
+            ("project", "tab")
+        );
+    }
+
+    #[test]
+    fn test_has_file_extension() {
+        let _config = reading_config();
+        assert!(has_file_extension("file.txt"));
+        assert!(has_file_extension("ARCHITECTURE.md"));
+        assert!(has_file_extension("commands.rs"));
+        assert!(has_file_extension("eslint.config.mjs"));
+        assert!(has_file_extension("package.json"));
+        assert!(has_file_extension("main.c"));
+        assert!(has_file_extension("schema.prisma"));
+        assert!(has_file_extension("api.dockerfile"));
+
+        assert!(!has_file_extension("project"));
+        assert!(!has_file_extension("switch"));
+        assert!(!has_file_extension("no-extension"));
+        assert!(!has_file_extension("file."));
+        assert!(!has_file_extension("file.muchtoolongext"));
+        assert!(!has_file_extension("v1.2"));
+    }
+
+    #[test]
+    fn test_configured_extensions() {
+        let _config = reading_config();
+        let (config, _) = ParserConfig::from_rules(&[]);
+        let config =
+            config.with_extensions(Some((2, 4)), &[".Dockerfile".to_string(), "c".to_string()]);
+        assert!(config.accepts_extension("rs"));
+        assert!(config.accepts_extension("c"));
+        assert!(config.accepts_extension("dockerfile"));
+        assert!(!config.accepts_extension("prisma"));
+        assert!(!config.accepts_extension("x"));
+    }
+
+    #[test]
+    fn test_user_rules() {
+        let _guard = replacing_config();
+        let (config, errors) = ParserConfig::from_rules(&[
+            TitleRule {
+                editor: Some("Nova".to_string()),
+                pattern: r"^(?P<tab>[^·]+) · (?P<project>.+)$".to_string(),
+            },
+            TitleRule {
+                editor: None,
+                pattern: r"^edit: (?P<path>\S+)$".to_string(),
+            },
+            TitleRule {
+                editor: None,
+                pattern: "(unclosed".to_string(),
+            },
+        ]);
+        assert_eq!(config.rule_count(), 2);
+        assert_eq!(errors.len(), 1);
+        set_config(config);
+
+        let parsed = parse_title(Some("nova"), "main.swift · weather");
+        assert_eq!(parsed.project.as_deref(), Some("weather"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.swift"));
+        assert_eq!(parsed.confidence, Confidence::High);
+        // Rules scoped to another editor don't apply
+        assert_eq!(parse_title(Some("Zed"), "main.swift · weather").tab, None);
+
+        let parsed = parse_title(None, "edit: /Users/dev/shop/src/cart.rs");
+        assert_eq!(parsed.project.as_deref(), Some("shop"));
+        assert_eq!(parsed.path.as_deref(), Some("/Users/dev/shop/src/cart.rs"));
+
+        set_config(ParserConfig::default());
+    }
+
+    #[test]
+    fn test_extract_project_and_tab_from_title_many_segments() {
+        let _config = reading_config();
+        assert_eq!(
+            extract_project_and_tab_from_title("main.rs — swii — SSH: build-box"),
+            (Some("swii".to_string()), Some("main.rs".to_string()))
+        );
+
+        assert_eq!(
+            extract_project_and_tab_from_title("App.tsx — web — Visual Studio Code"),
+            (Some("web".to_string()), Some("App.tsx".to_string()))
+        );
+
+        // Without decorations the first two segments are used
+        assert_eq!(
+            extract_project_and_tab_from_title("switch — lib.rs — extra"),
+            (Some("switch".to_string()), Some("lib.rs".to_string()))
+        );
+
+        assert_eq!(
+            parse_title(None, "main.rs — swii — SSH: build-box").confidence,
+            Confidence::Medium
+        );
+    }
+
+    #[test]
+    fn test_modified_markers() {
+        let _config = reading_config();
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "● main.rs - swii - Visual Studio Code",
+        );
+        assert!(parsed.is_modified);
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+
+        let parsed = parse_title(Some("Xcode"), "ContentView.swift — SwiftUIDemo — Edited");
+        assert!(parsed.is_modified);
+        assert_eq!(parsed.project.as_deref(), Some("SwiftUIDemo"));
+        assert_eq!(parsed.tab.as_deref(), Some("ContentView.swift"));
+
+        assert!(!parse_title(Some("Zed"), "switch — ARCHITECTURE.md").is_modified);
+    }
+
+    #[test]
+    fn test_remote_ssh_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "main.rs - swii [SSH: devbox] - Visual Studio Code",
+        );
+        assert_eq!(parsed.remote_host.as_deref(), Some("devbox"));
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+
+        let parsed = parse_title(Some("Cursor"), "main.rs — swii — SSH: build-box");
+        assert_eq!(parsed.remote_host.as_deref(), Some("build-box"));
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+
+        assert_eq!(parse_title(None, "main.rs — swii").remote_host, None);
+    }
+
+    #[test]
+    fn test_environment_markers() {
+        let _config = reading_config();
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "compose.yaml - api [Dev Container: api-dev] - Visual Studio Code",
+        );
+        assert_eq!(parsed.project.as_deref(), Some("api"));
+        assert_eq!(
+            parsed.environment,
+            Some(DevEnvironment {
+                kind: DevEnvironmentKind::DevContainer,
+                name: "api-dev".to_string(),
+            })
+        );
+
+        let parsed = parse_title(Some("Visual Studio Code"), "main.go — tools [WSL: Ubuntu]");
+        assert_eq!(parsed.project.as_deref(), Some("tools"));
+        assert_eq!(parsed.environment.unwrap().kind, DevEnvironmentKind::Wsl);
+
+        let parsed = parse_title(None, "main.go — tools — WSL: Debian");
+        assert_eq!(parsed.environment.unwrap().name, "Debian");
+        assert_eq!(parsed.remote_host, None);
+    }
+
+    #[test]
+    fn test_workspace_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "main.rs — platform.code-workspace (Workspace) — Visual Studio Code",
+        );
+        assert_eq!(parsed.project.as_deref(), Some("platform"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+        assert!(parsed.workspace);
+
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "README - platform (Workspace) - Visual Studio Code",
+        );
+        assert_eq!(parsed.project.as_deref(), Some("platform"));
+        assert!(parsed.workspace);
+
+        assert!(!parse_title(None, "main.rs — swii").workspace);
+    }
+
+    #[test]
+    fn test_profile_suffixes() {
+        let _guard = replacing_config();
+        // The position of the profile is known when the app name is present
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "main.rs - swii - Work - Visual Studio Code",
+        );
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.profile.as_deref(), Some("Work"));
+
+        let (config, _) = ParserConfig::from_rules(&[]);
+        set_config(config.with_profiles(&["Rust Dev".to_string()]));
+
+        // Without an open file the profile would otherwise pass for the tab
+        let parsed = parse_title(Some("Visual Studio Code"), "swii — Rust Dev");
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab, None);
+        assert_eq!(parsed.profile.as_deref(), Some("Rust Dev"));
+
+        let parsed = parse_title(Some("Visual Studio Code"), "main.rs — swii — Rust Dev");
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+
+        set_config(ParserConfig::default());
+    }
+
+    #[test]
+    fn test_zed_worktrees() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("Zed"), "swii, tauri-plugins — main.rs");
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+        assert_eq!(parsed.worktrees, vec!["swii", "tauri-plugins"]);
+
+        let parsed = parse_title(Some("Zed"), "swii — main.rs");
+        assert!(parsed.worktrees.is_empty());
+
+        // Commas only separate worktrees in Zed titles
+        let parsed = parse_title(Some("Sublime Text"), "notes, draft — todo.md");
+        assert_eq!(parsed.project.as_deref(), Some("notes, draft"));
+        assert!(parsed.worktrees.is_empty());
+    }
+
+    #[test]
+    fn test_jetbrains_auxiliary_windows() {
+        let _config = reading_config();
+        for title in [
+            "Welcome to IntelliJ IDEA",
+            "Settings",
+            "Project Structure",
+            "main.rs (~/work/api/src) vs main.rs (HEAD)",
+            "Diff: build.gradle",
+        ] {
+            let parsed = parse_title(Some("IntelliJ IDEA"), title);
+            assert!(parsed.auxiliary, "{} should be auxiliary", title);
+            assert_eq!(parsed.project, None, "{}", title);
+        }
+
+        let parsed = parse_title(
+            Some("PyCharm"),
+            "django-webapp [/Users/dev/django-webapp] - PyCharm",
+        );
+        assert!(!parsed.auxiliary);
+        assert_eq!(parsed.project.as_deref(), Some("django-webapp"));
+
+        // Other editors are unaffected
+        assert!(!parse_title(Some("Zed"), "Settings").auxiliary);
+    }
+
+    #[test]
+    fn test_terminal_vim_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("iTerm2"), "main.rs + (~/code/swii/src) - NVIM");
+        assert_eq!(parsed.editor_kind, EditorKind::Vim);
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+        assert_eq!(parsed.path.as_deref(), Some("~/code/swii/src/main.rs"));
+        assert!(parsed.is_modified);
+
+        let parsed = parse_title(Some("MacVim"), "notes.md (/Users/dev/wiki) - VIM");
+        assert_eq!(parsed.project.as_deref(), Some("wiki"));
+        assert!(!parsed.is_modified);
+
+        // Relative directories don't reveal the project
+        let parsed = parse_title(Some("kitty"), "lib.rs (src) - VIM");
+        assert_eq!(parsed.tab.as_deref(), Some("lib.rs"));
+        assert_eq!(parsed.project, None);
+
+        // Ordinary terminal titles aren't mistaken for Vim
+        assert_eq!(
+            parse_title(Some("iTerm2"), "dev@host: ~/code").editor_kind,
+            EditorKind::Unknown
+        );
+    }
+
+    #[test]
+    fn test_tmux_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("iTerm2"), "swii:1:nvim - \"dev-mbp.local\" ");
+        assert_eq!(parsed.editor_kind, EditorKind::Tmux);
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("nvim"));
+        assert_eq!(parsed.confidence, Confidence::Medium);
+
+        // Unnamed sessions are numbered, which says nothing about the project
+        let parsed = parse_title(Some("kitty"), "0:2:zsh - \"~/code/api\" 1!");
+        assert_eq!(parsed.editor_kind, EditorKind::Tmux);
+        assert_eq!(parsed.project, None);
+        assert_eq!(parsed.tab.as_deref(), Some("zsh"));
+
+        assert_eq!(
+            tmux_session_from_title("work api:3:cargo - \"host\""),
+            Some("work api")
+        );
+        assert_eq!(tmux_session_from_title("main.rs - swii"), None);
+        assert_eq!(
+            parse_title(Some("Terminal"), "dev@host: ~/code").editor_kind,
+            EditorKind::Unknown
+        );
+    }
+
+    #[test]
+    fn test_newer_editor_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("Pulsar"), "main.rs — ~/code/swii — Pulsar");
+        assert_eq!(parsed.editor_kind, EditorKind::Pulsar);
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+        assert_eq!(parsed.path.as_deref(), Some("~/code/swii"));
+
+        let parsed = parse_title(Some("Lapce"), "lib.rs - parser - Lapce");
+        assert_eq!(parsed.project.as_deref(), Some("parser"));
+        assert_eq!(parsed.tab.as_deref(), Some("lib.rs"));
+
+        let parsed = parse_title(Some("Lapce"), "parser - Lapce");
+        assert_eq!(parsed.project.as_deref(), Some("parser"));
+        assert_eq!(parsed.tab, None);
+
+        // Terminal editors are recognized from the title alone
+        let parsed = parse_title(Some("kitty"), "main.rs [+] - swii - Kakoune");
+        assert_eq!(parsed.editor_kind, EditorKind::Kakoune);
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert!(parsed.is_modified);
+
+        let parsed = parse_title(Some("Alacritty"), "/home/dev/api/src/db.rs - Helix");
+        assert_eq!(parsed.editor_kind, EditorKind::Helix);
+        assert_eq!(parsed.tab.as_deref(), Some("db.rs"));
+        assert_eq!(parsed.project.as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn test_editor_channel_app_names() {
+        let _config = reading_config();
+        for (app_name, kind) in [
+            ("Code - Insiders", EditorKind::VsCode),
+            ("VSCodium", EditorKind::VsCode),
+            ("Cursor Nightly", EditorKind::Cursor),
+            ("Zed Preview", EditorKind::Zed),
+        ] {
+            assert_eq!(EditorKind::from_app_name(app_name), kind, "{}", app_name);
+        }
+    }
+
+    #[test]
+    fn test_native_mac_editor_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("BBEdit"), "notes.md – journal – BBEdit");
+        assert_eq!(parsed.editor_kind, EditorKind::BbEdit);
+        assert_eq!(parsed.project.as_deref(), Some("journal"));
+        assert_eq!(parsed.tab.as_deref(), Some("notes.md"));
+        assert_eq!(parsed.confidence, Confidence::High);
+
+        let parsed = parse_title(None, "index.html – site – BBEdit");
+        assert_eq!(parsed.editor_kind, EditorKind::BbEdit);
+        assert_eq!(parsed.project.as_deref(), Some("site"));
+
+        let parsed = parse_title(Some("Nova"), "App.swift — Weather");
+        assert_eq!(parsed.editor_kind, EditorKind::Nova);
+        assert_eq!(parsed.project.as_deref(), Some("Weather"));
+        assert_eq!(parsed.tab.as_deref(), Some("App.swift"));
+
+        let parsed = parse_title(Some("Nova"), "Weather");
+        assert_eq!(parsed.project.as_deref(), Some("Weather"));
+        assert_eq!(parsed.tab, None);
+
+        let parsed = parse_title(Some("TextMate"), "routes.rb — blog");
+        assert_eq!(parsed.editor_kind, EditorKind::TextMate);
+        assert_eq!(parsed.project.as_deref(), Some("blog"));
+        assert_eq!(parsed.tab.as_deref(), Some("routes.rb"));
+    }
+
+    #[test]
+    fn test_eclipse_and_netbeans_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(
+            Some("Eclipse"),
+            "workspace - billing/src/main/java/App.java - Eclipse IDE",
+        );
+        assert_eq!(parsed.editor_kind, EditorKind::Eclipse);
+        assert_eq!(parsed.project.as_deref(), Some("billing"));
+        assert_eq!(parsed.tab.as_deref(), Some("App.java"));
+        assert_eq!(
+            parsed.path.as_deref(),
+            Some("billing/src/main/java/App.java")
+        );
+
+        let parsed = parse_title(Some("Eclipse"), "workspace - billing - Eclipse IDE");
+        assert_eq!(parsed.project.as_deref(), Some("billing"));
+        assert_eq!(parsed.tab, None);
+
+        let parsed = parse_title(None, "dev-ws - Eclipse Platform");
+        assert_eq!(parsed.editor_kind, EditorKind::Eclipse);
+        assert_eq!(parsed.project.as_deref(), Some("dev-ws"));
+
+        let parsed = parse_title(
+            Some("Apache NetBeans"),
+            "inventory - Apache NetBeans IDE 21",
+        );
+        assert_eq!(parsed.editor_kind, EditorKind::NetBeans);
+        assert_eq!(parsed.project.as_deref(), Some("inventory"));
+        assert_eq!(parsed.confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_data_science_ide_titles() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("RStudio"), "survey-analysis - RStudio");
+        assert_eq!(parsed.editor_kind, EditorKind::RStudio);
+        assert_eq!(parsed.project.as_deref(), Some("survey-analysis"));
+
+        let parsed = parse_title(Some("RStudio"), "~/code/survey-analysis - main - RStudio");
+        assert_eq!(parsed.project.as_deref(), Some("survey-analysis"));
+        assert_eq!(parsed.path.as_deref(), Some("~/code/survey-analysis"));
+
+        let parsed = parse_title(None, "survey.Rproj - RStudio");
+        assert_eq!(parsed.editor_kind, EditorKind::RStudio);
+        assert_eq!(parsed.project.as_deref(), Some("survey"));
+
+        let parsed = parse_title(
+            Some("MATLAB_R2024a"),
+            "Editor - /Users/dev/control/src/pid_tune.m",
+        );
+        assert_eq!(parsed.editor_kind, EditorKind::Matlab);
+        assert_eq!(parsed.tab.as_deref(), Some("pid_tune.m"));
+        assert_eq!(parsed.project.as_deref(), Some("control"));
+
+        let parsed = parse_title(Some("MATLAB_R2024a"), "MATLAB R2024a - academic use");
+        assert_eq!(parsed.project, None);
+        assert_eq!(parsed.tab, None);
+
+        let parsed = parse_title(Some("JupyterLab"), "churn.ipynb - JupyterLab");
+        assert_eq!(parsed.editor_kind, EditorKind::Jupyter);
+        assert_eq!(parsed.tab.as_deref(), Some("churn.ipynb"));
+        assert_eq!(parsed.project, None);
+
+        let parsed = parse_title(Some("JupyterLab"), "churn.ipynb - retention - JupyterLab");
+        assert_eq!(parsed.tab.as_deref(), Some("churn.ipynb"));
+        assert_eq!(parsed.project.as_deref(), Some("retention"));
+    }
+
+    #[test]
+    fn test_xcode_containers() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("Xcode"), "Weather.xcworkspace");
+        assert_eq!(parsed.project.as_deref(), Some("Weather"));
+        assert_eq!(parsed.xcode_container, Some(XcodeContainer::Workspace));
+
+        let parsed = parse_title(Some("Xcode"), "Weather — WeatherKit.xcodeproj");
+        assert_eq!(parsed.project.as_deref(), Some("WeatherKit"));
+        assert_eq!(parsed.xcode_container, Some(XcodeContainer::Project));
+
+        let parsed = parse_title(Some("Xcode"), "Sketches.playground");
+        assert_eq!(parsed.project.as_deref(), Some("Sketches"));
+        assert_eq!(parsed.xcode_container, Some(XcodeContainer::Playground));
+
+        // File names alone are left for the window document to resolve
+        let parsed = parse_title(Some("Xcode"), "ContentView.swift");
+        assert_eq!(parsed.project, None);
+        assert_eq!(parsed.tab.as_deref(), Some("ContentView.swift"));
+
+        let parsed = parse_title(Some("Xcode"), "ContentView.swift — SwiftUIDemo");
+        assert_eq!(parsed.project.as_deref(), Some("SwiftUIDemo"));
+        assert_eq!(parsed.xcode_container, None);
+    }
+
+    #[test]
+    fn test_xcode_container_from_path() {
+        let _config = reading_config();
+        assert_eq!(
+            xcode_container_from_path("file:///Users/dev/Weather/Weather.xcworkspace/"),
+            Some(("Weather".to_string(), XcodeContainer::Workspace))
+        );
+        assert_eq!(
+            xcode_container_from_path("/Users/dev/Weather/WeatherKit.xcodeproj"),
+            Some(("WeatherKit".to_string(), XcodeContainer::Project))
+        );
+        assert_eq!(
+            xcode_container_from_path("/Users/dev/Weather/Sources/App.swift"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reconcile_with_document() {
+        let _guard = replacing_config();
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        let ledger = root.join("ledger");
+        std::fs::create_dir_all(ledger.join("src")).unwrap();
+        std::fs::write(ledger.join("Cargo.toml"), "").unwrap();
+        let file = ledger.join("src/main.rs").to_string_lossy().to_string();
+        let ledger_path = Some(ledger.to_string_lossy().to_string());
+
+        let title = |title: &str| parse_title(Some("Code"), title);
+
+        // The document's root wins over a conflicting title
+        let parsed = reconcile_with_document(title("main.rs - api - Visual Studio Code"), &file);
+        assert_eq!(parsed.project.as_deref(), Some("ledger"));
+        assert_eq!(parsed.project_path, ledger_path);
+        assert_eq!(parsed.project_source, ProjectSource::Document);
+        assert_eq!(parsed.tab.as_deref(), Some("main.rs"));
+
+        // Agreement only adds the path
+        let parsed = reconcile_with_document(title("main.rs - ledger - Visual Studio Code"), &file);
+        assert_eq!(parsed.project.as_deref(), Some("ledger"));
+        assert_eq!(parsed.project_path, ledger_path);
+        assert_eq!(parsed.project_source, ProjectSource::Title);
+
+        // Workspaces name the workspace, not the folder the document is in
+        let workspace = title("main.rs - team (Workspace) - Visual Studio Code");
+        assert_eq!(reconcile_with_document(workspace.clone(), &file), workspace);
+
+        // Without a root on disk the title is kept
+        let parsed = title("main.rs - api - Visual Studio Code");
+        assert_eq!(
+            reconcile_with_document(parsed.clone(), "/nonexistent/swii/main.rs"),
+            parsed
+        );
+    }
+
+    #[test]
+    fn test_scratch_buffers() {
+        let _config = reading_config();
+        for (app_name, title, tab) in [
+            ("Code", "Untitled-1 — Visual Studio Code", "Untitled-1"),
+            ("Code", "● Untitled-3 - Visual Studio Code", "Untitled-3"),
+            ("Cursor", "Untitled-1 - Cursor", "Untitled-1"),
+            ("Sublime Text", "untitled - Sublime Text", "untitled"),
+            ("Zed", "empty project — untitled", "untitled"),
+            ("Neovim", "[No Name] - NVIM", "[No Name]"),
+            ("Emacs", "*scratch* - GNU Emacs", "*scratch*"),
+            ("TextEdit", "Untitled", "Untitled"),
+        ] {
+            let parsed = parse_title(Some(app_name), title);
+            assert!(parsed.is_scratch, "{title}");
+            assert_eq!(parsed.project, None, "{title}");
+            assert_eq!(parsed.tab.as_deref(), Some(tab), "{title}");
+        }
+
+        // An untitled tab in a project window still belongs to the project
+        let parsed = parse_title(
+            Some("Visual Studio Code"),
+            "Untitled-2 - swii - Visual Studio Code",
+        );
+        assert!(!parsed.is_scratch);
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+        assert_eq!(parsed.tab.as_deref(), Some("Untitled-2"));
+
+        assert!(!parse_title(Some("Zed"), "untitled-notes").is_scratch);
+    }
+
+    #[test]
+    fn test_windows_paths() {
+        let _config = reading_config();
+        assert_eq!(
+            extract_project_from_file_path(r"C:\Users\dev\billing\src\main.rs"),
+            Some("billing".to_string())
+        );
+        assert_eq!(
+            extract_project_name_from_path(r"D:\work\inventory"),
+            Some("inventory".to_string())
+        );
+        assert!(is_absolute_path(r"C:\code"));
+        assert!(is_absolute_path(r"\\fileserver\share"));
+        assert!(!is_absolute_path(r"src\main.rs"));
+
+        let parsed = parse_title(
+            Some("MATLAB_R2024a"),
+            r"Editor - C:\Users\dev\control\src\pid_tune.m",
+        );
+        assert_eq!(parsed.tab.as_deref(), Some("pid_tune.m"));
+        assert_eq!(parsed.project.as_deref(), Some("control"));
+
+        let parsed = parse_title(Some("Neovim"), r"main.rs (C:\code\swii\src) - NVIM");
+        assert_eq!(parsed.path.as_deref(), Some(r"C:\code\swii\src\main.rs"));
+        assert_eq!(parsed.project.as_deref(), Some("swii"));
+
+        let parsed = parse_title(Some("Helix"), r"C:\code\api\src\db.rs - Helix");
+        assert_eq!(parsed.tab.as_deref(), Some("db.rs"));
+        assert_eq!(parsed.project.as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn test_project_root_markers() {
+        let _guard = replacing_config();
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        let flake = root.join("dotfiles");
+        let custom = root.join("monorepo");
+        std::fs::create_dir_all(flake.join("modules")).unwrap();
+        std::fs::create_dir_all(custom.join("services/api")).unwrap();
+        std::fs::write(flake.join("flake.nix"), "{}").unwrap();
+        std::fs::write(custom.join("WORKSPACE"), "").unwrap();
+
+        let file = flake.join("modules/shell.nix");
+        assert_eq!(
+            extract_project_name_from_path(&file.to_string_lossy()),
+            Some("dotfiles".to_string())
+        );
+        assert_eq!(find_project_root(&file.to_string_lossy()), Some(flake));
+
+        let (config, _) = ParserConfig::from_rules(&[]);
+        set_config(config.with_root_markers(&["WORKSPACE".to_string()]));
+        let file = custom.join("services/api/main.go");
+        assert_eq!(
+            extract_project_name_from_path(&file.to_string_lossy()),
+            Some("monorepo".to_string())
+        );
+        set_config(ParserConfig::default());
+    }
+
+    #[test]
+    fn test_project_root_cache() {
+        let _guard = replacing_config();
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        let src = root.join("ledger/src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(root.join("ledger/Cargo.toml"), "").unwrap();
+
+        assert_eq!(cached_project_root(&src), Some(root.join("ledger")));
+
+        // Served from the cache until it is cleared
+        std::fs::remove_file(root.join("ledger/Cargo.toml")).unwrap();
+        assert_eq!(cached_project_root(&src), Some(root.join("ledger")));
+        clear_root_cache();
+        assert_eq!(cached_project_root(&src), None);
+    }
+
+    #[test]
+    fn test_segment_separators() {
+        let _guard = replacing_config();
+        // En dash is recognized out of the box
+        assert_eq!(
+            extract_project_and_tab_from_title("main.rs – swii"),
+            (Some("swii".to_string()), Some("main.rs".to_string()))
+        );
+
+        let (config, _) = ParserConfig::from_rules(&[]);
+        let separators = [" — ", " – ", " :: ", "  "].map(String::from);
+        set_config(config.with_separators(&separators));
+
+        assert_eq!(
+            extract_project_and_tab_from_title("lib.rs :: parser"),
+            (Some("parser".to_string()), Some("lib.rs".to_string()))
+        );
+
+        set_config(ParserConfig::default());
+    }
+
+    #[test]
+    fn test_parse_title() {
+        let _config = reading_config();
+        let parsed = parse_title(Some("Cursor"), "App.tsx - my-project - Cursor");
+        assert_eq!(parsed.editor_kind, EditorKind::Cursor);
+        assert_eq!(parsed.project.as_deref(), Some("my-project"));
+        assert_eq!(parsed.tab.as_deref(), Some("App.tsx"));
+        assert_eq!(parsed.confidence, Confidence::High);
+        assert!(!parsed.is_modified);
+
+        let parsed = parse_title(Some("Zed"), "switch — ARCHITECTURE.md");
+        assert_eq!(parsed.editor_kind, EditorKind::Zed);
+        assert_eq!(parsed.confidence, Confidence::Medium);
+
+        // Without an app name the editor is recognized from the title
+        let parsed = parse_title(
+            None,
+            "my-spring-boot [~/projects/my-spring-boot] - IntelliJ IDEA",
+        );
+        assert_eq!(parsed.editor_kind, EditorKind::JetBrains);
+        assert_eq!(parsed.project.as_deref(), Some("my-spring-boot"));
+        assert_eq!(parsed.path.as_deref(), Some("~/projects/my-spring-boot"));
+        assert_eq!(parsed.confidence, Confidence::Low);
+
+        let parsed = parse_title(Some("Sublime Text"), "");
+        assert_eq!(parsed.editor_kind, EditorKind::Sublime);
+        assert_eq!(parsed.project, None);
+    }
+
+    #[test]
+    fn test_is_command_like() {
+        let _config = reading_config();
+        assert!(is_command_like("bun run tauri dev"));
+        assert!(is_command_like("npm start"));
+        assert!(is_command_like("yarn build"));
+        assert!(is_command_like("cargo test"));
+        assert!(is_command_like("pnpm dev"));
+        assert!(is_command_like("$ make release"));
+        assert!(is_command_like("build swii"));
+
+        assert!(!is_command_like("project"));
+        assert!(!is_command_like("switch"));
+        assert!(!is_command_like("file.txt"));
+        assert!(!is_command_like("short"));
+        assert!(!is_command_like("cargo"));
+        assert!(!is_command_like("My Notes File.md"));
+        assert!(!is_command_like("this is a long phrase"));
+        assert!(!is_command_like("rebuild cache"));
+    }
+
+    #[test]
+    fn test_configured_command_patterns() {
+        let _guard = replacing_config();
+        let (config, _) = ParserConfig::from_rules(&[]);
+        set_config(
+            config.with_command_patterns(&["terraform".to_string(), "docker compose".to_string()]),
+        );
+
+        assert!(is_command_like("terraform plan"));
+        assert!(is_command_like("docker compose up"));
+        assert!(is_command_like("docker ps"));
+        assert!(!is_command_like("compose up"));
+        assert_eq!(
+            extract_project_and_tab_from_title("terraform apply — infra"),
+            (
+                Some("infra".to_string()),
+                Some("terraform apply".to_string())
+            )
+        );
+
+        set_config(ParserConfig::default());
+    }
+}
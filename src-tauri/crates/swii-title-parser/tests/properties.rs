@@ -0,0 +1,72 @@
+//! Property tests for the title parser.
+//!
+//! These only assert invariants that hold for any input; the exact parses of
+//! real editor titles are covered by the unit tests in `lib.rs`.
+
+use proptest::prelude::*;
+use swii_title_parser::{extract_project_and_tab_from_title, parse_title, EditorKind};
+
+/// Names made of a few plain words, as editors show for projects and files
+fn name() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_]{0,11}( [a-z0-9_]{1,8}){0,2}"
+}
+
+fn app_name() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of(prop_oneof![
+        Just("Visual Studio Code".to_string()),
+        Just("Cursor".to_string()),
+        Just("Zed".to_string()),
+        Just("IntelliJ IDEA".to_string()),
+        Just("Xcode".to_string()),
+        Just("Sublime Text".to_string()),
+        Just("iTerm2".to_string()),
+        ".{0,20}",
+    ])
+}
+
+proptest! {
+    #[test]
+    fn parse_title_never_panics(app_name in app_name(), title in ".{0,200}") {
+        parse_title(app_name.as_deref(), &title);
+    }
+
+    #[test]
+    fn separators_and_paths_never_panic(title in "[ -~—–•·/\\\\\\[\\]]{0,120}") {
+        parse_title(None, &title);
+    }
+
+    #[test]
+    fn parsed_names_are_trimmed_and_non_empty(app_name in app_name(), title in ".{0,200}") {
+        let parsed = parse_title(app_name.as_deref(), &title);
+        for value in [&parsed.project, &parsed.tab].into_iter().flatten() {
+            prop_assert!(!value.is_empty());
+            prop_assert_eq!(value.trim(), value.as_str());
+        }
+    }
+
+    #[test]
+    fn scratch_buffers_have_no_project(app_name in app_name(), title in ".{0,200}") {
+        let parsed = parse_title(app_name.as_deref(), &title);
+        if parsed.is_scratch {
+            prop_assert!(parsed.project.is_none());
+        }
+    }
+
+    #[test]
+    fn shorthand_matches_parse_title(title in ".{0,200}") {
+        let parsed = parse_title(None, &title);
+        prop_assert_eq!(
+            extract_project_and_tab_from_title(&title),
+            (parsed.project, parsed.tab)
+        );
+    }
+
+    #[test]
+    fn vscode_titles_round_trip(file in name(), project in name()) {
+        let title = format!("{}.rs - {} - Visual Studio Code", file, project);
+        let parsed = parse_title(Some("Visual Studio Code"), &title);
+        prop_assert_eq!(parsed.editor_kind, EditorKind::VsCode);
+        prop_assert_eq!(parsed.project, Some(project));
+        prop_assert_eq!(parsed.tab, Some(format!("{}.rs", file)));
+    }
+}
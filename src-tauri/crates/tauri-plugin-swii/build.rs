@@ -0,0 +1,5 @@
+const COMMANDS: &[&str] = &["list_windows", "focus_window", "get_capabilities"];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
@@ -0,0 +1,29 @@
+use swii_core::error::ErrorInfo;
+use swii_core::provider::ProviderCapabilities;
+use swii_core::types::WindowInfo;
+use tauri::State;
+
+use crate::Swii;
+
+#[tauri::command]
+pub(crate) async fn list_windows(swii: State<'_, Swii>) -> Result<Vec<WindowInfo>, ErrorInfo> {
+    swii.provider().list_windows().map_err(ErrorInfo::from)
+}
+
+#[tauri::command]
+pub(crate) async fn focus_window(
+    swii: State<'_, Swii>,
+    pid: i32,
+    window_number: u32,
+) -> Result<(), ErrorInfo> {
+    swii.provider()
+        .focus_window(pid, window_number)
+        .map_err(ErrorInfo::from)
+}
+
+#[tauri::command]
+pub(crate) async fn get_capabilities(
+    swii: State<'_, Swii>,
+) -> Result<ProviderCapabilities, ErrorInfo> {
+    Ok(swii.provider().capabilities())
+}
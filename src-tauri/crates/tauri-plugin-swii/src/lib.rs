@@ -0,0 +1,87 @@
+//! Editor window listing and focusing for any Tauri app.
+//!
+//! The plugin registers as `swii` and exposes `list_windows`,
+//! `focus_window` and `get_capabilities`, invoked from the frontend as
+//! `plugin:swii|list_windows` and so on once a capability grants
+//! `swii:default`. Windows come from the [`WindowProvider`] given to
+//! [`Builder::provider`]; without one the plugin serves the mock windows when
+//! `SWII_MOCK_WINDOWS` is set and the platform's native backend otherwise.
+//! Rust code reaches the same provider through [`SwiiExt::swii`]. Failed
+//! commands reject with swii's error shape, `{ code, user_message, message,
+//! details }`.
+
+use swii_core::mock::MockProvider;
+use swii_core::provider::{platform_provider, WindowProvider};
+use tauri::plugin::TauriPlugin;
+use tauri::{Manager, Runtime};
+
+mod commands;
+
+/// Plugin state, managed by the app the plugin is registered with
+pub struct Swii {
+    provider: &'static dyn WindowProvider,
+}
+
+impl Swii {
+    /// The provider the plugin's commands talk to
+    pub fn provider(&self) -> &'static dyn WindowProvider {
+        self.provider
+    }
+}
+
+/// Access to the plugin state from anything that can manage state
+pub trait SwiiExt<R: Runtime> {
+    fn swii(&self) -> &Swii;
+}
+
+impl<R: Runtime, T: Manager<R>> SwiiExt<R> for T {
+    fn swii(&self) -> &Swii {
+        self.state::<Swii>().inner()
+    }
+}
+
+/// Configures the plugin
+#[derive(Default)]
+pub struct Builder {
+    provider: Option<&'static dyn WindowProvider>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the backend windows are listed and focused with
+    pub fn provider(mut self, provider: &'static dyn WindowProvider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    pub fn build<R: Runtime>(self) -> TauriPlugin<R> {
+        let provider = self.provider.unwrap_or_else(default_provider);
+        tauri::plugin::Builder::new("swii")
+            .invoke_handler(tauri::generate_handler![
+                commands::list_windows,
+                commands::focus_window,
+                commands::get_capabilities,
+            ])
+            .setup(move |app, _api| {
+                app.manage(Swii { provider });
+                Ok(())
+            })
+            .build()
+    }
+}
+
+fn default_provider() -> &'static dyn WindowProvider {
+    if swii_core::mock::is_enabled() {
+        &MockProvider
+    } else {
+        platform_provider()
+    }
+}
+
+/// Initializes the plugin with the platform's native backend
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::new().build()
+}
@@ -0,0 +1,103 @@
+//! Whether swii shows a Dock icon.
+//!
+//! swii runs as an accessory app by default: no Dock icon and no menu bar of
+//! its own, so the panel comes up over whatever is in front. The
+//! `activation_policy` setting makes it a regular app instead, and
+//! `set_activation_policy` switches at runtime and saves the choice to the
+//! settings file. macOS only.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+/// How swii presents itself to the system
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivationPolicy {
+    /// No Dock icon
+    #[default]
+    Accessory,
+    /// A Dock icon and an entry in the app switcher
+    Regular,
+}
+
+static REGULAR: AtomicBool = AtomicBool::new(false);
+
+/// Sets the policy applied by [`apply`]
+pub fn set_policy(policy: ActivationPolicy) {
+    REGULAR.store(policy == ActivationPolicy::Regular, Ordering::Relaxed);
+}
+
+fn policy() -> ActivationPolicy {
+    if REGULAR.load(Ordering::Relaxed) {
+        ActivationPolicy::Regular
+    } else {
+        ActivationPolicy::Accessory
+    }
+}
+
+/// Applies the configured policy to the running app
+#[cfg(target_os = "macos")]
+pub fn apply(app: &tauri::AppHandle) -> Result<(), SwiiError> {
+    let policy = match policy() {
+        ActivationPolicy::Accessory => tauri::ActivationPolicy::Accessory,
+        ActivationPolicy::Regular => tauri::ActivationPolicy::Regular,
+    };
+    app.set_activation_policy(policy)
+        .map_err(|e| SwiiError::Internal(format!("Failed to set activation policy: {}", e)))
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn apply(_app: &tauri::AppHandle) -> Result<(), SwiiError> {
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_activation_policy(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<ActivationPolicy> {
+    crate::command_wrapper::create_typed_command("set_activation_policy", args, |ctx| {
+        let requested: ActivationPolicy = ctx
+            .parameters
+            .get("policy")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .ok_or(SwiiError::InvalidParameter("policy"))?;
+        if !cfg!(target_os = "macos") {
+            return Err(SwiiError::Unsupported(
+                "The activation policy only applies on macOS".to_string(),
+            ));
+        }
+
+        set_policy(requested);
+        apply(&app)?;
+        crate::settings::store_value(&app, "activation_policy", serde_json::json!(requested))?;
+        ctx.logger.info(&format!(
+            "Switched to the {:?} activation policy",
+            requested
+        ));
+        Ok(requested)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_round_trip() {
+        let regular: ActivationPolicy = serde_json::from_str(r#""regular""#).unwrap();
+        assert_eq!(regular, ActivationPolicy::Regular);
+        assert!(serde_json::from_str::<ActivationPolicy>(r#""prohibited""#).is_err());
+
+        set_policy(ActivationPolicy::Regular);
+        assert_eq!(policy(), ActivationPolicy::Regular);
+        set_policy(ActivationPolicy::default());
+        assert_eq!(policy(), ActivationPolicy::Accessory);
+    }
+}
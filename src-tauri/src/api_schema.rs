@@ -0,0 +1,646 @@
+//! Self-description of the IPC API for external integrators.
+//!
+//! CLIs, Raycast extensions or Stream Deck plugins call `get_api_schema` to
+//! discover which commands exist and what they accept before invoking them.
+//! Bump [`constants::API_VERSION`] whenever a command is removed or its
+//! parameters change incompatibly; adding commands or optional parameters does
+//! not require a bump.
+
+use serde::Serialize;
+
+pub mod constants {
+    /// Version of the command API described by `get_api_schema`
+    pub const API_VERSION: u32 = 1;
+}
+
+/// JSON type of a command parameter
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterKind {
+    String,
+    Integer,
+    Boolean,
+    Any,
+}
+
+/// A single command parameter
+#[derive(Debug, Serialize, specta::Type)]
+pub struct ParameterSchema {
+    pub name: &'static str,
+    pub kind: ParameterKind,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+/// Description of one invokable command
+#[derive(Debug, Serialize, specta::Type)]
+pub struct CommandSchema {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// True when parameters are passed inside an `args` object, as with all
+    /// commands built on `command_wrapper`; false when they are top-level
+    pub wrapped_args: bool,
+    pub parameters: Vec<ParameterSchema>,
+    /// TypeScript name of the response payload
+    pub returns: &'static str,
+}
+
+/// Payload returned by `get_api_schema`
+#[derive(Debug, Serialize, specta::Type)]
+pub struct ApiSchema {
+    pub api_version: u32,
+    /// Version of the `WindowInfo` layout returned by the window commands
+    pub window_schema_version: u32,
+    pub app_version: &'static str,
+    pub commands: Vec<CommandSchema>,
+}
+
+fn param(
+    name: &'static str,
+    kind: ParameterKind,
+    required: bool,
+    description: &'static str,
+) -> ParameterSchema {
+    ParameterSchema {
+        name,
+        kind,
+        required,
+        description,
+    }
+}
+
+fn wrapped(
+    name: &'static str,
+    description: &'static str,
+    parameters: Vec<ParameterSchema>,
+    returns: &'static str,
+) -> CommandSchema {
+    CommandSchema {
+        name,
+        description,
+        wrapped_args: true,
+        parameters,
+        returns,
+    }
+}
+
+/// Builds the schema for every registered command
+pub fn api_schema() -> ApiSchema {
+    use ParameterKind as Kind;
+
+    let log_params = || {
+        vec![
+            param(
+                "level",
+                Kind::String,
+                true,
+                "One of debug, info, warn, error",
+            ),
+            param(
+                "tag",
+                Kind::String,
+                true,
+                "Short source tag shown in the log line",
+            ),
+            param("message", Kind::String, true, "Log message"),
+        ]
+    };
+    let mut log_with_data_params = log_params();
+    log_with_data_params.push(param(
+        "data",
+        Kind::Any,
+        true,
+        "Arbitrary JSON attached to the entry",
+    ));
+
+    let commands = vec![
+        wrapped(
+            "list_editor_windows",
+            "Lists all open editor windows",
+            vec![
+                param(
+                    "include_untitled",
+                    Kind::Boolean,
+                    false,
+                    "List windows without a title; defaults to the include_untitled setting",
+                ),
+                param("offset", Kind::Integer, false, "Windows to skip"),
+                param("limit", Kind::Integer, false, "Most windows to return"),
+                param(
+                    "stream",
+                    Kind::Boolean,
+                    false,
+                    "Also emit the windows in editor-windows-chunk events",
+                ),
+            ],
+            "CommandResult<WindowInfo[]>",
+        ),
+        wrapped(
+            "search_windows",
+            "Ranks the listed windows and recent projects against a fuzzy query",
+            vec![
+                param(
+                    "query",
+                    Kind::String,
+                    true,
+                    "Terms matched against project, tab, path and app name",
+                ),
+                param("limit", Kind::Integer, false, "Most matches to return"),
+            ],
+            "CommandResult<SearchMatch[]>",
+        ),
+        wrapped(
+            "record_search",
+            "Records a query and the result accepted for it",
+            vec![
+                param("query", Kind::String, true, "Query as typed"),
+                param(
+                    "app_name",
+                    Kind::String,
+                    false,
+                    "Editor of the accepted result",
+                ),
+                param(
+                    "project",
+                    Kind::String,
+                    false,
+                    "Project of the accepted result",
+                ),
+                param(
+                    "project_path",
+                    Kind::String,
+                    false,
+                    "Project root of the accepted result",
+                ),
+                param(
+                    "window_name",
+                    Kind::String,
+                    false,
+                    "Window title of the accepted result",
+                ),
+            ],
+            "CommandResult<null>",
+        ),
+        wrapped(
+            "get_search_suggestions",
+            "Lists previous queries starting with a prefix, most used first",
+            vec![
+                param("prefix", Kind::String, true, "Text typed so far"),
+                param("limit", Kind::Integer, false, "Most suggestions to return"),
+            ],
+            "CommandResult<SearchSuggestion[]>",
+        ),
+        wrapped(
+            "bring_window_to_front",
+            "Focuses an editor window",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the window owner"),
+                param(
+                    "window_number",
+                    Kind::Integer,
+                    true,
+                    "Window number from list_editor_windows",
+                ),
+                param(
+                    "raise_only",
+                    Kind::Boolean,
+                    false,
+                    "Raise only this window instead of activating its whole app",
+                ),
+            ],
+            "CommandResult<FocusOutcome>",
+        ),
+        wrapped(
+            "list_open_files",
+            "Lists the documents open in an editor window",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the window owner"),
+                param(
+                    "window_number",
+                    Kind::Integer,
+                    true,
+                    "Window number from list_editor_windows",
+                ),
+            ],
+            "CommandResult<OpenFile[]>",
+        ),
+        wrapped(
+            "get_focus_history",
+            "Lists editor windows that came to the front, most recent first",
+            vec![
+                param(
+                    "offset",
+                    Kind::Integer,
+                    false,
+                    "Records to skip, 0 by default",
+                ),
+                param(
+                    "limit",
+                    Kind::Integer,
+                    false,
+                    "Records to return, 50 by default",
+                ),
+            ],
+            "CommandResult<FocusHistoryPage>",
+        ),
+        wrapped(
+            "get_running_editors",
+            "Lists running editor applications, including ones without windows",
+            vec![],
+            "CommandResult<RunningEditor[]>",
+        ),
+        wrapped(
+            "open_editor_window",
+            "Opens a project or a new window in a running editor",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the editor"),
+                param(
+                    "path",
+                    Kind::String,
+                    false,
+                    "Project to open; a new empty window when omitted",
+                ),
+            ],
+            "CommandResult<OpenWindowOutcome>",
+        ),
+        wrapped(
+            "focus_browser_tab",
+            "Selects a browser tab by URL (browser mode, macOS only)",
+            vec![
+                param(
+                    "browser",
+                    Kind::String,
+                    true,
+                    "Browser application name, e.g. \"Google Chrome\" or \"Safari\"",
+                ),
+                param(
+                    "url",
+                    Kind::String,
+                    true,
+                    "Prefix of the tab URL; the first matching tab is selected",
+                ),
+            ],
+            "CommandResult<BrowserTabOutcome>",
+        ),
+        CommandSchema {
+            name: "log_from_frontend",
+            description: "Writes a message to the backend log",
+            wrapped_args: false,
+            parameters: log_params(),
+            returns: "null",
+        },
+        CommandSchema {
+            name: "log_from_frontend_with_data",
+            description: "Writes a message with structured data to the backend log",
+            wrapped_args: false,
+            parameters: log_with_data_params,
+            returns: "null",
+        },
+        CommandSchema {
+            name: "log_batch_from_frontend",
+            description: "Writes several messages to the backend log in one call",
+            wrapped_args: false,
+            parameters: vec![param(
+                "entries",
+                Kind::Any,
+                true,
+                "Array of entries with level, tag, message and optional data",
+            )],
+            returns: "null",
+        },
+        wrapped(
+            "open_devtools",
+            "Opens the webview devtools (debug builds only)",
+            vec![param(
+                "label",
+                Kind::String,
+                false,
+                "Webview window label, \"swii\" by default",
+            )],
+            "CommandResult<null>",
+        ),
+        wrapped(
+            "list_webview_windows",
+            "Lists the labels of the app's webview windows",
+            vec![],
+            "CommandResult<string[]>",
+        ),
+        wrapped(
+            "health_check",
+            "Checks permissions, installed editors and window enumeration",
+            vec![],
+            "CommandResult<HealthReport>",
+        ),
+        wrapped(
+            "run_self_test",
+            "Lists windows, focuses one and checks the result stage by stage",
+            vec![
+                param(
+                    "pid",
+                    Kind::Integer,
+                    false,
+                    "Process id of the window to focus; the first listed window when omitted",
+                ),
+                param(
+                    "window_number",
+                    Kind::Integer,
+                    false,
+                    "Window number of the window to focus, required with pid",
+                ),
+            ],
+            "CommandResult<SelfTestReport>",
+        ),
+        wrapped(
+            "run_diagnostics",
+            "Times each phase of window enumeration",
+            vec![],
+            "CommandResult<DiagnosticsReport>",
+        ),
+        wrapped(
+            "dump_window_debug_info",
+            "Dumps raw window and accessibility data for bug reports",
+            vec![param(
+                "include_all_apps",
+                Kind::Boolean,
+                false,
+                "Include windows of non-editor applications",
+            )],
+            "CommandResult<WindowDebugDump>",
+        ),
+        wrapped(
+            "start_snapshot_recording",
+            "Records every window listing to a JSON Lines file",
+            vec![param(
+                "path",
+                Kind::String,
+                false,
+                "Output file; defaults to a new file in the app data dir",
+            )],
+            "CommandResult<SnapshotStatus>",
+        ),
+        wrapped(
+            "start_snapshot_replay",
+            "Serves window listings from a recorded file",
+            vec![param(
+                "path",
+                Kind::String,
+                true,
+                "Recorded JSON Lines file",
+            )],
+            "CommandResult<SnapshotStatus>",
+        ),
+        wrapped(
+            "stop_snapshots",
+            "Stops recording or replaying snapshots",
+            vec![],
+            "CommandResult<SnapshotStatus>",
+        ),
+        wrapped(
+            "get_snapshot_status",
+            "Reports the current snapshot mode",
+            vec![],
+            "CommandResult<SnapshotStatus>",
+        ),
+        wrapped(
+            "get_idle_status",
+            "Reports whether the user is currently idle",
+            vec![],
+            "CommandResult<IdleStatus>",
+        ),
+        wrapped(
+            "set_idle_threshold",
+            "Changes how long without input counts as idle",
+            vec![param(
+                "seconds",
+                Kind::Integer,
+                true,
+                "Idle threshold in seconds",
+            )],
+            "CommandResult<IdleStatus>",
+        ),
+        wrapped(
+            "get_window_provider_capabilities",
+            "Reports what the active window backend supports",
+            vec![],
+            "CommandResult<ProviderCapabilities>",
+        ),
+        wrapped(
+            "reload_settings",
+            "Re-reads settings.json from the app config dir",
+            vec![],
+            "CommandResult<SettingsStatus>",
+        ),
+        wrapped(
+            "set_activation_policy",
+            "Shows or hides swii's Dock icon and saves the choice (macOS)",
+            vec![param(
+                "policy",
+                Kind::String,
+                true,
+                "\"regular\" for a Dock icon, \"accessory\" for none",
+            )],
+            "CommandResult<ActivationPolicy>",
+        ),
+        wrapped(
+            "watch_project",
+            "Notifies when windows of a project open or close, and saves the watch",
+            vec![param("name", Kind::String, true, "Project name or label")],
+            "CommandResult<string[]>",
+        ),
+        wrapped(
+            "unwatch_project",
+            "Stops watching a project",
+            vec![param("name", Kind::String, true, "Project name or label")],
+            "CommandResult<string[]>",
+        ),
+        wrapped(
+            "set_dnd",
+            "Pauses window observation, focus rules and notifications",
+            vec![
+                param("enabled", Kind::Boolean, true, "Turn the mode on or off"),
+                param(
+                    "duration",
+                    Kind::Integer,
+                    false,
+                    "Seconds until the mode ends by itself",
+                ),
+            ],
+            "CommandResult<DndStatus>",
+        ),
+        wrapped(
+            "export_session",
+            "Describes the open editor windows for sharing, without icons",
+            vec![param(
+                "format",
+                Kind::String,
+                false,
+                "\"markdown\" (the default) or \"json\"",
+            )],
+            "CommandResult<string>",
+        ),
+        wrapped(
+            "start_focus_timer",
+            "Gives a project a time budget, replacing the running timer",
+            vec![
+                param("project", Kind::String, true, "Project to focus on"),
+                param("minutes", Kind::Integer, true, "Length of the budget"),
+            ],
+            "CommandResult<FocusTimerStatus>",
+        ),
+        wrapped(
+            "stop_focus_timer",
+            "Ends the running focus timer early",
+            vec![],
+            "CommandResult<FocusTimerStatus | null>",
+        ),
+        wrapped(
+            "copy_project_path",
+            "Copies the project root of a window to the clipboard",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the window owner"),
+                param("window_number", Kind::Integer, true, "Window to copy from"),
+            ],
+            "CommandResult<string | null>",
+        ),
+        wrapped(
+            "copy_file_path",
+            "Copies the path of the document a window shows to the clipboard",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the window owner"),
+                param("window_number", Kind::Integer, true, "Window to copy from"),
+            ],
+            "CommandResult<string | null>",
+        ),
+        wrapped(
+            "open_file",
+            "Opens a file in a specific editor window",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the window owner"),
+                param(
+                    "window_number",
+                    Kind::Integer,
+                    true,
+                    "Window to open the file in",
+                ),
+                param(
+                    "path",
+                    Kind::String,
+                    true,
+                    "Absolute path of an existing file",
+                ),
+                param(
+                    "line",
+                    Kind::Integer,
+                    false,
+                    "1-based line to place the cursor on",
+                ),
+                param(
+                    "column",
+                    Kind::Integer,
+                    false,
+                    "1-based column, used with a line",
+                ),
+            ],
+            "CommandResult<OpenFileOutcome>",
+        ),
+        wrapped(
+            "set_window_label",
+            "Names a window until it closes; a blank label clears it",
+            vec![
+                param("pid", Kind::Integer, true, "Process id of the window owner"),
+                param(
+                    "window_number",
+                    Kind::Integer,
+                    true,
+                    "Window number from list_editor_windows",
+                ),
+                param("label", Kind::String, false, "Name to show for the window"),
+            ],
+            "CommandResult<WindowInfo>",
+        ),
+        wrapped(
+            "get_shortcut_status",
+            "Tells which panel shortcut is registered and why the configured one isn't",
+            vec![],
+            "CommandResult<ShortcutStatus>",
+        ),
+        wrapped(
+            "get_project_summary",
+            "Gathers a project's windows, roots, git branches, focus time and watch status",
+            vec![param(
+                "project",
+                Kind::String,
+                true,
+                "Project name or label",
+            )],
+            "CommandResult<ProjectSummary>",
+        ),
+        wrapped(
+            "get_api_schema",
+            "Describes the available commands",
+            vec![],
+            "CommandResult<ApiSchema>",
+        ),
+    ];
+
+    ApiSchema {
+        api_version: constants::API_VERSION,
+        window_schema_version: swii_core::types::constants::WINDOW_SCHEMA_VERSION,
+        app_version: env!("CARGO_PKG_VERSION"),
+        commands,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_api_schema(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("get_api_schema", args, |ctx| {
+        let schema = api_schema();
+        ctx.logger.info(&format!(
+            "Returning API schema v{} with {} commands",
+            schema.api_version,
+            schema.commands.len()
+        ));
+        Ok(schema)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_command_names_are_unique() {
+        let schema = api_schema();
+        let names: HashSet<&str> = schema.commands.iter().map(|c| c.name).collect();
+        assert_eq!(names.len(), schema.commands.len());
+        assert!(names.contains("get_api_schema"));
+        assert!(names.contains("list_editor_windows"));
+    }
+
+    #[test]
+    fn test_schema_serialization() {
+        let value = serde_json::to_value(api_schema()).unwrap();
+        assert_eq!(value["api_version"], constants::API_VERSION);
+        assert_eq!(
+            value["window_schema_version"],
+            swii_core::types::constants::WINDOW_SCHEMA_VERSION
+        );
+
+        let focus = value["commands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["name"] == "bring_window_to_front")
+            .unwrap();
+        assert_eq!(focus["wrapped_args"], true);
+        assert_eq!(focus["parameters"][0]["name"], "pid");
+        assert_eq!(focus["parameters"][0]["kind"], "integer");
+        assert_eq!(focus["parameters"][0]["required"], true);
+    }
+}
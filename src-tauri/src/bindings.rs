@@ -0,0 +1,109 @@
+//! TypeScript bindings for the IPC surface.
+//!
+//! All commands are registered through a single `tauri-specta` builder, which
+//! both produces the invoke handler and, in debug builds, regenerates
+//! `src/lib/tauri/bindings.ts` so the frontend types can't drift from the
+//! Rust definitions.
+
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use swii_core::provider::ProviderCapabilities;
+use swii_title_parser::ParsedTitle;
+use tauri_specta::{collect_commands, Builder};
+
+use crate::api_schema::ApiSchema;
+use crate::diagnostics::DiagnosticsReport;
+use crate::dnd::DndStatus;
+use crate::error::ErrorInfo;
+use crate::focus_rules::FocusRuleFired;
+use crate::idle::IdleStatus;
+use crate::project_summary::ProjectSummary;
+use crate::session_export::SessionFormat;
+use crate::settings::SettingsStatus;
+use crate::shortcut::{ShortcutPressed, ShortcutStatus};
+use crate::snapshots::SnapshotStatus;
+use crate::watchlist::WatchedProjectChanged;
+use crate::window_list::WindowChunk;
+
+pub mod constants {
+    /// Output path of the generated bindings, relative to `src-tauri`
+    pub const BINDINGS_PATH: &str = "../src/lib/tauri/bindings.ts";
+}
+
+/// Builds the command registry shared by the invoke handler and the exporter
+pub fn builder() -> Builder<tauri::Wry> {
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![
+            crate::window_list::list_editor_windows,
+            crate::search::search_windows,
+            crate::search_history::record_search,
+            crate::search_history::get_search_suggestions,
+            crate::window_focus::bring_window_to_front,
+            crate::open_files::list_open_files,
+            crate::focus_history::get_focus_history,
+            crate::running_editors::get_running_editors,
+            crate::running_editors::open_editor_window,
+            crate::browser_tabs::focus_browser_tab,
+            crate::logger::log_from_frontend,
+            crate::logger::log_from_frontend_with_data,
+            crate::devtools::open_devtools,
+            crate::devtools::list_webview_windows,
+            crate::diagnostics::run_diagnostics,
+            crate::diagnostics::dump_window_debug_info,
+            crate::health::health_check,
+            crate::self_test::run_self_test,
+            crate::snapshots::start_snapshot_recording,
+            crate::snapshots::start_snapshot_replay,
+            crate::snapshots::stop_snapshots,
+            crate::snapshots::get_snapshot_status,
+            crate::idle::get_idle_status,
+            crate::idle::set_idle_threshold,
+            crate::window_provider::get_window_provider_capabilities,
+            crate::api_schema::get_api_schema,
+            crate::settings::reload_settings,
+            crate::activation_policy::set_activation_policy,
+            crate::watchlist::watch_project,
+            crate::watchlist::unwatch_project,
+            crate::dnd::set_dnd,
+            crate::session_export::export_session,
+            crate::focus_timer::start_focus_timer,
+            crate::focus_timer::stop_focus_timer,
+            crate::copy_path::copy_project_path,
+            crate::copy_path::copy_file_path,
+            crate::open_file::open_file,
+            crate::window_labels::set_window_label,
+            crate::logger::log_batch_from_frontend,
+            crate::shortcut::get_shortcut_status,
+            crate::project_summary::get_project_summary,
+        ])
+        // Most commands still return untyped JSON, so their payload types are
+        // registered explicitly to make them available to the frontend.
+        .typ::<DiagnosticsReport>()
+        .typ::<SnapshotStatus>()
+        .typ::<IdleStatus>()
+        .typ::<FocusRuleFired>()
+        .typ::<ProviderCapabilities>()
+        .typ::<ApiSchema>()
+        .typ::<ErrorInfo>()
+        .typ::<SettingsStatus>()
+        .typ::<ParsedTitle>()
+        .typ::<WindowChunk>()
+        .typ::<ShortcutPressed>()
+        .typ::<ShortcutStatus>()
+        .typ::<ProjectSummary>()
+        .typ::<WatchedProjectChanged>()
+        .typ::<DndStatus>()
+        .typ::<SessionFormat>()
+}
+
+/// Writes the TypeScript bindings to [`constants::BINDINGS_PATH`]
+pub fn export(builder: &Builder<tauri::Wry>) -> Result<(), String> {
+    builder
+        .export(
+            // Millisecond timings and counts comfortably fit in a JS number
+            Typescript::default()
+                .bigint(BigIntExportBehavior::Number)
+                .header("// @ts-nocheck"),
+            constants::BINDINGS_PATH,
+        )
+        .map_err(|e| format!("Failed to export TypeScript bindings: {}", e))
+}
@@ -0,0 +1,206 @@
+//! Focusing browser tabs for the optional browser mode.
+//!
+//! Project documentation often lives in browser tabs, so with `browser_mode`
+//! enabled the switcher can jump to them like it jumps to editor windows.
+//! Tabs are selected with JXA through `osascript`, which needs the user to
+//! allow swii to control the browser (Automation permission). Only macOS is
+//! supported.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+use swii_core::focus::WindowFocusError;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Browsers scripted through Chromium's AppleScript dictionary
+    pub const CHROMIUM_BROWSERS: &[&str] = &[
+        "Google Chrome",
+        "Google Chrome Canary",
+        "Chromium",
+        "Brave Browser",
+        "Microsoft Edge",
+        "Vivaldi",
+        "Arc",
+    ];
+
+    /// Browsers scripted through Safari's AppleScript dictionary
+    pub const SAFARI_BROWSERS: &[&str] = &["Safari", "Safari Technology Preview"];
+
+    /// Selects the first tab whose URL starts with `argv[1]` in a Chromium
+    /// browser and prints its URL
+    pub const CHROMIUM_SCRIPT: &str = r#"function run(argv) {
+  const app = Application(argv[0]);
+  for (const window of app.windows()) {
+    const tabs = window.tabs();
+    for (let i = 0; i < tabs.length; i++) {
+      const url = tabs[i].url();
+      if (url && url.startsWith(argv[1])) {
+        window.activeTabIndex = i + 1;
+        window.index = 1;
+        app.activate();
+        return url;
+      }
+    }
+  }
+  return "";
+}"#;
+
+    /// Selects the first tab whose URL starts with `argv[1]` in Safari and
+    /// prints its URL
+    pub const SAFARI_SCRIPT: &str = r#"function run(argv) {
+  const app = Application(argv[0]);
+  for (const window of app.windows()) {
+    for (const tab of window.tabs()) {
+      const url = tab.url();
+      if (url && url.startsWith(argv[1])) {
+        window.currentTab = tab;
+        window.index = 1;
+        app.activate();
+        return url;
+      }
+    }
+  }
+  return "";
+}"#;
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables browser tab focusing
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// AppleScript dictionary a browser is scripted through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BrowserFamily {
+    Chromium,
+    Safari,
+}
+
+impl BrowserFamily {
+    fn from_app_name(app_name: &str) -> Option<Self> {
+        if constants::CHROMIUM_BROWSERS.contains(&app_name) {
+            Some(BrowserFamily::Chromium)
+        } else if constants::SAFARI_BROWSERS.contains(&app_name) {
+            Some(BrowserFamily::Safari)
+        } else {
+            None
+        }
+    }
+
+    fn script(self) -> &'static str {
+        match self {
+            BrowserFamily::Chromium => constants::CHROMIUM_SCRIPT,
+            BrowserFamily::Safari => constants::SAFARI_SCRIPT,
+        }
+    }
+}
+
+/// Result of a successful `focus_browser_tab`
+#[derive(Debug, PartialEq, Serialize, specta::Type)]
+pub struct BrowserTabOutcome {
+    pub browser: String,
+    /// Full URL of the tab that was selected
+    pub url: String,
+}
+
+/// Selects the first tab of `browser` whose URL starts with `url` and
+/// returns the tab's URL
+#[cfg(target_os = "macos")]
+fn focus_tab(browser: &str, family: BrowserFamily, url: &str) -> Result<String, WindowFocusError> {
+    let output = std::process::Command::new("osascript")
+        .args(["-l", "JavaScript", "-e", family.script(), browser, url])
+        .output()
+        .map_err(|e| WindowFocusError::SystemError(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(swii_core::applescript_focus::osascript_error(
+            &String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    let selected = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if selected.is_empty() {
+        return Err(WindowFocusError::WindowNotFound);
+    }
+    Ok(selected)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn focus_tab(
+    _browser: &str,
+    _family: BrowserFamily,
+    _url: &str,
+) -> Result<String, WindowFocusError> {
+    Err(WindowFocusError::SystemError(
+        "Browser tabs can only be focused on macOS".to_string(),
+    ))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn focus_browser_tab(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<BrowserTabOutcome> {
+    crate::command_wrapper::create_typed_command("focus_browser_tab", args, |ctx| {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return Err(SwiiError::Unsupported(
+                "Browser mode is disabled in the settings".to_string(),
+            ));
+        }
+
+        let browser = ctx
+            .parameters
+            .get("browser")
+            .and_then(|v| v.as_str())
+            .ok_or(SwiiError::InvalidParameter("browser"))?
+            .to_string();
+        let family =
+            BrowserFamily::from_app_name(&browser).ok_or(SwiiError::InvalidParameter("browser"))?;
+
+        let url = ctx
+            .parameters
+            .get("url")
+            .and_then(|v| v.as_str())
+            .filter(|url| !url.is_empty())
+            .ok_or(SwiiError::InvalidParameter("url"))?;
+
+        ctx.logger
+            .info(&format!("Focusing {} tab matching {}", browser, url));
+        match focus_tab(&browser, family, url) {
+            Ok(url) => Ok(BrowserTabOutcome { browser, url }),
+            Err(e) => {
+                ctx.logger
+                    .error(&format!("Failed to focus browser tab: {}", e));
+                Err(e.into())
+            }
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_browser_family_from_app_name() {
+        assert_eq!(
+            BrowserFamily::from_app_name("Google Chrome"),
+            Some(BrowserFamily::Chromium)
+        );
+        assert_eq!(
+            BrowserFamily::from_app_name("Arc"),
+            Some(BrowserFamily::Chromium)
+        );
+        assert_eq!(
+            BrowserFamily::from_app_name("Safari"),
+            Some(BrowserFamily::Safari)
+        );
+        assert_eq!(BrowserFamily::from_app_name("Firefox"), None);
+    }
+}
@@ -3,13 +3,15 @@
 //! This module provides a generic command wrapper for Tauri commands that includes:
 //! - Automatic logging of command execution
 //! - Execution time tracking
-//! - Standardized error handling and response format
+//! - Standardized error handling and response format (errors are `SwiiError`s,
+//!   sent as `{ code, user_message, message, details }`)
 //! - Structured logging with timestamps
 //!
 //! ## Usage Example
 //!
 //! ```rust,ignore
 //! #[tauri::command]
+//! #[specta::specta]
 //! async fn my_command(args: Option<serde_json::Value>) -> Result<serde_json::Value, String> {
 //!     swii_lib::command_wrapper::create_command("my_command", args, |ctx| {
 //!         ctx.logger.info("Starting my command");
@@ -28,6 +30,7 @@
 //! For actions that don't return data:
 //! ```rust,ignore
 //! #[tauri::command]
+//! #[specta::specta]
 //! async fn my_action(args: Option<serde_json::Value>) -> Result<serde_json::Value, String> {
 //!     swii_lib::command_wrapper::create_command("my_action", args, |ctx| {
 //!         ctx.logger.info("Performing action");
@@ -37,6 +40,23 @@
 //! }
 //! ```
 //!
+//! New commands must also be added to `crate::bindings::builder` so they are
+//! registered with Tauri and exported to the TypeScript bindings.
+//!
+//! ## Typed Responses
+//!
+//! Commands that want their payload type to reach the TypeScript bindings
+//! return `CommandResult<T>` via `create_typed_command`:
+//! ```rust,ignore
+//! #[tauri::command]
+//! #[specta::specta]
+//! async fn my_query(args: Option<serde_json::Value>) -> CommandResult<Vec<WindowInfo>> {
+//!     swii_lib::command_wrapper::create_typed_command("my_query", args, |_ctx| {
+//!         Ok(Vec::new())
+//!     }).await
+//! }
+//! ```
+//!
 //! ## Benefits
 //!
 //! - **Consistent Logging**: Every command automatically logs start, completion, and errors
@@ -48,6 +68,9 @@
 use serde::Serialize;
 use std::time::Instant;
 
+use crate::error::{ErrorInfo, SwiiError};
+use crate::logger::LogLevel;
+
 /// Command execution context containing metadata and utilities
 pub struct CommandContext {
     pub command_name: String,
@@ -82,45 +105,32 @@ impl CommandLogger {
 
     /// Log an info message
     pub fn info(&self, message: &str) {
-        self.log("INFO", message, None);
+        self.log(LogLevel::Info, message);
     }
 
     /// Log an error message
     pub fn error(&self, message: &str) {
-        self.log("ERROR", message, None);
+        self.log(LogLevel::Error, message);
     }
 
-    /// Internal logging method with reduced duplication
-    fn log(&self, level: &str, message: &str, params: Option<&serde_json::Value>) {
-        let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S%.3f");
-
-        let log_message = match params {
-            Some(params) => format!(
-                "[{}] [{}] [{}] {} - Params: {}",
-                timestamp, level, self.command_name, message, params
-            ),
-            None => format!(
-                "[{}] [{}] [{}] {}",
-                timestamp, level, self.command_name, message
-            ),
-        };
-
-        println!("{}", log_message);
+    fn log(&self, level: LogLevel, message: &str) {
+        crate::session_log::record(level, &format!("command:{}", self.command_name), message);
     }
 }
 
 /// Standardized command result wrapper
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, specta::Type)]
 pub struct CommandResult<T>
 where
     T: Serialize,
 {
     pub success: bool,
     pub data: Option<T>,
-    pub error: Option<String>,
+    pub error: Option<ErrorInfo>,
     pub execution_time_ms: u64,
     pub timestamp: String,
     #[serde(skip_serializing)]
+    #[specta(skip)]
     pub command_name: String,
 }
 
@@ -141,11 +151,11 @@ where
     }
 
     /// Create an error result
-    pub fn error(error: String, execution_time_ms: u64, command_name: &str) -> Self {
+    pub fn error(error: impl Into<SwiiError>, execution_time_ms: u64, command_name: &str) -> Self {
         Self {
             success: false,
             data: None,
-            error: Some(error),
+            error: Some(error.into().to_info()),
             execution_time_ms,
             timestamp: chrono::Utc::now().to_rfc3339(),
             command_name: command_name.to_string(),
@@ -170,7 +180,16 @@ impl CommandExecutor {
     /// Execute a command with automatic logging and error handling
     pub async fn execute<F, T>(ctx: CommandContext, handler: F) -> CommandResult<T>
     where
-        F: FnOnce(CommandContext) -> Result<T, String>,
+        F: FnOnce(CommandContext) -> Result<T, SwiiError>,
+        T: Serialize,
+    {
+        Self::run(ctx, handler)
+    }
+
+    /// Runs the handler on the current thread, logging start and completion
+    fn run<F, T>(ctx: CommandContext, handler: F) -> CommandResult<T>
+    where
+        F: FnOnce(CommandContext) -> Result<T, SwiiError>,
         T: Serialize,
     {
         Self::log_command_start(&ctx);
@@ -190,7 +209,7 @@ impl CommandExecutor {
     /// Execute the command handler and measure execution time
     fn execute_handler<F, T>(ctx: CommandContext, handler: F) -> CommandResult<T>
     where
-        F: FnOnce(CommandContext) -> Result<T, String>,
+        F: FnOnce(CommandContext) -> Result<T, SwiiError>,
         T: Serialize,
     {
         let start_time = ctx.start_time;
@@ -209,7 +228,7 @@ impl CommandExecutor {
     where
         T: Serialize,
     {
-        let logger = CommandLogger::new(&result.command_name());
+        let logger = CommandLogger::new(result.command_name());
 
         if result.success {
             logger.info(&format!(
@@ -217,7 +236,10 @@ impl CommandExecutor {
                 result.execution_time_ms
             ));
         } else if let Some(ref error) = result.error {
-            logger.error(&format!("Command failed: {}", error));
+            logger.error(&format!(
+                "Command failed [{}]: {}",
+                error.code, error.message
+            ));
         }
     }
 }
@@ -229,13 +251,56 @@ pub async fn create_command<F, T>(
     handler: F,
 ) -> Result<serde_json::Value, String>
 where
-    F: FnOnce(CommandContext) -> Result<T, String>,
+    F: FnOnce(CommandContext) -> Result<T, SwiiError>,
+    T: Serialize,
+{
+    create_typed_command(command_name, args, handler)
+        .await
+        .into_tauri_response()
+}
+
+/// Like [`create_command`], but keeps the payload type so the command can
+/// return `CommandResult<T>` directly and have it checked at compile time
+pub async fn create_typed_command<F, T>(
+    command_name: &str,
+    args: Option<serde_json::Value>,
+    handler: F,
+) -> CommandResult<T>
+where
+    F: FnOnce(CommandContext) -> Result<T, SwiiError>,
     T: Serialize,
 {
     let args = args.unwrap_or(serde_json::Value::Null);
     let ctx = CommandContext::new(command_name, args);
-    let result = CommandExecutor::execute(ctx, handler).await;
-    result.into_tauri_response()
+    CommandExecutor::execute(ctx, handler).await
+}
+
+/// Like [`create_typed_command`], but runs the handler on a blocking thread,
+/// so slow native calls such as listing or focusing windows don't hold up the
+/// async runtime serving the other commands
+pub async fn create_blocking_command<F, T>(
+    command_name: &str,
+    args: Option<serde_json::Value>,
+    handler: F,
+) -> CommandResult<T>
+where
+    F: FnOnce(CommandContext) -> Result<T, SwiiError> + Send + 'static,
+    T: Serialize + Send + 'static,
+{
+    let args = args.unwrap_or(serde_json::Value::Null);
+    let ctx = CommandContext::new(command_name, args);
+    let start_time = ctx.start_time;
+    tauri::async_runtime::spawn_blocking(move || CommandExecutor::run(ctx, handler))
+        .await
+        .unwrap_or_else(|e| {
+            let result = CommandResult::error(
+                SwiiError::Internal(format!("Command thread failed: {}", e)),
+                start_time.elapsed().as_millis() as u64,
+                command_name,
+            );
+            CommandExecutor::log_command_completion(&result);
+            result
+        })
 }
 
 #[cfg(test)]
@@ -294,7 +359,9 @@ mod tests {
 
         assert!(!result.success);
         assert_eq!(result.data, None);
-        assert_eq!(result.error, Some("test error".to_string()));
+        let error = result.error.as_ref().unwrap();
+        assert_eq!(error.code, "internal");
+        assert_eq!(error.message, "test error");
         assert_eq!(result.execution_time_ms, 50);
         assert!(!result.timestamp.is_empty());
         assert_eq!(result.command_name(), "test_command");
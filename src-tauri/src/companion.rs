@@ -0,0 +1,506 @@
+//! Local socket server for the optional VS Code/Cursor companion extension.
+//!
+//! The extension runs once per editor window and pushes the window's
+//! workspace and active file, which are more reliable than anything that can
+//! be parsed from the title. The protocol is newline-delimited JSON over a
+//! Unix domain socket in the app data dir:
+//!
+//! ```text
+//! > {"type":"hello","version":1,"editor":"vscode"}
+//! < {"ok":true,"version":1}
+//! > {"type":"window_state","pid":812,"window_id":"3f2c...","workspace_name":"swii",
+//!    "workspace_folders":["/Users/dev/swii"],"active_file":"/Users/dev/swii/src/main.rs",
+//!    "open_files":["/Users/dev/swii/src/main.rs","/Users/dev/swii/Cargo.toml"]}
+//! < {"ok":true}
+//! > {"type":"window_closed","pid":812,"window_id":"3f2c..."}
+//! < {"ok":true}
+//! ```
+//!
+//! `pid` is the process owning the editor windows and `window_id` any value
+//! that is stable for the lifetime of the window, e.g. `vscode.env.sessionId`.
+//! Every message is answered with one line; reports are dropped when the
+//! connection that sent them closes. A connection that doesn't say hello
+//! within a few seconds is closed, and at most `MAX_CONNECTIONS` are served
+//! at once.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use swii_title_parser::{EditorKind, ProjectSource};
+use tracing::{debug, info, warn};
+
+use swii_core::types::constants::UNKNOWN_PID;
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// Socket file name inside the app data dir
+    pub const SOCKET_FILE: &str = "companion.sock";
+
+    /// Protocol version spoken by this server
+    pub const PROTOCOL_VERSION: u32 = 1;
+
+    /// Longest accepted message line, in bytes
+    pub const MAX_MESSAGE_BYTES: usize = 64 * 1024;
+
+    /// Most connections served at once
+    pub const MAX_CONNECTIONS: usize = 64;
+
+    /// Seconds a new connection has to say hello, and any reply to be written
+    pub const HANDSHAKE_TIMEOUT_SECS: u64 = 5;
+}
+
+/// Workspace and active file of one editor window, as reported by the extension
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WindowReport {
+    pub pid: i32,
+    pub window_id: String,
+    #[serde(default)]
+    pub workspace_name: Option<String>,
+    #[serde(default)]
+    pub workspace_folders: Vec<String>,
+    #[serde(default)]
+    pub active_file: Option<String>,
+    /// Documents open in the window's tabs
+    #[serde(default)]
+    pub open_files: Vec<String>,
+}
+
+/// Messages sent by the extension
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CompanionMessage {
+    Hello { version: u32, editor: String },
+    WindowState(WindowReport),
+    WindowClosed { pid: i32, window_id: String },
+}
+
+/// Reply sent for every message
+#[derive(Debug, PartialEq, Serialize)]
+struct Reply {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Reply {
+    fn ok() -> Self {
+        Reply {
+            ok: true,
+            version: None,
+            error: None,
+        }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Reply {
+            ok: false,
+            version: None,
+            error: Some(message.into()),
+        }
+    }
+}
+
+type ReportKey = (i32, String);
+
+fn with_reports<T>(f: impl FnOnce(&mut HashMap<ReportKey, WindowReport>) -> T) -> T {
+    static REPORTS: OnceLock<Mutex<HashMap<ReportKey, WindowReport>>> = OnceLock::new();
+    let mut reports = REPORTS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut reports)
+}
+
+/// Per-connection protocol state
+#[derive(Default)]
+struct Connection {
+    greeted: bool,
+    /// Windows reported on this connection, forgotten when it closes
+    windows: HashSet<ReportKey>,
+}
+
+impl Connection {
+    /// Handles one message line and returns the reply, plus whether the
+    /// connection should be closed
+    fn handle_line(&mut self, line: &str) -> (Reply, bool) {
+        let message = match serde_json::from_str::<CompanionMessage>(line) {
+            Ok(message) => message,
+            Err(e) => return (Reply::error(format!("Invalid message: {}", e)), false),
+        };
+
+        match message {
+            CompanionMessage::Hello { version, editor } => {
+                if version != constants::PROTOCOL_VERSION {
+                    let reply = Reply::error(format!(
+                        "Unsupported protocol version {}, expected {}",
+                        version,
+                        constants::PROTOCOL_VERSION
+                    ));
+                    return (reply, true);
+                }
+                debug!("Companion extension connected from {}", editor);
+                self.greeted = true;
+                let reply = Reply {
+                    version: Some(constants::PROTOCOL_VERSION),
+                    ..Reply::ok()
+                };
+                (reply, false)
+            }
+            _ if !self.greeted => (Reply::error("Expected hello first"), true),
+            CompanionMessage::WindowState(report) => {
+                let key = (report.pid, report.window_id.clone());
+                with_reports(|reports| reports.insert(key.clone(), report));
+                self.windows.insert(key);
+                (Reply::ok(), false)
+            }
+            CompanionMessage::WindowClosed { pid, window_id } => {
+                let key = (pid, window_id);
+                with_reports(|reports| reports.remove(&key));
+                self.windows.remove(&key);
+                (Reply::ok(), false)
+            }
+        }
+    }
+
+    /// Forgets every window this connection reported
+    fn close(self) {
+        with_reports(|reports| {
+            for key in self.windows.iter() {
+                reports.remove(key);
+            }
+        });
+    }
+}
+
+/// Overrides title-derived fields with companion reports where one matches
+///
+/// A report matches a VS Code/Cursor window of the same process whose project
+/// is the reported workspace; a process with a single window and a single
+/// report is matched regardless of the title.
+pub fn apply_reports(windows: &mut [WindowInfo]) {
+    let reports: Vec<WindowReport> = with_reports(|reports| reports.values().cloned().collect());
+    if reports.is_empty() {
+        return;
+    }
+
+    let mut windows_per_pid: HashMap<i32, usize> = HashMap::new();
+    for window in windows.iter() {
+        *windows_per_pid.entry(window.pid).or_default() += 1;
+    }
+
+    for window in windows.iter_mut() {
+        // Reports carry the extension host's real pid, which a window whose
+        // process is unknown can't be matched against
+        if !matches!(window.editor_kind, EditorKind::VsCode | EditorKind::Cursor)
+            || window.pid == UNKNOWN_PID
+        {
+            continue;
+        }
+
+        if let Some(report) = matching_report(window, &reports, windows_per_pid[&window.pid]) {
+            apply_report(window, report);
+        }
+    }
+}
+
+/// The report for `window`: the one of its process for its workspace or,
+/// when the process has a single window and a single report, that report
+fn matching_report<'a>(
+    window: &WindowInfo,
+    reports: impl IntoIterator<Item = &'a WindowReport>,
+    windows_of_pid: usize,
+) -> Option<&'a WindowReport> {
+    let candidates: Vec<&WindowReport> = reports
+        .into_iter()
+        .filter(|report| report.pid == window.pid)
+        .collect();
+    candidates
+        .iter()
+        .find(|report| report.workspace_name.is_some() && report.workspace_name == window.project)
+        .or_else(|| (candidates.len() == 1 && windows_of_pid == 1).then(|| &candidates[0]))
+        .copied()
+}
+
+fn apply_report(window: &mut WindowInfo, report: &WindowReport) {
+    if let Some(name) = report.workspace_name.as_ref() {
+        window.project = Some(name.clone());
+        window.workspace = report.workspace_folders.len() > 1;
+        window.project_path = match report.workspace_folders.as_slice() {
+            [folder] => Some(folder.clone()),
+            _ => None,
+        };
+        window.project_source = ProjectSource::Extension;
+    }
+    if let Some(file) = report.active_file.as_deref() {
+        window.active_editor_tab = Path::new(file)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+    }
+}
+
+/// Documents the extension reported as open in a window, matched like
+/// `apply_reports` matches reports to the latest listing
+pub fn open_files(window: &WindowInfo) -> Vec<String> {
+    let windows_of_pid = crate::window_list::listed_window_numbers(window.pid).len();
+    with_reports(|reports| {
+        matching_report(window, reports.values(), windows_of_pid)
+            .map(|report| report.open_files.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// Starts listening for the companion extension. Failures are logged; the
+/// app keeps working from window titles alone.
+#[cfg(unix)]
+pub fn start_server(app: &tauri::AppHandle) {
+    use std::os::unix::fs::PermissionsExt;
+    use std::os::unix::net::UnixListener;
+    use tauri::Manager;
+
+    let path = match app.path().app_data_dir() {
+        Ok(dir) => dir.join(constants::SOCKET_FILE),
+        Err(e) => {
+            warn!("Companion server disabled, no app data dir: {}", e);
+            return;
+        }
+    };
+
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    // A socket left behind by a previous run would make bind fail
+    let _ = std::fs::remove_file(&path);
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            warn!("Failed to bind companion socket {}: {}", path.display(), e);
+            return;
+        }
+    };
+    if let Err(e) = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)) {
+        warn!("Failed to restrict companion socket permissions: {}", e);
+    }
+    info!("Listening for companion extensions on {}", path.display());
+
+    std::thread::Builder::new()
+        .name("swii-companion".to_string())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => spawn_connection(stream),
+                    Err(e) => debug!("Failed to accept companion connection: {}", e),
+                }
+            }
+        })
+        .expect("failed to spawn companion server thread");
+}
+
+#[cfg(not(unix))]
+pub fn start_server(_app: &tauri::AppHandle) {
+    info!("Companion extension server is not supported on this platform");
+}
+
+/// Number of open connections
+#[cfg(unix)]
+static OPEN_CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// A place among the open connections, given back when dropped
+#[cfg(unix)]
+struct ConnectionSlot;
+
+#[cfg(unix)]
+impl ConnectionSlot {
+    fn acquire() -> Option<Self> {
+        OPEN_CONNECTIONS
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |open| {
+                (open < constants::MAX_CONNECTIONS).then_some(open + 1)
+            })
+            .ok()
+            .map(|_| ConnectionSlot)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        OPEN_CONNECTIONS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(unix)]
+fn spawn_connection(mut stream: std::os::unix::net::UnixStream) {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::time::Duration;
+
+    let timeout = Some(Duration::from_secs(constants::HANDSHAKE_TIMEOUT_SECS));
+    if stream.set_read_timeout(timeout).is_err() || stream.set_write_timeout(timeout).is_err() {
+        return;
+    }
+    let Some(slot) = ConnectionSlot::acquire() else {
+        warn!("Refusing companion connection, too many open");
+        if let Ok(reply) = serde_json::to_string(&Reply::error("Too many connections")) {
+            let _ = writeln!(stream, "{}", reply);
+        }
+        return;
+    };
+
+    let spawned = std::thread::Builder::new()
+        .name("swii-companion-connection".to_string())
+        .spawn(move || {
+            let _slot = slot;
+            let Ok(mut writer) = stream.try_clone() else {
+                return;
+            };
+            let mut reader = BufReader::new(stream);
+            let mut connection = Connection::default();
+            let mut line = String::new();
+            let mut awaiting_hello = true;
+
+            loop {
+                // Greeted extensions stay connected for the window's lifetime
+                // and may go quiet for as long as nothing changes
+                if awaiting_hello && connection.greeted {
+                    awaiting_hello = false;
+                    if reader.get_ref().set_read_timeout(None).is_err() {
+                        break;
+                    }
+                }
+                line.clear();
+                let limit = constants::MAX_MESSAGE_BYTES as u64 + 1;
+                match (&mut reader).take(limit).read_line(&mut line) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {}
+                }
+
+                let (reply, close) = if line.len() > constants::MAX_MESSAGE_BYTES {
+                    (Reply::error("Message too long"), true)
+                } else if line.trim().is_empty() {
+                    continue;
+                } else {
+                    connection.handle_line(line.trim())
+                };
+
+                let Ok(reply) = serde_json::to_string(&reply) else {
+                    break;
+                };
+                if writeln!(writer, "{}", reply).is_err() || close {
+                    break;
+                }
+            }
+
+            connection.close();
+        });
+
+    if let Err(e) = spawned {
+        warn!("Failed to spawn companion connection thread: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(pid: i32, number: u32, project: &str) -> WindowInfo {
+        WindowInfo::builder("Visual Studio Code", pid, number)
+            .project(Some(project))
+            .build()
+    }
+
+    #[test]
+    fn test_protocol_requires_hello() {
+        let mut connection = Connection::default();
+        let (reply, close) =
+            connection.handle_line(r#"{"type":"window_closed","pid":1,"window_id":"a"}"#);
+        assert!(!reply.ok);
+        assert!(close);
+
+        let (reply, close) =
+            connection.handle_line(r#"{"type":"hello","version":99,"editor":"vscode"}"#);
+        assert!(!reply.ok);
+        assert!(close);
+
+        let (reply, close) =
+            connection.handle_line(r#"{"type":"hello","version":1,"editor":"vscode"}"#);
+        assert_eq!(reply.version, Some(constants::PROTOCOL_VERSION));
+        assert!(reply.ok);
+        assert!(!close);
+
+        let (reply, close) = connection.handle_line("{ not json");
+        assert!(!reply.ok);
+        assert!(!close);
+    }
+
+    #[test]
+    fn test_reports_override_titles() {
+        let mut connection = Connection::default();
+        connection.handle_line(r#"{"type":"hello","version":1,"editor":"cursor"}"#);
+        let state = serde_json::json!({
+            "type": "window_state",
+            "pid": -4242,
+            "window_id": "a",
+            "workspace_name": "swii",
+            "workspace_folders": ["/Users/dev/swii"],
+            "active_file": "/Users/dev/swii/src/lib.rs",
+        });
+        let (reply, _) = connection.handle_line(&state.to_string());
+        assert_eq!(reply, Reply::ok());
+
+        let mut windows = vec![window(-4242, 1, "swii"), window(-4242, 2, "other")];
+        apply_reports(&mut windows);
+        assert_eq!(windows[0].project_source, ProjectSource::Extension);
+        assert_eq!(windows[0].project_path.as_deref(), Some("/Users/dev/swii"));
+        assert_eq!(windows[0].active_editor_tab.as_deref(), Some("lib.rs"));
+        assert_eq!(windows[1].project_source, ProjectSource::Title);
+
+        // A single window of the process matches even with a stale title
+        let mut windows = vec![window(-4242, 1, "Welcome")];
+        apply_reports(&mut windows);
+        assert_eq!(windows[0].project.as_deref(), Some("swii"));
+
+        connection.close();
+        let mut windows = vec![window(-4242, 1, "Welcome")];
+        apply_reports(&mut windows);
+        assert_eq!(windows[0].project.as_deref(), Some("Welcome"));
+    }
+
+    #[test]
+    fn test_matching_report() {
+        let report = WindowReport {
+            pid: 7,
+            window_id: "a".to_string(),
+            workspace_name: Some("swii".to_string()),
+            workspace_folders: Vec::new(),
+            active_file: None,
+            open_files: vec!["/src/swii/lib.rs".to_string()],
+        };
+        let reports = [report];
+        let other = window(7, 2, "Welcome");
+
+        assert!(matching_report(&window(7, 1, "swii"), &reports, 2).is_some());
+        assert!(matching_report(&other, &reports, 1).is_some());
+        // With a second window of the process, the report may be that one's
+        assert!(matching_report(&other, &reports, 2).is_none());
+        assert!(matching_report(&window(8, 1, "swii"), &reports, 1).is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_connection_slots() {
+        let slots: Vec<ConnectionSlot> = (0..constants::MAX_CONNECTIONS)
+            .map_while(|_| ConnectionSlot::acquire())
+            .collect();
+        assert_eq!(slots.len(), constants::MAX_CONNECTIONS);
+        assert!(ConnectionSlot::acquire().is_none());
+
+        drop(slots);
+        assert!(ConnectionSlot::acquire().is_some());
+        assert_eq!(OPEN_CONNECTIONS.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,90 @@
+//! Frontend console output in the backend log.
+//!
+//! With `capture_frontend_console` set, a small bridge is injected into every
+//! webview once its page has loaded. It wraps the `console` methods and the
+//! uncaught error handlers, passing each message on to `log_from_frontend`
+//! tagged with the webview's label, so frontend errors show up next to the
+//! backend's own output. The wrapped methods still write to the devtools
+//! console.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::webview::{PageLoadEvent, PageLoadPayload};
+use tracing::debug;
+
+pub mod constants {
+    /// Tag prefix of forwarded console messages, followed by the webview label
+    pub const CONSOLE_TAG: &str = "console";
+}
+
+/// Placeholder in [`BRIDGE_SCRIPT`] replaced by the quoted log tag
+const TAG_PLACEHOLDER: &str = "__SWII_CONSOLE_TAG__";
+
+/// Wraps the console once per page. The original methods are kept on
+/// `window.__swiiConsoleCapture` so the frontend logger, which forwards its
+/// own messages, can write to the console without sending them twice.
+const BRIDGE_SCRIPT: &str = r#"(() => {
+  const invoke = window.__TAURI_INTERNALS__ && window.__TAURI_INTERNALS__.invoke;
+  if (!invoke || window.__swiiConsoleCapture) return;
+  const tag = __SWII_CONSOLE_TAG__;
+  const format = (args) => args.map((arg) => {
+    if (typeof arg === 'string') return arg;
+    if (arg instanceof Error) return arg.stack || String(arg);
+    try { return JSON.stringify(arg); } catch { return String(arg); }
+  }).join(' ');
+  const forward = (level, message) => {
+    invoke('log_from_frontend', { level, tag, message }).catch(() => {});
+  };
+  const levels = { debug: 'debug', log: 'info', info: 'info', warn: 'warn', error: 'error' };
+  const original = {};
+  for (const [method, level] of Object.entries(levels)) {
+    original[method] = console[method].bind(console);
+    console[method] = (...args) => {
+      original[method](...args);
+      forward(level, format(args));
+    };
+  }
+  window.addEventListener('error', (event) => {
+    forward('error', `Uncaught ${event.message} at ${event.filename}:${event.lineno}`);
+  });
+  window.addEventListener('unhandledrejection', (event) => {
+    forward('error', `Unhandled rejection: ${format([event.reason])}`);
+  });
+  window.__swiiConsoleCapture = original;
+})();"#;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables capturing for pages loaded from now on
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// The bridge script for the webview labelled `label`
+fn bridge_script(label: &str) -> String {
+    let tag = format!("{}:{}", constants::CONSOLE_TAG, label);
+    let quoted = serde_json::to_string(&tag).unwrap_or_else(|_| "\"console\"".to_string());
+    BRIDGE_SCRIPT.replace(TAG_PLACEHOLDER, &quoted)
+}
+
+/// Injects the bridge into a webview that finished loading, when enabled
+pub fn on_page_load(webview: &tauri::Webview, payload: &PageLoadPayload<'_>) {
+    if !ENABLED.load(Ordering::Relaxed) || payload.event() != PageLoadEvent::Finished {
+        return;
+    }
+    if let Err(e) = webview.eval(bridge_script(webview.label())) {
+        debug!("Failed to capture console of '{}': {}", webview.label(), e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_script_quotes_tag() {
+        let script = bridge_script("pre\"view");
+        assert!(script.contains(r#"const tag = "console:pre\"view";"#));
+        assert!(!script.contains(TAG_PLACEHOLDER));
+    }
+}
@@ -0,0 +1,93 @@
+//! Tagging windows whose project runs in a container.
+//!
+//! VS Code and Cursor mark dev container and codespace windows in the title,
+//! which the title parser reports as the window's environment. Other editors
+//! open such projects without a marker, so a dev container config in the
+//! project root tags the window as well.
+
+use std::path::Path;
+
+use swii_title_parser::DevEnvironmentKind;
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// Files and directories holding a dev container config, relative to
+    /// the project root
+    pub const DEV_CONTAINER_CONFIGS: &[&str] = &[".devcontainer", ".devcontainer.json"];
+}
+
+/// Sets `containerized` on every window whose project runs in a container
+pub fn tag_containerized(windows: &mut [WindowInfo]) {
+    for window in windows.iter_mut() {
+        window.containerized = is_containerized(window);
+    }
+}
+
+fn is_containerized(window: &WindowInfo) -> bool {
+    if let Some(environment) = &window.environment {
+        // Codespaces run in a dev container too; WSL is a VM
+        return matches!(
+            environment.kind,
+            DevEnvironmentKind::DevContainer | DevEnvironmentKind::Codespaces
+        );
+    }
+    // Paths of SSH windows are on the remote machine
+    if window.remote_host.is_some() {
+        return false;
+    }
+    window.project_path.as_deref().is_some_and(|root| {
+        constants::DEV_CONTAINER_CONFIGS
+            .iter()
+            .any(|config| Path::new(root).join(config).exists())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use swii_title_parser::DevEnvironment;
+
+    use super::*;
+
+    fn window(project_path: Option<&str>, environment: Option<DevEnvironmentKind>) -> WindowInfo {
+        WindowInfo::builder("Code", 1, 1)
+            .project(Some("api"))
+            .environment(environment.map(|kind| DevEnvironment {
+                kind,
+                name: "api".to_string(),
+            }))
+            .project_path(project_path)
+            .build()
+    }
+
+    #[test]
+    fn test_tag_containerized() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        std::fs::create_dir_all(dir.join("api/.devcontainer")).unwrap();
+        std::fs::create_dir_all(dir.join("web")).unwrap();
+        std::fs::write(dir.join("web/.devcontainer.json"), "{}").unwrap();
+        std::fs::create_dir_all(dir.join("cli")).unwrap();
+        let path = |name: &str| dir.join(name).to_string_lossy().to_string();
+
+        let mut remote = window(Some(&path("api")), None);
+        remote.remote_host = Some("prod-box".to_string());
+        let mut windows = vec![
+            window(None, Some(DevEnvironmentKind::DevContainer)),
+            window(None, Some(DevEnvironmentKind::Codespaces)),
+            window(None, Some(DevEnvironmentKind::Wsl)),
+            window(Some(&path("api")), None),
+            window(Some(&path("web")), None),
+            window(Some(&path("cli")), None),
+            window(None, None),
+            remote,
+        ];
+        tag_containerized(&mut windows);
+
+        let tags: Vec<bool> = windows.iter().map(|window| window.containerized).collect();
+        assert_eq!(
+            tags,
+            vec![true, true, false, true, true, false, false, false]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! "Copy path" actions for editor windows.
+//!
+//! `copy_project_path` puts a window's project root on the clipboard and
+//! `copy_file_path` the document it shows, as exposed by the platform (the
+//! `AXDocument` of the window on macOS). Both return what they copied, or
+//! `None` when the window has no such path, so the switcher can offer the
+//! actions without listing paths itself. The clipboard is written with
+//! `pbcopy`, so copying is macOS only.
+
+use swii_core::focus::WindowFocusError;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Prefix of documents exposed as URLs
+    pub const FILE_URL_PREFIX: &str = "file://";
+
+    /// Host some applications put in local file URLs
+    pub const LOCALHOST: &str = "localhost";
+}
+
+/// Decodes `%XX` escapes, keeping malformed ones as they are
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// The absolute file path of a document exposed as a path or `file://` URL
+pub(crate) fn document_file_path(document: &str) -> Option<String> {
+    let path = match document.strip_prefix(constants::FILE_URL_PREFIX) {
+        Some(url) => percent_decode(url.strip_prefix(constants::LOCALHOST).unwrap_or(url)),
+        None => document.to_string(),
+    };
+    if !path.starts_with('/') {
+        return None;
+    }
+    match path.trim_end_matches('/') {
+        "" => None,
+        trimmed => Some(trimmed.to_string()),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn copy_to_clipboard(text: &str) -> Result<(), SwiiError> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new("pbcopy").stdin(Stdio::piped()).spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(SwiiError::Internal(format!(
+            "pbcopy exited with {}",
+            status
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn copy_to_clipboard(_text: &str) -> Result<(), SwiiError> {
+    Err(SwiiError::Unsupported(
+        "Copying to the clipboard is only supported on macOS".to_string(),
+    ))
+}
+
+/// Reads the `pid` and `window_number` parameters
+fn window_parameters(parameters: &serde_json::Value) -> Result<(i32, u32), SwiiError> {
+    let pid = parameters
+        .get("pid")
+        .and_then(|v| v.as_i64())
+        .ok_or(SwiiError::InvalidParameter("pid"))? as i32;
+    let window_number = parameters
+        .get("window_number")
+        .and_then(|v| v.as_u64())
+        .ok_or(SwiiError::InvalidParameter("window_number"))? as u32;
+    Ok((pid, window_number))
+}
+
+/// Copies `path` when there is one
+fn copy_path(path: Option<String>) -> Result<Option<String>, SwiiError> {
+    if let Some(path) = &path {
+        copy_to_clipboard(path)?;
+    }
+    Ok(path)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn copy_project_path(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Option<String>> {
+    crate::command_wrapper::create_typed_command("copy_project_path", args, |ctx| {
+        let (pid, window_number) = window_parameters(&ctx.parameters)?;
+        let window = crate::window_list::known_window(pid, window_number)?
+            .ok_or(WindowFocusError::WindowNotFound)?;
+
+        let copied = copy_path(window.project_path)?;
+        match &copied {
+            Some(path) => ctx.logger.info(&format!("Copied project path {}", path)),
+            None => ctx.logger.info(&format!(
+                "No project path known for window {} of PID {}",
+                window_number, pid
+            )),
+        }
+        Ok(copied)
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn copy_file_path(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Option<String>> {
+    crate::command_wrapper::create_typed_command("copy_file_path", args, |ctx| {
+        let (pid, window_number) = window_parameters(&ctx.parameters)?;
+
+        let document = crate::window_provider::provider().window_document(pid, window_number)?;
+        let copied = copy_path(document.as_deref().and_then(document_file_path))?;
+        match &copied {
+            Some(path) => ctx.logger.info(&format!("Copied file path {}", path)),
+            None => ctx.logger.info(&format!(
+                "No document exposed by window {} of PID {}",
+                window_number, pid
+            )),
+        }
+        Ok(copied)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_file_path() {
+        assert_eq!(
+            document_file_path("file:///Users/me/My%20Project/src/main.rs").as_deref(),
+            Some("/Users/me/My Project/src/main.rs")
+        );
+        assert_eq!(
+            document_file_path("file://localhost/tmp/notes/").as_deref(),
+            Some("/tmp/notes")
+        );
+        assert_eq!(
+            document_file_path("/tmp/100%.txt").as_deref(),
+            Some("/tmp/100%.txt")
+        );
+        assert_eq!(document_file_path("src/main.rs"), None);
+        assert_eq!(document_file_path("file:///"), None);
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("caf%C3%A9%2"), "café%2");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+}
@@ -0,0 +1,223 @@
+//! The day's projects appended to a Markdown note.
+//!
+//! With `daily_note` set, swii appends a "Worked on" section to a Markdown
+//! file once a day at a configured local time, listing the projects worked
+//! in that day and how long their editor windows were in front while the
+//! user wasn't idle (see `focus_history`), e.g. for an Obsidian daily note.
+//! `{date}` in the path is replaced with the day's date, and a leading `~`
+//! with the home directory.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{Local, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// How often the clock is checked against the note time
+    pub const NOTE_POLL_INTERVAL_SECS: u64 = 30;
+
+    /// Local time the note is written at when none is configured
+    pub const DEFAULT_NOTE_TIME: &str = "18:00";
+
+    /// Replaced with the day's date in the note path
+    pub const DATE_PLACEHOLDER: &str = "{date}";
+
+    /// Format of the date substituted for `{date}`
+    pub const NOTE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+    /// Heading of the appended section
+    pub const SECTION_HEADING: &str = "## Worked on";
+}
+
+/// Where and when to write the note
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct DailyNote {
+    /// Markdown file to append to, e.g. `"~/Notes/Daily/{date}.md"`
+    pub path: String,
+    /// Local time to write at, e.g. `"17:30"`; 18:00 by default
+    pub at: Option<String>,
+}
+
+static NOTE: Mutex<Option<(String, NaiveTime)>> = Mutex::new(None);
+static MONITOR_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Replaces the note configuration. Returns a message when it's invalid and
+/// no note will be written.
+pub fn set_note(note: Option<&DailyNote>) -> Option<String> {
+    let (config, error) = match note {
+        None => (None, None),
+        Some(note) if note.path.trim().is_empty() => {
+            (None, Some("Daily note: the path is empty".to_string()))
+        }
+        Some(note) => match crate::focus_rules::parse_time(
+            note.at.as_deref().unwrap_or(constants::DEFAULT_NOTE_TIME),
+        ) {
+            Ok(at) => (Some((note.path.clone(), at)), None),
+            Err(error) => (None, Some(format!("Daily note: {}", error))),
+        },
+    };
+    *NOTE.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+    error
+}
+
+/// The note file for `date`
+fn note_path(template: &str, date: NaiveDate, home: Option<&str>) -> PathBuf {
+    let path = template.replace(
+        constants::DATE_PLACEHOLDER,
+        &date.format(constants::NOTE_DATE_FORMAT).to_string(),
+    );
+    match (path.strip_prefix("~/"), home) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// `1h 25m`, `40m` or `<1m`
+fn format_duration(duration_ms: u64) -> String {
+    let minutes = duration_ms / 60_000;
+    match (minutes / 60, minutes % 60) {
+        (0, 0) => "<1m".to_string(),
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+/// The appended section for per-project durations
+fn note_section(durations: &[(String, u64)]) -> String {
+    let mut section = format!("{}\n\n", constants::SECTION_HEADING);
+    for (project, duration_ms) in durations {
+        section.push_str(&format!(
+            "- {} ({})\n",
+            project,
+            format_duration(*duration_ms)
+        ));
+    }
+    section
+}
+
+/// Appends `section` to `path`, separated from earlier content by a blank line
+fn append(path: &Path, section: &str) -> Result<(), SwiiError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let existing = std::fs::read_to_string(path).unwrap_or_default();
+    let separator = match existing.as_str() {
+        "" => "",
+        text if text.ends_with("\n\n") => "",
+        text if text.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(format!("{}{}", separator, section).as_bytes())?;
+    Ok(())
+}
+
+/// Writes today's projects to the note
+fn write_note(template: &str) -> Result<(), SwiiError> {
+    let today = Local::now().date_naive();
+    let midnight = today
+        .and_time(NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|midnight| midnight.timestamp_millis().max(0) as u64)
+        .unwrap_or(0);
+    let durations = crate::focus_history::project_durations(midnight);
+    if durations.is_empty() {
+        info!("No projects focused today, skipping the daily note");
+        return Ok(());
+    }
+
+    let home = std::env::var("HOME").ok();
+    let path = note_path(template, today, home.as_deref());
+    append(&path, &note_section(&durations))?;
+    info!(
+        "Wrote {} projects to the daily note {}",
+        durations.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Starts the note writer. Calling this more than once is a no-op.
+pub fn start() {
+    if MONITOR_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    std::thread::Builder::new()
+        .name("swii-daily-note".to_string())
+        .spawn(|| {
+            let mut previous_time = Local::now().naive_local();
+            loop {
+                std::thread::sleep(Duration::from_secs(constants::NOTE_POLL_INTERVAL_SECS));
+
+                let time = Local::now().naive_local();
+                let note = NOTE
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clone();
+                if let Some((template, at)) = note {
+                    if crate::focus_rules::passed(at, previous_time, time) {
+                        if let Err(e) = write_note(&template) {
+                            warn!("Failed to write the daily note: {}", e);
+                        }
+                    }
+                }
+                previous_time = time;
+            }
+        })
+        .expect("failed to spawn daily note thread");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_path() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        assert_eq!(
+            note_path("~/Notes/Daily/{date}.md", date, Some("/Users/me")),
+            PathBuf::from("/Users/me/Notes/Daily/2026-03-02.md")
+        );
+        assert_eq!(
+            note_path("/notes/log.md", date, Some("/Users/me")),
+            PathBuf::from("/notes/log.md")
+        );
+    }
+
+    #[test]
+    fn test_note_section() {
+        let section = note_section(&[
+            ("api".to_string(), 85 * 60_000),
+            ("web".to_string(), 2 * 3_600_000),
+            ("docs".to_string(), 30_000),
+        ]);
+        assert_eq!(
+            section,
+            "## Worked on\n\n- api (1h 25m)\n- web (2h)\n- docs (<1m)\n"
+        );
+    }
+
+    #[test]
+    fn test_set_note_rejects_invalid_time() {
+        let note = DailyNote {
+            path: "~/notes.md".to_string(),
+            at: Some("6pm".to_string()),
+        };
+        assert!(set_note(Some(&note)).is_some());
+        assert!(NOTE.lock().unwrap().is_none());
+        assert!(set_note(None).is_none());
+    }
+}
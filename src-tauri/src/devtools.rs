@@ -2,31 +2,62 @@
 
 use tauri::Manager;
 
+pub mod constants {
+    /// Label of the main switcher window, used when no label is given
+    pub const DEFAULT_WINDOW_LABEL: &str = "swii";
+}
+
 #[tauri::command]
+#[specta::specta]
 pub async fn open_devtools(
     app: tauri::AppHandle,
     _args: Option<serde_json::Value>,
 ) -> Result<serde_json::Value, String> {
     crate::command_wrapper::create_command("open_devtools", _args, |ctx| {
-        ctx.logger.info("Opening DevTools");
-        
+        let label = ctx
+            .parameters
+            .get("label")
+            .and_then(|v| v.as_str())
+            .unwrap_or(constants::DEFAULT_WINDOW_LABEL)
+            .to_string();
+        ctx.logger
+            .info(&format!("Opening DevTools for '{}'", label));
+
         #[cfg(debug_assertions)]
         {
-            if let Some(window) = app.get_webview_window("swii") {
+            if let Some(window) = app.get_webview_window(&label) {
                 window.open_devtools();
                 ctx.logger.info("DevTools opened successfully");
             } else {
-                ctx.logger.error("Could not find 'swii' window");
-                return Err("Could not find 'swii' window".to_string());
+                let message = format!("Could not find '{}' window", label);
+                ctx.logger.error(&message);
+                return Err(message.into());
             }
         }
-        
+
         #[cfg(not(debug_assertions))]
         {
+            let _ = &app;
             ctx.logger.info("DevTools not available in release mode");
         }
-        
+
         Ok(())
     })
     .await
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_webview_windows(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("list_webview_windows", args, |ctx| {
+        let mut labels: Vec<String> = app.webview_windows().into_keys().collect();
+        labels.sort();
+        ctx.logger
+            .info(&format!("Found {} webview windows", labels.len()));
+        Ok(labels)
+    })
+    .await
+}
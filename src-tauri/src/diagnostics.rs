@@ -0,0 +1,244 @@
+//! Diagnostics commands for troubleshooting slow or incorrect window listings.
+//!
+//! The enumeration internals inspected here are macOS-specific; on other
+//! platforms the commands are registered but report that they are unsupported.
+
+use serde::Serialize;
+#[cfg(target_os = "macos")]
+use std::collections::BTreeMap;
+#[cfg(target_os = "macos")]
+use std::time::Instant;
+#[cfg(target_os = "macos")]
+use swii_core::macos_accessibility::get_ax_window_titles_for_pid;
+#[cfg(target_os = "macos")]
+use swii_core::macos_window::{get_editor_windows_timed, get_raw_window_list, RawCgWindow};
+use swii_core::provider::EnumerationTimings;
+#[cfg(target_os = "macos")]
+use swii_title_parser::{parse_title, ParsedTitle};
+
+#[cfg(not(target_os = "macos"))]
+use crate::error::SwiiError;
+
+/// Timing report returned by `run_diagnostics`; exported to the bindings on
+/// every platform but only built on macOS
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+#[derive(Debug, Serialize, specta::Type)]
+pub struct DiagnosticsReport {
+    pub window_count: usize,
+    pub app_count: usize,
+    pub timings: EnumerationTimings,
+    pub serialization_ms: f64,
+    pub payload_bytes: usize,
+    pub total_ms: f64,
+}
+
+/// A single AX window title together with what the parser made of it
+#[cfg(target_os = "macos")]
+#[derive(Debug, Serialize)]
+pub struct AxTitleEntry {
+    pub index: usize,
+    pub title: Option<String>,
+    pub parsed: Option<ParsedTitle>,
+}
+
+/// AX titles seen for one editor process, in AX window order
+#[cfg(target_os = "macos")]
+#[derive(Debug, Serialize)]
+pub struct PidAxTitles {
+    pub pid: i32,
+    pub app_name: Option<String>,
+    pub titles: Vec<AxTitleEntry>,
+}
+
+/// Raw enumeration data attached to parsing bug reports
+#[cfg(target_os = "macos")]
+#[derive(Debug, Serialize)]
+pub struct WindowDebugDump {
+    pub generated_at: String,
+    pub cg_windows: Vec<RawCgWindow>,
+    pub ax_titles: Vec<PidAxTitles>,
+}
+
+/// Replaces the user's home directory with `~` so dumps don't leak usernames
+#[cfg(any(target_os = "macos", test))]
+pub fn sanitize_debug_string(value: &str, home: Option<&str>) -> String {
+    match home {
+        Some(home) if !home.is_empty() && home != "/" => value.replace(home, "~"),
+        _ => value.to_string(),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_diagnostics(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("run_diagnostics", args, |ctx| {
+        ctx.logger.info("Running enumeration diagnostics");
+
+        #[cfg(target_os = "macos")]
+        {
+            let total_start = Instant::now();
+            let mut timings = EnumerationTimings::default();
+
+            let windows = get_editor_windows_timed(&mut timings).map_err(|e| {
+                ctx.logger
+                    .error(&format!("Diagnostics enumeration failed: {}", e));
+                e
+            })?;
+
+            let serialization_start = Instant::now();
+            let payload = serde_json::to_vec(&windows).map_err(|e| e.to_string())?;
+            let serialization_ms = serialization_start.elapsed().as_secs_f64() * 1000.0;
+
+            let report = DiagnosticsReport {
+                window_count: windows.len(),
+                app_count: timings.ax_extraction_per_app.len(),
+                timings,
+                serialization_ms,
+                payload_bytes: payload.len(),
+                total_ms: total_start.elapsed().as_secs_f64() * 1000.0,
+            };
+
+            ctx.logger.info(&format!(
+                "Diagnostics: {} windows across {} apps in {:.1}ms",
+                report.window_count, report.app_count, report.total_ms
+            ));
+            Ok(report)
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            ctx.logger.info("Not on macOS, diagnostics unavailable");
+            Err::<DiagnosticsReport, _>(SwiiError::Unsupported(
+                "Diagnostics are only supported on macOS".to_string(),
+            ))
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn dump_window_debug_info(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("dump_window_debug_info", args, |ctx| {
+        let include_all_apps = ctx
+            .parameters
+            .get("include_all_apps")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        ctx.logger.info(&format!(
+            "Dumping raw window info (include_all_apps: {})",
+            include_all_apps
+        ));
+
+        #[cfg(target_os = "macos")]
+        {
+            let home = std::env::var("HOME").ok();
+            let sanitize =
+                |value: Option<String>| value.map(|v| sanitize_debug_string(&v, home.as_deref()));
+
+            let mut cg_windows = get_raw_window_list(include_all_apps).map_err(|e| {
+                ctx.logger
+                    .error(&format!("Failed to read raw window list: {}", e));
+                e
+            })?;
+
+            let mut editor_pids = BTreeMap::new();
+            for window in cg_windows.iter_mut() {
+                window.window_name = sanitize(window.window_name.take());
+                if let (true, Some(pid)) = (window.is_editor, window.owner_pid) {
+                    editor_pids
+                        .entry(pid as i32)
+                        .or_insert_with(|| window.owner_name.clone());
+                }
+            }
+
+            let ax_titles = editor_pids
+                .into_iter()
+                .map(|(pid, app_name)| {
+                    // SAFETY: get_ax_window_titles_for_pid handles invalid PIDs gracefully
+                    let titles = unsafe { get_ax_window_titles_for_pid(pid) }
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, title)| {
+                            let parsed = title.as_deref().map(|t| {
+                                let mut parsed = parse_title(app_name.as_deref(), t);
+                                parsed.project = sanitize(parsed.project);
+                                parsed.tab = sanitize(parsed.tab);
+                                parsed.path = sanitize(parsed.path);
+                                parsed
+                            });
+                            AxTitleEntry {
+                                index,
+                                title: sanitize(title),
+                                parsed,
+                            }
+                        })
+                        .collect();
+
+                    PidAxTitles {
+                        pid,
+                        app_name,
+                        titles,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            ctx.logger.info(&format!(
+                "Dumped {} CG windows and AX titles for {} processes",
+                cg_windows.len(),
+                ax_titles.len()
+            ));
+
+            Ok(WindowDebugDump {
+                generated_at: chrono::Utc::now().to_rfc3339(),
+                cg_windows,
+                ax_titles,
+            })
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            Err::<(), _>(SwiiError::Unsupported(
+                "Window debug dumps are only supported on macOS".to_string(),
+            ))
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_debug_string_replaces_home() {
+        assert_eq!(
+            sanitize_debug_string("/Users/alice/code/swii/src/main.rs", Some("/Users/alice")),
+            "~/code/swii/src/main.rs"
+        );
+        assert_eq!(
+            sanitize_debug_string("main.rs — swii", Some("/Users/alice")),
+            "main.rs — swii"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_debug_string_ignores_degenerate_home() {
+        assert_eq!(sanitize_debug_string("/tmp/file.rs", None), "/tmp/file.rs");
+        assert_eq!(
+            sanitize_debug_string("/tmp/file.rs", Some("")),
+            "/tmp/file.rs"
+        );
+        assert_eq!(
+            sanitize_debug_string("/tmp/file.rs", Some("/")),
+            "/tmp/file.rs"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Do-not-disturb mode.
+//!
+//! `set_dnd` makes swii go quiet, e.g. during a presentation or a screen
+//! recording: the window observer detaches, idle detection stops polling,
+//! focus rules stop firing, and watched projects and focus timers post no
+//! notifications. Given a duration, the mode ends by itself; otherwise it
+//! lasts until turned off. Every change, including the end of a timed mode,
+//! is emitted as a `dnd-changed` event, on which the frontend stops or
+//! resumes its own polling. When the mode ends, windows are listed again and
+//! whatever opened or closed in the meantime is emitted.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tauri::Emitter;
+use tracing::{debug, info, warn};
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Event emitted to the frontend when the mode is turned on or off
+    pub const DND_CHANGED_EVENT: &str = "dnd-changed";
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// End of the mode in milliseconds since the Unix epoch, 0 for none
+static UNTIL_MS: AtomicU64 = AtomicU64::new(0);
+
+/// State of the mode, returned by `set_dnd` and sent with `dnd-changed`
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct DndStatus {
+    pub enabled: bool,
+    /// Milliseconds since the Unix epoch, `None` until turned off
+    pub until: Option<u64>,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Whether a mode ending at `until_ms` (0 for never) is still on at `now_ms`
+fn still_on(until_ms: u64, now_ms: u64) -> bool {
+    until_ms == 0 || now_ms < until_ms
+}
+
+/// True while swii should stay quiet
+pub fn is_active() -> bool {
+    ENABLED.load(Ordering::Relaxed) && still_on(UNTIL_MS.load(Ordering::Relaxed), now_ms())
+}
+
+fn status() -> DndStatus {
+    let until = UNTIL_MS.load(Ordering::Relaxed);
+    let enabled = is_active();
+    DndStatus {
+        enabled,
+        until: (enabled && until != 0).then_some(until),
+    }
+}
+
+/// Turns a mode ending at `until_ms` off once it's over, unless `set_dnd`
+/// changed it in the meantime
+fn end_at(app: tauri::AppHandle, until_ms: u64) {
+    let spawned = std::thread::Builder::new()
+        .name("swii-dnd-timer".to_string())
+        .spawn(move || {
+            std::thread::sleep(Duration::from_millis(until_ms.saturating_sub(now_ms())));
+            if UNTIL_MS
+                .compare_exchange(until_ms, 0, Ordering::Relaxed, Ordering::Relaxed)
+                .is_err()
+            {
+                return;
+            }
+            ENABLED.store(false, Ordering::Relaxed);
+            info!("Do not disturb ended");
+            if let Err(e) = app.emit(constants::DND_CHANGED_EVENT, status()) {
+                debug!("Failed to emit do not disturb change: {}", e);
+            }
+            crate::window_events::sync_observation(&app);
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to spawn do not disturb timer: {}", e);
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_dnd(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<DndStatus> {
+    crate::command_wrapper::create_blocking_command("set_dnd", args, move |ctx| {
+        let enabled = ctx
+            .parameters
+            .get("enabled")
+            .and_then(|v| v.as_bool())
+            .ok_or(SwiiError::InvalidParameter("enabled"))?;
+        let duration_secs = match ctx.parameters.get("duration") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => Some(
+                value
+                    .as_u64()
+                    .filter(|seconds| *seconds > 0)
+                    .ok_or(SwiiError::InvalidParameter("duration"))?,
+            ),
+        };
+
+        let until = match duration_secs {
+            Some(seconds) if enabled => now_ms().saturating_add(seconds * 1000),
+            _ => 0,
+        };
+        UNTIL_MS.store(until, Ordering::Relaxed);
+        ENABLED.store(enabled, Ordering::Relaxed);
+        if until != 0 {
+            end_at(app.clone(), until);
+        }
+
+        let status = status();
+        match (enabled, duration_secs) {
+            (false, _) => ctx.logger.info("Do not disturb off"),
+            (true, Some(seconds)) => ctx
+                .logger
+                .info(&format!("Do not disturb on for {} seconds", seconds)),
+            (true, None) => ctx.logger.info("Do not disturb on until turned off"),
+        }
+        if let Err(e) = app.emit(constants::DND_CHANGED_EVENT, status.clone()) {
+            ctx.logger
+                .error(&format!("Failed to emit do not disturb change: {}", e));
+        }
+        crate::window_events::sync_observation(&app);
+        Ok(status)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_still_on() {
+        assert!(still_on(0, 5_000));
+        assert!(still_on(6_000, 5_000));
+        assert!(!still_on(5_000, 5_000));
+        assert!(!still_on(4_000, 5_000));
+    }
+}
@@ -0,0 +1,148 @@
+//! Crate-wide error type returned by commands.
+//!
+//! Every command failure ends up as a [`SwiiError`], which is sent to the
+//! frontend as `{ code, user_message, message, details }` in
+//! `CommandResult.error`. `user_message` is short enough to show in the UI,
+//! while `message` keeps the full detail for logs and bug reports. The wire
+//! format, the codes of window and focus errors and the user messages are
+//! shared with the plugin through `swii_core::error`; codes are part of the
+//! API contract and must never be renamed.
+
+use serde::{Serialize, Serializer};
+pub use swii_core::error::ErrorInfo;
+use swii_core::focus::WindowFocusError;
+use swii_core::provider::WindowError;
+use thiserror::Error;
+
+/// Errors surfaced by commands
+#[derive(Debug, Error)]
+pub enum SwiiError {
+    #[error(transparent)]
+    Window(#[from] WindowError),
+
+    #[error(transparent)]
+    Focus(#[from] WindowFocusError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+
+    #[error("Missing or invalid '{0}' parameter")]
+    InvalidParameter(&'static str),
+
+    #[error("{0}")]
+    Unsupported(String),
+
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl SwiiError {
+    /// Stable identifier of the error, safe to match on from the frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            SwiiError::Window(e) => e.code(),
+            SwiiError::Focus(e) => e.code(),
+            SwiiError::Io(_) => "io",
+            SwiiError::Config(_) => "config",
+            SwiiError::InvalidParameter(_) => "invalid_parameter",
+            SwiiError::Unsupported(_) => "unsupported",
+            SwiiError::Internal(_) => "internal",
+        }
+    }
+
+    /// Short description of the failure that makes sense to users
+    pub fn user_message(&self) -> &'static str {
+        swii_core::error::user_message(self.code())
+    }
+
+    fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            SwiiError::Window(e) => e.details(),
+            SwiiError::InvalidParameter(name) => Some(serde_json::json!({ "parameter": name })),
+            SwiiError::Io(e) => Some(serde_json::json!({ "kind": e.kind().to_string() })),
+            _ => None,
+        }
+    }
+
+    /// Converts the error into its wire format
+    pub fn to_info(&self) -> ErrorInfo {
+        ErrorInfo {
+            code: self.code(),
+            user_message: self.user_message(),
+            message: self.to_string(),
+            details: self.details(),
+        }
+    }
+}
+
+impl Serialize for SwiiError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_info().serialize(serializer)
+    }
+}
+
+/// Errors that have not been given a dedicated variant yet
+impl From<String> for SwiiError {
+    fn from(message: String) -> Self {
+        SwiiError::Internal(message)
+    }
+}
+
+impl From<&str> for SwiiError {
+    fn from(message: &str) -> Self {
+        SwiiError::Internal(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_serialization() {
+        let value = serde_json::to_value(SwiiError::InvalidParameter("pid")).unwrap();
+        assert_eq!(value["code"], "invalid_parameter");
+        assert_eq!(value["message"], "Missing or invalid 'pid' parameter");
+        assert_eq!(value["details"]["parameter"], "pid");
+        assert_eq!(value["user_message"], "That request was invalid");
+    }
+
+    #[test]
+    fn test_user_messages_hide_detail() {
+        let focus = SwiiError::from(WindowFocusError::SystemError(
+            "AX error: -25204".to_string(),
+        ));
+        let info = focus.to_info();
+        assert_eq!(info.user_message, "Couldn't switch to that window");
+        assert_eq!(info.message, "System error: AX error: -25204");
+
+        let internal = SwiiError::from("serde: trailing characters at line 1");
+        assert_eq!(internal.user_message(), "Something went wrong");
+    }
+
+    #[test]
+    fn test_wrapped_error_codes() {
+        let focus = SwiiError::from(WindowFocusError::PermissionDenied);
+        assert_eq!(focus.code(), "permission_denied");
+        assert_eq!(focus.to_string(), "Accessibility permissions required");
+
+        let unsupported = SwiiError::from(WindowError::Unsupported {
+            provider: "mock",
+            operation: "Observing windows",
+        });
+        let info = unsupported.to_info();
+        assert_eq!(info.code, "unsupported");
+        assert_eq!(info.details.unwrap()["provider"], "mock");
+    }
+
+    #[test]
+    fn test_string_errors_are_internal() {
+        let info = SwiiError::from("boom".to_string()).to_info();
+        assert_eq!(info.code, "internal");
+        assert_eq!(info.message, "boom");
+        assert_eq!(info.details, None);
+    }
+}
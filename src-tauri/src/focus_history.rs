@@ -0,0 +1,528 @@
+//! Editor windows that came to the front, most recent first.
+//!
+//! Every window focused through the switcher and every focus change the
+//! window observer reports, e.g. after Cmd-Tab, is recorded with the window's
+//! project at that moment. A record's duration runs until the next focus or
+//! until the user goes idle, so the latest record has none yet; it's picked
+//! up again when the user is back. Switching to an application that isn't an
+//! editor isn't observed, so that time still counts for the last editor.
+//!
+//! The most recent records are kept in a JSON file in the app data
+//! directory, so restarting swii doesn't lose the day. Changes are written a
+//! moment later on a background thread, batching bursts of focus changes,
+//! and replace the file through a rename so a crash mid-write can't leave it
+//! truncated; quitting writes whatever is pending right away.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use swii_core::types::WindowInfo;
+use tauri::Manager;
+use tracing::{info, warn};
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// History file name inside the app data dir
+    pub const HISTORY_FILE: &str = "focus_history.json";
+
+    /// Records kept before the oldest ones are dropped
+    pub const MAX_FOCUS_HISTORY: usize = 2000;
+
+    /// Records returned by `get_focus_history` when no limit is given
+    pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+    /// Milliseconds to wait after a change before writing the history file,
+    /// so a burst of focus changes is written once
+    pub const SAVE_DELAY_MS: u64 = 2000;
+}
+
+/// An editor window that came to the front
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct FocusRecord {
+    pub pid: i32,
+    pub window_number: u32,
+    pub app_name: String,
+    pub window_name: Option<String>,
+    pub project: Option<String>,
+    pub project_path: Option<String>,
+    /// Milliseconds since the Unix epoch
+    pub focused_at: u64,
+    /// Milliseconds until the next focus or until the user went idle,
+    /// `None` for the latest record
+    pub duration_ms: Option<u64>,
+}
+
+/// One page of the focus history
+#[derive(Debug, PartialEq, Serialize, specta::Type)]
+pub struct FocusHistoryPage {
+    pub records: Vec<FocusRecord>,
+    /// Number of records in the whole history
+    pub total: usize,
+    /// Offset of the next page, `None` on the last page
+    pub next_offset: Option<usize>,
+}
+
+/// A project focused before
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct RecentProject {
+    pub project: String,
+    pub project_path: Option<String>,
+    /// Editor the project was last focused in
+    pub app_name: String,
+    /// Milliseconds since the Unix epoch
+    pub last_focused_at: u64,
+}
+
+static HISTORY: Mutex<VecDeque<FocusRecord>> = Mutex::new(VecDeque::new());
+static HISTORY_PATH: OnceLock<PathBuf> = OnceLock::new();
+/// Wakes the thread writing the history file
+static SAVE_REQUESTS: OnceLock<Sender<()>> = OnceLock::new();
+/// Held while the history file is written, so writes don't interleave
+static WRITING: Mutex<()> = Mutex::new(());
+/// Set while the latest record was ended by going idle
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+fn lock_history() -> std::sync::MutexGuard<'static, VecDeque<FocusRecord>> {
+    HISTORY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Adds a record to the front of `history`, ending the previous one. A
+/// window that is already the open latest record isn't recorded again, since
+/// the observer also reports focuses made through the switcher.
+fn push_record(history: &mut VecDeque<FocusRecord>, record: FocusRecord) -> bool {
+    if let Some(previous) = history.front_mut() {
+        if previous.duration_ms.is_none()
+            && previous.pid == record.pid
+            && previous.window_number == record.window_number
+        {
+            return false;
+        }
+        previous
+            .duration_ms
+            .get_or_insert(record.focused_at.saturating_sub(previous.focused_at));
+    }
+    history.push_front(record);
+    history.truncate(constants::MAX_FOCUS_HISTORY);
+    true
+}
+
+/// Ends the open latest record of `history` at `at_ms`
+fn end_latest(history: &mut VecDeque<FocusRecord>, at_ms: u64) -> bool {
+    match history.front_mut() {
+        Some(latest) if latest.duration_ms.is_none() => {
+            latest.duration_ms = Some(at_ms.saturating_sub(latest.focused_at));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Starts a new record for the window of the ended latest record
+fn reopen_latest(history: &mut VecDeque<FocusRecord>, at_ms: u64) {
+    let Some(latest) = history
+        .front()
+        .filter(|latest| latest.duration_ms.is_some())
+    else {
+        return;
+    };
+    let record = FocusRecord {
+        focused_at: at_ms,
+        duration_ms: None,
+        ..latest.clone()
+    };
+    push_record(history, record);
+}
+
+/// Writes `contents` next to `path` and renames it over `path`, so readers
+/// see either the old file or the new one
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let temp = path.with_extension("json.tmp");
+    std::fs::write(&temp, contents)?;
+    std::fs::rename(&temp, path)
+}
+
+/// Writes the current history to the history file
+fn write_history() {
+    let Some(path) = HISTORY_PATH.get() else {
+        return;
+    };
+    let _writing = WRITING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // Copied so focus changes aren't held up by serializing and writing
+    let snapshot = lock_history().clone();
+    let result = serde_json::to_string(&snapshot)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| write_atomically(path, &contents).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        warn!("Failed to save focus history: {}", e);
+    }
+}
+
+/// Asks the writer thread to write the history file soon
+fn save() {
+    if let Some(requests) = SAVE_REQUESTS.get() {
+        let _ = requests.send(());
+    }
+}
+
+/// Starts the thread writing the history file after changes
+fn start_writer() {
+    let (requests, pending) = mpsc::channel::<()>();
+    let spawned = std::thread::Builder::new()
+        .name("swii-focus-history".to_string())
+        .spawn(move || {
+            while pending.recv().is_ok() {
+                std::thread::sleep(Duration::from_millis(constants::SAVE_DELAY_MS));
+                while pending.try_recv().is_ok() {}
+                write_history();
+            }
+        });
+    match spawned {
+        Ok(_) => {
+            let _ = SAVE_REQUESTS.set(requests);
+        }
+        Err(e) => warn!("Failed to start the focus history writer: {}", e),
+    }
+}
+
+/// Records that `window` was just focused
+pub fn record(window: &WindowInfo) {
+    let record = FocusRecord {
+        pid: window.pid,
+        window_number: window.window_number,
+        app_name: window.app_name.clone(),
+        window_name: window.window_name.clone(),
+        project: window.project.clone(),
+        project_path: window.project_path.clone(),
+        focused_at: now_ms(),
+        duration_ms: None,
+    };
+    PAUSED.store(false, Ordering::Relaxed);
+    if push_record(&mut lock_history(), record) {
+        save();
+    }
+}
+
+/// Ends the latest record when the user went idle, `idle_ms` ago
+pub fn pause(idle_ms: u64) {
+    if end_latest(&mut lock_history(), now_ms().saturating_sub(idle_ms)) {
+        PAUSED.store(true, Ordering::Relaxed);
+        save();
+    }
+}
+
+/// Picks the window in front before the user went idle up again
+pub fn resume() {
+    if !PAUSED.swap(false, Ordering::Relaxed) {
+        return;
+    }
+    reopen_latest(&mut lock_history(), now_ms());
+    save();
+}
+
+/// Ends the latest record when swii quits and writes the history file
+/// without waiting for the writer thread
+pub fn end() {
+    end_latest(&mut lock_history(), now_ms());
+    write_history();
+}
+
+/// Loads the history file from the app data dir, if there is one
+pub fn init(app: &tauri::AppHandle) {
+    let path = match app.path().app_data_dir() {
+        Ok(dir) => dir.join(constants::HISTORY_FILE),
+        Err(e) => {
+            warn!("Focus history won't be kept: {}", e);
+            return;
+        }
+    };
+    if path.exists() {
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                serde_json::from_str::<VecDeque<FocusRecord>>(&contents).map_err(|e| e.to_string())
+            }) {
+            Ok(mut history) => {
+                info!(
+                    "Loaded {} focus history records from {}",
+                    history.len(),
+                    path.display()
+                );
+                // Left open when swii didn't quit cleanly; when it stopped is
+                // unknown, so it isn't counted
+                if let Some(latest) = history.front() {
+                    let focused_at = latest.focused_at;
+                    end_latest(&mut history, focused_at);
+                }
+                *lock_history() = history;
+            }
+            Err(e) => warn!("Ignoring {}: {}", path.display(), e),
+        }
+    }
+    let _ = HISTORY_PATH.set(path);
+    start_writer();
+}
+
+/// Projects of `history`, most recently focused first, one entry per project
+/// root (or name, for projects without one)
+fn collect_recent_projects(history: &VecDeque<FocusRecord>) -> Vec<RecentProject> {
+    let mut projects: Vec<RecentProject> = Vec::new();
+    for record in history.iter() {
+        let Some(project) = &record.project else {
+            continue;
+        };
+        if projects
+            .iter()
+            .any(|seen| seen.project == *project && seen.project_path == record.project_path)
+        {
+            continue;
+        }
+        projects.push(RecentProject {
+            project: project.clone(),
+            project_path: record.project_path.clone(),
+            app_name: record.app_name.clone(),
+            last_focused_at: record.focused_at,
+        });
+    }
+    projects
+}
+
+/// Projects focused before, most recently focused first
+pub fn recent_projects() -> Vec<RecentProject> {
+    collect_recent_projects(&lock_history())
+}
+
+/// Time spent in each project of `history` between `since_ms` and `now_ms`,
+/// longest first. The latest record runs until `now_ms`.
+fn collect_project_durations(
+    history: &VecDeque<FocusRecord>,
+    since_ms: u64,
+    now_ms: u64,
+) -> Vec<(String, u64)> {
+    let mut durations: Vec<(String, u64)> = Vec::new();
+    for record in history.iter() {
+        let Some(project) = &record.project else {
+            continue;
+        };
+        let end = match record.duration_ms {
+            Some(duration_ms) => record.focused_at.saturating_add(duration_ms),
+            None => now_ms,
+        };
+        let spent = end
+            .min(now_ms)
+            .saturating_sub(record.focused_at.max(since_ms));
+        if spent == 0 {
+            continue;
+        }
+        match durations.iter_mut().find(|(name, _)| name == project) {
+            Some((_, total)) => *total += spent,
+            None => durations.push((project.clone(), spent)),
+        }
+    }
+    durations.sort_by_key(|(_, total)| std::cmp::Reverse(*total));
+    durations
+}
+
+/// Milliseconds spent in each project since `since_ms`, longest first
+pub fn project_durations(since_ms: u64) -> Vec<(String, u64)> {
+    collect_project_durations(&lock_history(), since_ms, now_ms())
+}
+
+fn page(history: &VecDeque<FocusRecord>, offset: usize, limit: usize) -> FocusHistoryPage {
+    let records: Vec<FocusRecord> = history.iter().skip(offset).take(limit).cloned().collect();
+    let end = offset + records.len();
+    FocusHistoryPage {
+        next_offset: (end < history.len()).then_some(end),
+        total: history.len(),
+        records,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_focus_history(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<FocusHistoryPage> {
+    crate::command_wrapper::create_typed_command("get_focus_history", args, |ctx| {
+        let offset = match ctx.parameters.get("offset") {
+            None | Some(serde_json::Value::Null) => 0,
+            Some(value) => value
+                .as_u64()
+                .ok_or(SwiiError::InvalidParameter("offset"))? as usize,
+        };
+        let limit = match ctx.parameters.get("limit") {
+            None | Some(serde_json::Value::Null) => constants::DEFAULT_PAGE_SIZE,
+            Some(value) => value
+                .as_u64()
+                .filter(|limit| *limit > 0)
+                .ok_or(SwiiError::InvalidParameter("limit"))? as usize,
+        };
+
+        let page = page(&lock_history(), offset, limit);
+        ctx.logger.info(&format!(
+            "Returning {} of {} focus history records from offset {}",
+            page.records.len(),
+            page.total,
+            offset
+        ));
+        Ok(page)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn focus(window_number: u32, focused_at: u64) -> FocusRecord {
+        FocusRecord {
+            pid: 1,
+            window_number,
+            app_name: "Zed".to_string(),
+            window_name: None,
+            project: Some(format!("project-{}", window_number)),
+            project_path: None,
+            focused_at,
+            duration_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_focus_history_paging() {
+        let mut history = VecDeque::new();
+        push_record(&mut history, focus(1, 1_000));
+        push_record(&mut history, focus(2, 4_000));
+        push_record(&mut history, focus(3, 4_500));
+
+        let first = page(&history, 0, 2);
+        assert_eq!(first.total, 3);
+        assert_eq!(first.next_offset, Some(2));
+        assert_eq!(first.records[0].window_number, 3);
+        assert_eq!(first.records[0].duration_ms, None);
+        assert_eq!(first.records[1].duration_ms, Some(500));
+
+        let last = page(&history, 2, 2);
+        assert_eq!(last.next_offset, None);
+        assert_eq!(last.records.len(), 1);
+        assert_eq!(last.records[0].duration_ms, Some(3_000));
+
+        assert!(page(&history, 5, 2).records.is_empty());
+    }
+
+    #[test]
+    fn test_focus_history_is_bounded() {
+        let mut history = VecDeque::new();
+        for number in 0..(constants::MAX_FOCUS_HISTORY as u32 + 10) {
+            push_record(&mut history, focus(number, number as u64));
+        }
+        assert_eq!(history.len(), constants::MAX_FOCUS_HISTORY);
+        assert_eq!(
+            history.front().map(|record| record.window_number),
+            Some(constants::MAX_FOCUS_HISTORY as u32 + 9)
+        );
+    }
+
+    #[test]
+    fn test_repeated_focus_is_recorded_once() {
+        let mut history = VecDeque::new();
+        assert!(push_record(&mut history, focus(1, 1_000)));
+        assert!(!push_record(&mut history, focus(1, 1_200)));
+        assert!(push_record(&mut history, focus(2, 2_000)));
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].duration_ms, Some(1_000));
+    }
+
+    #[test]
+    fn test_idle_ends_the_latest_record() {
+        let mut history = VecDeque::new();
+        push_record(&mut history, focus(1, 1_000));
+        assert!(end_latest(&mut history, 3_000));
+        assert!(!end_latest(&mut history, 9_000));
+        assert_eq!(history[0].duration_ms, Some(2_000));
+
+        // Back at 8_000, still in the same window
+        reopen_latest(&mut history, 8_000);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].focused_at, 8_000);
+        assert_eq!(history[0].duration_ms, None);
+        assert_eq!(history[1].duration_ms, Some(2_000));
+
+        // project-1 from 1_000 to 3_000 and from 8_000 to 9_000
+        assert_eq!(
+            collect_project_durations(&history, 0, 9_000),
+            vec![("project-1".to_string(), 3_000)]
+        );
+    }
+
+    #[test]
+    fn test_recent_projects_are_distinct() {
+        let mut history = VecDeque::new();
+        push_record(&mut history, focus(1, 1_000));
+        push_record(&mut history, focus(2, 2_000));
+        push_record(&mut history, focus(1, 3_000));
+        push_record(
+            &mut history,
+            FocusRecord {
+                project: None,
+                ..focus(3, 4_000)
+            },
+        );
+
+        let projects = collect_recent_projects(&history);
+        let names: Vec<(&str, u64)> = projects
+            .iter()
+            .map(|project| (project.project.as_str(), project.last_focused_at))
+            .collect();
+        assert_eq!(names, vec![("project-1", 3_000), ("project-2", 2_000)]);
+    }
+
+    #[test]
+    fn test_project_durations() {
+        let mut history = VecDeque::new();
+        push_record(&mut history, focus(1, 1_000));
+        push_record(&mut history, focus(2, 4_000));
+        push_record(&mut history, focus(1, 5_000));
+
+        // project-1 from 2_000 to 4_000 and from 5_000 to 9_000
+        assert_eq!(
+            collect_project_durations(&history, 2_000, 9_000),
+            vec![
+                ("project-1".to_string(), 6_000),
+                ("project-2".to_string(), 1_000)
+            ]
+        );
+        assert!(collect_project_durations(&history, 9_000, 9_000).is_empty());
+    }
+
+    #[test]
+    fn test_write_atomically() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("data").join(constants::HISTORY_FILE);
+
+        write_atomically(&path, "[1]").unwrap();
+        write_atomically(&path, "[2]").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[2]");
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+}
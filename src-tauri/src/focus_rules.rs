@@ -0,0 +1,345 @@
+//! Focusing projects automatically when something happens.
+//!
+//! Rules are configured as `focus_rules` in the settings, e.g. "when the
+//! display `DELL U2720Q` connects, focus project `api`" or "every day at 09:30,
+//! focus project `standup`". A monitor thread samples the connected displays
+//! and the local time, focuses the first window of each triggered rule's
+//! project and emits a `focus-rule-fired` event with the outcome.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{Local, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+use tracing::{debug, info};
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// How often displays and the clock are checked against the rules
+    pub const RULE_POLL_INTERVAL_SECS: u64 = 15;
+
+    /// Event emitted to the frontend whenever a rule fires
+    pub const FOCUS_RULE_FIRED_EVENT: &str = "focus-rule-fired";
+
+    /// Format of the time of a `daily_at` rule
+    pub const RULE_TIME_FORMAT: &str = "%H:%M";
+}
+
+/// A focus action and what triggers it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct FocusRule {
+    /// Name shown in logs and rule events
+    pub name: String,
+    pub when: RuleTrigger,
+    /// Project to focus, matched against window projects and labels
+    pub focus_project: String,
+}
+
+/// Event that fires a rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RuleTrigger {
+    /// A display whose name contains `name` connects
+    DisplayConnected { name: String },
+    /// Every day at the local time `at`, e.g. `"09:30"`
+    DailyAt { at: String },
+}
+
+/// Outcome of a fired rule, sent with `focus-rule-fired`
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct FocusRuleFired {
+    pub rule: String,
+    pub project: String,
+    /// Window that was focused, `None` when no window shows the project
+    pub pid: Option<i32>,
+    pub window_number: Option<u32>,
+    /// Why focusing failed
+    pub error: Option<String>,
+}
+
+static RULES: Mutex<Vec<FocusRule>> = Mutex::new(Vec::new());
+static MONITOR_STARTED: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn parse_time(at: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(at, constants::RULE_TIME_FORMAT)
+        .map_err(|_| format!("Invalid time \"{}\", expected HH:MM", at))
+}
+
+/// Replaces the rules, skipping invalid ones. Returns a message per skipped
+/// rule.
+pub fn set_rules(rules: &[FocusRule]) -> Vec<String> {
+    let mut errors = Vec::new();
+    let valid = rules
+        .iter()
+        .filter(|rule| match &rule.when {
+            RuleTrigger::DailyAt { at } => match parse_time(at) {
+                Ok(_) => true,
+                Err(error) => {
+                    errors.push(format!("Focus rule \"{}\": {}", rule.name, error));
+                    false
+                }
+            },
+            RuleTrigger::DisplayConnected { .. } => true,
+        })
+        .cloned()
+        .collect();
+    *RULES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = valid;
+    errors
+}
+
+/// What changed between two samples
+struct Sample<'a> {
+    previous_time: NaiveDateTime,
+    time: NaiveDateTime,
+    connected: &'a [String],
+}
+
+/// Whether the local time passed `at` between two samples
+pub(crate) fn passed(at: NaiveTime, previous: NaiveDateTime, now: NaiveDateTime) -> bool {
+    [previous.date(), now.date()].iter().any(|date| {
+        let moment = date.and_time(at);
+        previous < moment && moment <= now
+    })
+}
+
+fn is_triggered(rule: &FocusRule, sample: &Sample) -> bool {
+    match &rule.when {
+        RuleTrigger::DisplayConnected { name } => sample
+            .connected
+            .iter()
+            .any(|display| display.to_lowercase().contains(&name.to_lowercase())),
+        RuleTrigger::DailyAt { at } => parse_time(at)
+            .map(|at| passed(at, sample.previous_time, sample.time))
+            .unwrap_or(false),
+    }
+}
+
+/// Displays in `current` that weren't in `previous`
+fn newly_connected(previous: &[String], current: &[String]) -> Vec<String> {
+    current
+        .iter()
+        .filter(|display| !previous.contains(display))
+        .cloned()
+        .collect()
+}
+
+/// The window to focus for `project`, preferring exact project names over
+/// labels
+fn project_window<'a>(windows: &'a [WindowInfo], project: &str) -> Option<&'a WindowInfo> {
+    let open = || {
+        windows
+            .iter()
+            .filter(|window| window.has_windows && !window.auxiliary)
+    };
+    open()
+        .find(|window| {
+            window
+                .project
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(project))
+        })
+        .or_else(|| {
+            open().find(|window| {
+                window
+                    .project_label
+                    .as_deref()
+                    .is_some_and(|label| label.eq_ignore_ascii_case(project))
+            })
+        })
+}
+
+/// Focuses the project of a triggered rule
+fn fire(rule: &FocusRule) -> FocusRuleFired {
+    let mut fired = FocusRuleFired {
+        rule: rule.name.clone(),
+        project: rule.focus_project.clone(),
+        pid: None,
+        window_number: None,
+        error: None,
+    };
+
+    if let Err(e) = crate::window_list::relist_windows() {
+        fired.error = Some(e.to_string());
+        return fired;
+    }
+    let windows = crate::window_list::listed_windows();
+    let Some(window) = project_window(&windows, &rule.focus_project) else {
+        fired.error = Some("No window shows the project".to_string());
+        return fired;
+    };
+
+    fired.pid = Some(window.pid);
+    fired.window_number = Some(window.window_number);
+    match crate::window_provider::provider().focus_window(window.pid, window.window_number) {
+        Ok(()) => crate::focus_history::record(window),
+        Err(e) => fired.error = Some(e.to_string()),
+    }
+    fired
+}
+
+fn display_names(app: &tauri::AppHandle) -> Vec<String> {
+    match app.available_monitors() {
+        Ok(monitors) => monitors
+            .iter()
+            .filter_map(|monitor| monitor.name().cloned())
+            .collect(),
+        Err(e) => {
+            debug!("Failed to list displays: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Starts the rule monitor. Calling this more than once is a no-op.
+pub fn start(app: tauri::AppHandle) {
+    if MONITOR_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    std::thread::Builder::new()
+        .name("swii-focus-rules".to_string())
+        .spawn(move || {
+            let mut displays = display_names(&app);
+            let mut previous_time = Local::now().naive_local();
+            loop {
+                std::thread::sleep(Duration::from_secs(constants::RULE_POLL_INTERVAL_SECS));
+
+                let current_displays = display_names(&app);
+                let connected = newly_connected(&displays, &current_displays);
+                let sample = Sample {
+                    previous_time,
+                    time: Local::now().naive_local(),
+                    connected: &connected,
+                };
+                displays = current_displays;
+                previous_time = sample.time;
+
+                // Nobody is around to use the focused window, or the user
+                // asked not to be disturbed
+                if crate::idle::is_idle() || crate::dnd::is_active() {
+                    continue;
+                }
+
+                let rules = RULES
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clone();
+                for rule in rules.iter().filter(|rule| is_triggered(rule, &sample)) {
+                    let fired = fire(rule);
+                    match &fired.error {
+                        Some(error) => info!("Focus rule \"{}\" failed: {}", rule.name, error),
+                        None => info!("Focus rule \"{}\" focused {}", rule.name, fired.project),
+                    }
+                    if let Err(e) = app.emit(constants::FOCUS_RULE_FIRED_EVENT, fired) {
+                        debug!("Failed to emit focus rule event: {}", e);
+                    }
+                }
+            }
+        })
+        .expect("failed to spawn focus rule monitor thread");
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 2)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn rule(when: RuleTrigger) -> FocusRule {
+        FocusRule {
+            name: "morning".to_string(),
+            when,
+            focus_project: "standup".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_daily_rule_fires_once() {
+        let daily = rule(RuleTrigger::DailyAt {
+            at: "09:30".to_string(),
+        });
+        let sample = |previous_time, time| Sample {
+            previous_time,
+            time,
+            connected: &[],
+        };
+
+        assert!(is_triggered(&daily, &sample(at(9, 29), at(9, 30))));
+        assert!(!is_triggered(&daily, &sample(at(9, 30), at(9, 31))));
+        assert!(!is_triggered(&daily, &sample(at(9, 0), at(9, 15))));
+
+        let midnight = rule(RuleTrigger::DailyAt {
+            at: "00:00".to_string(),
+        });
+        let next_day = at(0, 1) + chrono::Duration::days(1);
+        assert!(is_triggered(&midnight, &sample(at(23, 59), next_day)));
+    }
+
+    #[test]
+    fn test_display_rule() {
+        let display = rule(RuleTrigger::DisplayConnected {
+            name: "dell".to_string(),
+        });
+        let connected = newly_connected(
+            &["Built-in Retina Display".to_string()],
+            &[
+                "Built-in Retina Display".to_string(),
+                "DELL U2720Q".to_string(),
+            ],
+        );
+        assert_eq!(connected, vec!["DELL U2720Q".to_string()]);
+
+        let sample = Sample {
+            previous_time: at(9, 0),
+            time: at(9, 0),
+            connected: &connected,
+        };
+        assert!(is_triggered(&display, &sample));
+    }
+
+    #[test]
+    fn test_set_rules_skips_invalid_times() {
+        let errors = set_rules(&[
+            rule(RuleTrigger::DailyAt {
+                at: "9:30am".to_string(),
+            }),
+            rule(RuleTrigger::DailyAt {
+                at: "18:00".to_string(),
+            }),
+        ]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(RULES.lock().unwrap().len(), 1);
+        set_rules(&[]);
+    }
+
+    #[test]
+    fn test_project_window_prefers_project_name() {
+        let window = |window_number: u32, project: &str, label: &str| {
+            WindowInfo::builder("Zed", 1, window_number)
+                .project(Some(project))
+                .project_label(Some(label))
+                .build()
+        };
+        let windows = vec![
+            window(1, "api", "standup (work)"),
+            window(2, "standup", "standup (home)"),
+        ];
+
+        let found = |project| project_window(&windows, project).map(|w| w.window_number);
+        assert_eq!(found("Standup"), Some(2));
+        assert_eq!(found("standup (work)"), Some(1));
+        assert_eq!(found("web"), None);
+    }
+}
@@ -0,0 +1,291 @@
+//! A focus timer for one project.
+//!
+//! `start_focus_timer` gives a project a time budget, pomodoro style. When
+//! the time is up, or when an editor window of another project comes to the
+//! front before then, swii posts a notification; every change of the timer
+//! is emitted as a `focus-timer` event so the panel can show it. Only one
+//! timer runs at a time, and starting another replaces it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use swii_core::types::WindowInfo;
+use tauri::Emitter;
+use tracing::{debug, info};
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Event emitted to the frontend whenever the timer changes
+    pub const FOCUS_TIMER_EVENT: &str = "focus-timer";
+
+    /// How often a running timer is checked for the end of its budget
+    pub const TIMER_POLL_INTERVAL_SECS: u64 = 5;
+
+    /// Longest accepted budget
+    pub const MAX_TIMER_MINUTES: u64 = 24 * 60;
+}
+
+/// What happened to the timer
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusTimerPhase {
+    /// `start_focus_timer` gave the project a budget
+    Started,
+    /// The budget is used up; the timer is gone
+    Finished,
+    /// A window of another project came to the front early
+    LeftProject,
+    /// A window of the project is in front again
+    Returned,
+    /// `stop_focus_timer` ended the timer early
+    Stopped,
+}
+
+/// Payload of `focus-timer`, also returned by the timer commands
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct FocusTimerStatus {
+    pub project: String,
+    pub phase: FocusTimerPhase,
+    /// Milliseconds since the Unix epoch
+    pub started_at: u64,
+    /// Milliseconds since the Unix epoch
+    pub ends_at: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Timer {
+    project: String,
+    started_at: u64,
+    ends_at: u64,
+    /// Another project is in front
+    away: bool,
+}
+
+impl Timer {
+    fn status(&self, phase: FocusTimerPhase) -> FocusTimerStatus {
+        FocusTimerStatus {
+            project: self.project.clone(),
+            phase,
+            started_at: self.started_at,
+            ends_at: self.ends_at,
+        }
+    }
+}
+
+static TIMER: Mutex<Option<Timer>> = Mutex::new(None);
+static MONITOR_STARTED: AtomicBool = AtomicBool::new(false);
+
+fn lock_timer() -> std::sync::MutexGuard<'static, Option<Timer>> {
+    TIMER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn shows_project(window: &WindowInfo, project: &str) -> bool {
+    [window.project.as_deref(), window.project_label.as_deref()]
+        .into_iter()
+        .flatten()
+        .any(|name| name.eq_ignore_ascii_case(project))
+}
+
+/// How the timer changes when `window` comes to the front
+fn focus_change(timer: &Timer, window: &WindowInfo) -> Option<FocusTimerPhase> {
+    if !window.has_windows || window.auxiliary {
+        return None;
+    }
+    match (timer.away, shows_project(window, &timer.project)) {
+        (false, false) => Some(FocusTimerPhase::LeftProject),
+        (true, true) => Some(FocusTimerPhase::Returned),
+        _ => None,
+    }
+}
+
+fn minutes_left(timer: &Timer, now_ms: u64) -> u64 {
+    timer.ends_at.saturating_sub(now_ms).div_ceil(60_000)
+}
+
+fn emit(app: &tauri::AppHandle, status: FocusTimerStatus) {
+    if let Err(e) = app.emit(constants::FOCUS_TIMER_EVENT, status) {
+        debug!("Failed to emit focus timer change: {}", e);
+    }
+}
+
+/// Notifies when a window of another project comes to the front early
+pub fn on_focus(app: &tauri::AppHandle, window: Option<&WindowInfo>) {
+    let Some(window) = window else {
+        return;
+    };
+    let (status, minutes_left) = {
+        let mut timer = lock_timer();
+        let Some(timer) = timer.as_mut() else {
+            return;
+        };
+        let Some(phase) = focus_change(timer, window) else {
+            return;
+        };
+        timer.away = phase == FocusTimerPhase::LeftProject;
+        (timer.status(phase), minutes_left(timer, now_ms()))
+    };
+
+    if status.phase == FocusTimerPhase::LeftProject && !crate::dnd::is_active() {
+        crate::notifications::post(&format!(
+            "Left {} with {} minutes to go",
+            status.project, minutes_left
+        ));
+    }
+    emit(app, status);
+}
+
+/// Ends the timer once its budget is used up
+fn finish_due(app: &tauri::AppHandle) {
+    let status = {
+        let mut timer = lock_timer();
+        match timer.as_ref() {
+            Some(running) if running.ends_at <= now_ms() => {
+                let status = running.status(FocusTimerPhase::Finished);
+                *timer = None;
+                status
+            }
+            _ => return,
+        }
+    };
+
+    info!("Focus timer for {} finished", status.project);
+    if !crate::dnd::is_active() {
+        crate::notifications::post(&format!("Time is up for {}", status.project));
+    }
+    emit(app, status);
+}
+
+/// Starts the thread that ends timers. Calling this more than once is a
+/// no-op.
+fn start_monitor(app: tauri::AppHandle) {
+    if MONITOR_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    std::thread::Builder::new()
+        .name("swii-focus-timer".to_string())
+        .spawn(move || loop {
+            std::thread::sleep(Duration::from_secs(constants::TIMER_POLL_INTERVAL_SECS));
+            finish_due(&app);
+        })
+        .expect("failed to spawn focus timer thread");
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_focus_timer(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<FocusTimerStatus> {
+    crate::command_wrapper::create_typed_command("start_focus_timer", args, |ctx| {
+        let project = ctx
+            .parameters
+            .get("project")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|project| !project.is_empty())
+            .ok_or(SwiiError::InvalidParameter("project"))?;
+        let minutes = ctx
+            .parameters
+            .get("minutes")
+            .and_then(|v| v.as_u64())
+            .filter(|minutes| (1..=constants::MAX_TIMER_MINUTES).contains(minutes))
+            .ok_or(SwiiError::InvalidParameter("minutes"))?;
+
+        let started_at = now_ms();
+        let timer = Timer {
+            project: project.to_string(),
+            started_at,
+            ends_at: started_at + minutes * 60_000,
+            away: false,
+        };
+        let status = timer.status(FocusTimerPhase::Started);
+        *lock_timer() = Some(timer);
+        start_monitor(app.clone());
+
+        ctx.logger.info(&format!(
+            "Started a {} minute focus timer for {}",
+            minutes, project
+        ));
+        emit(&app, status.clone());
+        Ok(status)
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_focus_timer(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Option<FocusTimerStatus>> {
+    crate::command_wrapper::create_typed_command("stop_focus_timer", args, |ctx| {
+        let Some(timer) = lock_timer().take() else {
+            ctx.logger.info("No focus timer to stop");
+            return Ok(None);
+        };
+
+        let status = timer.status(FocusTimerPhase::Stopped);
+        ctx.logger
+            .info(&format!("Stopped the focus timer for {}", timer.project));
+        emit(&app, status.clone());
+        Ok(Some(status))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    fn timer(away: bool) -> Timer {
+        Timer {
+            project: "api".to_string(),
+            started_at: 0,
+            ends_at: 25 * 60_000,
+            away,
+        }
+    }
+
+    #[test]
+    fn test_focus_change() {
+        let mut window = mock_editor_windows().remove(0);
+        window.has_windows = true;
+        window.auxiliary = false;
+        window.project = Some("API".to_string());
+        assert_eq!(focus_change(&timer(false), &window), None);
+        assert_eq!(
+            focus_change(&timer(true), &window),
+            Some(FocusTimerPhase::Returned)
+        );
+
+        window.project = Some("web".to_string());
+        window.project_label = None;
+        assert_eq!(
+            focus_change(&timer(false), &window),
+            Some(FocusTimerPhase::LeftProject)
+        );
+        assert_eq!(focus_change(&timer(true), &window), None);
+    }
+
+    #[test]
+    fn test_minutes_left_rounds_up() {
+        assert_eq!(minutes_left(&timer(false), 0), 25);
+        assert_eq!(minutes_left(&timer(false), 24 * 60_000 + 1), 1);
+        assert_eq!(minutes_left(&timer(false), 30 * 60_000), 0);
+    }
+}
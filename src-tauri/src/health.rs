@@ -0,0 +1,318 @@
+//! Health check for onboarding and troubleshooting.
+//!
+//! `health_check` runs every check swii depends on and reports each with a
+//! status and, when something is off, a hint on how to fix it: the
+//! Accessibility and Screen Recording permissions, which of the known editors
+//! are installed, and a dry run of the window enumeration. Nothing is focused
+//! or changed.
+
+use std::time::Instant;
+
+use serde::Serialize;
+use swii_core::editor_config::EDITOR_PATHS;
+use swii_core::provider::WindowError;
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+use crate::window_provider::provider;
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn AXIsProcessTrusted() -> bool;
+    fn CGPreflightScreenCaptureAccess() -> bool;
+}
+
+pub mod constants {
+    /// System Settings pane listing apps with Accessibility access
+    pub const ACCESSIBILITY_SETTINGS_URL: &str =
+        "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility";
+
+    /// System Settings pane listing apps with Screen Recording access
+    pub const SCREEN_RECORDING_SETTINGS_URL: &str =
+        "x-apple.systempreferences:com.apple.preference.security?Privacy_ScreenCapture";
+}
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    /// swii works, but with reduced detail
+    Warn,
+    Fail,
+    /// The check doesn't apply on this platform
+    Skipped,
+}
+
+/// A single check of the health report
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct HealthCheck {
+    /// Stable identifier, e.g. "accessibility"
+    pub id: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// What the user can do about a warning or failure
+    pub remediation: Option<String>,
+}
+
+/// Whether a known editor is installed at its usual location
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct EditorInstall {
+    pub name: String,
+    pub path: String,
+    pub installed: bool,
+}
+
+/// Result of `health_check`
+#[derive(Debug, Serialize, specta::Type)]
+pub struct HealthReport {
+    /// False when any check failed
+    pub healthy: bool,
+    /// Window provider the checks ran against
+    pub provider: &'static str,
+    pub checks: Vec<HealthCheck>,
+    pub editors: Vec<EditorInstall>,
+}
+
+fn check(id: &'static str, status: CheckStatus, detail: impl Into<String>) -> HealthCheck {
+    HealthCheck {
+        id,
+        status,
+        detail: detail.into(),
+        remediation: None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn permission_checks() -> Vec<HealthCheck> {
+    // SAFETY: both functions only query the TCC database for this process
+    let (accessibility, screen_recording) =
+        unsafe { (AXIsProcessTrusted(), CGPreflightScreenCaptureAccess()) };
+
+    let accessibility = if accessibility {
+        check(
+            "accessibility",
+            CheckStatus::Pass,
+            "Accessibility access granted",
+        )
+    } else {
+        HealthCheck {
+            remediation: Some(format!(
+                "Allow swii in System Settings > Privacy & Security > Accessibility ({})",
+                constants::ACCESSIBILITY_SETTINGS_URL
+            )),
+            ..check(
+                "accessibility",
+                CheckStatus::Fail,
+                "Accessibility access is required to read and focus editor windows",
+            )
+        }
+    };
+    let screen_recording = if screen_recording {
+        check(
+            "screen_recording",
+            CheckStatus::Pass,
+            "Screen Recording access granted",
+        )
+    } else {
+        HealthCheck {
+            remediation: Some(format!(
+                "Allow swii in System Settings > Privacy & Security > Screen Recording ({})",
+                constants::SCREEN_RECORDING_SETTINGS_URL
+            )),
+            ..check(
+                "screen_recording",
+                CheckStatus::Warn,
+                "Without Screen Recording access some window titles can't be read",
+            )
+        }
+    };
+    vec![accessibility, screen_recording]
+}
+
+#[cfg(not(target_os = "macos"))]
+fn permission_checks() -> Vec<HealthCheck> {
+    vec![
+        check(
+            "accessibility",
+            CheckStatus::Skipped,
+            "Accessibility permission is macOS only",
+        ),
+        check(
+            "screen_recording",
+            CheckStatus::Skipped,
+            "Screen Recording permission is macOS only",
+        ),
+    ]
+}
+
+/// Known editors and whether their bundle exists, one entry per bundle
+fn editor_installs(exists: impl Fn(&str) -> bool) -> Vec<EditorInstall> {
+    let mut installs: Vec<EditorInstall> = Vec::new();
+    for (name, path) in EDITOR_PATHS {
+        if installs.iter().any(|install| install.path == *path) {
+            continue;
+        }
+        installs.push(EditorInstall {
+            name: name.to_string(),
+            path: path.to_string(),
+            installed: exists(path),
+        });
+    }
+    installs
+}
+
+fn editors_check(installs: &[EditorInstall]) -> HealthCheck {
+    if !cfg!(target_os = "macos") {
+        return check(
+            "editors",
+            CheckStatus::Skipped,
+            "Editor bundles are only looked up on macOS",
+        );
+    }
+    let installed: Vec<&str> = installs
+        .iter()
+        .filter(|install| install.installed)
+        .map(|install| install.name.as_str())
+        .collect();
+    if installed.is_empty() {
+        HealthCheck {
+            remediation: Some(
+                "Editors installed outside /Applications are still listed while running"
+                    .to_string(),
+            ),
+            ..check(
+                "editors",
+                CheckStatus::Warn,
+                "No known editor found in /Applications",
+            )
+        }
+    } else {
+        check(
+            "editors",
+            CheckStatus::Pass,
+            format!("Installed: {}", installed.join(", ")),
+        )
+    }
+}
+
+/// Judges a dry-run enumeration
+fn enumeration_check(
+    result: &Result<Vec<WindowInfo>, WindowError>,
+    elapsed_ms: f64,
+) -> HealthCheck {
+    match result {
+        Err(e) => HealthCheck {
+            remediation: Some(
+                "Check the permissions above, then run diagnostics for details".to_string(),
+            ),
+            ..check(
+                "enumeration",
+                CheckStatus::Fail,
+                format!("Listing windows failed: {}", e),
+            )
+        },
+        Ok(windows) if !windows.is_empty() && windows.iter().all(|w| w.window_name.is_none()) => {
+            HealthCheck {
+                remediation: Some(
+                    "Grant Screen Recording access to read window titles".to_string(),
+                ),
+                ..check(
+                    "enumeration",
+                    CheckStatus::Warn,
+                    format!(
+                        "Listed {} windows in {:.0}ms, none with a title",
+                        windows.len(),
+                        elapsed_ms
+                    ),
+                )
+            }
+        }
+        Ok(windows) => check(
+            "enumeration",
+            CheckStatus::Pass,
+            format!("Listed {} windows in {:.0}ms", windows.len(), elapsed_ms),
+        ),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn health_check(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<HealthReport> {
+    crate::command_wrapper::create_typed_command("health_check", args, |ctx| {
+        let provider = provider();
+        let mut checks = permission_checks();
+
+        let editors = editor_installs(|path| std::path::Path::new(path).exists());
+        checks.push(editors_check(&editors));
+
+        let start = Instant::now();
+        let windows = provider.list_windows();
+        checks.push(enumeration_check(
+            &windows,
+            start.elapsed().as_secs_f64() * 1000.0,
+        ));
+
+        let failed = checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Fail)
+            .count();
+        ctx.logger.info(&format!(
+            "Health check finished with {} of {} checks failing",
+            failed,
+            checks.len()
+        ));
+
+        Ok(HealthReport {
+            healthy: failed == 0,
+            provider: provider.name(),
+            checks,
+            editors,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    #[test]
+    fn test_editor_installs_dedupes_bundles() {
+        let installs = editor_installs(|path| path == "/Applications/Zed.app");
+        let code = installs
+            .iter()
+            .filter(|install| install.path == "/Applications/Visual Studio Code.app")
+            .count();
+        assert_eq!(code, 1);
+        assert!(installs
+            .iter()
+            .any(|install| install.name == "Zed" && install.installed));
+    }
+
+    #[test]
+    fn test_enumeration_check() {
+        let listed = enumeration_check(&Ok(mock_editor_windows()), 12.0);
+        assert_eq!(listed.status, CheckStatus::Pass);
+
+        let mut untitled = mock_editor_windows();
+        untitled
+            .iter_mut()
+            .for_each(|window| window.window_name = None);
+        let untitled = enumeration_check(&Ok(untitled), 12.0);
+        assert_eq!(untitled.status, CheckStatus::Warn);
+        assert!(untitled.remediation.is_some());
+
+        let failed = enumeration_check(
+            &Err(WindowError::WindowListAccess {
+                message: "denied".to_string(),
+            }),
+            1.0,
+        );
+        assert_eq!(failed.status, CheckStatus::Fail);
+    }
+}
@@ -0,0 +1,216 @@
+//! User idle detection.
+//!
+//! Polls the HID idle time reported by `CGEventSource` and flips a global
+//! idle flag once the user has been inactive for longer than the configured
+//! threshold (`idle_threshold_secs` in the settings file, 300 by default).
+//! Watchers and pollers consult [`is_idle`] before doing work; the window
+//! observer detaches from editors entirely until the user is back. Polling
+//! stops in do-not-disturb mode, which already keeps everything quiet. The
+//! frontend receives an `idle-state-changed` event so it can suspend its own
+//! timers too.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::Emitter;
+use tracing::{debug, info};
+
+use crate::error::SwiiError;
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn CGEventSourceSecondsSinceLastEventType(state_id: i32, event_type: u32) -> f64;
+}
+
+pub mod constants {
+    /// Default inactivity period after which swii considers the user idle
+    pub const DEFAULT_IDLE_THRESHOLD_SECS: u64 = 300;
+
+    /// Minimum accepted threshold, to avoid flapping between states
+    pub const MIN_IDLE_THRESHOLD_SECS: u64 = 10;
+
+    /// How often the idle monitor samples the system idle time
+    pub const IDLE_POLL_INTERVAL_SECS: u64 = 5;
+
+    /// Settings key the threshold is stored under
+    pub const IDLE_THRESHOLD_KEY: &str = "idle_threshold_secs";
+
+    /// Event emitted to the frontend whenever the idle state flips
+    pub const IDLE_STATE_CHANGED_EVENT: &str = "idle-state-changed";
+
+    /// kCGEventSourceStateCombinedSessionState
+    pub const CG_EVENT_SOURCE_STATE_COMBINED_SESSION: i32 = 0;
+
+    /// kCGAnyInputEventType
+    pub const CG_ANY_INPUT_EVENT_TYPE: u32 = !0;
+}
+
+static IDLE: AtomicBool = AtomicBool::new(false);
+static MONITOR_STARTED: AtomicBool = AtomicBool::new(false);
+static IDLE_THRESHOLD_SECS: AtomicU64 = AtomicU64::new(constants::DEFAULT_IDLE_THRESHOLD_SECS);
+
+/// Idle state snapshot sent to the frontend
+#[derive(Debug, Clone, Serialize, specta::Type)]
+pub struct IdleStatus {
+    pub idle: bool,
+    pub idle_seconds: Option<f64>,
+    pub threshold_secs: u64,
+}
+
+/// Returns true while the user is considered idle
+pub fn is_idle() -> bool {
+    IDLE.load(Ordering::Relaxed)
+}
+
+/// Returns the currently configured idle threshold in seconds
+pub fn idle_threshold_secs() -> u64 {
+    IDLE_THRESHOLD_SECS.load(Ordering::Relaxed)
+}
+
+/// Updates the idle threshold, clamping it to the supported minimum
+pub fn set_idle_threshold_secs(seconds: u64) -> u64 {
+    let seconds = seconds.max(constants::MIN_IDLE_THRESHOLD_SECS);
+    IDLE_THRESHOLD_SECS.store(seconds, Ordering::Relaxed);
+    seconds
+}
+
+/// Seconds since the last keyboard/mouse event in the current session
+#[cfg(target_os = "macos")]
+pub fn seconds_since_last_input() -> Option<f64> {
+    // SAFETY: CGEventSourceSecondsSinceLastEventType only reads session state
+    // and accepts any state/event type combination.
+    let seconds = unsafe {
+        CGEventSourceSecondsSinceLastEventType(
+            constants::CG_EVENT_SOURCE_STATE_COMBINED_SESSION,
+            constants::CG_ANY_INPUT_EVENT_TYPE,
+        )
+    };
+
+    if seconds.is_finite() && seconds >= 0.0 {
+        Some(seconds)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn seconds_since_last_input() -> Option<f64> {
+    None
+}
+
+/// Decides whether the given idle time crosses the threshold
+fn exceeds_threshold(idle_seconds: f64, threshold_secs: u64) -> bool {
+    idle_seconds >= threshold_secs as f64
+}
+
+/// Builds the current idle status without waiting for the next poll
+pub fn current_status() -> IdleStatus {
+    IdleStatus {
+        idle: is_idle(),
+        idle_seconds: seconds_since_last_input(),
+        threshold_secs: idle_threshold_secs(),
+    }
+}
+
+/// Starts the background idle monitor. Calling this more than once is a no-op.
+pub fn start_idle_monitor(app: tauri::AppHandle) {
+    if MONITOR_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    std::thread::Builder::new()
+        .name("swii-idle-monitor".to_string())
+        .spawn(move || loop {
+            std::thread::sleep(Duration::from_secs(constants::IDLE_POLL_INTERVAL_SECS));
+            if crate::dnd::is_active() {
+                continue;
+            }
+
+            let Some(idle_seconds) = seconds_since_last_input() else {
+                continue;
+            };
+
+            let now_idle = exceeds_threshold(idle_seconds, idle_threshold_secs());
+            if IDLE.swap(now_idle, Ordering::Relaxed) == now_idle {
+                continue;
+            }
+
+            if now_idle {
+                info!("User idle for {:.0}s, pausing watchers", idle_seconds);
+                crate::focus_history::pause((idle_seconds * 1000.0) as u64);
+            } else {
+                info!("User activity detected, resuming watchers");
+                crate::focus_history::resume();
+            }
+            crate::window_events::sync_observation(&app);
+
+            if let Err(e) = app.emit(constants::IDLE_STATE_CHANGED_EVENT, current_status()) {
+                debug!("Failed to emit idle state change: {}", e);
+            }
+        })
+        .expect("failed to spawn idle monitor thread");
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_idle_status(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("get_idle_status", args, |_ctx| Ok(current_status()))
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_idle_threshold(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("set_idle_threshold", args, |ctx| {
+        let seconds = ctx
+            .parameters
+            .get("seconds")
+            .and_then(|v| v.as_u64())
+            .ok_or(SwiiError::InvalidParameter("seconds"))?;
+
+        let applied = set_idle_threshold_secs(seconds);
+        crate::settings::store_value(
+            &app,
+            constants::IDLE_THRESHOLD_KEY,
+            serde_json::json!(applied),
+        )?;
+        ctx.logger
+            .info(&format!("Idle threshold set to {} seconds", applied));
+        Ok(current_status())
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_threshold() {
+        assert!(!exceeds_threshold(0.0, 300));
+        assert!(!exceeds_threshold(299.9, 300));
+        assert!(exceeds_threshold(300.0, 300));
+        assert!(exceeds_threshold(3600.0, 300));
+    }
+
+    #[test]
+    fn test_set_idle_threshold_clamps_to_minimum() {
+        assert_eq!(
+            set_idle_threshold_secs(1),
+            constants::MIN_IDLE_THRESHOLD_SECS
+        );
+        assert_eq!(idle_threshold_secs(), constants::MIN_IDLE_THRESHOLD_SECS);
+
+        assert_eq!(set_idle_threshold_secs(600), 600);
+        assert_eq!(idle_threshold_secs(), 600);
+
+        set_idle_threshold_secs(constants::DEFAULT_IDLE_THRESHOLD_SECS);
+    }
+}
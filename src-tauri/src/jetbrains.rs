@@ -0,0 +1,319 @@
+//! Open project paths from running JetBrains IDEs.
+//!
+//! IntelliJ-family titles show the project's display name, which can be
+//! renamed freely and then no longer matches any directory. Each running IDE
+//! answers `GET /api/about` on its built-in web server (ports 63342-63361)
+//! with its config directory, whose `options/recentProjects.xml` lists the
+//! open projects with their real paths, display names and frame titles.
+//!
+//! Disabled unless `jetbrains_integration` is set in the settings.
+
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use serde::Deserialize;
+use swii_title_parser::{EditorKind, ProjectSource};
+use tracing::debug;
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// First port of the built-in web server; further instances count up
+    pub const BUILTIN_SERVER_FIRST_PORT: u16 = 63342;
+
+    /// Number of ports the built-in web server may use
+    pub const BUILTIN_SERVER_PORT_COUNT: u16 = 20;
+
+    /// Connect and read timeout for each built-in server request
+    pub const REQUEST_TIMEOUT_MS: u64 = 150;
+
+    /// How long discovered projects are reused before asking the IDEs again
+    pub const PROJECTS_CACHE_TTL_SECS: u64 = 10;
+
+    /// Recent projects file, relative to the IDE config directory
+    pub const RECENT_PROJECTS_FILE: &str = "options/recentProjects.xml";
+
+    /// Placeholder for the home directory in IDE config files
+    pub const USER_HOME_MACRO: &str = "$USER_HOME$";
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables querying running JetBrains IDEs
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Subset of the built-in server's `/api/about` response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AboutInfo {
+    config_path: String,
+}
+
+/// A project currently open in a JetBrains IDE
+#[derive(Debug, Clone, PartialEq)]
+struct OpenProject {
+    path: String,
+    display_name: Option<String>,
+    frame_title: Option<String>,
+}
+
+impl OpenProject {
+    /// Name shown in the IDE: the display name, else the directory name
+    fn name(&self) -> Option<String> {
+        self.display_name.clone().or_else(|| {
+            Path::new(&self.path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+    }
+}
+
+/// Body of a successful HTTP/1.x response
+fn response_body(response: &str) -> Option<&str> {
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    let status = head.lines().next()?;
+    status
+        .split_whitespace()
+        .nth(1)
+        .filter(|code| *code == "200")?;
+    Some(body)
+}
+
+/// Asks the built-in server on `port` for the IDE's config directory
+fn fetch_config_path(port: u16) -> Option<PathBuf> {
+    let timeout = Duration::from_millis(constants::REQUEST_TIMEOUT_MS);
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let mut stream = TcpStream::connect_timeout(&address, timeout).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+
+    write!(
+        stream,
+        "GET /api/about HTTP/1.1\r\nHost: localhost:{}\r\nConnection: close\r\n\r\n",
+        port
+    )
+    .ok()?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+    let about: AboutInfo = serde_json::from_str(response_body(&response)?).ok()?;
+    Some(PathBuf::from(about.config_path))
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Open projects listed in a `recentProjects.xml` file
+fn parse_open_projects(xml: &str, home: Option<&str>) -> Vec<OpenProject> {
+    static ENTRY: OnceLock<Regex> = OnceLock::new();
+    static ATTRIBUTE: OnceLock<Regex> = OnceLock::new();
+    let entry = ENTRY.get_or_init(|| {
+        Regex::new(r#"<entry key="([^"]*)">\s*<value>\s*<RecentProjectMetaInfo\b([^>]*)>"#)
+            .expect("valid recent project regex")
+    });
+    let attribute =
+        ATTRIBUTE.get_or_init(|| Regex::new(r#"(\w+)="([^"]*)""#).expect("valid attribute regex"));
+
+    entry
+        .captures_iter(xml)
+        .filter_map(|captures| {
+            let mut opened = false;
+            let mut display_name = None;
+            let mut frame_title = None;
+            for attr in attribute.captures_iter(&captures[2]) {
+                let value = unescape_xml(&attr[2]);
+                match &attr[1] {
+                    "opened" => opened = value == "true",
+                    "displayName" => display_name = Some(value),
+                    "frameTitle" => frame_title = Some(value),
+                    _ => {}
+                }
+            }
+            if !opened {
+                return None;
+            }
+
+            let key = unescape_xml(&captures[1]);
+            let path = match home {
+                Some(home) => key.replace(constants::USER_HOME_MACRO, home),
+                None => key,
+            };
+            Some(OpenProject {
+                path,
+                display_name,
+                frame_title,
+            })
+        })
+        .collect()
+}
+
+/// Open projects of every running IDE, cached for
+/// [`constants::PROJECTS_CACHE_TTL_SECS`]
+fn open_projects() -> Vec<OpenProject> {
+    static CACHE: Mutex<Option<(Instant, Vec<OpenProject>)>> = Mutex::new(None);
+
+    let mut cache = CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let ttl = Duration::from_secs(constants::PROJECTS_CACHE_TTL_SECS);
+    if let Some((fetched, projects)) = cache.as_ref() {
+        if fetched.elapsed() < ttl {
+            return projects.clone();
+        }
+    }
+
+    let home = std::env::var("HOME").ok();
+    let first = constants::BUILTIN_SERVER_FIRST_PORT;
+    let projects: Vec<OpenProject> = (first..first + constants::BUILTIN_SERVER_PORT_COUNT)
+        .filter_map(fetch_config_path)
+        .filter_map(|config| {
+            std::fs::read_to_string(config.join(constants::RECENT_PROJECTS_FILE)).ok()
+        })
+        .flat_map(|xml| parse_open_projects(&xml, home.as_deref()))
+        .collect();
+    debug!("Found {} open JetBrains projects", projects.len());
+
+    *cache = Some((Instant::now(), projects.clone()));
+    projects
+}
+
+/// Fills in project paths of JetBrains windows from the running IDEs
+pub fn apply_open_projects(windows: &mut [WindowInfo]) {
+    if !ENABLED.load(Ordering::Relaxed)
+        || !windows
+            .iter()
+            .any(|window| window.editor_kind == EditorKind::JetBrains)
+    {
+        return;
+    }
+    merge_open_projects(windows, &open_projects());
+}
+
+/// Matches windows to projects by frame title first, then by name
+fn merge_open_projects(windows: &mut [WindowInfo], projects: &[OpenProject]) {
+    for window in windows.iter_mut() {
+        if window.editor_kind != EditorKind::JetBrains || window.auxiliary {
+            continue;
+        }
+
+        let by_title = projects.iter().find(|project| {
+            project.frame_title.is_some() && project.frame_title == window.window_name
+        });
+        let by_name = || {
+            projects
+                .iter()
+                .find(|project| window.project.is_some() && project.name() == window.project)
+        };
+
+        if let Some(project) = by_title.or_else(by_name) {
+            window.project = project.name();
+            window.project_path = Some(project.path.clone());
+            window.project_source = ProjectSource::Ide;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECENT_PROJECTS: &str = r#"<application>
+  <component name="RecentProjectsManager">
+    <option name="additionalInfo">
+      <map>
+        <entry key="$USER_HOME$/code/billing-service">
+          <value>
+            <RecentProjectMetaInfo displayName="Billing" frameTitle="Billing – Invoice.kt" opened="true" projectWorkspaceId="2a">
+              <option name="activationTimestamp" value="1700000000000" />
+            </RecentProjectMetaInfo>
+          </value>
+        </entry>
+        <entry key="$USER_HOME$/code/tools &amp; scripts">
+          <value>
+            <RecentProjectMetaInfo opened="true" projectWorkspaceId="2b" />
+          </value>
+        </entry>
+        <entry key="$USER_HOME$/code/old">
+          <value>
+            <RecentProjectMetaInfo frameTitle="old" projectWorkspaceId="2c" />
+          </value>
+        </entry>
+      </map>
+    </option>
+  </component>
+</application>"#;
+
+    fn window(window_name: &str, project: Option<&str>) -> WindowInfo {
+        WindowInfo::builder("IntelliJ IDEA", 1, 1)
+            .window_name(Some(window_name))
+            .project(project)
+            .build()
+    }
+
+    #[test]
+    fn test_parse_open_projects() {
+        let projects = parse_open_projects(RECENT_PROJECTS, Some("/Users/dev"));
+        assert_eq!(
+            projects,
+            vec![
+                OpenProject {
+                    path: "/Users/dev/code/billing-service".to_string(),
+                    display_name: Some("Billing".to_string()),
+                    frame_title: Some("Billing – Invoice.kt".to_string()),
+                },
+                OpenProject {
+                    path: "/Users/dev/code/tools & scripts".to_string(),
+                    display_name: None,
+                    frame_title: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_response_body() {
+        assert_eq!(
+            response_body("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{}"),
+            Some("{}")
+        );
+        assert_eq!(response_body("HTTP/1.1 404 Not Found\r\n\r\n"), None);
+        assert_eq!(response_body("garbage"), None);
+    }
+
+    #[test]
+    fn test_merge_open_projects() {
+        let projects = parse_open_projects(RECENT_PROJECTS, Some("/Users/dev"));
+        let mut windows = vec![
+            window("Billing – Invoice.kt", None),
+            window("tools & scripts – run.sh", Some("tools & scripts")),
+            window("scratch – notes.md", Some("scratch")),
+        ];
+        merge_open_projects(&mut windows, &projects);
+
+        assert_eq!(windows[0].project.as_deref(), Some("Billing"));
+        assert_eq!(
+            windows[0].project_path.as_deref(),
+            Some("/Users/dev/code/billing-service")
+        );
+        assert_eq!(windows[0].project_source, ProjectSource::Ide);
+        assert_eq!(
+            windows[1].project_path.as_deref(),
+            Some("/Users/dev/code/tools & scripts")
+        );
+        assert_eq!(windows[2].project_path, None);
+        assert_eq!(windows[2].project_source, ProjectSource::Title);
+    }
+}
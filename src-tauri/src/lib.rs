@@ -1,24 +1,75 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 
+mod activation_policy;
+mod api_schema;
+mod bindings;
+mod browser_tabs;
 mod command_wrapper;
+mod companion;
+mod console_capture;
+mod containers;
+mod copy_path;
+mod daily_note;
 mod devtools;
-mod editor_config;
+mod diagnostics;
+mod dnd;
+mod error;
+mod focus_history;
+mod focus_rules;
+mod focus_timer;
+mod health;
+mod idle;
+mod jetbrains;
 mod logger;
-mod macos_accessibility;
-mod macos_window;
-mod title_parser;
-mod types;
+mod notifications;
+mod open_file;
+mod open_files;
+mod project_summary;
+mod running_editors;
+mod search;
+mod search_history;
+mod self_test;
+mod session_export;
+mod session_log;
+mod settings;
+mod shortcut;
+mod snapshots;
+mod sublime;
+mod tmux;
+mod tray_title;
+mod watchlist;
+mod window_events;
 mod window_focus;
+mod window_labels;
 mod window_list;
+mod window_provider;
+mod zed;
+
+use swii_core::editor_config;
+use tauri::Manager;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let bindings = bindings::builder();
+
+    #[cfg(debug_assertions)]
+    if let Err(e) = bindings::export(&bindings) {
+        println!("[RUST] {}", e);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_positioner::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(
+            tauri_plugin_swii::Builder::new()
+                .provider(window_provider::provider())
+                .build(),
+        )
+        .on_page_load(console_capture::on_page_load)
         .setup(|app| {
+            session_log::init(app.handle());
             println!("[RUST] Starting Tauri application setup");
-            
+
             #[cfg(target_os = "macos")]
             {
                 println!("[RUST] Setting macOS activation policy to Accessory");
@@ -36,7 +87,7 @@ pub fn run() {
             #[cfg(desktop)]
             {
                 println!("[RUST] Building tray icon");
-                tauri::tray::TrayIconBuilder::new()
+                tauri::tray::TrayIconBuilder::with_id(tray_title::constants::TRAY_ID)
                     .on_tray_icon_event(|tray_handle, event| {
                         tauri_plugin_positioner::on_tray_event(tray_handle.app_handle(), &event);
                     })
@@ -44,16 +95,26 @@ pub fn run() {
                 println!("[RUST] Tray icon built successfully");
             }
 
+            editor_config::set_own_window_labels(app.webview_windows().into_keys().collect());
+            // Also registers the panel shortcut from the stored setting
+            settings::init(app.handle());
+            search_history::init(app.handle());
+            focus_history::init(app.handle());
+            idle::start_idle_monitor(app.handle().clone());
+            window_events::start(app.handle().clone());
+            focus_rules::start(app.handle().clone());
+            daily_note::start();
+            companion::start_server(app.handle());
+
             println!("[RUST] Setup completed successfully");
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
-            window_list::list_editor_windows,
-            window_focus::bring_window_to_front,
-            logger::log_from_frontend,
-            logger::log_from_frontend_with_data,
-            devtools::open_devtools
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .invoke_handler(bindings.invoke_handler())
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                focus_history::end();
+            }
+        });
 }
@@ -1,6 +1,18 @@
+//! Frontend log messages in the session log.
+//!
+//! Messages pass a filter first, configured with `frontend_log` in the
+//! settings: a minimum level, which defaults to `info` in release builds,
+//! and allow and deny lists of tags. A tag ending in `*` matches every tag
+//! starting with the rest, e.g. `"console:*"` for all captured console
+//! output. Denied tags are always dropped; when the allow list isn't empty,
+//! only its tags get through.
+
 use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, specta::Type,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum LogLevel {
     Debug,
@@ -9,27 +21,164 @@ pub enum LogLevel {
     Error,
 }
 
+/// Which frontend messages are written, from the `frontend_log` setting
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(default)]
+pub struct FrontendLogFilter {
+    /// Least severe level written; `debug` in debug builds and `info` in
+    /// release builds by default
+    pub min_level: Option<LogLevel>,
+    /// Tags to write, all of them when empty
+    pub allow_tags: Vec<String>,
+    /// Tags never written
+    pub deny_tags: Vec<String>,
+}
+
+impl FrontendLogFilter {
+    fn min_level(&self) -> LogLevel {
+        self.min_level.unwrap_or(if cfg!(debug_assertions) {
+            LogLevel::Debug
+        } else {
+            LogLevel::Info
+        })
+    }
+
+    fn allows(&self, level: LogLevel, tag: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix('*') {
+            Some(prefix) => tag.starts_with(prefix),
+            None => tag == pattern,
+        };
+        level >= self.min_level()
+            && !self.deny_tags.iter().any(matches)
+            && (self.allow_tags.is_empty() || self.allow_tags.iter().any(matches))
+    }
+}
+
+static FILTER: Mutex<Option<FrontendLogFilter>> = Mutex::new(None);
+
+/// Replaces the filter applied to frontend messages
+pub fn set_filter(filter: &FrontendLogFilter) {
+    *FILTER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(filter.clone());
+}
+
+fn is_allowed(level: LogLevel, tag: &str) -> bool {
+    FILTER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get_or_insert_with(FrontendLogFilter::default)
+        .allows(level, tag)
+}
+
+/// One message of a `log_batch_from_frontend` batch
+#[derive(Debug, Clone, Deserialize, specta::Type)]
+pub struct LogEntry {
+    pub level: LogLevel,
+    pub tag: String,
+    pub message: String,
+    /// Structured data attached to the message
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+/// The message with its data, if any, kept on one line
+fn format_message(message: &str, data: Option<&serde_json::Value>) -> String {
+    match data {
+        Some(data) => format!(
+            "{} | Data: {}",
+            message,
+            serde_json::to_string(data).unwrap_or_else(|_| "{}".to_string())
+        ),
+        None => message.to_string(),
+    }
+}
+
+fn write(level: LogLevel, tag: &str, message: &str, data: Option<&serde_json::Value>) {
+    if is_allowed(level, tag) {
+        crate::session_log::record(
+            level,
+            &format!("frontend:{}", tag),
+            &format_message(message, data),
+        );
+    }
+}
+
 #[tauri::command]
+#[specta::specta]
 pub fn log_from_frontend(level: LogLevel, tag: String, message: String) {
-    let prefix = match level {
-        LogLevel::Debug => "🔍 [DEBUG]",
-        LogLevel::Info => "ℹ️  [INFO]",
-        LogLevel::Warn => "⚠️  [WARN]",
-        LogLevel::Error => "❌ [ERROR]",
-    };
-    
-    println!("{} [{}] {}", prefix, tag, message);
+    write(level, &tag, &message, None);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn log_from_frontend_with_data(
+    level: LogLevel,
+    tag: String,
+    message: String,
+    data: serde_json::Value,
+) {
+    write(level, &tag, &message, Some(&data));
 }
 
+/// Writes messages the frontend buffered, in order, so busy views don't
+/// make one IPC call per line
 #[tauri::command]
-pub fn log_from_frontend_with_data(level: LogLevel, tag: String, message: String, data: serde_json::Value) {
-    let prefix = match level {
-        LogLevel::Debug => "🔍 [DEBUG]",
-        LogLevel::Info => "ℹ️  [INFO]",
-        LogLevel::Warn => "⚠️  [WARN]",
-        LogLevel::Error => "❌ [ERROR]",
-    };
-    
-    println!("{} [{}] {} | Data: {}", prefix, tag, message, serde_json::to_string_pretty(&data).unwrap_or_else(|_| "{}".to_string()));
+#[specta::specta]
+pub fn log_batch_from_frontend(entries: Vec<LogEntry>) {
+    for entry in entries.iter() {
+        write(entry.level, &entry.tag, &entry.message, entry.data.as_ref());
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_min_level() {
+        let filter = FrontendLogFilter {
+            min_level: Some(LogLevel::Warn),
+            ..Default::default()
+        };
+        assert!(!filter.allows(LogLevel::Info, "search"));
+        assert!(filter.allows(LogLevel::Warn, "search"));
+        assert!(filter.allows(LogLevel::Error, "search"));
+    }
+
+    #[test]
+    fn test_filter_tags() {
+        let filter = FrontendLogFilter {
+            min_level: Some(LogLevel::Debug),
+            allow_tags: vec!["search".to_string(), "console:*".to_string()],
+            deny_tags: vec!["console:swii".to_string()],
+        };
+        assert!(filter.allows(LogLevel::Debug, "search"));
+        assert!(filter.allows(LogLevel::Debug, "console:main"));
+        assert!(!filter.allows(LogLevel::Error, "console:swii"));
+        assert!(!filter.allows(LogLevel::Error, "shortcut"));
+
+        let filter = FrontendLogFilter {
+            min_level: Some(LogLevel::Debug),
+            deny_tags: vec!["list*".to_string()],
+            ..Default::default()
+        };
+        assert!(filter.allows(LogLevel::Debug, "shortcut"));
+        assert!(!filter.allows(LogLevel::Error, "list-updates"));
+    }
+
+    #[test]
+    fn test_log_entry_data_is_optional() {
+        let entries: Vec<LogEntry> = serde_json::from_value(serde_json::json!([
+            { "level": "info", "tag": "search", "message": "query changed" },
+            { "level": "error", "tag": "list", "message": "refresh failed", "data": { "count": 2 } }
+        ]))
+        .unwrap();
+        assert_eq!(entries[0].data, None);
+        assert_eq!(
+            format_message(&entries[1].message, entries[1].data.as_ref()),
+            "refresh failed | Data: {\"count\":2}"
+        );
+        assert_eq!(format_message("query changed", None), "query changed");
+    }
+}
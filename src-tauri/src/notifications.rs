@@ -0,0 +1,44 @@
+//! System notifications.
+//!
+//! swii has no notification plugin; on macOS it asks `osascript` to display
+//! one. Elsewhere notifications are only logged.
+
+#[cfg(target_os = "macos")]
+use tracing::debug;
+
+pub mod constants {
+    /// Title of the posted notifications
+    pub const NOTIFICATION_TITLE: &str = "swii";
+
+    /// Shows its first argument as a notification titled with its second
+    pub const NOTIFICATION_SCRIPT: &[&str] = &[
+        "on run argv",
+        "display notification (item 1 of argv) with title (item 2 of argv)",
+        "end run",
+    ];
+}
+
+/// Posts a notification showing `message`
+#[cfg(target_os = "macos")]
+pub fn post(message: &str) {
+    let mut command = std::process::Command::new("osascript");
+    for line in constants::NOTIFICATION_SCRIPT {
+        command.args(["-e", line]);
+    }
+    match command
+        .args([message, constants::NOTIFICATION_TITLE])
+        .output()
+    {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => debug!(
+            "Failed to post notification: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => debug!("Failed to post notification: {}", e),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn post(message: &str) {
+    tracing::debug!("Notifications are macOS only, skipped: {}", message);
+}
@@ -0,0 +1,153 @@
+//! Sending a file to a specific editor window.
+//!
+//! `open_file` opens a file in the window the user picked rather than in
+//! whichever window of the editor happens to be in front. A window already
+//! showing the file (by its `AXDocument`) is just focused. Otherwise the
+//! window is focused first and the file handed to the editor's own command
+//! line tool, which reuses its last active window (`--reuse-window` for the
+//! VS Code family). Editors without a known tool get the file through the
+//! provider's generic open, which goes to their front window too.
+//!
+//! An optional `line` and `column` place the cursor, e.g. to jump to a
+//! failing test. Positions are passed to the editor CLI as `path:line:col`,
+//! so editors without a known tool open the file without moving the cursor.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+use swii_core::editor_cli::{editor_cli, FileTarget};
+use swii_core::focus::WindowFocusError;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::window_provider::provider;
+
+pub mod constants {
+    /// Time for an editor to notice its window was focused before the file
+    /// is handed over
+    pub const FOCUS_SETTLE_MS: u64 = 150;
+}
+
+/// Result of a successful `open_file`
+#[derive(Debug, PartialEq, Serialize, specta::Type)]
+pub struct OpenFileOutcome {
+    pub pid: i32,
+    pub window_number: u32,
+    pub path: String,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    /// The window already showed the file and was only focused
+    pub already_open: bool,
+}
+
+/// Reads an optional 1-based position parameter
+fn position_parameter(
+    parameters: &serde_json::Value,
+    name: &'static str,
+) -> Result<Option<u32>, SwiiError> {
+    match parameters.get(name) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .filter(|position| (1..=u32::MAX as u64).contains(position))
+            .map(|position| Some(position as u32))
+            .ok_or(SwiiError::InvalidParameter(name)),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn open_file(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<OpenFileOutcome> {
+    crate::command_wrapper::create_blocking_command("open_file", args, |ctx| {
+        let pid = ctx
+            .parameters
+            .get("pid")
+            .and_then(|v| v.as_i64())
+            .ok_or(SwiiError::InvalidParameter("pid"))? as i32;
+        let window_number =
+            ctx.parameters
+                .get("window_number")
+                .and_then(|v| v.as_u64())
+                .ok_or(SwiiError::InvalidParameter("window_number"))? as u32;
+        let path = ctx
+            .parameters
+            .get("path")
+            .and_then(|v| v.as_str())
+            .filter(|path| Path::new(path).is_absolute() && Path::new(path).exists())
+            .ok_or(SwiiError::InvalidParameter("path"))?
+            .to_string();
+        let line = position_parameter(&ctx.parameters, "line")?;
+        let column = position_parameter(&ctx.parameters, "column")?;
+        if column.is_some() && line.is_none() {
+            return Err(SwiiError::InvalidParameter("column"));
+        }
+        let target = FileTarget { path, line, column };
+
+        let window = crate::window_list::known_window(pid, window_number)?
+            .ok_or(WindowFocusError::WindowNotFound)?;
+        let shown = provider()
+            .window_document(pid, window_number)
+            .unwrap_or_else(|e| {
+                ctx.logger
+                    .error(&format!("Failed to read the window's document: {}", e));
+                None
+            })
+            .as_deref()
+            .and_then(crate::copy_path::document_file_path);
+        // A window showing the file only needs focusing unless the cursor
+        // has to move
+        let already_open = shown.as_deref() == Some(target.path.as_str()) && line.is_none();
+
+        provider().focus_window(pid, window_number)?;
+        crate::focus_history::record(&window);
+        if already_open {
+            ctx.logger.info(&format!(
+                "{} is already open, focused its window",
+                target.path
+            ));
+        } else {
+            std::thread::sleep(Duration::from_millis(constants::FOCUS_SETTLE_MS));
+            let cli = editor_cli(window.editor_kind);
+            provider().open_file(pid, &target, cli)?;
+            let via = if cli.is_some() {
+                "its CLI"
+            } else {
+                "the system"
+            };
+            ctx.logger.info(&format!(
+                "Opened {} in window {} of {} through {}",
+                target.with_position(),
+                window_number,
+                window.app_name,
+                via
+            ));
+        }
+
+        Ok(OpenFileOutcome {
+            pid,
+            window_number,
+            path: target.path,
+            line,
+            column,
+            already_open,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_parameter() {
+        let parameters = serde_json::json!({ "line": 3, "column": 0 });
+        assert_eq!(position_parameter(&parameters, "line").unwrap(), Some(3));
+        assert!(position_parameter(&parameters, "column").is_err());
+        assert_eq!(position_parameter(&parameters, "missing").unwrap(), None);
+    }
+}
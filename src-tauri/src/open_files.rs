@@ -0,0 +1,182 @@
+//! Open documents of a single editor window, for a second-level "jump to
+//! file" search in the switcher.
+//!
+//! Documents come from the editor's companion provider (the VS Code/Cursor
+//! extension or Sublime's session), which know full paths, and from the tabs
+//! the platform exposes through the window provider, which only know names.
+
+use std::path::Path;
+
+use serde::Serialize;
+use swii_core::provider::WindowTab;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+/// A document open in an editor window
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct OpenFile {
+    /// File name as shown on its tab
+    pub name: String,
+    /// Absolute path, when the source knows it
+    pub path: Option<String>,
+    /// Whether this is the document the window currently shows
+    pub is_active: bool,
+}
+
+/// Merges documents known by path with tabs known by title, in that order,
+/// and marks the active one
+fn merge_open_files(
+    paths: Vec<String>,
+    tabs: Vec<WindowTab>,
+    active_tab: Option<&str>,
+) -> Vec<OpenFile> {
+    let mut files: Vec<OpenFile> = Vec::new();
+    for path in paths {
+        if files.iter().any(|file| file.path.as_ref() == Some(&path)) {
+            continue;
+        }
+        let name = Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.clone());
+        files.push(OpenFile {
+            name,
+            path: Some(path),
+            is_active: false,
+        });
+    }
+
+    for tab in tabs {
+        match files.iter_mut().find(|file| file.name == tab.title) {
+            Some(file) => file.is_active |= tab.selected,
+            None => files.push(OpenFile {
+                name: tab.title,
+                path: None,
+                is_active: tab.selected,
+            }),
+        }
+    }
+
+    if !files.iter().any(|file| file.is_active) {
+        if let Some(file) = files
+            .iter_mut()
+            .find(|file| Some(file.name.as_str()) == active_tab)
+        {
+            file.is_active = true;
+        }
+    }
+    files
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_open_files(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Vec<OpenFile>> {
+    crate::command_wrapper::create_typed_command("list_open_files", args, |ctx| {
+        let pid = ctx
+            .parameters
+            .get("pid")
+            .and_then(|v| v.as_i64())
+            .ok_or(SwiiError::InvalidParameter("pid"))? as i32;
+
+        let window_number =
+            ctx.parameters
+                .get("window_number")
+                .and_then(|v| v.as_u64())
+                .ok_or(SwiiError::InvalidParameter("window_number"))? as u32;
+
+        // Companion providers match windows by project, so they need the
+        // window as it was last listed
+        let window = crate::window_list::listed_window(pid, window_number);
+        let paths = window
+            .as_ref()
+            .map(|window| {
+                let mut paths = crate::companion::open_files(window);
+                paths.extend(crate::sublime::open_files(window));
+                paths
+            })
+            .unwrap_or_default();
+
+        let tabs = crate::window_provider::provider()
+            .window_tabs(pid, window_number)
+            .unwrap_or_else(|e| {
+                ctx.logger
+                    .error(&format!("Failed to read window tabs: {}", e));
+                Vec::new()
+            });
+
+        let active_tab = window
+            .as_ref()
+            .and_then(|window| window.active_editor_tab.as_deref());
+        let files = merge_open_files(paths, tabs, active_tab);
+        ctx.logger.info(&format!(
+            "Found {} open files in window {} of PID {}",
+            files.len(),
+            window_number,
+            pid
+        ));
+        Ok(files)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tab(title: &str, selected: bool) -> WindowTab {
+        WindowTab {
+            title: title.to_string(),
+            selected,
+        }
+    }
+
+    #[test]
+    fn test_merge_open_files() {
+        let files = merge_open_files(
+            vec![
+                "/Users/dev/swii/src/main.rs".to_string(),
+                "/Users/dev/swii/Cargo.toml".to_string(),
+                "/Users/dev/swii/src/main.rs".to_string(),
+            ],
+            vec![tab("Cargo.toml", true), tab("README.md", false)],
+            Some("main.rs"),
+        );
+
+        assert_eq!(
+            files,
+            vec![
+                OpenFile {
+                    name: "main.rs".to_string(),
+                    path: Some("/Users/dev/swii/src/main.rs".to_string()),
+                    is_active: false,
+                },
+                OpenFile {
+                    name: "Cargo.toml".to_string(),
+                    path: Some("/Users/dev/swii/Cargo.toml".to_string()),
+                    is_active: true,
+                },
+                OpenFile {
+                    name: "README.md".to_string(),
+                    path: None,
+                    is_active: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_open_files_falls_back_to_active_tab() {
+        let files = merge_open_files(
+            vec!["/Users/dev/swii/src/lib.rs".to_string()],
+            Vec::new(),
+            Some("lib.rs"),
+        );
+        assert!(files[0].is_active);
+
+        assert!(merge_open_files(Vec::new(), Vec::new(), Some("lib.rs")).is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+//! Everything swii knows about one project, in one call.
+//!
+//! `get_project_summary` gathers the project's open windows, their project
+//! roots with the git branch checked out in each, the time its windows spent
+//! in front over the last day and whether it's watched, so a detail pane
+//! doesn't have to ask for each separately. Projects are matched by name or
+//! label, ignoring case, like watched projects.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::focus_history::RecentProject;
+
+pub mod constants {
+    use std::time::Duration;
+
+    /// Oldest listing used instead of listing again
+    pub const LISTING_MAX_AGE: Duration = Duration::from_secs(2);
+
+    /// Period the focus time is counted over
+    pub const FOCUS_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Prefix of a `HEAD` that points at a branch
+    pub const BRANCH_REF_PREFIX: &str = "ref: refs/heads/";
+
+    /// Prefix of a `.git` file pointing at the worktree's git dir
+    pub const GITDIR_PREFIX: &str = "gitdir:";
+
+    /// Characters of the commit shown for a detached `HEAD`
+    pub const SHORT_COMMIT_LEN: usize = 7;
+}
+
+/// A project root and the branch checked out there
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct ProjectPath {
+    pub path: String,
+    /// Branch name, or the short commit for a detached `HEAD`; `None`
+    /// outside a git repository
+    pub git_branch: Option<String>,
+}
+
+/// Result of `get_project_summary`
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct ProjectSummary {
+    pub project: String,
+    /// Open windows of the project, as last listed
+    pub windows: Vec<WindowInfo>,
+    /// Roots of the open windows, then roots it was focused in before
+    pub paths: Vec<ProjectPath>,
+    /// Milliseconds the project's windows spent in front over the last day
+    pub focus_time_ms: u64,
+    /// Milliseconds since the Unix epoch, `None` if never focused
+    pub last_focused_at: Option<u64>,
+    /// Whether the project is in `watched_projects`
+    pub watched: bool,
+}
+
+/// Branch or short commit named by the contents of a `HEAD` file
+fn parse_head(head: &str) -> Option<String> {
+    let head = head.trim();
+    if let Some(branch) = head.strip_prefix(constants::BRANCH_REF_PREFIX) {
+        return (!branch.is_empty()).then(|| branch.to_string());
+    }
+    (head.len() >= constants::SHORT_COMMIT_LEN && head.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| head[..constants::SHORT_COMMIT_LEN].to_string())
+}
+
+/// Git dir of the repository `dir` is the top of, following the `.git` file
+/// of linked worktrees and submodules
+fn git_dir(dir: &Path) -> Option<PathBuf> {
+    let dot_git = dir.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    let contents = std::fs::read_to_string(&dot_git).ok()?;
+    let target = contents
+        .trim()
+        .strip_prefix(constants::GITDIR_PREFIX)?
+        .trim();
+    Some(dir.join(target))
+}
+
+/// Branch checked out in the repository containing `path`
+fn git_branch(path: &Path) -> Option<String> {
+    let git_dir = path.ancestors().find_map(git_dir)?;
+    parse_head(&std::fs::read_to_string(git_dir.join("HEAD")).ok()?)
+}
+
+/// Whether `window` is open on `project`
+fn is_project_window(window: &WindowInfo, project: &str) -> bool {
+    window.has_windows
+        && [window.project.as_deref(), window.project_label.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(|name| name.eq_ignore_ascii_case(project))
+}
+
+/// Puts together what's known about `project`; `branch` looks up the git
+/// branch of a project root
+fn summarize(
+    project: &str,
+    windows: &[WindowInfo],
+    recent: &[RecentProject],
+    durations: &[(String, u64)],
+    watched: bool,
+    branch: impl Fn(&str) -> Option<String>,
+) -> ProjectSummary {
+    let windows: Vec<WindowInfo> = windows
+        .iter()
+        .filter(|window| is_project_window(window, project))
+        .cloned()
+        .collect();
+    let recent: Vec<&RecentProject> = recent
+        .iter()
+        .filter(|entry| entry.project.eq_ignore_ascii_case(project))
+        .collect();
+
+    let mut paths: Vec<ProjectPath> = Vec::new();
+    let roots = windows
+        .iter()
+        .map(|window| window.project_path.as_deref())
+        .chain(recent.iter().map(|entry| entry.project_path.as_deref()))
+        .flatten();
+    for root in roots {
+        if paths.iter().all(|seen| seen.path != root) {
+            paths.push(ProjectPath {
+                path: root.to_string(),
+                git_branch: branch(root),
+            });
+        }
+    }
+
+    ProjectSummary {
+        project: project.to_string(),
+        focus_time_ms: durations
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(project))
+            .map(|(_, spent)| spent)
+            .sum(),
+        last_focused_at: recent.first().map(|entry| entry.last_focused_at),
+        windows,
+        paths,
+        watched,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_project_summary(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<ProjectSummary> {
+    crate::command_wrapper::create_blocking_command("get_project_summary", args, |ctx| {
+        let project = ctx
+            .parameters
+            .get("project")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|project| !project.is_empty())
+            .ok_or(SwiiError::InvalidParameter("project"))?
+            .to_string();
+
+        let windows = crate::window_list::recent_windows(constants::LISTING_MAX_AGE)?;
+        let since_ms = SystemTime::now()
+            .checked_sub(constants::FOCUS_PERIOD)
+            .and_then(|since| since.duration_since(UNIX_EPOCH).ok())
+            .unwrap_or(Duration::ZERO)
+            .as_millis() as u64;
+        let summary = summarize(
+            &project,
+            &windows,
+            &crate::focus_history::recent_projects(),
+            &crate::focus_history::project_durations(since_ms),
+            crate::watchlist::is_watched(&project),
+            |root| git_branch(Path::new(root)),
+        );
+        ctx.logger.info(&format!(
+            "Summarized {} with {} windows and {} paths",
+            project,
+            summary.windows.len(),
+            summary.paths.len()
+        ));
+        Ok(summary)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(number: u32, project: &str, project_path: &str) -> WindowInfo {
+        WindowInfo::builder("Zed", 1, number)
+            .project(Some(project))
+            .project_path(Some(project_path))
+            .build()
+    }
+
+    #[test]
+    fn test_parse_head() {
+        assert_eq!(
+            parse_head("ref: refs/heads/feature/login\n").as_deref(),
+            Some("feature/login")
+        );
+        assert_eq!(
+            parse_head("3f2a9c1e0b7d4a5f6e8c9b0a1d2e3f4a5b6c7d8e").as_deref(),
+            Some("3f2a9c1")
+        );
+        assert_eq!(parse_head("ref: refs/remotes/origin/main"), None);
+        assert_eq!(parse_head(""), None);
+    }
+
+    #[test]
+    fn test_git_branch() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let worktree = dir.join("worktree");
+        std::fs::create_dir_all(dir.join("repo/.git")).unwrap();
+        std::fs::create_dir_all(dir.join("repo/crates/api")).unwrap();
+        std::fs::create_dir_all(dir.join("linked")).unwrap();
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::write(dir.join("repo/.git/HEAD"), "ref: refs/heads/main\n").unwrap();
+        std::fs::write(worktree.join(".git"), "gitdir: ../linked\n").unwrap();
+        std::fs::write(dir.join("linked/HEAD"), "ref: refs/heads/fix\n").unwrap();
+
+        assert_eq!(
+            git_branch(&dir.join("repo/crates/api")).as_deref(),
+            Some("main")
+        );
+        assert_eq!(git_branch(&worktree).as_deref(), Some("fix"));
+    }
+
+    #[test]
+    fn test_summarize() {
+        let windows = vec![
+            window(1, "api", "/src/api"),
+            window(2, "API", "/src/api"),
+            window(3, "web", "/src/web"),
+        ];
+        let recent = vec![
+            RecentProject {
+                project: "api".to_string(),
+                project_path: Some("/src/api".to_string()),
+                app_name: "Zed".to_string(),
+                last_focused_at: 9_000,
+            },
+            RecentProject {
+                project: "api".to_string(),
+                project_path: Some("/old/api".to_string()),
+                app_name: "Code".to_string(),
+                last_focused_at: 4_000,
+            },
+        ];
+        let durations = vec![("web".to_string(), 500), ("api".to_string(), 2_000)];
+
+        let summary = summarize("Api", &windows, &recent, &durations, true, |root| {
+            (root == "/src/api").then(|| "main".to_string())
+        });
+        let numbers: Vec<u32> = summary.windows.iter().map(|w| w.window_number).collect();
+        assert_eq!(numbers, vec![1, 2]);
+        assert_eq!(
+            summary.paths,
+            vec![
+                ProjectPath {
+                    path: "/src/api".to_string(),
+                    git_branch: Some("main".to_string()),
+                },
+                ProjectPath {
+                    path: "/old/api".to_string(),
+                    git_branch: None,
+                },
+            ]
+        );
+        assert_eq!(summary.focus_time_ms, 2_000);
+        assert_eq!(summary.last_focused_at, Some(9_000));
+        assert!(summary.watched);
+
+        let unknown = summarize("docs", &windows, &recent, &durations, false, |_| None);
+        assert!(unknown.windows.is_empty() && unknown.paths.is_empty());
+        assert_eq!(unknown.last_focused_at, None);
+    }
+}
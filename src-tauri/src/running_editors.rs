@@ -0,0 +1,147 @@
+//! Editor applications that are running, with or without windows.
+//!
+//! The window listing only knows applications through their windows, so an
+//! editor running without any is invisible there. Listing running editors
+//! lets the UI offer opening a new window in them.
+
+use serde::Serialize;
+use swii_core::provider::RunningApp;
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::window_provider::provider;
+
+/// A running editor application
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct RunningEditor {
+    pub pid: i32,
+    pub name: String,
+    /// Platform identifier of the application, e.g. "dev.zed.Zed"
+    pub bundle_id: Option<String>,
+    /// Base64-encoded application icon
+    pub icon: Option<String>,
+    /// Number of listed editor windows, zero for an editor without windows
+    pub window_count: u32,
+}
+
+/// Pairs running applications with the number of windows each one owns
+fn count_windows(apps: Vec<RunningApp>, windows: &[WindowInfo]) -> Vec<RunningEditor> {
+    apps.into_iter()
+        .map(|app| RunningEditor {
+            window_count: windows
+                .iter()
+                .filter(|window| window.pid == app.pid)
+                .count() as u32,
+            pid: app.pid,
+            name: app.name,
+            bundle_id: app.bundle_id,
+            icon: app.icon,
+        })
+        .collect()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_running_editors(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Vec<RunningEditor>> {
+    crate::command_wrapper::create_typed_command("get_running_editors", args, |ctx| {
+        let provider = provider();
+        let apps = provider.running_editors().map_err(|e| {
+            ctx.logger
+                .error(&format!("Failed to list running editors: {}", e));
+            e
+        })?;
+        let windows = provider.list_windows().unwrap_or_else(|e| {
+            ctx.logger
+                .error(&format!("Failed to count editor windows: {}", e));
+            Vec::new()
+        });
+
+        let editors = count_windows(apps, &windows);
+        ctx.logger.info(&format!(
+            "Found {} running editors, {} without windows",
+            editors.len(),
+            editors
+                .iter()
+                .filter(|editor| editor.window_count == 0)
+                .count()
+        ));
+        Ok(editors)
+    })
+    .await
+}
+
+/// Result of a successful `open_editor_window`
+#[derive(Debug, PartialEq, Serialize, specta::Type)]
+pub struct OpenWindowOutcome {
+    pub pid: i32,
+    /// Project that was opened, or `None` for a new empty window
+    pub path: Option<String>,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn open_editor_window(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<OpenWindowOutcome> {
+    crate::command_wrapper::create_typed_command("open_editor_window", args, |ctx| {
+        let pid = ctx
+            .parameters
+            .get("pid")
+            .and_then(|v| v.as_i64())
+            .ok_or(SwiiError::InvalidParameter("pid"))? as i32;
+
+        let path = match ctx.parameters.get("path") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => Some(
+                value
+                    .as_str()
+                    .filter(|path| !path.is_empty())
+                    .ok_or(SwiiError::InvalidParameter("path"))?
+                    .to_string(),
+            ),
+        };
+
+        ctx.logger.info(&format!(
+            "Opening {} in PID {}",
+            path.as_deref().unwrap_or("a new window"),
+            pid
+        ));
+        match provider().open_window(pid, path.as_deref()) {
+            Ok(()) => Ok(OpenWindowOutcome { pid, path }),
+            Err(e) => {
+                ctx.logger
+                    .error(&format!("Failed to open editor window: {}", e));
+                Err(e.into())
+            }
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::{mock_editor_windows, MockProvider};
+    use swii_core::provider::WindowProvider;
+
+    #[test]
+    fn test_count_windows() {
+        let apps = MockProvider.running_editors().unwrap();
+        let editors = count_windows(apps, &mock_editor_windows());
+
+        let count = |name: &str| {
+            editors
+                .iter()
+                .find(|editor| editor.name == name)
+                .map(|editor| editor.window_count)
+        };
+        assert_eq!(count("Cursor"), Some(2));
+        assert_eq!(count("Zed"), Some(1));
+        assert_eq!(count("Sublime Text"), Some(0));
+    }
+}
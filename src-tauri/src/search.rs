@@ -0,0 +1,517 @@
+//! Fuzzy search over the listed windows and recent projects.
+//!
+//! `search_windows` ranks the windows of the latest listing, together with
+//! the recently focused projects that aren't open right now, against a query
+//! the way fzf does: every query character has to appear in order, and
+//! matches score higher when they are consecutive or start a word, a path
+//! segment or a camelCase hump. Whitespace separates terms that all have to
+//! match, each in whichever field fits it best. The query is case-sensitive
+//! only when it contains an uppercase letter. Recent projects score a bit
+//! lower than windows, so an open window wins over a closed project, and
+//! results accepted for the same query before score higher.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::focus_history::RecentProject;
+
+pub mod constants {
+    /// Score of every matched character
+    pub const SCORE_MATCH: i64 = 16;
+    /// Penalty for the first skipped character of a gap
+    pub const SCORE_GAP_START: i64 = -3;
+    /// Penalty for every further skipped character of a gap
+    pub const SCORE_GAP_EXTENSION: i64 = -1;
+    /// Bonus for a match at the start of a word or path segment
+    pub const BONUS_BOUNDARY: i64 = 8;
+    /// Bonus for a match at a camelCase hump or the first digit of a number
+    pub const BONUS_CAMEL: i64 = 7;
+    /// Least bonus of a match following another match
+    pub const BONUS_CONSECUTIVE: i64 = 4;
+    /// Factor applied to the bonus of the first matched character
+    pub const FIRST_CHAR_BONUS_MULTIPLIER: i64 = 2;
+
+    /// Percentage of the score kept for recent projects
+    pub const RECENT_PROJECT_WEIGHT: i64 = 80;
+
+    /// Bonus for every time a result was accepted for the same query
+    pub const ACCEPTED_BONUS: i64 = 20;
+    /// Accepts counted towards the bonus, so habits don't bury new matches
+    pub const MAX_ACCEPTED_BONUSES: u32 = 5;
+
+    /// Results returned by `search_windows` when no limit is given
+    pub const DEFAULT_RESULT_LIMIT: usize = 50;
+}
+
+/// Field a query term matched in
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    Project,
+    Tab,
+    Path,
+    AppName,
+}
+
+impl SearchField {
+    const ALL: [SearchField; 4] = [
+        SearchField::Project,
+        SearchField::Tab,
+        SearchField::Path,
+        SearchField::AppName,
+    ];
+
+    /// Percentage of the raw score kept for matches in this field, so a
+    /// project name beats the same text in a file name or path
+    fn weight(self) -> i64 {
+        match self {
+            SearchField::Project => 100,
+            SearchField::Tab => 90,
+            SearchField::Path => 70,
+            SearchField::AppName => 50,
+        }
+    }
+}
+
+/// Something a search can find
+#[derive(Debug, Clone, Serialize, specta::Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SearchItem {
+    Window {
+        window: Box<WindowInfo>,
+    },
+    /// A recently focused project without an open window
+    RecentProject {
+        project: RecentProject,
+    },
+}
+
+impl SearchItem {
+    fn text(&self, field: SearchField) -> Option<&str> {
+        match self {
+            SearchItem::Window { window } => match field {
+                SearchField::Project => window
+                    .project_label
+                    .as_deref()
+                    .or(window.project.as_deref()),
+                SearchField::Tab => window.active_editor_tab.as_deref(),
+                SearchField::Path => window.project_path.as_deref(),
+                SearchField::AppName => Some(&window.app_name),
+            },
+            SearchItem::RecentProject { project } => match field {
+                SearchField::Project => Some(&project.project),
+                SearchField::Tab => None,
+                SearchField::Path => project.project_path.as_deref(),
+                SearchField::AppName => Some(&project.app_name),
+            },
+        }
+    }
+
+    /// Identifies the item in the search history
+    fn key(&self) -> Option<String> {
+        match self {
+            SearchItem::Window { window } => crate::search_history::target_key(
+                &window.app_name,
+                window.project.as_deref(),
+                window.project_path.as_deref(),
+                window.window_name.as_deref(),
+            ),
+            SearchItem::RecentProject { project } => crate::search_history::target_key(
+                &project.app_name,
+                Some(&project.project),
+                project.project_path.as_deref(),
+                None,
+            ),
+        }
+    }
+
+    /// Percentage of the score kept for this kind of item
+    fn weight(&self) -> i64 {
+        match self {
+            SearchItem::Window { .. } => 100,
+            SearchItem::RecentProject { .. } => constants::RECENT_PROJECT_WEIGHT,
+        }
+    }
+}
+
+/// Characters of a field matched by one query term
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct FieldMatch {
+    pub field: SearchField,
+    /// Character indices into the field, for highlighting
+    pub positions: Vec<usize>,
+}
+
+/// An item matching the query
+#[derive(Debug, Clone, Serialize, specta::Type)]
+pub struct SearchMatch {
+    pub item: SearchItem,
+    /// Higher is better; 0 for every window of an empty query
+    pub score: i64,
+    /// One entry per query term
+    pub matches: Vec<FieldMatch>,
+}
+
+/// Score and matched character indices of `pattern` in `text`
+#[derive(Debug, PartialEq)]
+struct Scored {
+    score: i64,
+    positions: Vec<usize>,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+/// Bonus for a match at `index`, from how it relates to the character
+/// before it
+fn position_bonus(text: &[char], index: usize) -> i64 {
+    let current = text[index];
+    let Some(&previous) = index.checked_sub(1).and_then(|i| text.get(i)) else {
+        return constants::BONUS_BOUNDARY;
+    };
+    if !is_word_char(previous) && is_word_char(current) {
+        constants::BONUS_BOUNDARY
+    } else if (previous.is_lowercase() && current.is_uppercase())
+        || (!previous.is_ascii_digit() && current.is_ascii_digit())
+    {
+        constants::BONUS_CAMEL
+    } else {
+        0
+    }
+}
+
+/// Matches `pattern` as a subsequence of `text`, fzf v1 style: the first
+/// occurrence is found going forward, then narrowed going backward from its
+/// end, and the characters in between are scored.
+fn fuzzy_match(pattern: &[char], text: &str, case_sensitive: bool) -> Option<Scored> {
+    if pattern.is_empty() {
+        return None;
+    }
+    let original: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = if case_sensitive {
+        original.clone()
+    } else {
+        original
+            .iter()
+            .map(|c| c.to_lowercase().next().unwrap_or(*c))
+            .collect()
+    };
+
+    // Forward: end of the first full occurrence
+    let mut pattern_index = 0;
+    let mut end = None;
+    for (i, c) in folded.iter().enumerate() {
+        if *c == pattern[pattern_index] {
+            pattern_index += 1;
+            if pattern_index == pattern.len() {
+                end = Some(i);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    // Backward: latest start still containing the whole pattern
+    let mut start = end;
+    let mut pattern_index = pattern.len();
+    for i in (0..=end).rev() {
+        if folded[i] == pattern[pattern_index - 1] {
+            pattern_index -= 1;
+            if pattern_index == 0 {
+                start = i;
+                break;
+            }
+        }
+    }
+
+    let mut score = 0;
+    let mut positions = Vec::with_capacity(pattern.len());
+    let mut pattern_index = 0;
+    let mut in_gap = false;
+    let mut consecutive = 0;
+    let mut first_bonus = 0;
+    for (i, c) in folded.iter().enumerate().take(end + 1).skip(start) {
+        if pattern_index < pattern.len() && *c == pattern[pattern_index] {
+            let mut bonus = position_bonus(&original, i);
+            if consecutive == 0 {
+                first_bonus = bonus;
+            } else {
+                bonus = bonus.max(first_bonus).max(constants::BONUS_CONSECUTIVE);
+            }
+            score += constants::SCORE_MATCH
+                + if pattern_index == 0 {
+                    bonus * constants::FIRST_CHAR_BONUS_MULTIPLIER
+                } else {
+                    bonus
+                };
+            positions.push(i);
+            pattern_index += 1;
+            consecutive += 1;
+            in_gap = false;
+        } else {
+            score += if in_gap {
+                constants::SCORE_GAP_EXTENSION
+            } else {
+                constants::SCORE_GAP_START
+            };
+            in_gap = true;
+            consecutive = 0;
+            first_bonus = 0;
+        }
+    }
+    Some(Scored { score, positions })
+}
+
+/// Best field of `item` for one query term
+fn match_term(item: &SearchItem, term: &str) -> Option<(i64, FieldMatch)> {
+    let case_sensitive = term.chars().any(char::is_uppercase);
+    let pattern: Vec<char> = term.chars().collect();
+    SearchField::ALL
+        .iter()
+        .filter_map(|&field| {
+            let scored = fuzzy_match(&pattern, item.text(field)?, case_sensitive)?;
+            Some((
+                scored.score * field.weight() / 100,
+                FieldMatch {
+                    field,
+                    positions: scored.positions,
+                },
+            ))
+        })
+        // First field wins ties
+        .reduce(|best, candidate| {
+            if candidate.0 > best.0 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+/// Whether one of `windows` already shows `project`
+fn is_open(project: &RecentProject, windows: &[WindowInfo]) -> bool {
+    windows.iter().any(|window| match &project.project_path {
+        Some(path) => window.project_path.as_ref() == Some(path),
+        None => {
+            window.app_name == project.app_name && window.project.as_ref() == Some(&project.project)
+        }
+    })
+}
+
+/// Ranks `windows` and the `recent` projects that aren't open against
+/// `query`, best first. Items scoring the same keep their order, windows
+/// before projects; an empty query lists the windows only.
+pub fn search(windows: &[WindowInfo], recent: &[RecentProject], query: &str) -> Vec<SearchMatch> {
+    let terms: Vec<&str> = query.split_whitespace().collect();
+    if terms.is_empty() {
+        return windows
+            .iter()
+            .map(|window| SearchMatch {
+                item: SearchItem::Window {
+                    window: Box::new(window.clone()),
+                },
+                score: 0,
+                matches: Vec::new(),
+            })
+            .collect();
+    }
+
+    let items = windows
+        .iter()
+        .map(|window| SearchItem::Window {
+            window: Box::new(window.clone()),
+        })
+        .chain(
+            recent
+                .iter()
+                .filter(|project| !is_open(project, windows))
+                .map(|project| SearchItem::RecentProject {
+                    project: project.clone(),
+                }),
+        );
+    let mut results: Vec<SearchMatch> = items
+        .filter_map(|item| {
+            let mut score = 0;
+            let mut matches = Vec::with_capacity(terms.len());
+            for term in terms.iter() {
+                let (term_score, field_match) = match_term(&item, term)?;
+                score += term_score;
+                matches.push(field_match);
+            }
+            Some(SearchMatch {
+                score: score * item.weight() / 100,
+                item,
+                matches,
+            })
+        })
+        .collect();
+    results.sort_by_key(|result| std::cmp::Reverse(result.score));
+    results
+}
+
+/// Raises the results accepted for the query before, given how often each
+/// was accepted by its history key, and ranks them again
+fn apply_accepted(results: &mut [SearchMatch], accepted: &HashMap<String, u32>) {
+    if accepted.is_empty() {
+        return;
+    }
+    for result in results.iter_mut() {
+        if let Some(accepts) = result.item.key().and_then(|key| accepted.get(&key)) {
+            result.score += constants::ACCEPTED_BONUS
+                * i64::from((*accepts).min(constants::MAX_ACCEPTED_BONUSES));
+        }
+    }
+    results.sort_by_key(|result| std::cmp::Reverse(result.score));
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn search_windows(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Vec<SearchMatch>> {
+    crate::command_wrapper::create_typed_command("search_windows", args, |ctx| {
+        let query = ctx
+            .parameters
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or(SwiiError::InvalidParameter("query"))?
+            .to_string();
+        let limit = match ctx.parameters.get("limit") {
+            None | Some(serde_json::Value::Null) => constants::DEFAULT_RESULT_LIMIT,
+            Some(value) => value
+                .as_u64()
+                .filter(|limit| *limit > 0)
+                .ok_or(SwiiError::InvalidParameter("limit"))? as usize,
+        };
+
+        let windows = crate::window_list::listed_windows();
+        let recent = crate::focus_history::recent_projects();
+        let mut results = search(&windows, &recent, &query);
+        apply_accepted(
+            &mut results,
+            &crate::search_history::accepted_targets(&query),
+        );
+        ctx.logger.info(&format!(
+            "Query matched {} of {} windows and {} recent projects",
+            results.len(),
+            windows.len(),
+            recent.len()
+        ));
+        results.truncate(limit);
+        Ok(results)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    fn project_of(result: &SearchMatch) -> Option<&str> {
+        match &result.item {
+            SearchItem::Window { window } => window.project.as_deref(),
+            SearchItem::RecentProject { project } => Some(&project.project),
+        }
+    }
+
+    fn recent(project: &str, path: &str) -> RecentProject {
+        RecentProject {
+            project: project.to_string(),
+            project_path: Some(path.to_string()),
+            app_name: "Zed".to_string(),
+            last_focused_at: 0,
+        }
+    }
+
+    fn score(pattern: &str, text: &str) -> Option<i64> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        fuzzy_match(&pattern, text, false).map(|scored| scored.score)
+    }
+
+    #[test]
+    fn test_fuzzy_match_prefers_boundaries_and_runs() {
+        assert_eq!(score("xyz", "swii"), None);
+        assert!(score("api", "api-server") > score("api", "rapid"));
+        assert!(score("sw", "swii") > score("sw", "s-wii"));
+        assert!(score("ec", "EditorConfig") > score("ec", "electron"));
+
+        let pattern: Vec<char> = "mrs".chars().collect();
+        let scored = fuzzy_match(&pattern, "src/main.rs", false).unwrap();
+        assert_eq!(scored.positions, vec![4, 9, 10]);
+    }
+
+    #[test]
+    fn test_fuzzy_match_smart_case() {
+        let pattern: Vec<char> = "Api".chars().collect();
+        assert!(fuzzy_match(&pattern, "api", true).is_none());
+        assert!(fuzzy_match(&pattern, "Api", true).is_some());
+    }
+
+    #[test]
+    fn test_search_ranks_project_matches_first() {
+        let mut windows = mock_editor_windows();
+        windows.truncate(2);
+        windows[0].project = Some("web".to_string());
+        windows[0].active_editor_tab = Some("api.ts".to_string());
+        windows[1].project = Some("api".to_string());
+        windows[1].active_editor_tab = Some("main.rs".to_string());
+
+        let results = search(&windows, &[], "api");
+        assert_eq!(results.len(), 2);
+        assert_eq!(project_of(&results[0]), Some("api"));
+        assert_eq!(results[0].matches[0].field, SearchField::Project);
+        assert_eq!(results[1].matches[0].field, SearchField::Tab);
+
+        // Every term has to match
+        let results = search(&windows, &[], "api main");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matches.len(), 2);
+
+        assert_eq!(search(&windows, &[], "  ").len(), 2);
+    }
+
+    #[test]
+    fn test_search_merges_recent_projects() {
+        let mut windows = mock_editor_windows();
+        windows.truncate(1);
+        windows[0].project = Some("api".to_string());
+        windows[0].project_path = Some("/src/api".to_string());
+        let recent = vec![
+            recent("api", "/src/api"),
+            recent("api-docs", "/src/api-docs"),
+        ];
+
+        let results = search(&windows, &recent, "api");
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0].item, SearchItem::Window { .. }));
+        assert!(matches!(results[1].item, SearchItem::RecentProject { .. }));
+        assert_eq!(project_of(&results[1]), Some("api-docs"));
+
+        // The same match scores lower for a project that isn't open
+        let closed = search(&[], &recent[..1], "api");
+        assert!(closed[0].score < results[0].score);
+
+        assert_eq!(search(&windows, &recent, "").len(), 1);
+    }
+
+    #[test]
+    fn test_accepted_results_rank_higher() {
+        let recent = vec![
+            recent("api", "/src/api"),
+            recent("api-docs", "/src/api-docs"),
+        ];
+        let mut results = search(&[], &recent, "api");
+        assert_eq!(project_of(&results[0]), Some("api"));
+
+        let accepted = HashMap::from([("/src/api-docs".to_string(), 3)]);
+        apply_accepted(&mut results, &accepted);
+        assert_eq!(project_of(&results[0]), Some("api-docs"));
+    }
+}
@@ -0,0 +1,334 @@
+//! Search queries and the results they led to, kept across launches.
+//!
+//! Every accepted search result is recorded under its query in a JSON file in
+//! the app data directory. `get_search_suggestions` offers the recorded
+//! queries that start with what has been typed, most used first, and
+//! `search_windows` ranks the results a query led to before a little higher
+//! each time. Queries are compared case-insensitively with whitespace
+//! collapsed.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+use tracing::{info, warn};
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// History file name inside the app data dir
+    pub const HISTORY_FILE: &str = "search_history.json";
+
+    /// Queries kept before the least recently used ones are dropped
+    pub const MAX_QUERIES: usize = 200;
+
+    /// Accepted results kept per query
+    pub const MAX_TARGETS_PER_QUERY: usize = 10;
+
+    /// Suggestions returned by `get_search_suggestions` when no limit is given
+    pub const DEFAULT_SUGGESTION_LIMIT: usize = 8;
+}
+
+/// A result accepted for a query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AcceptedTarget {
+    /// See [`target_key`]
+    key: String,
+    accepts: u32,
+}
+
+/// A query and what it led to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct QueryRecord {
+    /// Normalized query
+    query: String,
+    uses: u32,
+    /// Milliseconds since the Unix epoch
+    last_used_at: u64,
+    /// Most accepted first
+    targets: Vec<AcceptedTarget>,
+}
+
+/// Contents of the history file
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SearchHistory {
+    /// Most recently used first
+    queries: Vec<QueryRecord>,
+}
+
+/// A previous query starting with the typed prefix
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct SearchSuggestion {
+    pub query: String,
+    /// Number of times a result was accepted for the query
+    pub uses: u32,
+}
+
+static HISTORY: Mutex<SearchHistory> = Mutex::new(SearchHistory {
+    queries: Vec::new(),
+});
+static HISTORY_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn lock_history() -> std::sync::MutexGuard<'static, SearchHistory> {
+    HISTORY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Lowercases `query` and collapses its whitespace
+pub fn normalize_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Identifies a search result across listings: the project root when known,
+/// otherwise the editor with its project or window title
+pub fn target_key(
+    app_name: &str,
+    project: Option<&str>,
+    project_path: Option<&str>,
+    window_name: Option<&str>,
+) -> Option<String> {
+    if let Some(path) = project_path {
+        return Some(path.to_string());
+    }
+    project
+        .or(window_name)
+        .map(|name| format!("{}:{}", app_name, name))
+}
+
+/// Counts a use of `query` and, when given, the result it led to
+fn record_accept(history: &mut SearchHistory, query: &str, target: Option<String>, now: u64) {
+    let index = history
+        .queries
+        .iter()
+        .position(|record| record.query == query);
+    let mut record = match index {
+        Some(index) => history.queries.remove(index),
+        None => QueryRecord {
+            query: query.to_string(),
+            uses: 0,
+            last_used_at: now,
+            targets: Vec::new(),
+        },
+    };
+    record.uses += 1;
+    record.last_used_at = now;
+    if let Some(key) = target {
+        match record.targets.iter_mut().find(|target| target.key == key) {
+            Some(target) => target.accepts += 1,
+            None => record.targets.push(AcceptedTarget { key, accepts: 1 }),
+        }
+        record
+            .targets
+            .sort_by_key(|target| std::cmp::Reverse(target.accepts));
+        record.targets.truncate(constants::MAX_TARGETS_PER_QUERY);
+    }
+    history.queries.insert(0, record);
+    history.queries.truncate(constants::MAX_QUERIES);
+}
+
+/// Queries starting with `prefix`, most used first, then most recent
+fn suggestions(history: &SearchHistory, prefix: &str, limit: usize) -> Vec<SearchSuggestion> {
+    let prefix = normalize_query(prefix);
+    let mut suggestions: Vec<SearchSuggestion> = history
+        .queries
+        .iter()
+        .filter(|record| record.query.starts_with(&prefix) && record.query != prefix)
+        .map(|record| SearchSuggestion {
+            query: record.query.clone(),
+            uses: record.uses,
+        })
+        .collect();
+    suggestions.sort_by_key(|suggestion| std::cmp::Reverse(suggestion.uses));
+    suggestions.truncate(limit);
+    suggestions
+}
+
+/// How often each result was accepted for `query`, by [`target_key`]
+pub fn accepted_targets(query: &str) -> HashMap<String, u32> {
+    let query = normalize_query(query);
+    lock_history()
+        .queries
+        .iter()
+        .find(|record| record.query == query)
+        .map(|record| {
+            record
+                .targets
+                .iter()
+                .map(|target| (target.key.clone(), target.accepts))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn save(history: &SearchHistory) -> Result<(), SwiiError> {
+    let Some(path) = HISTORY_PATH.get() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let contents = serde_json::to_string(history)
+        .map_err(|e| SwiiError::Internal(format!("Failed to serialize search history: {}", e)))?;
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Loads the history file from the app data dir, if there is one
+pub fn init(app: &tauri::AppHandle) {
+    let path = match app.path().app_data_dir() {
+        Ok(dir) => dir.join(constants::HISTORY_FILE),
+        Err(e) => {
+            warn!("Search history won't be kept: {}", e);
+            return;
+        }
+    };
+    if path.exists() {
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                serde_json::from_str::<SearchHistory>(&contents).map_err(|e| e.to_string())
+            }) {
+            Ok(history) => {
+                info!(
+                    "Loaded {} search queries from {}",
+                    history.queries.len(),
+                    path.display()
+                );
+                *lock_history() = history;
+            }
+            Err(e) => warn!("Ignoring {}: {}", path.display(), e),
+        }
+    }
+    let _ = HISTORY_PATH.set(path);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn record_search(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<()> {
+    crate::command_wrapper::create_typed_command("record_search", args, |ctx| {
+        let text = |name: &str| ctx.parameters.get(name).and_then(|v| v.as_str());
+        let query = normalize_query(text("query").ok_or(SwiiError::InvalidParameter("query"))?);
+        if query.is_empty() {
+            return Ok(());
+        }
+        let target = text("app_name").and_then(|app_name| {
+            target_key(
+                app_name,
+                text("project"),
+                text("project_path"),
+                text("window_name"),
+            )
+        });
+
+        let mut history = lock_history();
+        record_accept(&mut history, &query, target, now_ms());
+        if let Err(e) = save(&history) {
+            ctx.logger
+                .error(&format!("Failed to save search history: {}", e));
+        }
+        Ok(())
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_search_suggestions(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Vec<SearchSuggestion>> {
+    crate::command_wrapper::create_typed_command("get_search_suggestions", args, |ctx| {
+        let prefix = ctx
+            .parameters
+            .get("prefix")
+            .and_then(|v| v.as_str())
+            .ok_or(SwiiError::InvalidParameter("prefix"))?
+            .to_string();
+        let limit = match ctx.parameters.get("limit") {
+            None | Some(serde_json::Value::Null) => constants::DEFAULT_SUGGESTION_LIMIT,
+            Some(value) => value
+                .as_u64()
+                .filter(|limit| *limit > 0)
+                .ok_or(SwiiError::InvalidParameter("limit"))? as usize,
+        };
+        Ok(suggestions(&lock_history(), &prefix, limit))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_accept_counts_queries_and_targets() {
+        let mut history = SearchHistory::default();
+        record_accept(&mut history, "api", Some("/src/api".to_string()), 1);
+        record_accept(&mut history, "web", None, 2);
+        record_accept(&mut history, "api", Some("/src/api-docs".to_string()), 3);
+        record_accept(&mut history, "api", Some("/src/api-docs".to_string()), 4);
+
+        let queries: Vec<(&str, u32)> = history
+            .queries
+            .iter()
+            .map(|record| (record.query.as_str(), record.uses))
+            .collect();
+        assert_eq!(queries, vec![("api", 3), ("web", 1)]);
+        let targets: Vec<(&str, u32)> = history.queries[0]
+            .targets
+            .iter()
+            .map(|target| (target.key.as_str(), target.accepts))
+            .collect();
+        assert_eq!(targets, vec![("/src/api-docs", 2), ("/src/api", 1)]);
+    }
+
+    #[test]
+    fn test_suggestions_rank_frequent_queries_first() {
+        let mut history = SearchHistory::default();
+        record_accept(&mut history, "swii", None, 1);
+        record_accept(&mut history, "swift", None, 2);
+        record_accept(&mut history, "swii", None, 3);
+        record_accept(&mut history, "web", None, 4);
+
+        let queries: Vec<String> = suggestions(&history, " SW ", 5)
+            .into_iter()
+            .map(|suggestion| suggestion.query)
+            .collect();
+        assert_eq!(queries, vec!["swii", "swift"]);
+        assert!(suggestions(&history, "swii", 5).is_empty());
+        assert_eq!(normalize_query("  Api   Docs "), "api docs");
+    }
+
+    #[test]
+    fn test_target_key() {
+        assert_eq!(
+            target_key("Zed", Some("api"), Some("/src/api"), None).as_deref(),
+            Some("/src/api")
+        );
+        assert_eq!(
+            target_key("Zed", None, None, Some("untitled")).as_deref(),
+            Some("Zed:untitled")
+        );
+        assert_eq!(target_key("Zed", None, None, None), None);
+    }
+}
@@ -0,0 +1,228 @@
+//! End-to-end self test of listing and focusing.
+//!
+//! `run_self_test` goes through what a switch does: it lists windows, picks a
+//! target (the first listed window unless one is given), focuses it, lists
+//! again to check the window survived, and hands focus back to swii's own
+//! window. Each stage is reported separately, so a macOS update that breaks
+//! one part of the accessibility API shows up as a single failing stage.
+
+use std::time::Instant;
+
+use serde::Serialize;
+use swii_core::provider::WindowProvider;
+use tauri::Manager;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::health::CheckStatus;
+use crate::window_provider::provider;
+
+/// One stage of the self test
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct SelfTestStage {
+    /// Stable identifier, e.g. "focus"
+    pub id: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub elapsed_ms: f64,
+}
+
+/// Result of `run_self_test`
+#[derive(Debug, Serialize, specta::Type)]
+pub struct SelfTestReport {
+    /// True when no stage failed
+    pub passed: bool,
+    /// Window provider under test
+    pub provider: &'static str,
+    /// Window that was focused, as `[pid, window_number]`
+    pub target: Option<(i32, u32)>,
+    pub stages: Vec<SelfTestStage>,
+}
+
+/// Times `run` and turns its outcome into a stage
+fn stage(id: &'static str, run: impl FnOnce() -> Result<String, String>) -> SelfTestStage {
+    let start = Instant::now();
+    let (status, detail) = match run() {
+        Ok(detail) => (CheckStatus::Pass, detail),
+        Err(detail) => (CheckStatus::Fail, detail),
+    };
+    SelfTestStage {
+        id,
+        status,
+        detail,
+        elapsed_ms: start.elapsed().as_secs_f64() * 1000.0,
+    }
+}
+
+fn skipped(id: &'static str) -> SelfTestStage {
+    SelfTestStage {
+        id,
+        status: CheckStatus::Skipped,
+        detail: "Skipped after an earlier failure".to_string(),
+        elapsed_ms: 0.0,
+    }
+}
+
+/// Lists, focuses and re-lists through `provider`. Returns the stages and
+/// the focused window; stages after a failure are skipped.
+fn run_stages(
+    provider: &dyn WindowProvider,
+    requested: Option<(i32, u32)>,
+) -> (Vec<SelfTestStage>, Option<(i32, u32)>) {
+    let mut stages = Vec::new();
+    let mut windows = Vec::new();
+    stages.push(stage("enumerate", || {
+        windows = provider.list_windows().map_err(|e| e.to_string())?;
+        Ok(format!("Listed {} windows", windows.len()))
+    }));
+
+    let mut target = None;
+    if stages[0].status == CheckStatus::Pass {
+        stages.push(stage("pick_target", || {
+            let window = windows
+                .iter()
+                .find(|window| match requested {
+                    Some((pid, number)) => window.pid == pid && window.window_number == number,
+                    None => window.has_windows && !window.auxiliary,
+                })
+                .ok_or_else(|| match requested {
+                    Some((pid, number)) => format!("Window {} of PID {} isn't listed", number, pid),
+                    None => "No editor window to focus; open one and retry".to_string(),
+                })?;
+            target = Some((window.pid, window.window_number));
+            Ok(format!(
+                "{} window {}",
+                window.app_name,
+                window.window_name.as_deref().unwrap_or("without a title")
+            ))
+        }));
+    }
+
+    let Some((pid, window_number)) = target else {
+        for id in ["pick_target", "focus", "verify"]
+            .into_iter()
+            .skip(stages.len() - 1)
+        {
+            stages.push(skipped(id));
+        }
+        return (stages, None);
+    };
+
+    stages.push(stage("focus", || {
+        provider
+            .focus_window(pid, window_number)
+            .map(|()| "Focused the window".to_string())
+            .map_err(|e| e.to_string())
+    }));
+    if stages[2].status != CheckStatus::Pass {
+        stages.push(skipped("verify"));
+        return (stages, target);
+    }
+
+    stages.push(stage("verify", || {
+        let windows = provider.list_windows().map_err(|e| e.to_string())?;
+        windows
+            .iter()
+            .any(|window| window.pid == pid && window.window_number == window_number)
+            .then(|| "The window is still listed after focusing".to_string())
+            .ok_or_else(|| "The window disappeared after focusing".to_string())
+    }));
+    (stages, target)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_self_test(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<SelfTestReport> {
+    crate::command_wrapper::create_typed_command("run_self_test", args, |ctx| {
+        let pid = ctx.parameters.get("pid").and_then(|v| v.as_i64());
+        let window_number = ctx.parameters.get("window_number").and_then(|v| v.as_u64());
+        let requested = match (pid, window_number) {
+            (Some(pid), Some(window_number)) => Some((pid as i32, window_number as u32)),
+            (None, None) => None,
+            (None, Some(_)) => return Err(SwiiError::InvalidParameter("pid")),
+            (Some(_), None) => return Err(SwiiError::InvalidParameter("window_number")),
+        };
+
+        let provider = provider();
+        let (mut stages, target) = run_stages(provider, requested);
+
+        // Hand focus back so the test leaves the user where they started
+        if target.is_some() {
+            stages.push(stage("restore", || {
+                let window = app
+                    .get_webview_window(crate::devtools::constants::DEFAULT_WINDOW_LABEL)
+                    .ok_or_else(|| "swii's window is missing".to_string())?;
+                window
+                    .set_focus()
+                    .map(|()| "Focused swii's window again".to_string())
+                    .map_err(|e| e.to_string())
+            }));
+        }
+
+        let failed: Vec<&str> = stages
+            .iter()
+            .filter(|stage| stage.status == CheckStatus::Fail)
+            .map(|stage| stage.id)
+            .collect();
+        if failed.is_empty() {
+            ctx.logger.info("Self test passed");
+        } else {
+            ctx.logger
+                .error(&format!("Self test failed at: {}", failed.join(", ")));
+        }
+
+        Ok(SelfTestReport {
+            passed: failed.is_empty(),
+            provider: provider.name(),
+            target,
+            stages,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::MockProvider;
+
+    fn statuses(stages: &[SelfTestStage]) -> Vec<(&str, CheckStatus)> {
+        stages
+            .iter()
+            .map(|stage| (stage.id, stage.status))
+            .collect()
+    }
+
+    #[test]
+    fn test_self_test_round_trip() {
+        let (stages, target) = run_stages(&MockProvider, None);
+        assert_eq!(
+            statuses(&stages),
+            vec![
+                ("enumerate", CheckStatus::Pass),
+                ("pick_target", CheckStatus::Pass),
+                ("focus", CheckStatus::Pass),
+                ("verify", CheckStatus::Pass),
+            ]
+        );
+        assert!(target.is_some());
+    }
+
+    #[test]
+    fn test_self_test_skips_after_failure() {
+        let (stages, target) = run_stages(&MockProvider, Some((1, 1)));
+        assert_eq!(
+            statuses(&stages),
+            vec![
+                ("enumerate", CheckStatus::Pass),
+                ("pick_target", CheckStatus::Fail),
+                ("focus", CheckStatus::Skipped),
+                ("verify", CheckStatus::Skipped),
+            ]
+        );
+        assert_eq!(target, None);
+    }
+}
@@ -0,0 +1,186 @@
+//! Shareable snapshots of the open editor windows.
+//!
+//! `export_session` lists windows again and describes each open one by its
+//! editor, project, path and active tab, as JSON or as a Markdown list ready
+//! to paste into standup notes or an issue. Icons, process ids and other
+//! machine-specific details are left out.
+
+use serde::{Deserialize, Serialize};
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Heading of a Markdown export, followed by the export time
+    pub const MARKDOWN_HEADING: &str = "Open in my editors";
+
+    /// Format of the export time in Markdown exports
+    pub const MARKDOWN_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+}
+
+/// Output format of `export_session`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionFormat {
+    Json,
+    Markdown,
+}
+
+/// A window as exported
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ExportedWindow {
+    app_name: String,
+    project: Option<String>,
+    project_path: Option<String>,
+    active_editor_tab: Option<String>,
+    remote_host: Option<String>,
+    is_modified: bool,
+}
+
+impl From<&WindowInfo> for ExportedWindow {
+    fn from(window: &WindowInfo) -> Self {
+        ExportedWindow {
+            app_name: window.app_name.clone(),
+            project: window
+                .project_label
+                .clone()
+                .or_else(|| window.project.clone()),
+            project_path: window.project_path.clone(),
+            active_editor_tab: window.active_editor_tab.clone(),
+            remote_host: window.remote_host.clone(),
+            is_modified: window.is_modified,
+        }
+    }
+}
+
+/// Contents of a JSON export
+#[derive(Debug, Serialize)]
+struct ExportedSession {
+    /// RFC 3339
+    exported_at: String,
+    windows: Vec<ExportedWindow>,
+}
+
+/// Open editor windows, leaving out windowless editors and palettes
+fn exported_windows(windows: &[WindowInfo]) -> Vec<ExportedWindow> {
+    windows
+        .iter()
+        .filter(|window| window.has_windows && !window.auxiliary)
+        .map(ExportedWindow::from)
+        .collect()
+}
+
+fn markdown_line(window: &ExportedWindow) -> String {
+    let mut line = match &window.project {
+        Some(project) => format!("- **{}** in {}", project, window.app_name),
+        None => format!("- {}", window.app_name),
+    };
+    if let Some(host) = &window.remote_host {
+        line.push_str(&format!(" on {}", host));
+    }
+    if let Some(path) = &window.project_path {
+        line.push_str(&format!(" (`{}`)", path));
+    }
+    if let Some(tab) = &window.active_editor_tab {
+        line.push_str(&format!(", editing `{}`", tab));
+        if window.is_modified {
+            line.push_str(" (unsaved)");
+        }
+    }
+    line
+}
+
+fn to_markdown(windows: &[ExportedWindow], exported_at: &str) -> String {
+    let mut markdown = format!("## {} ({})\n\n", constants::MARKDOWN_HEADING, exported_at);
+    if windows.is_empty() {
+        markdown.push_str("No editor windows are open.\n");
+    }
+    for window in windows {
+        markdown.push_str(&markdown_line(window));
+        markdown.push('\n');
+    }
+    markdown
+}
+
+/// Renders `windows` in `format`
+fn export(windows: &[WindowInfo], format: SessionFormat) -> Result<String, SwiiError> {
+    let exported = exported_windows(windows);
+    match format {
+        SessionFormat::Json => serde_json::to_string_pretty(&ExportedSession {
+            exported_at: chrono::Local::now().to_rfc3339(),
+            windows: exported,
+        })
+        .map_err(|e| SwiiError::Internal(format!("Failed to serialize session: {}", e))),
+        SessionFormat::Markdown => Ok(to_markdown(
+            &exported,
+            &chrono::Local::now()
+                .format(constants::MARKDOWN_TIME_FORMAT)
+                .to_string(),
+        )),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_session(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<String> {
+    crate::command_wrapper::create_typed_command("export_session", args, |ctx| {
+        let format: SessionFormat = match ctx.parameters.get("format") {
+            None | Some(serde_json::Value::Null) => SessionFormat::Markdown,
+            Some(value) => serde_json::from_value(value.clone())
+                .map_err(|_| SwiiError::InvalidParameter("format"))?,
+        };
+
+        crate::window_list::relist_windows()?;
+        let windows = crate::window_list::listed_windows();
+        let exported = export(&windows, format)?;
+        ctx.logger.info(&format!(
+            "Exported {} windows as {:?}",
+            windows.len(),
+            format
+        ));
+        Ok(exported)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    #[test]
+    fn test_export_leaves_out_icons() {
+        let json = export(&mock_editor_windows(), SessionFormat::Json).unwrap();
+        let session: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let windows = session["windows"].as_array().unwrap();
+        assert!(!windows.is_empty());
+        assert!(windows[0].get("app_icon").is_none());
+        assert!(windows[0].get("pid").is_none());
+    }
+
+    #[test]
+    fn test_markdown_line() {
+        let window = ExportedWindow {
+            app_name: "Zed".to_string(),
+            project: Some("api".to_string()),
+            project_path: Some("/src/api".to_string()),
+            active_editor_tab: Some("main.rs".to_string()),
+            remote_host: None,
+            is_modified: true,
+        };
+        assert_eq!(
+            markdown_line(&window),
+            "- **api** in Zed (`/src/api`), editing `main.rs` (unsaved)"
+        );
+
+        let markdown = to_markdown(&[], "2026-03-02 09:30");
+        assert_eq!(
+            markdown,
+            "## Open in my editors (2026-03-02 09:30)\n\nNo editor windows are open.\n"
+        );
+    }
+}
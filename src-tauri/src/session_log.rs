@@ -0,0 +1,292 @@
+//! One log file for the frontend and the backend.
+//!
+//! Each run gets a session ID at startup, and every line written through
+//! [`record`] carries it along with a timestamp, the level and where the line
+//! came from: `frontend:<tag>` for messages the frontend logs,
+//! `command:<name>` for command logging and `backend:<module>` for the
+//! backend's `tracing` events. Lines go to stdout and to `swii.log` in the
+//! app log directory, so a single file tells what both sides did around a
+//! failed switch. The file is rotated once it reaches
+//! [`constants::MAX_LOG_BYTES`], keeping [`constants::KEPT_LOGS`] older files.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use tauri::Manager;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+use crate::logger::LogLevel;
+
+pub mod constants {
+    /// Log file name inside the app log dir
+    pub const LOG_FILE: &str = "swii.log";
+
+    /// Size at which the log file is rotated
+    pub const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+    /// Rotated files kept next to the current one, `swii.1.log` the newest
+    pub const KEPT_LOGS: usize = 3;
+
+    /// Prefix of the `tracing` targets written to the log, i.e. swii's own
+    /// modules
+    pub const BACKEND_TARGET_PREFIX: &str = "swii";
+}
+
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    len: u64,
+}
+
+static LOG_FILE: Mutex<Option<LogFile>> = Mutex::new(None);
+static SESSION_ID: OnceLock<String> = OnceLock::new();
+
+/// ID of this run, e.g. `20261016-142301-4f2a`
+pub fn session_id() -> &'static str {
+    SESSION_ID.get_or_init(|| {
+        format!(
+            "{}-{:04x}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            std::process::id() & 0xffff
+        )
+    })
+}
+
+fn level_name(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Debug => "DEBUG",
+        LogLevel::Info => "INFO",
+        LogLevel::Warn => "WARN",
+        LogLevel::Error => "ERROR",
+    }
+}
+
+fn format_line(
+    timestamp: &str,
+    session: &str,
+    level: LogLevel,
+    origin: &str,
+    message: &str,
+) -> String {
+    format!(
+        "{} {} {:<5} [{}] {}",
+        timestamp,
+        session,
+        level_name(level),
+        origin,
+        message
+    )
+}
+
+/// Path of the `index`th rotated file next to `path`, e.g. `swii.2.log`
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    match path.extension() {
+        Some(extension) => path.with_file_name(format!(
+            "{}.{}.{}",
+            stem,
+            index,
+            extension.to_string_lossy()
+        )),
+        None => path.with_file_name(format!("{}.{}", stem, index)),
+    }
+}
+
+fn open(path: &Path) -> std::io::Result<LogFile> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let len = file.metadata()?.len();
+    Ok(LogFile {
+        path: path.to_path_buf(),
+        file,
+        len,
+    })
+}
+
+/// Shifts the rotated files up by one and starts a new current file
+fn rotate(path: &Path) -> std::io::Result<LogFile> {
+    for index in (1..constants::KEPT_LOGS).rev() {
+        let from = rotated_path(path, index);
+        if from.exists() {
+            std::fs::rename(&from, rotated_path(path, index + 1))?;
+        }
+    }
+    std::fs::rename(path, rotated_path(path, 1))?;
+    open(path)
+}
+
+fn append(log: &mut Option<LogFile>, line: &str) {
+    let Some(current) = log.as_mut() else {
+        return;
+    };
+    if current.len > 0 && current.len + line.len() as u64 + 1 > constants::MAX_LOG_BYTES {
+        match rotate(&current.path) {
+            Ok(rotated) => *current = rotated,
+            Err(e) => {
+                eprintln!("Failed to rotate {}: {}", current.path.display(), e);
+                *log = None;
+                return;
+            }
+        }
+    }
+    match writeln!(current.file, "{}", line) {
+        Ok(()) => current.len += line.len() as u64 + 1,
+        Err(e) => {
+            eprintln!("Failed to write {}: {}", current.path.display(), e);
+            *log = None;
+        }
+    }
+}
+
+/// Writes a line to stdout and, once [`init`] has run, to the log file
+pub fn record(level: LogLevel, origin: &str, message: &str) {
+    let timestamp = chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z");
+    let line = format_line(&timestamp.to_string(), session_id(), level, origin, message);
+    println!("{}", line);
+    append(
+        &mut LOG_FILE
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        &line,
+    );
+}
+
+/// Collects an event's message and fields into one line
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+/// Writes swii's `tracing` events through [`record`]
+struct SessionLayer;
+
+impl<S: tracing::Subscriber> Layer<S> for SessionLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let max_level = if cfg!(debug_assertions) {
+            tracing::Level::DEBUG
+        } else {
+            tracing::Level::INFO
+        };
+        if *metadata.level() > max_level
+            || !metadata
+                .target()
+                .starts_with(constants::BACKEND_TARGET_PREFIX)
+        {
+            return;
+        }
+
+        let level = match *metadata.level() {
+            tracing::Level::ERROR => LogLevel::Error,
+            tracing::Level::WARN => LogLevel::Warn,
+            tracing::Level::INFO => LogLevel::Info,
+            _ => LogLevel::Debug,
+        };
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        if !visitor.fields.is_empty() {
+            visitor.message = format!("{} {}", visitor.message, visitor.fields.join(" "));
+        }
+        record(
+            level,
+            &format!("backend:{}", metadata.target()),
+            &visitor.message,
+        );
+    }
+}
+
+/// Opens the log file, rotating it if it is already full, and routes the
+/// backend's `tracing` events into it
+pub fn init(app: &tauri::AppHandle) {
+    if tracing::subscriber::set_global_default(tracing_subscriber::registry().with(SessionLayer))
+        .is_err()
+    {
+        eprintln!("A tracing subscriber is already installed; backend events won't be logged");
+    }
+
+    let opened = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| e.to_string())
+        .and_then(|dir| {
+            std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+            let path = dir.join(constants::LOG_FILE);
+            let log = open(&path).map_err(|e| e.to_string())?;
+            if log.len >= constants::MAX_LOG_BYTES {
+                return rotate(&path).map_err(|e| e.to_string());
+            }
+            Ok(log)
+        });
+    match opened {
+        Ok(log) => {
+            let path = log.path.display().to_string();
+            *LOG_FILE
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(log);
+            record(
+                LogLevel::Info,
+                "backend:session",
+                &format!(
+                    "Session {} started, swii {} ({})",
+                    session_id(),
+                    env!("CARGO_PKG_VERSION"),
+                    path
+                ),
+            );
+        }
+        Err(e) => record(
+            LogLevel::Warn,
+            "backend:session",
+            &format!("Logging to stdout only: {}", e),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_line() {
+        assert_eq!(
+            format_line(
+                "2026-10-16T14:23:01.120+02:00",
+                "20261016-142301-4f2a",
+                LogLevel::Info,
+                "frontend:search",
+                "query changed"
+            ),
+            "2026-10-16T14:23:01.120+02:00 20261016-142301-4f2a INFO  [frontend:search] query changed"
+        );
+    }
+
+    #[test]
+    fn test_rotated_path() {
+        let path = Path::new("/logs/swii.log");
+        assert_eq!(rotated_path(path, 1), Path::new("/logs/swii.1.log"));
+        assert_eq!(rotated_path(path, 3), Path::new("/logs/swii.3.log"));
+        assert_eq!(
+            rotated_path(Path::new("/logs/swii"), 2),
+            Path::new("/logs/swii.2")
+        );
+    }
+
+    #[test]
+    fn test_session_id_is_stable() {
+        assert_eq!(session_id(), session_id());
+        assert!(session_id().len() > 16);
+    }
+}
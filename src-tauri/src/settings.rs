@@ -0,0 +1,365 @@
+//! User settings stored as JSON in the app config directory.
+//!
+//! The file is optional and every field has a default, so users only write
+//! the keys they want to change. Settings are loaded at startup and can be
+//! re-read with `reload_settings` after editing the file.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use swii_core::editor_config;
+use swii_title_parser::{ParserConfig, TitleRule};
+use tauri::Manager;
+use tracing::{info, warn};
+
+use crate::activation_policy::ActivationPolicy;
+use crate::daily_note::DailyNote;
+use crate::error::SwiiError;
+use crate::focus_rules::FocusRule;
+use crate::logger::FrontendLogFilter;
+use crate::window_labels::WindowLabel;
+
+pub mod constants {
+    /// Settings file name inside the app config dir
+    pub const SETTINGS_FILE: &str = "settings.json";
+}
+
+/// Contents of the settings file
+#[derive(Debug, Default, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(default)]
+pub struct Settings {
+    /// Custom title parsing rules, applied before the built-in heuristics
+    pub title_rules: Vec<TitleRule>,
+    /// Separators between project and tab in titles, e.g. `" — "`;
+    /// empty keeps the built-in set
+    pub title_separators: Vec<String>,
+    /// Names of non-default VS Code profiles, so they aren't mistaken for
+    /// projects or tabs
+    pub vscode_profiles: Vec<String>,
+    /// List terminal windows running Vim or Neovim
+    pub terminal_editors: bool,
+    /// Shortest text after the last dot that counts as a file extension
+    pub min_extension_length: Option<usize>,
+    /// Longest text after the last dot that counts as a file extension
+    pub max_extension_length: Option<usize>,
+    /// Extensions always treated as such, e.g. `"code-workspace"`
+    pub extension_allowlist: Vec<String>,
+    /// Extra leading words of commands shown in terminal tab titles,
+    /// e.g. `"terraform"`
+    pub command_patterns: Vec<String>,
+    /// Extra files or directories that mark a project root, e.g. `"WORKSPACE"`
+    pub project_root_markers: Vec<String>,
+    /// Ask running JetBrains IDEs for the paths of their open projects
+    pub jetbrains_integration: bool,
+    /// Allow `focus_browser_tab` to select browser tabs
+    pub browser_mode: bool,
+    /// Ask iTerm2 for the working directories of its sessions
+    pub iterm2_integration: bool,
+    /// Projects to focus automatically, e.g. when a display connects
+    pub focus_rules: Vec<FocusRule>,
+    /// Write the webviews' console output to the backend log
+    pub capture_frontend_console: bool,
+    /// List windows without a title; on unless set to false
+    pub include_untitled: Option<bool>,
+    /// `"regular"` shows a Dock icon; `"accessory"`, the default, doesn't
+    pub activation_policy: ActivationPolicy,
+    /// Show the project of the focused editor window next to the tray icon
+    pub tray_title: bool,
+    /// Projects to post a notification for when their windows open or close
+    pub watched_projects: Vec<String>,
+    /// Markdown file to append the day's focused projects to
+    pub daily_note: Option<DailyNote>,
+    /// Minimum level and tag lists for messages logged by the frontend
+    pub frontend_log: FrontendLogFilter,
+    /// Shortcut that toggles the panel, e.g. `"Command+Shift+Space"`;
+    /// `"Command+Y"` unless set
+    pub panel_shortcut: Option<String>,
+    /// Seconds without input after which the user counts as idle; 300
+    /// unless set
+    pub idle_threshold_secs: Option<u64>,
+    /// Window labels, saved by `set_window_label`
+    pub window_labels: Vec<WindowLabel>,
+}
+
+impl Settings {
+    /// The configured extension length range, if both bounds make sense
+    fn extension_lengths(&self) -> Result<Option<(usize, usize)>, String> {
+        if self.min_extension_length.is_none() && self.max_extension_length.is_none() {
+            return Ok(None);
+        }
+        let min = self
+            .min_extension_length
+            .unwrap_or(swii_title_parser::constants::MIN_EXTENSION_LENGTH);
+        let max = self
+            .max_extension_length
+            .unwrap_or(swii_title_parser::constants::MAX_EXTENSION_LENGTH);
+        if min == 0 || min > max {
+            return Err(format!(
+                "Invalid extension lengths {}..={}, keeping the defaults",
+                min, max
+            ));
+        }
+        Ok(Some((min, max)))
+    }
+}
+
+/// Result of loading the settings file
+#[derive(Debug, Serialize, specta::Type)]
+pub struct SettingsStatus {
+    pub path: String,
+    pub exists: bool,
+    pub title_rule_count: usize,
+    /// Problems with individual entries; the rest of the file still applies
+    pub errors: Vec<String>,
+}
+
+fn settings_path(app: &tauri::AppHandle) -> Result<PathBuf, SwiiError> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(constants::SETTINGS_FILE))
+        .map_err(|e| SwiiError::Config(format!("Failed to resolve config dir: {}", e)))
+}
+
+/// Parses settings file contents
+pub fn parse_settings(contents: &str) -> Result<Settings, SwiiError> {
+    serde_json::from_str(contents)
+        .map_err(|e| SwiiError::Config(format!("Invalid {}: {}", constants::SETTINGS_FILE, e)))
+}
+
+/// Pushes the settings into the subsystems that use them
+fn apply(settings: &Settings) -> (usize, Vec<String>) {
+    let (parser_config, mut errors) = ParserConfig::from_rules(&settings.title_rules);
+    let rule_count = parser_config.rule_count();
+    let extension_lengths = settings.extension_lengths().unwrap_or_else(|error| {
+        errors.push(error);
+        None
+    });
+    editor_config::set_terminal_editors_enabled(settings.terminal_editors);
+    crate::jetbrains::set_enabled(settings.jetbrains_integration);
+    crate::browser_tabs::set_enabled(settings.browser_mode);
+    swii_core::iterm2::set_enabled(settings.iterm2_integration);
+    crate::console_capture::set_enabled(settings.capture_frontend_console);
+    crate::logger::set_filter(&settings.frontend_log);
+    crate::window_list::set_include_untitled(settings.include_untitled.unwrap_or(true));
+    crate::activation_policy::set_policy(settings.activation_policy);
+    crate::tray_title::set_enabled(settings.tray_title);
+    crate::watchlist::set_projects(&settings.watched_projects);
+    crate::window_labels::set_labels(&settings.window_labels);
+    crate::idle::set_idle_threshold_secs(
+        settings
+            .idle_threshold_secs
+            .unwrap_or(crate::idle::constants::DEFAULT_IDLE_THRESHOLD_SECS),
+    );
+    errors.extend(crate::focus_rules::set_rules(&settings.focus_rules));
+    errors.extend(crate::daily_note::set_note(settings.daily_note.as_ref()));
+    errors.extend(crate::shortcut::set_shortcut(
+        settings.panel_shortcut.as_deref(),
+    ));
+    swii_title_parser::set_config(
+        parser_config
+            .with_separators(&settings.title_separators)
+            .with_profiles(&settings.vscode_profiles)
+            .with_extensions(extension_lengths, &settings.extension_allowlist)
+            .with_command_patterns(&settings.command_patterns)
+            .with_root_markers(&settings.project_root_markers),
+    );
+    (rule_count, errors)
+}
+
+/// Reads the settings file (if any) and applies it
+pub fn load(app: &tauri::AppHandle) -> Result<SettingsStatus, SwiiError> {
+    let path = settings_path(app)?;
+    let exists = path.exists();
+
+    let settings = if exists {
+        parse_settings(&std::fs::read_to_string(&path)?)?
+    } else {
+        Settings::default()
+    };
+
+    let (title_rule_count, mut errors) = apply(&settings);
+    if let Err(e) = crate::activation_policy::apply(app) {
+        errors.push(e.to_string());
+    }
+    crate::tray_title::apply(app);
+    crate::shortcut::apply(app);
+    for error in errors.iter() {
+        warn!("{}", error);
+    }
+
+    Ok(SettingsStatus {
+        path: path.to_string_lossy().to_string(),
+        exists,
+        title_rule_count,
+        errors,
+    })
+}
+
+/// `contents` of a settings file with `key` set to `value`; the other keys
+/// stay as they were written
+fn with_value(
+    contents: Option<&str>,
+    key: &str,
+    value: serde_json::Value,
+) -> Result<String, SwiiError> {
+    let mut settings = match contents {
+        Some(contents) => serde_json::from_str(contents).map_err(|e| {
+            SwiiError::Config(format!("Invalid {}: {}", constants::SETTINGS_FILE, e))
+        })?,
+        None => serde_json::json!({}),
+    };
+    let object = settings.as_object_mut().ok_or_else(|| {
+        SwiiError::Config(format!("{} isn't a JSON object", constants::SETTINGS_FILE))
+    })?;
+    object.insert(key.to_string(), value);
+    serde_json::to_string_pretty(&settings)
+        .map_err(|e| SwiiError::Internal(format!("Failed to serialize settings: {}", e)))
+}
+
+/// Saves a single setting, creating the settings file if needed
+pub fn store_value(
+    app: &tauri::AppHandle,
+    key: &str,
+    value: serde_json::Value,
+) -> Result<(), SwiiError> {
+    let path = settings_path(app)?;
+    let contents = if path.exists() {
+        Some(std::fs::read_to_string(&path)?)
+    } else {
+        None
+    };
+    let updated = with_value(contents.as_deref(), key, value)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, updated)?;
+    Ok(())
+}
+
+/// Loads settings during startup, falling back to defaults on error
+pub fn init(app: &tauri::AppHandle) {
+    match load(app) {
+        Ok(status) => info!(
+            "Loaded settings from {} ({} title rules)",
+            status.path, status.title_rule_count
+        ),
+        Err(e) => warn!("Using default settings: {}", e),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn reload_settings(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("reload_settings", args, |ctx| {
+        let status = load(&app)?;
+        ctx.logger.info(&format!(
+            "Reloaded settings from {} ({} title rules, {} errors)",
+            status.path,
+            status.title_rule_count,
+            status.errors.len()
+        ));
+        Ok(status)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_settings_defaults() {
+        let settings = parse_settings("{}").unwrap();
+        assert!(settings.title_rules.is_empty());
+        assert!(settings.title_separators.is_empty());
+        assert!(settings.vscode_profiles.is_empty());
+    }
+
+    #[test]
+    fn test_parse_settings_title_rules() {
+        let settings = parse_settings(
+            r#"{ "title_rules": [{ "editor": "Nova", "pattern": "^(?P<project>.+)$" }] }"#,
+        )
+        .unwrap();
+        assert_eq!(settings.title_rules.len(), 1);
+        assert_eq!(settings.title_rules[0].editor.as_deref(), Some("Nova"));
+    }
+
+    #[test]
+    fn test_parse_settings_focus_rules() {
+        let settings = parse_settings(
+            r#"{ "focus_rules": [
+                { "name": "desk", "when": { "kind": "display_connected", "name": "DELL" }, "focus_project": "api" },
+                { "name": "standup", "when": { "kind": "daily_at", "at": "09:30" }, "focus_project": "notes" }
+            ] }"#,
+        )
+        .unwrap();
+        assert_eq!(settings.focus_rules.len(), 2);
+        assert_eq!(
+            settings.focus_rules[1].when,
+            crate::focus_rules::RuleTrigger::DailyAt {
+                at: "09:30".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_settings_frontend_log() {
+        let settings = parse_settings(
+            r#"{ "frontend_log": { "min_level": "warn", "deny_tags": ["console:*"] } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            settings.frontend_log.min_level,
+            Some(crate::logger::LogLevel::Warn)
+        );
+        assert_eq!(settings.frontend_log.deny_tags, vec!["console:*"]);
+        assert!(settings.frontend_log.allow_tags.is_empty());
+    }
+
+    #[test]
+    fn test_parse_settings_panel_shortcut() {
+        let settings = parse_settings(r#"{ "panel_shortcut": "Command+Shift+Space" }"#).unwrap();
+        assert_eq!(
+            settings.panel_shortcut.as_deref(),
+            Some("Command+Shift+Space")
+        );
+        assert_eq!(Settings::default().panel_shortcut, None);
+    }
+
+    #[test]
+    fn test_extension_lengths() {
+        let settings = parse_settings(r#"{ "max_extension_length": 6 }"#).unwrap();
+        assert_eq!(settings.extension_lengths(), Ok(Some((1, 6))));
+
+        let settings =
+            parse_settings(r#"{ "min_extension_length": 5, "max_extension_length": 3 }"#).unwrap();
+        assert!(settings.extension_lengths().is_err());
+
+        assert_eq!(Settings::default().extension_lengths(), Ok(None));
+    }
+
+    #[test]
+    fn test_with_value_keeps_other_keys() {
+        let updated = with_value(
+            Some(r#"{ "browser_mode": true }"#),
+            "activation_policy",
+            serde_json::json!("regular"),
+        )
+        .unwrap();
+        let settings = parse_settings(&updated).unwrap();
+        assert!(settings.browser_mode);
+        assert_eq!(settings.activation_policy, ActivationPolicy::Regular);
+
+        assert!(with_value(None, "browser_mode", serde_json::json!(true)).is_ok());
+        assert!(with_value(Some("[]"), "browser_mode", serde_json::json!(true)).is_err());
+    }
+
+    #[test]
+    fn test_parse_settings_reports_config_error() {
+        let err = parse_settings("{ not json").unwrap_err();
+        assert_eq!(err.code(), "config");
+    }
+}
@@ -0,0 +1,373 @@
+//! The global shortcut that toggles the panel.
+//!
+//! The shortcut is registered from Rust, so when it's pressed the window list
+//! can be sent along with the event: the latest listing if it's recent,
+//! otherwise a new one. The panel then shows windows without asking for them
+//! first. Key repeats while the shortcut is held are dropped, and releases
+//! aren't forwarded.
+//!
+//! `panel_shortcut` in the settings replaces the default. It's checked before
+//! registering, and when it's malformed or another application already holds
+//! it, the default is registered instead. Every registration is reported as a
+//! `shortcut-status-changed` event, and `get_shortcut_status` returns the
+//! latest one for a panel that wasn't listening yet.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+
+pub mod constants {
+    use std::time::Duration;
+
+    /// Shortcut that toggles the panel unless `panel_shortcut` is set
+    pub const PANEL_SHORTCUT: &str = "Command+Y";
+
+    /// Modifier names accepted in a shortcut, compared case-insensitively
+    pub const MODIFIERS: &[&str] = &[
+        "command",
+        "cmd",
+        "super",
+        "control",
+        "ctrl",
+        "commandorcontrol",
+        "commandorctrl",
+        "cmdorctrl",
+        "cmdorcontrol",
+        "shift",
+        "alt",
+        "option",
+    ];
+
+    /// Event emitted to the frontend when the shortcut is pressed
+    pub const SHORTCUT_PRESSED_EVENT: &str = "global-shortcut-pressed";
+
+    /// Event emitted to the frontend after the shortcut is registered or
+    /// fails to be
+    pub const SHORTCUT_STATUS_EVENT: &str = "shortcut-status-changed";
+
+    /// Oldest listing sent with the event instead of listing again
+    pub const LISTING_MAX_AGE: Duration = Duration::from_secs(2);
+}
+
+/// Payload of the shortcut event
+#[derive(Debug, Clone, Serialize, specta::Type)]
+pub struct ShortcutPressed {
+    pub shortcut: String,
+    /// Editor windows to show right away, `None` when listing failed
+    pub windows: Option<Vec<WindowInfo>>,
+}
+
+/// Whether the panel shortcut is registered, returned by
+/// `get_shortcut_status` and sent with `shortcut-status-changed`
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+pub struct ShortcutStatus {
+    /// Shortcut in use, or the one that was tried when none could be
+    /// registered
+    pub shortcut: String,
+    pub registered: bool,
+    /// Why the configured shortcut isn't the one in use, if it isn't
+    pub error: Option<String>,
+}
+
+/// Shortcut picked from the settings, with the reason it's the default
+/// when the configured one was rejected
+#[derive(Debug, Clone, PartialEq)]
+struct Choice {
+    shortcut: String,
+    error: Option<String>,
+}
+
+static CHOICE: Mutex<Option<Choice>> = Mutex::new(None);
+static STATUS: Mutex<Option<ShortcutStatus>> = Mutex::new(None);
+
+#[cfg(desktop)]
+static HELD: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Checks the shape of an accelerator such as `"Command+Shift+Space"`:
+/// modifiers first, then exactly one key. Returns it without stray spaces
+fn validate_shortcut(shortcut: &str) -> Result<String, String> {
+    let parts: Vec<&str> = shortcut.split('+').map(str::trim).collect();
+    let is_modifier = |part: &str| {
+        constants::MODIFIERS
+            .iter()
+            .any(|modifier| modifier.eq_ignore_ascii_case(part))
+    };
+    let Some((key, modifiers)) = parts.split_last() else {
+        return Err(format!("Invalid shortcut \"{}\"", shortcut));
+    };
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(format!("Invalid shortcut \"{}\": empty key", shortcut));
+    }
+    if is_modifier(key) {
+        return Err(format!(
+            "Invalid shortcut \"{}\": it must end with a key",
+            shortcut
+        ));
+    }
+    if modifiers.is_empty() {
+        return Err(format!(
+            "Invalid shortcut \"{}\": it needs at least one modifier",
+            shortcut
+        ));
+    }
+    if let Some(part) = modifiers.iter().find(|part| !is_modifier(part)) {
+        return Err(format!(
+            "Invalid shortcut \"{}\": \"{}\" isn't a modifier",
+            shortcut, part
+        ));
+    }
+    Ok(parts.join("+"))
+}
+
+/// Picks the shortcut to register on the next [`apply`]: the configured one
+/// if it's valid, otherwise the default. Returns the problem with the
+/// configured one, if any
+pub fn set_shortcut(configured: Option<&str>) -> Option<String> {
+    let choice = match configured.map(validate_shortcut) {
+        Some(Ok(shortcut)) => Choice {
+            shortcut,
+            error: None,
+        },
+        Some(Err(e)) => Choice {
+            shortcut: constants::PANEL_SHORTCUT.to_string(),
+            error: Some(format!("{}, using {}", e, constants::PANEL_SHORTCUT)),
+        },
+        None => Choice {
+            shortcut: constants::PANEL_SHORTCUT.to_string(),
+            error: None,
+        },
+    };
+    let error = choice.error.clone();
+    *CHOICE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(choice);
+    error
+}
+
+fn choice() -> Choice {
+    CHOICE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or(Choice {
+            shortcut: constants::PANEL_SHORTCUT.to_string(),
+            error: None,
+        })
+}
+
+/// Registers the chosen shortcut with `register`, falling back to the
+/// default when that fails
+fn register_first(
+    choice: Choice,
+    mut register: impl FnMut(&str) -> Result<(), String>,
+) -> ShortcutStatus {
+    let mut errors: Vec<String> = choice.error.into_iter().collect();
+    let mut candidates = vec![choice.shortcut.clone()];
+    if choice.shortcut != constants::PANEL_SHORTCUT {
+        candidates.push(constants::PANEL_SHORTCUT.to_string());
+    }
+    for shortcut in candidates {
+        match register(&shortcut) {
+            Ok(()) => {
+                return ShortcutStatus {
+                    shortcut,
+                    registered: true,
+                    error: (!errors.is_empty()).then(|| errors.join("; ")),
+                }
+            }
+            Err(e) => errors.push(format!("Failed to register {}: {}", shortcut, e)),
+        }
+    }
+    ShortcutStatus {
+        shortcut: choice.shortcut,
+        registered: false,
+        error: Some(errors.join("; ")),
+    }
+}
+
+/// The latest registration, or the chosen shortcut if none was tried yet
+fn status() -> ShortcutStatus {
+    STATUS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or_else(|| ShortcutStatus {
+            shortcut: choice().shortcut,
+            registered: false,
+            error: None,
+        })
+}
+
+/// Sends the window list to the frontend when the shortcut is pressed
+#[cfg(desktop)]
+fn on_pressed(
+    app: &tauri::AppHandle,
+    shortcut: &tauri_plugin_global_shortcut::Shortcut,
+    event: tauri_plugin_global_shortcut::ShortcutEvent,
+) {
+    use std::sync::atomic::Ordering;
+    use tauri::Emitter;
+    use tauri_plugin_global_shortcut::ShortcutState;
+    use tracing::{info, warn};
+
+    if event.state() == ShortcutState::Released {
+        HELD.store(false, Ordering::Relaxed);
+        return;
+    }
+    if HELD.swap(true, Ordering::Relaxed) {
+        return;
+    }
+
+    // Listing can take a while; keep it off the event loop
+    let app = app.clone();
+    let shortcut = shortcut.to_string();
+    std::thread::spawn(move || {
+        let windows = crate::window_list::recent_windows(constants::LISTING_MAX_AGE)
+            .map_err(|e| warn!("Sending the shortcut without windows: {}", e))
+            .ok();
+        info!(
+            "Shortcut {} pressed with {} windows",
+            shortcut,
+            windows.as_ref().map_or(0, Vec::len)
+        );
+        let payload = ShortcutPressed { shortcut, windows };
+        if let Err(e) = app.emit(constants::SHORTCUT_PRESSED_EVENT, payload) {
+            warn!("Failed to emit shortcut event: {}", e);
+        }
+    });
+}
+
+/// Registers the chosen shortcut in place of the current one and reports
+/// the outcome
+#[cfg(desktop)]
+pub fn apply(app: &tauri::AppHandle) {
+    use tauri::Emitter;
+    use tauri_plugin_global_shortcut::GlobalShortcutExt;
+    use tracing::{info, warn};
+
+    let choice = choice();
+    let mut current = STATUS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let registered = current
+        .as_ref()
+        .filter(|status| status.registered)
+        .map(|status| status.shortcut.clone());
+
+    let status = if registered.as_deref() == Some(choice.shortcut.as_str()) {
+        ShortcutStatus {
+            shortcut: choice.shortcut,
+            registered: true,
+            error: choice.error,
+        }
+    } else {
+        if let Some(shortcut) = &registered {
+            if let Err(e) = app.global_shortcut().unregister(shortcut.as_str()) {
+                warn!("Failed to unregister {}: {}", shortcut, e);
+            }
+        }
+        register_first(choice, |shortcut| {
+            app.global_shortcut()
+                .on_shortcut(shortcut, on_pressed)
+                .map_err(|e| e.to_string())
+        })
+    };
+    if current.as_ref() == Some(&status) {
+        return;
+    }
+
+    match (status.registered, &status.error) {
+        (true, None) => info!("Registered shortcut {}", status.shortcut),
+        (true, Some(e)) => warn!("Registered shortcut {}: {}", status.shortcut, e),
+        (false, e) => warn!(
+            "No shortcut registered: {}",
+            e.as_deref().unwrap_or_default()
+        ),
+    }
+    if let Err(e) = app.emit(constants::SHORTCUT_STATUS_EVENT, status.clone()) {
+        warn!("Failed to emit shortcut status: {}", e);
+    }
+    *current = Some(status);
+}
+
+#[cfg(not(desktop))]
+pub fn apply(_app: &tauri::AppHandle) {}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_shortcut_status(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<ShortcutStatus> {
+    crate::command_wrapper::create_typed_command("get_shortcut_status", args, |ctx| {
+        let status = status();
+        ctx.logger.info(&format!(
+            "Shortcut {} is {}",
+            status.shortcut,
+            if status.registered {
+                "registered"
+            } else {
+                "not registered"
+            }
+        ));
+        Ok(status)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_shortcut() {
+        assert_eq!(
+            validate_shortcut("Command + Shift + Space").as_deref(),
+            Ok("Command+Shift+Space")
+        );
+        assert_eq!(
+            validate_shortcut("CmdOrCtrl+Y").as_deref(),
+            Ok("CmdOrCtrl+Y")
+        );
+        assert!(validate_shortcut("").is_err());
+        assert!(validate_shortcut("Space").is_err());
+        assert!(validate_shortcut("Command+Shift").is_err());
+        assert!(validate_shortcut("Command++Y").is_err());
+        assert!(validate_shortcut("Hyper+Y").is_err());
+        assert!(validate_shortcut("Y+Command").is_err());
+    }
+
+    #[test]
+    fn test_register_first_falls_back_to_default() {
+        let choice = Choice {
+            shortcut: "Command+Shift+Space".to_string(),
+            error: None,
+        };
+
+        let status = register_first(choice.clone(), |_| Ok(()));
+        assert_eq!(status.shortcut, "Command+Shift+Space");
+        assert!(status.registered);
+        assert_eq!(status.error, None);
+
+        // Another application holds the configured shortcut
+        let status = register_first(choice.clone(), |shortcut| {
+            if shortcut == constants::PANEL_SHORTCUT {
+                Ok(())
+            } else {
+                Err("already registered".to_string())
+            }
+        });
+        assert_eq!(status.shortcut, constants::PANEL_SHORTCUT);
+        assert!(status.registered);
+        assert!(status.error.unwrap().contains("Command+Shift+Space"));
+
+        let status = register_first(choice, |_| Err("already registered".to_string()));
+        assert_eq!(status.shortcut, "Command+Shift+Space");
+        assert!(!status.registered);
+        assert!(status.error.is_some());
+    }
+}
@@ -0,0 +1,324 @@
+//! Recording and replaying of window-list snapshots.
+//!
+//! While recording, every successful `list_editor_windows` result is appended
+//! to a JSON Lines file. A recorded file can later be replayed as the data
+//! source of `list_editor_windows`, one snapshot per call, so intermittent
+//! correlation bugs can be reproduced deterministically.
+
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use swii_core::types::WindowInfo;
+use tauri::Manager;
+
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Directory (inside the app data dir) holding recorded sessions
+    pub const SNAPSHOT_DIR: &str = "snapshots";
+
+    /// File extension of recorded sessions
+    pub const SNAPSHOT_EXTENSION: &str = "jsonl";
+}
+
+/// One recorded enumeration result
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WindowSnapshot {
+    pub recorded_at: String,
+    pub windows: Vec<WindowInfo>,
+}
+
+/// Current snapshot mode
+enum SnapshotMode {
+    Idle,
+    Recording {
+        path: PathBuf,
+        count: usize,
+    },
+    Replaying {
+        path: PathBuf,
+        snapshots: Vec<WindowSnapshot>,
+        cursor: usize,
+    },
+}
+
+static MODE: Mutex<SnapshotMode> = Mutex::new(SnapshotMode::Idle);
+
+/// Status payload returned by the snapshot commands
+#[derive(Debug, Serialize, specta::Type)]
+pub struct SnapshotStatus {
+    pub mode: &'static str,
+    pub path: Option<String>,
+    pub snapshot_count: usize,
+    pub cursor: Option<usize>,
+}
+
+fn status_of(mode: &SnapshotMode) -> SnapshotStatus {
+    match mode {
+        SnapshotMode::Idle => SnapshotStatus {
+            mode: "idle",
+            path: None,
+            snapshot_count: 0,
+            cursor: None,
+        },
+        SnapshotMode::Recording { path, count } => SnapshotStatus {
+            mode: "recording",
+            path: Some(path.to_string_lossy().to_string()),
+            snapshot_count: *count,
+            cursor: None,
+        },
+        SnapshotMode::Replaying {
+            path,
+            snapshots,
+            cursor,
+        } => SnapshotStatus {
+            mode: "replaying",
+            path: Some(path.to_string_lossy().to_string()),
+            snapshot_count: snapshots.len(),
+            cursor: Some(*cursor),
+        },
+    }
+}
+
+fn lock_mode() -> std::sync::MutexGuard<'static, SnapshotMode> {
+    MODE.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Parses a recorded JSON Lines file, skipping blank lines. Windows recorded
+/// by older builds are upgraded to the current layout.
+pub fn parse_snapshot_file(contents: &str) -> Result<Vec<WindowSnapshot>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let snapshot = serde_json::from_str::<WindowSnapshot>(line)
+                .map_err(|e| format!("Invalid snapshot on line {}: {}", i + 1, e))?;
+            let windows = snapshot
+                .windows
+                .into_iter()
+                .map(WindowInfo::upgrade)
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("Invalid snapshot on line {}: {}", i + 1, e))?;
+            Ok(WindowSnapshot {
+                windows,
+                ..snapshot
+            })
+        })
+        .collect()
+}
+
+/// Appends a snapshot to the given file
+fn append_snapshot(path: &Path, windows: &[WindowInfo]) -> Result<(), String> {
+    let snapshot = WindowSnapshot {
+        recorded_at: chrono::Utc::now().to_rfc3339(),
+        windows: windows.to_vec(),
+    };
+    let line = serde_json::to_string(&snapshot).map_err(|e| e.to_string())?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write snapshot: {}", e))
+}
+
+/// Records an enumeration result if a recording is in progress
+pub fn record_if_active(windows: &[WindowInfo]) {
+    let mut mode = lock_mode();
+    if let SnapshotMode::Recording { path, count } = &mut *mode {
+        match append_snapshot(path, windows) {
+            Ok(()) => *count += 1,
+            Err(e) => tracing::warn!("Failed to record window snapshot: {}", e),
+        }
+    }
+}
+
+/// Returns the next replayed snapshot if replay is active
+///
+/// Snapshots are served in recorded order; once the end is reached the last
+/// snapshot keeps being returned so the UI stays stable.
+pub fn next_replay_snapshot() -> Option<Vec<WindowInfo>> {
+    let mut mode = lock_mode();
+    if let SnapshotMode::Replaying {
+        snapshots, cursor, ..
+    } = &mut *mode
+    {
+        let index = (*cursor).min(snapshots.len().checked_sub(1)?);
+        if *cursor < snapshots.len() {
+            *cursor += 1;
+        }
+        return Some(snapshots[index].windows.clone());
+    }
+    None
+}
+
+fn default_recording_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?
+        .join(constants::SNAPSHOT_DIR);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let file_name = format!(
+        "session-{}.{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        constants::SNAPSHOT_EXTENSION
+    );
+    Ok(dir.join(file_name))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_snapshot_recording(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("start_snapshot_recording", args, |ctx| {
+        let path = match ctx.parameters.get("path").and_then(|v| v.as_str()) {
+            Some(path) => PathBuf::from(path),
+            None => default_recording_path(&app)?,
+        };
+
+        ctx.logger
+            .info(&format!("Recording window snapshots to {}", path.display()));
+
+        let mut mode = lock_mode();
+        *mode = SnapshotMode::Recording { path, count: 0 };
+        Ok(status_of(&mode))
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_snapshot_replay(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("start_snapshot_replay", args, |ctx| {
+        let path = ctx
+            .parameters
+            .get("path")
+            .and_then(|v| v.as_str())
+            .map(PathBuf::from)
+            .ok_or(SwiiError::InvalidParameter("path"))?;
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let snapshots = parse_snapshot_file(&contents)?;
+        if snapshots.is_empty() {
+            return Err(format!("No snapshots found in {}", path.display()).into());
+        }
+
+        ctx.logger.info(&format!(
+            "Replaying {} snapshots from {}",
+            snapshots.len(),
+            path.display()
+        ));
+
+        let mut mode = lock_mode();
+        *mode = SnapshotMode::Replaying {
+            path,
+            snapshots,
+            cursor: 0,
+        };
+        Ok(status_of(&mode))
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_snapshots(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("stop_snapshots", args, |ctx| {
+        let mut mode = lock_mode();
+        let previous = status_of(&mode);
+        *mode = SnapshotMode::Idle;
+        ctx.logger
+            .info(&format!("Stopped snapshot {} mode", previous.mode));
+        Ok(previous)
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_snapshot_status(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("get_snapshot_status", args, |_ctx| {
+        Ok(status_of(&lock_mode()))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(number: u32, project: &str) -> WindowInfo {
+        WindowInfo::builder("Zed", 100, number)
+            .window_name(Some(format!("{} — main.rs", project)))
+            .project(Some(project))
+            .active_editor_tab(Some("main.rs"))
+            .build()
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("snapshots.jsonl");
+
+        append_snapshot(&path, &[window(1, "swii")]).unwrap();
+        append_snapshot(&path, &[window(1, "swii"), window(2, "api")]).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let snapshots = parse_snapshot_file(&contents).unwrap();
+
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].windows.len(), 1);
+        assert_eq!(snapshots[1].windows[1].project, Some("api".to_string()));
+    }
+
+    #[test]
+    fn test_parse_snapshot_file_reports_bad_line() {
+        let err = parse_snapshot_file("\n{not json}\n").unwrap_err();
+        assert!(err.contains("line 2"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_replay_serves_snapshots_in_order_then_sticks() {
+        *lock_mode() = SnapshotMode::Replaying {
+            path: PathBuf::from("test.jsonl"),
+            snapshots: vec![
+                WindowSnapshot {
+                    recorded_at: "t0".to_string(),
+                    windows: vec![window(1, "first")],
+                },
+                WindowSnapshot {
+                    recorded_at: "t1".to_string(),
+                    windows: vec![window(1, "second")],
+                },
+            ],
+            cursor: 0,
+        };
+
+        let project = |windows: Vec<WindowInfo>| windows[0].project.clone().unwrap();
+        assert_eq!(project(next_replay_snapshot().unwrap()), "first");
+        assert_eq!(project(next_replay_snapshot().unwrap()), "second");
+        assert_eq!(project(next_replay_snapshot().unwrap()), "second");
+
+        *lock_mode() = SnapshotMode::Idle;
+        assert!(next_replay_snapshot().is_none());
+    }
+}
@@ -0,0 +1,476 @@
+//! Folders of Sublime Text windows from Sublime's session file.
+//!
+//! Sublime titles usually show only the active file, so guessing the project
+//! from its path often lands on the wrong directory. Sublime saves every open
+//! window, with its project file or folders and its open files, in
+//! `Local/Session.sublime_session` (and `Auto Save Session.sublime_session`
+//! with hot exit) under its data dir. Windows are matched to those entries by
+//! the folder in the title, the project name, or the active file.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::Deserialize;
+use swii_title_parser::{EditorKind, ProjectSource};
+use tracing::debug;
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// Data directory names of Sublime Text 4 and 3
+    pub const DATA_DIR_NAMES: &[&str] = &["Sublime Text", "Sublime Text 3"];
+
+    /// Data directory names of Sublime Text 4 and 3 on Linux
+    pub const LINUX_DATA_DIR_NAMES: &[&str] = &["sublime-text", "sublime-text-3"];
+
+    /// Session files inside the data dir; the newest one wins
+    pub const SESSION_FILES: &[&str] = &[
+        "Local/Session.sublime_session",
+        "Local/Auto Save Session.sublime_session",
+    ];
+
+    /// How long windows read from the session are reused
+    pub const SESSION_CACHE_TTL_SECS: u64 = 5;
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Session {
+    #[serde(default)]
+    windows: Vec<SessionWindow>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SessionWindow {
+    /// Path of the window's `.sublime-project` file, if it has one
+    project: Option<String>,
+    #[serde(default)]
+    folders: Vec<ProjectFolder>,
+    #[serde(default)]
+    buffers: Vec<SessionBuffer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectFolder {
+    path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionBuffer {
+    file: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectFile {
+    #[serde(default)]
+    folders: Vec<ProjectFolder>,
+}
+
+/// A window from the session with its folders resolved to absolute paths
+#[derive(Debug, Clone, PartialEq)]
+struct SublimeWindow {
+    /// Project file name without extension, which Sublime shows in titles
+    project_name: Option<String>,
+    folders: Vec<String>,
+    files: Vec<String>,
+}
+
+impl SublimeWindow {
+    /// The folder containing `file`, or the first folder
+    fn folder_for(&self, file: Option<&str>) -> Option<&String> {
+        file.and_then(|file| {
+            self.folders
+                .iter()
+                .find(|folder| Path::new(file).starts_with(folder.as_str()))
+        })
+        .or_else(|| self.folders.first())
+    }
+}
+
+fn path_name(path: &str) -> Option<String> {
+    Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+}
+
+/// Sublime's data directories for the current platform
+fn data_dirs() -> Vec<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let (base, names) = if cfg!(target_os = "macos") {
+        (
+            home.map(|home| home.join("Library/Application Support")),
+            constants::DATA_DIR_NAMES,
+        )
+    } else if cfg!(target_os = "windows") {
+        (
+            std::env::var_os("APPDATA").map(PathBuf::from),
+            constants::DATA_DIR_NAMES,
+        )
+    } else {
+        (
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| home.map(|home| home.join(".config"))),
+            constants::LINUX_DATA_DIR_NAMES,
+        )
+    };
+    base.map(|base| names.iter().map(|name| base.join(name)).collect())
+        .unwrap_or_default()
+}
+
+/// Turns Sublime's relaxed JSON into plain JSON by dropping comments and
+/// trailing commas, leaving string contents untouched
+fn strip_relaxed_json(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.next_if(|&next| next != '\n').is_some() {}
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = '\0';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            ']' | '}' => {
+                let trimmed = output.trim_end().len();
+                if output[..trimmed].ends_with(',') {
+                    output.truncate(trimmed - 1);
+                }
+                output.push(c);
+            }
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+/// Reads the folders of a `.sublime-project` file, relative to the file
+fn read_project_folders(project: &Path) -> Vec<String> {
+    let project_file: ProjectFile = std::fs::read_to_string(project)
+        .ok()
+        .and_then(|json| serde_json::from_str(&strip_relaxed_json(&json)).ok())
+        .unwrap_or_default();
+    let dir = project.parent().unwrap_or(Path::new(""));
+    project_file
+        .folders
+        .into_iter()
+        .map(|folder| dir.join(folder.path).to_string_lossy().to_string())
+        .collect()
+}
+
+/// Windows of a session file, with project files read from disk
+fn parse_session(json: &str) -> Vec<SublimeWindow> {
+    let session: Session = match serde_json::from_str(&strip_relaxed_json(json)) {
+        Ok(session) => session,
+        Err(e) => {
+            debug!("Failed to parse Sublime session: {}", e);
+            return Vec::new();
+        }
+    };
+
+    session
+        .windows
+        .into_iter()
+        .map(|window| {
+            let folders = match &window.project {
+                Some(project) => read_project_folders(Path::new(project)),
+                None => window.folders.into_iter().map(|f| f.path).collect(),
+            };
+            SublimeWindow {
+                project_name: window.project.as_deref().and_then(|project| {
+                    Path::new(project)
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string())
+                }),
+                folders,
+                files: window.buffers.into_iter().filter_map(|b| b.file).collect(),
+            }
+        })
+        .filter(|window| !window.folders.is_empty())
+        .collect()
+}
+
+/// Windows of the most recently written session file, cached for
+/// [`constants::SESSION_CACHE_TTL_SECS`]
+fn session_windows() -> Vec<SublimeWindow> {
+    static CACHE: Mutex<Option<(Instant, Vec<SublimeWindow>)>> = Mutex::new(None);
+
+    let mut cache = CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let ttl = Duration::from_secs(constants::SESSION_CACHE_TTL_SECS);
+    if let Some((read_at, windows)) = cache.as_ref() {
+        if read_at.elapsed() < ttl {
+            return windows.clone();
+        }
+    }
+
+    let newest = data_dirs()
+        .iter()
+        .flat_map(|dir| {
+            constants::SESSION_FILES
+                .iter()
+                .map(move |file| dir.join(file))
+        })
+        .filter_map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((modified, path))
+        })
+        .max_by_key(|(modified, _): &(SystemTime, PathBuf)| *modified);
+    let windows = newest
+        .and_then(|(_, path)| std::fs::read_to_string(path).ok())
+        .map(|json| parse_session(&json))
+        .unwrap_or_default();
+
+    *cache = Some((Instant::now(), windows.clone()));
+    windows
+}
+
+/// Fills in project paths of Sublime Text windows from Sublime's session
+pub fn apply_session_folders(windows: &mut [WindowInfo]) {
+    if !windows
+        .iter()
+        .any(|window| window.editor_kind == EditorKind::Sublime)
+    {
+        return;
+    }
+    let home = std::env::var("HOME").ok();
+    merge_session_windows(windows, &session_windows(), home.as_deref());
+}
+
+/// Files the session lists as open in a Sublime Text window
+pub fn open_files(window: &WindowInfo) -> Vec<String> {
+    if window.editor_kind != EditorKind::Sublime {
+        return Vec::new();
+    }
+    let home = std::env::var("HOME").ok();
+    let sessions = session_windows();
+    match_window(window, &sessions, home.as_deref())
+        .map(|(session, _)| session.files.clone())
+        .unwrap_or_default()
+}
+
+/// Finds the session window and folder a Sublime window shows
+fn match_window<'a>(
+    window: &WindowInfo,
+    sessions: &'a [SublimeWindow],
+    home: Option<&str>,
+) -> Option<(&'a SublimeWindow, &'a String)> {
+    // A folder in the title, as an absolute path or relative to home
+    if let Some(title) = window.window_name.as_deref() {
+        let in_title = |folder: &str| {
+            let tilde = home
+                .filter(|home| !home.is_empty() && *home != "/")
+                .and_then(|home| folder.strip_prefix(home))
+                .map(|rest| format!("~{}", rest));
+            [Some(folder.to_string()), tilde]
+                .into_iter()
+                .flatten()
+                .any(|form| {
+                    title
+                        .match_indices(form.as_str())
+                        .any(|(i, _)| title[i + form.len()..].starts_with(['/', '\\']))
+                })
+        };
+        let by_title = sessions
+            .iter()
+            .flat_map(|session| session.folders.iter().map(move |f| (session, f)))
+            .filter(|(_, folder)| in_title(folder))
+            .max_by_key(|(_, folder)| folder.len());
+        if by_title.is_some() {
+            return by_title;
+        }
+    }
+
+    let unique =
+        |mut found: Vec<&'a SublimeWindow>| (found.len() == 1).then(|| found.pop()).flatten();
+
+    // The project name Sublime shows for windows with a project file
+    if let Some(session) = unique(
+        sessions
+            .iter()
+            .filter(|session| window.project.is_some() && session.project_name == window.project)
+            .collect(),
+    ) {
+        return Some((session, session.folder_for(None)?));
+    }
+
+    // The active file, when only one window has a file of that name open
+    let tab = window.active_editor_tab.as_deref()?;
+    let file_named = |session: &SublimeWindow| {
+        session
+            .files
+            .iter()
+            .find(|file| path_name(file).as_deref() == Some(tab))
+            .cloned()
+    };
+    let session = unique(
+        sessions
+            .iter()
+            .filter(|session| file_named(session).is_some())
+            .collect(),
+    )?;
+    Some((session, session.folder_for(file_named(session).as_deref())?))
+}
+
+fn merge_session_windows(
+    windows: &mut [WindowInfo],
+    sessions: &[SublimeWindow],
+    home: Option<&str>,
+) {
+    for window in windows.iter_mut() {
+        if window.editor_kind != EditorKind::Sublime || window.auxiliary {
+            continue;
+        }
+
+        if let Some((session, folder)) = match_window(window, sessions, home) {
+            window.project = session.project_name.clone().or_else(|| path_name(folder));
+            window.project_path = Some(folder.clone());
+            if session.folders.len() > 1 {
+                window.worktrees = session
+                    .folders
+                    .iter()
+                    .filter_map(|f| path_name(f))
+                    .collect();
+            }
+            window.project_source = ProjectSource::EditorSession;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(title: &str, project: Option<&str>, tab: Option<&str>) -> WindowInfo {
+        WindowInfo::builder("Sublime Text", 1, 1)
+            .window_name(Some(title))
+            .project(project)
+            .active_editor_tab(tab)
+            .build()
+    }
+
+    fn session(project_name: Option<&str>, folders: &[&str], files: &[&str]) -> SublimeWindow {
+        SublimeWindow {
+            project_name: project_name.map(str::to_string),
+            folders: folders.iter().map(|f| f.to_string()).collect(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_strip_relaxed_json() {
+        let json = r#"{
+            // folders of the project
+            "folders": [
+                { "path": "src", /* main */ },
+                { "path": "http://example.com//x", },
+            ],
+        }"#;
+        let value: serde_json::Value = serde_json::from_str(&strip_relaxed_json(json)).unwrap();
+        assert_eq!(value["folders"][0]["path"], "src");
+        assert_eq!(value["folders"][1]["path"], "http://example.com//x");
+    }
+
+    #[test]
+    fn test_parse_session() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let project = dir.join("billing.sublime-project");
+        std::fs::write(
+            &project,
+            r#"{ "folders": [{ "path": "api" }, { "path": "web", },] }"#,
+        )
+        .unwrap();
+
+        let json = format!(
+            r#"{{
+                "version": 4,
+                "windows": [
+                    {{
+                        "project": "{}",
+                        "buffers": [{{ "file": "/tmp/a.rs" }}, {{ "contents": "scratch" }}]
+                    }},
+                    {{ "folders": [{{ "path": "/Users/dev/notes" }}], "buffers": [] }},
+                    {{ "buffers": [{{ "file": "/tmp/loose.txt" }}] }}
+                ]
+            }}"#,
+            project.display()
+        );
+        let windows = parse_session(&json);
+
+        assert_eq!(
+            windows,
+            vec![
+                session(
+                    Some("billing"),
+                    &[
+                        &dir.join("api").to_string_lossy(),
+                        &dir.join("web").to_string_lossy()
+                    ],
+                    &["/tmp/a.rs"]
+                ),
+                session(None, &["/Users/dev/notes"], &[]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_session_windows() {
+        let sessions = [
+            session(
+                Some("billing"),
+                &["/Users/dev/billing/api", "/Users/dev/billing/web"],
+                &["/Users/dev/billing/web/app.ts"],
+            ),
+            session(None, &["/Users/dev/notes"], &["/Users/dev/notes/todo.md"]),
+        ];
+        let mut windows = vec![
+            window(
+                "~/billing/web/app.ts (billing) - Sublime Text",
+                Some("billing"),
+                Some("app.ts"),
+            ),
+            window("/Users/dev/notes/todo.md - Sublime Text", Some("dev"), None),
+            window("todo.md - Sublime Text", None, Some("todo.md")),
+            window("other.md - Sublime Text", None, Some("other.md")),
+        ];
+        merge_session_windows(&mut windows, &sessions, Some("/Users/dev"));
+
+        assert_eq!(windows[0].project.as_deref(), Some("billing"));
+        assert_eq!(
+            windows[0].project_path.as_deref(),
+            Some("/Users/dev/billing/web")
+        );
+        assert_eq!(windows[0].worktrees, vec!["api", "web"]);
+        assert_eq!(windows[0].project_source, ProjectSource::EditorSession);
+        assert_eq!(windows[1].project.as_deref(), Some("notes"));
+        assert_eq!(windows[1].project_path.as_deref(), Some("/Users/dev/notes"));
+        assert_eq!(windows[2].project_path.as_deref(), Some("/Users/dev/notes"));
+        assert_eq!(windows[3].project_path, None);
+        assert_eq!(windows[3].project_source, ProjectSource::Title);
+    }
+}
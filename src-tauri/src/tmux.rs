@@ -0,0 +1,175 @@
+//! Projects of terminal windows hosting tmux.
+//!
+//! With `set-titles` on, tmux titles its terminal window after the attached
+//! session. The session name is often not a project name (unnamed sessions
+//! are just numbered), so the tmux server is asked for the current path of
+//! the session's active pane and the project root around it.
+
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use swii_title_parser::{
+    find_project_root_from_dir, is_default_tmux_session_name, tmux_session_from_title, EditorKind,
+    ProjectSource,
+};
+use tracing::debug;
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// Where to look for the tmux binary; apps started from the Dock or
+    /// Finder don't inherit the shell's `PATH`
+    pub const TMUX_CANDIDATES: &[&str] = &[
+        "tmux",
+        "/opt/homebrew/bin/tmux",
+        "/usr/local/bin/tmux",
+        "/usr/bin/tmux",
+    ];
+}
+
+/// Runs tmux with `args`, trying each of [`constants::TMUX_CANDIDATES`]
+fn run_tmux(args: &[&str]) -> Option<String> {
+    for candidate in constants::TMUX_CANDIDATES {
+        match Command::new(candidate).args(args).output() {
+            Ok(Output { status, stdout, .. }) if status.success() => {
+                return Some(String::from_utf8_lossy(&stdout).to_string());
+            }
+            // No server running, or the session is gone
+            Ok(_) => return None,
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => {
+                debug!("Failed to run tmux: {}", e);
+                return None;
+            }
+        }
+    }
+    None
+}
+
+/// Names of the sessions of the running tmux server
+fn list_sessions() -> Vec<String> {
+    run_tmux(&["list-sessions", "-F", "#{session_name}"])
+        .map(|output| parse_session_names(&output))
+        .unwrap_or_default()
+}
+
+fn parse_session_names(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Current directory of the active pane of `session`
+fn pane_path(session: &str) -> Option<String> {
+    // "=name:" targets exactly that session rather than a prefix match
+    let target = format!("={}:", session);
+    run_tmux(&[
+        "display-message",
+        "-p",
+        "-t",
+        &target,
+        "#{pane_current_path}",
+    ])
+    .map(|output| output.trim().to_string())
+    .filter(|path| !path.is_empty())
+}
+
+/// Fills in projects of terminal windows titled by tmux
+pub fn apply_sessions(windows: &mut [WindowInfo]) {
+    if !windows
+        .iter()
+        .any(|window| window.editor_kind == EditorKind::Tmux)
+    {
+        return;
+    }
+    merge_sessions(windows, &list_sessions(), pane_path);
+}
+
+fn merge_sessions(
+    windows: &mut [WindowInfo],
+    sessions: &[String],
+    pane_path: impl Fn(&str) -> Option<String>,
+) {
+    for window in windows.iter_mut() {
+        if window.editor_kind != EditorKind::Tmux {
+            continue;
+        }
+        let Some(session) = window
+            .window_name
+            .as_deref()
+            .and_then(tmux_session_from_title)
+            .filter(|session| sessions.iter().any(|s| s == session))
+        else {
+            continue;
+        };
+        let Some(path) = pane_path(session) else {
+            continue;
+        };
+
+        let root = find_project_root_from_dir(&path)
+            .map(|root| root.to_string_lossy().to_string())
+            .unwrap_or(path);
+        // A session the user named is kept as the project name
+        if is_default_tmux_session_name(session) {
+            window.project = Path::new(&root)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string());
+        } else {
+            window.project = Some(session.to_string());
+        }
+        window.project_path = Some(root);
+        window.project_source = ProjectSource::Multiplexer;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(title: &str) -> WindowInfo {
+        WindowInfo::builder("iTerm2", 1, 1)
+            .window_name(Some(title))
+            .editor_kind(EditorKind::Tmux)
+            .build()
+    }
+
+    #[test]
+    fn test_parse_session_names() {
+        assert_eq!(
+            parse_session_names("0\nwork api\n\n"),
+            vec!["0", "work api"]
+        );
+    }
+
+    #[test]
+    fn test_merge_sessions() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let pane = dir.join("api");
+        std::fs::create_dir_all(&pane).unwrap();
+        std::fs::write(dir.join("api/Cargo.toml"), "").unwrap();
+        let root = dir.join("api").to_string_lossy().to_string();
+
+        let sessions = vec!["0".to_string(), "work".to_string()];
+        let mut windows = vec![
+            window("0:1:zsh - \"host\""),
+            window("work:2:nvim - \"host\""),
+            window("gone:1:zsh - \"host\""),
+        ];
+        merge_sessions(&mut windows, &sessions, |_| {
+            Some(pane.to_string_lossy().to_string())
+        });
+
+        assert_eq!(windows[0].project.as_deref(), Some("api"));
+        assert_eq!(windows[0].project_path.as_deref(), Some(root.as_str()));
+        assert_eq!(windows[0].project_source, ProjectSource::Multiplexer);
+        assert_eq!(windows[1].project.as_deref(), Some("work"));
+        assert_eq!(windows[1].project_path.as_deref(), Some(root.as_str()));
+        assert_eq!(windows[2].project_path, None);
+        assert_eq!(windows[2].project_source, ProjectSource::Title);
+    }
+}
@@ -0,0 +1,95 @@
+//! The focused editor's project next to the tray icon.
+//!
+//! With `tray_title` set, the tray item's title follows the project of the
+//! editor window in front, as reported by the window observer, so the menu
+//! bar answers "which project am I in?" at a glance. Long names are
+//! shortened, and the title is cleared when the window has no project.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(desktop)]
+use tracing::debug;
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// Id the tray icon is built with
+    pub const TRAY_ID: &str = "swii-tray";
+
+    /// Longest title shown, in characters, including the ellipsis
+    pub const MAX_TITLE_CHARS: usize = 24;
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the project title
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Title for a focused window, `None` when it has no project
+fn title_for(window: &WindowInfo) -> Option<String> {
+    let project = window
+        .project_label
+        .as_deref()
+        .or(window.project.as_deref())?;
+    if project.chars().count() <= constants::MAX_TITLE_CHARS {
+        return Some(project.to_string());
+    }
+    let shortened: String = project
+        .chars()
+        .take(constants::MAX_TITLE_CHARS - 1)
+        .collect();
+    Some(format!("{}…", shortened))
+}
+
+#[cfg(desktop)]
+fn set_title(app: &tauri::AppHandle, title: Option<String>) {
+    let Some(tray) = app.tray_by_id(constants::TRAY_ID) else {
+        return;
+    };
+    if let Err(e) = tray.set_title(title) {
+        debug!("Failed to set the tray title: {}", e);
+    }
+}
+
+#[cfg(not(desktop))]
+fn set_title(_app: &tauri::AppHandle, _title: Option<String>) {}
+
+/// Shows the project of a window that just came to the front
+pub fn on_focus(app: &tauri::AppHandle, window: Option<&WindowInfo>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    set_title(app, window.and_then(title_for));
+}
+
+/// Clears the title when the mode is off
+pub fn apply(app: &tauri::AppHandle) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        set_title(app, None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    #[test]
+    fn test_title_for() {
+        let mut window = mock_editor_windows().remove(0);
+        window.project = Some("swii".to_string());
+        window.project_label = None;
+        assert_eq!(title_for(&window).as_deref(), Some("swii"));
+
+        window.project_label = Some("a-very-long-project-name-indeed".to_string());
+        let title = title_for(&window).unwrap();
+        assert_eq!(title.chars().count(), constants::MAX_TITLE_CHARS);
+        assert!(title.ends_with('…'));
+
+        window.project = None;
+        window.project_label = None;
+        assert_eq!(title_for(&window), None);
+    }
+}
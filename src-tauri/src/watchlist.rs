@@ -0,0 +1,194 @@
+//! Notifications when watched projects open or close.
+//!
+//! `watch_project` adds a project name to `watched_projects` in the settings
+//! file. Whenever the window observer sees a window of a watched project
+//! appear or disappear, swii posts a system notification and emits a
+//! `watched-project-changed` event, e.g. while waiting for a slow editor to
+//! finish opening a checkout. Names match window projects and labels,
+//! ignoring case.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use swii_core::types::WindowInfo;
+use tauri::Emitter;
+use tracing::debug;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::window_list::WindowChanges;
+
+pub mod constants {
+    /// Event emitted to the frontend when a watched project opens or closes
+    pub const WATCHED_PROJECT_CHANGED_EVENT: &str = "watched-project-changed";
+
+    /// Settings key the watched projects are stored under
+    pub const WATCHED_PROJECTS_KEY: &str = "watched_projects";
+}
+
+/// Payload of `watched-project-changed`
+#[derive(Debug, Clone, Serialize, specta::Type)]
+pub struct WatchedProjectChanged {
+    /// The watched name that matched
+    pub project: String,
+    /// True when the window opened, false when it closed
+    pub opened: bool,
+    pub window: WindowInfo,
+}
+
+static WATCHED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn lock_watched() -> std::sync::MutexGuard<'static, Vec<String>> {
+    WATCHED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Replaces the watched projects
+pub fn set_projects(projects: &[String]) {
+    *lock_watched() = projects.to_vec();
+}
+
+/// Whether `project` is watched, ignoring case
+pub fn is_watched(project: &str) -> bool {
+    lock_watched()
+        .iter()
+        .any(|watched| watched.eq_ignore_ascii_case(project))
+}
+
+/// The watched name `window` shows, if any
+fn watched_project<'a>(window: &WindowInfo, watched: &'a [String]) -> Option<&'a String> {
+    if !window.has_windows {
+        return None;
+    }
+    watched.iter().find(|name| {
+        [window.project.as_deref(), window.project_label.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(|project| project.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Watched projects among opened and closed windows
+fn watched_changes(changes: &WindowChanges, watched: &[String]) -> Vec<WatchedProjectChanged> {
+    let opened = changes.opened.iter().map(|window| (true, window));
+    let closed = changes.closed.iter().map(|window| (false, window));
+    opened
+        .chain(closed)
+        .filter_map(|(opened, window)| {
+            Some(WatchedProjectChanged {
+                project: watched_project(window, watched)?.clone(),
+                opened,
+                window: window.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Notifies about watched projects among opened and closed windows
+pub fn notify_changes(app: &tauri::AppHandle, changes: &WindowChanges) {
+    let watched = lock_watched().clone();
+    if watched.is_empty() || crate::dnd::is_active() {
+        return;
+    }
+    for change in watched_changes(changes, &watched) {
+        crate::notifications::post(&format!(
+            "{} {} in {}",
+            change.project,
+            if change.opened { "opened" } else { "closed" },
+            change.window.app_name
+        ));
+        if let Err(e) = app.emit(constants::WATCHED_PROJECT_CHANGED_EVENT, change) {
+            debug!("Failed to emit watched project change: {}", e);
+        }
+    }
+}
+
+/// Reads the `name` parameter, adds or removes it and saves the result
+fn update_watched(
+    app: &tauri::AppHandle,
+    parameters: &serde_json::Value,
+    watch: bool,
+) -> Result<Vec<String>, SwiiError> {
+    let name = parameters
+        .get("name")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or(SwiiError::InvalidParameter("name"))?;
+
+    // Only replaced once saved, so a failed write leaves the list as it was
+    let mut watched = lock_watched().clone();
+    watched.retain(|watched| !watched.eq_ignore_ascii_case(name));
+    if watch {
+        watched.push(name.to_string());
+    }
+    crate::settings::store_value(
+        app,
+        constants::WATCHED_PROJECTS_KEY,
+        serde_json::json!(watched),
+    )?;
+    set_projects(&watched);
+    Ok(watched)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn watch_project(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Vec<String>> {
+    crate::command_wrapper::create_typed_command("watch_project", args, |ctx| {
+        let watched = update_watched(&app, &ctx.parameters, true)?;
+        ctx.logger
+            .info(&format!("Watching {} projects", watched.len()));
+        Ok(watched)
+    })
+    .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn unwatch_project(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<Vec<String>> {
+    crate::command_wrapper::create_typed_command("unwatch_project", args, |ctx| {
+        let watched = update_watched(&app, &ctx.parameters, false)?;
+        ctx.logger
+            .info(&format!("Watching {} projects", watched.len()));
+        Ok(watched)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    #[test]
+    fn test_watched_changes() {
+        let mut windows = mock_editor_windows();
+        windows.truncate(2);
+        windows[0].project = Some("API".to_string());
+        windows[1].project = Some("web".to_string());
+        windows[1].project_label = Some("web (main)".to_string());
+        let changes = WindowChanges {
+            opened: vec![windows[0].clone()],
+            closed: vec![windows[1].clone()],
+        };
+
+        let watched = vec!["api".to_string(), "WEB (main)".to_string()];
+        let found: Vec<(String, bool)> = watched_changes(&changes, &watched)
+            .into_iter()
+            .map(|change| (change.project, change.opened))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("api".to_string(), true), ("WEB (main)".to_string(), false)]
+        );
+
+        assert!(watched_changes(&changes, &["docs".to_string()]).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Live window updates pushed to the frontend.
+//!
+//! Providers that can observe windows report title changes, e.g. when the
+//! user switches files in an editor. Each change is re-parsed against the
+//! window as it was last listed and emitted as a `window-title-changed` event,
+//! so the panel shows the open file without enumerating windows again.
+//!
+//! Opened and closed windows need a fresh listing to be described, so those
+//! reports list windows again and emit a `window-opened` or `window-closed`
+//! event with every window that appeared or disappeared, notifying about
+//! watched projects among them. Focus changes update the tray title, the
+//! focus timer and the focus history.
+//!
+//! While the user is idle or in do-not-disturb mode the observer detaches
+//! where the provider supports it, and changes that still arrive update the
+//! listing without being emitted or notified. Windows are listed again once
+//! neither holds, emitting what opened or closed in the meantime.
+
+use serde::Serialize;
+use swii_core::provider::WindowEvent;
+use swii_core::types::WindowInfo;
+use swii_title_parser::{parse_title, ProjectSource};
+use tauri::Emitter;
+use tracing::{debug, info};
+
+use crate::window_provider::provider;
+
+pub mod constants {
+    /// Event emitted to the frontend when a listed window is retitled
+    pub const WINDOW_TITLE_CHANGED_EVENT: &str = "window-title-changed";
+
+    /// Event emitted with each window that appeared since the last listing
+    pub const WINDOW_OPENED_EVENT: &str = "window-opened";
+
+    /// Event emitted with each listed window that disappeared
+    pub const WINDOW_CLOSED_EVENT: &str = "window-closed";
+}
+
+/// Fields of a listed window that follow its title
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WindowTitleChanged {
+    pub pid: i32,
+    pub window_number: u32,
+    pub window_name: Option<String>,
+    pub project: Option<String>,
+    pub project_label: Option<String>,
+    pub active_editor_tab: Option<String>,
+    pub is_modified: bool,
+}
+
+impl From<&WindowInfo> for WindowTitleChanged {
+    fn from(window: &WindowInfo) -> Self {
+        WindowTitleChanged {
+            pid: window.pid,
+            window_number: window.window_number,
+            window_name: window.window_name.clone(),
+            project: window.project.clone(),
+            project_label: window.project_label.clone(),
+            active_editor_tab: window.active_editor_tab.clone(),
+            is_modified: window.is_modified,
+        }
+    }
+}
+
+/// Re-parses a window after its title changed
+fn apply_title(window: &mut WindowInfo, title: &str) {
+    let parsed = parse_title(Some(&window.app_name), title);
+    window.window_name = Some(title.to_string());
+    window.active_editor_tab = parsed.tab;
+    window.is_modified = parsed.is_modified;
+    // Projects read from the document, an extension or the editor's own
+    // state are more reliable than the title
+    if window.project_source == ProjectSource::Title && parsed.project.is_some() {
+        window.project = parsed.project;
+    }
+}
+
+fn emit(app: &tauri::AppHandle, event: &str, payload: impl Serialize + Clone) {
+    if let Err(e) = app.emit(event, payload) {
+        debug!("Failed to emit {}: {}", event, e);
+    }
+}
+
+/// Whether changes should be applied without telling anyone
+fn quiet() -> bool {
+    crate::idle::is_idle() || crate::dnd::is_active()
+}
+
+/// Re-parses a listed window after its title changed and emits the result
+fn retitle(app: &tauri::AppHandle, pid: i32, window_number: u32, title: &str, quiet: bool) {
+    let unchanged = crate::window_list::listed_window(pid, window_number)
+        .is_none_or(|window| window.window_name.as_deref() == Some(title));
+    if unchanged {
+        return;
+    }
+
+    if let Some(window) = crate::window_list::update_listed_window(pid, window_number, |window| {
+        apply_title(window, title)
+    }) {
+        if quiet {
+            return;
+        }
+        emit(
+            app,
+            constants::WINDOW_TITLE_CHANGED_EVENT,
+            WindowTitleChanged::from(&window),
+        );
+    }
+}
+
+/// Lists windows again and emits the ones opened or closed since
+fn relist(app: &tauri::AppHandle, quiet: bool) {
+    let changes = match crate::window_list::relist_windows() {
+        Ok(changes) => changes,
+        Err(e) => {
+            debug!("Failed to list windows after a window change: {}", e);
+            return;
+        }
+    };
+    if quiet {
+        return;
+    }
+    crate::watchlist::notify_changes(app, &changes);
+    for window in changes.opened {
+        emit(app, constants::WINDOW_OPENED_EVENT, window);
+    }
+    for window in changes.closed {
+        emit(app, constants::WINDOW_CLOSED_EVENT, window);
+    }
+}
+
+/// Lists windows again after observation resumed, emitting what opened or
+/// closed in the meantime
+pub fn catch_up(app: &tauri::AppHandle) {
+    relist(app, quiet());
+}
+
+/// Detaches the observer while the user is idle or in do-not-disturb mode
+/// and catches up once neither holds. Blocks while windows are listed.
+pub fn sync_observation(app: &tauri::AppHandle) {
+    let paused = quiet();
+    provider().pause_observation(paused);
+    if !paused {
+        catch_up(app);
+    }
+}
+
+/// Starts forwarding changes of listed windows to the frontend, when the
+/// provider can observe windows
+pub fn start(app: tauri::AppHandle) {
+    let result = provider().observe(Box::new(move |event| {
+        let quiet = quiet();
+        match event {
+            WindowEvent::TitleChanged {
+                pid,
+                window_number: Some(window_number),
+                title,
+            } => retitle(&app, pid, window_number, &title, quiet),
+            // Without a window number the change can't be attributed
+            WindowEvent::TitleChanged { .. } => {}
+            WindowEvent::Opened { .. } | WindowEvent::Closed { .. } => relist(&app, quiet),
+            WindowEvent::Focused { pid, window_number } => {
+                let window = crate::window_list::focused_window(pid, window_number);
+                if let Some(window) = &window {
+                    crate::focus_history::record(window);
+                }
+                crate::tray_title::on_focus(&app, window.as_ref());
+                crate::focus_timer::on_focus(&app, window.as_ref());
+            }
+        }
+    }));
+
+    match result {
+        Ok(()) => info!("Observing window changes"),
+        Err(e) => info!("Window changes are not observed: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(title: &str, project_source: ProjectSource) -> WindowInfo {
+        WindowInfo::builder("Code", 1, 1)
+            .window_name(Some(title))
+            .project(Some("swii"))
+            .active_editor_tab(Some("main.rs"))
+            .project_label(Some("swii"))
+            .project_source(project_source)
+            .build()
+    }
+
+    #[test]
+    fn test_apply_title() {
+        let mut retitled = window("main.rs — swii", ProjectSource::Title);
+        apply_title(&mut retitled, "● lib.rs — api");
+        assert_eq!(
+            WindowTitleChanged::from(&retitled),
+            WindowTitleChanged {
+                pid: 1,
+                window_number: 1,
+                window_name: Some("● lib.rs — api".to_string()),
+                project: Some("api".to_string()),
+                project_label: Some("swii".to_string()),
+                active_editor_tab: Some("lib.rs".to_string()),
+                is_modified: true,
+            }
+        );
+
+        // Only the tab follows the title when the project came from elsewhere
+        let mut extension = window("main.rs — swii", ProjectSource::Extension);
+        apply_title(&mut extension, "lib.rs — api");
+        assert_eq!(extension.project.as_deref(), Some("swii"));
+        assert_eq!(extension.active_editor_tab.as_deref(), Some("lib.rs"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Names the user gives to individual windows.
+//!
+//! `set_window_label` attaches a label such as "scratchpad" or "prod logs"
+//! to one window, identified like everywhere else by its owner's PID and
+//! its window number, which stay the same for as long as the window is
+//! open. Every listing fills in `label` for the windows that have one, so
+//! the name survives refreshes and title changes. Labels are saved to
+//! `window_labels` in the settings file, so they're back after restarting
+//! swii while the windows are still open, and are dropped once their
+//! application quits, since its window numbers are never handed out again.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use swii_core::focus::WindowFocusError;
+use swii_core::types::WindowInfo;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+
+pub mod constants {
+    /// Longest accepted label, in characters
+    pub const MAX_LABEL_CHARS: usize = 80;
+
+    /// Settings key the labels are stored under
+    pub const WINDOW_LABELS_KEY: &str = "window_labels";
+}
+
+/// A label as saved in the settings file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct WindowLabel {
+    pid: i32,
+    window_number: u32,
+    label: String,
+}
+
+static LABELS: Mutex<Vec<WindowLabel>> = Mutex::new(Vec::new());
+
+fn lock_labels() -> std::sync::MutexGuard<'static, Vec<WindowLabel>> {
+    LABELS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Replaces the labels, e.g. with the ones saved in the settings file
+pub fn set_labels(labels: &[WindowLabel]) {
+    *lock_labels() = labels.to_vec();
+}
+
+/// Fills in the labels of `windows` and forgets the labels of applications
+/// that own none of them anymore
+fn apply(labels: &mut Vec<WindowLabel>, windows: &mut [WindowInfo]) {
+    labels.retain(|entry| windows.iter().any(|window| window.pid == entry.pid));
+    for window in windows.iter_mut() {
+        window.label = labels
+            .iter()
+            .find(|entry| entry.pid == window.pid && entry.window_number == window.window_number)
+            .map(|entry| entry.label.clone());
+    }
+}
+
+/// Fills in the labels of a fresh listing
+pub fn apply_labels(windows: &mut [WindowInfo]) {
+    apply(&mut lock_labels(), windows);
+}
+
+/// Reads the `label` parameter; missing, null or blank clears the label
+fn label_parameter(parameters: &serde_json::Value) -> Result<Option<String>, SwiiError> {
+    match parameters.get("label") {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(value) => {
+            let label = value
+                .as_str()
+                .map(str::trim)
+                .filter(|label| label.chars().count() <= constants::MAX_LABEL_CHARS)
+                .ok_or(SwiiError::InvalidParameter("label"))?;
+            Ok((!label.is_empty()).then(|| label.to_string()))
+        }
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_window_label(
+    app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> CommandResult<WindowInfo> {
+    crate::command_wrapper::create_typed_command("set_window_label", args, |ctx| {
+        let pid = ctx
+            .parameters
+            .get("pid")
+            .and_then(|v| v.as_i64())
+            .ok_or(SwiiError::InvalidParameter("pid"))? as i32;
+        let window_number =
+            ctx.parameters
+                .get("window_number")
+                .and_then(|v| v.as_u64())
+                .ok_or(SwiiError::InvalidParameter("window_number"))? as u32;
+        let label = label_parameter(&ctx.parameters)?;
+
+        crate::window_list::known_window(pid, window_number)?
+            .filter(|window| window.has_windows)
+            .ok_or(WindowFocusError::WindowNotFound)?;
+        // Only replaced once saved, so a failed write leaves the labels as
+        // they were
+        let mut labels = lock_labels().clone();
+        labels.retain(|entry| entry.pid != pid || entry.window_number != window_number);
+        if let Some(label) = &label {
+            labels.push(WindowLabel {
+                pid,
+                window_number,
+                label: label.clone(),
+            });
+        }
+        crate::settings::store_value(
+            &app,
+            constants::WINDOW_LABELS_KEY,
+            serde_json::json!(labels),
+        )?;
+        set_labels(&labels);
+        let window = crate::window_list::update_listed_window(pid, window_number, |window| {
+            window.label = label.clone()
+        })
+        .ok_or(WindowFocusError::WindowNotFound)?;
+
+        match &label {
+            Some(label) => ctx.logger.info(&format!(
+                "Labeled window {} of {} \"{}\"",
+                window_number, window.app_name, label
+            )),
+            None => ctx.logger.info(&format!(
+                "Cleared the label of window {} of {}",
+                window_number, window.app_name
+            )),
+        }
+        Ok(window)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swii_core::mock::mock_editor_windows;
+
+    #[test]
+    fn test_apply() {
+        let mut windows = mock_editor_windows();
+        let first = (windows[0].pid, windows[0].window_number);
+        let mut labels = vec![
+            WindowLabel {
+                pid: first.0,
+                window_number: first.1,
+                label: "scratchpad".to_string(),
+            },
+            WindowLabel {
+                pid: -1,
+                window_number: 7,
+                label: "prod logs".to_string(),
+            },
+        ];
+
+        apply(&mut labels, &mut windows);
+        assert_eq!(windows[0].label.as_deref(), Some("scratchpad"));
+        assert!(windows[1..].iter().all(|window| window.label.is_none()));
+        assert_eq!(labels.len(), 1);
+    }
+
+    #[test]
+    fn test_saved_labels() {
+        let saved = serde_json::json!([
+            { "pid": 41001, "window_number": 5101, "label": "scratchpad" }
+        ]);
+        let labels: Vec<WindowLabel> = serde_json::from_value(saved.clone()).unwrap();
+        assert_eq!(
+            labels,
+            vec![WindowLabel {
+                pid: 41001,
+                window_number: 5101,
+                label: "scratchpad".to_string(),
+            }]
+        );
+        assert_eq!(serde_json::to_value(&labels).unwrap(), saved);
+    }
+
+    #[test]
+    fn test_label_parameter() {
+        let label = |value| label_parameter(&serde_json::json!({ "label": value }));
+        assert_eq!(
+            label(serde_json::json!(" prod logs ")).unwrap().as_deref(),
+            Some("prod logs")
+        );
+        assert_eq!(label(serde_json::json!("  ")).unwrap(), None);
+        assert_eq!(label(serde_json::Value::Null).unwrap(), None);
+        assert!(label(serde_json::json!(3)).is_err());
+        assert!(label(serde_json::json!("x".repeat(81))).is_err());
+    }
+}
@@ -1,55 +1,490 @@
-use crate::macos_window::get_editor_windows;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use swii_core::provider::{RunningApp, WindowError};
+use swii_core::types::WindowInfo;
+use tauri::Emitter;
+
+use crate::command_wrapper::CommandResult;
+use crate::error::SwiiError;
+use crate::window_provider::provider;
+
+pub mod constants {
+    /// Event carrying one chunk of a streamed `list_editor_windows` result
+    pub const WINDOW_CHUNK_EVENT: &str = "editor-windows-chunk";
+
+    /// Windows per streamed chunk
+    pub const STREAM_CHUNK_SIZE: usize = 10;
+}
+
+/// Part of a streamed window listing, sent with `editor-windows-chunk`
+#[derive(Debug, Clone, Serialize, specta::Type)]
+pub struct WindowChunk {
+    /// Position of the first window of the chunk in the requested page
+    pub offset: usize,
+    pub windows: Vec<WindowInfo>,
+    /// Windows in the requested page
+    pub total: usize,
+    /// True for the last chunk
+    pub done: bool,
+}
 
+/// Lists editor windows
+///
+/// `offset` and `limit` select a page of the listing; the whole listing is
+/// still remembered for follow-up commands. With `stream` set, the page is
+/// also emitted in chunks as `editor-windows-chunk` events before the command
+/// returns, so a long list can be shown before its last icons have crossed
+/// the IPC bridge.
 #[tauri::command]
+#[specta::specta]
 pub async fn list_editor_windows(
-    _app: tauri::AppHandle,
+    app: tauri::AppHandle,
     args: Option<serde_json::Value>,
-) -> Result<serde_json::Value, String> {
-    crate::command_wrapper::create_command("list_editor_windows", args, |ctx| {
+) -> CommandResult<Vec<WindowInfo>> {
+    crate::command_wrapper::create_blocking_command("list_editor_windows", args, move |ctx| {
         ctx.logger.info("Starting to list editor windows");
-
-        #[cfg(target_os = "macos")]
-        {
-            match get_editor_windows() {
-                Ok(windows) => {
-                    ctx.logger
-                        .info(&format!("Found {} editor windows", windows.len()));
-                    Ok(windows)
+        let include_untitled = ctx
+            .parameters
+            .get("include_untitled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(include_untitled);
+        let offset = match ctx.parameters.get("offset") {
+            None | Some(serde_json::Value::Null) => 0,
+            Some(value) => value
+                .as_u64()
+                .ok_or(SwiiError::InvalidParameter("offset"))? as usize,
+        };
+        let limit = match ctx.parameters.get("limit") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => Some(
+                value
+                    .as_u64()
+                    .filter(|limit| *limit > 0)
+                    .ok_or(SwiiError::InvalidParameter("limit"))? as usize,
+            ),
+        };
+        let stream = ctx
+            .parameters
+            .get("stream")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let windows = if let Some(mut windows) = crate::snapshots::next_replay_snapshot() {
+            ctx.logger.info(&format!(
+                "Replaying snapshot with {} windows",
+                windows.len()
+            ));
+            filter_untitled(&mut windows, include_untitled);
+            crate::containers::tag_containerized(&mut windows);
+            assign_project_labels(&mut windows);
+            remember_windows(&windows);
+            windows
+        } else {
+            let provider = provider();
+            match provider.list_windows() {
+                Ok(mut windows) => {
+                    filter_untitled(&mut windows, include_untitled);
+                    enrich_windows(&mut windows);
+                    remember_windows(&windows);
+                    ctx.logger.info(&format!(
+                        "Found {} editor windows via {} provider",
+                        windows.len(),
+                        provider.name()
+                    ));
+                    crate::snapshots::record_if_active(&windows);
+                    windows
                 }
                 Err(e) => {
                     ctx.logger
                         .error(&format!("Failed to get editor windows: {}", e));
-                    Err(format!("Failed to enumerate editor windows: {}", e))
+                    return Err(e.into());
                 }
             }
-        }
+        };
 
-        #[cfg(not(target_os = "macos"))]
-        {
-            ctx.logger.info("Not on macOS, returning empty list");
-            Ok(Vec::new())
+        let page = paginate(windows, offset, limit);
+        if stream {
+            for chunk in chunk_windows(&page, constants::STREAM_CHUNK_SIZE) {
+                if let Err(e) = app.emit(constants::WINDOW_CHUNK_EVENT, chunk) {
+                    ctx.logger
+                        .error(&format!("Failed to emit window chunk: {}", e));
+                }
+            }
         }
+        Ok(page)
     })
     .await
 }
 
+/// The windows from `offset` on, at most `limit` of them
+fn paginate(windows: Vec<WindowInfo>, offset: usize, limit: Option<usize>) -> Vec<WindowInfo> {
+    windows
+        .into_iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+/// Splits a page into stream chunks; an empty page still gets a final chunk
+fn chunk_windows(page: &[WindowInfo], size: usize) -> Vec<WindowChunk> {
+    let mut chunks: Vec<WindowChunk> = page
+        .chunks(size)
+        .enumerate()
+        .map(|(i, windows)| WindowChunk {
+            offset: i * size,
+            windows: windows.to_vec(),
+            total: page.len(),
+            done: (i + 1) * size >= page.len(),
+        })
+        .collect();
+    if chunks.is_empty() {
+        chunks.push(WindowChunk {
+            offset: 0,
+            windows: Vec::new(),
+            total: 0,
+            done: true,
+        });
+    }
+    chunks
+}
+
+static INCLUDE_UNTITLED: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether windows without a title are listed when the caller doesn't say
+pub fn set_include_untitled(include: bool) {
+    INCLUDE_UNTITLED.store(include, Ordering::Relaxed);
+}
+
+fn include_untitled() -> bool {
+    INCLUDE_UNTITLED.load(Ordering::Relaxed)
+}
+
+/// Treats blank titles as missing and, unless `include` is set, drops the
+/// windows without one
+fn filter_untitled(windows: &mut Vec<WindowInfo>, include: bool) {
+    for window in windows.iter_mut() {
+        if window
+            .window_name
+            .as_deref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            window.window_name = None;
+        }
+    }
+    if !include {
+        windows.retain(|window| window.window_name.is_some());
+    }
+}
+
+/// Fills in what the provider can't tell from the window itself: projects
+/// known to companions and editor state, container tags, project labels and
+/// the user's window labels. Running editors without windows are appended as
+/// entries of their own.
+fn enrich_windows(windows: &mut Vec<WindowInfo>) {
+    crate::companion::apply_reports(windows);
+    crate::jetbrains::apply_open_projects(windows);
+    crate::zed::apply_workspace_paths(windows);
+    crate::sublime::apply_session_folders(windows);
+    crate::tmux::apply_sessions(windows);
+    crate::containers::tag_containerized(windows);
+    assign_project_labels(windows);
+
+    let mut recent = RECENT_PROJECTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    remember_recent_projects(&mut recent, windows);
+    // Providers that can't list applications only list windows
+    if let Ok(apps) = provider().running_editors() {
+        let windowless = windowless_editors(apps, windows, &recent);
+        windows.extend(windowless);
+    }
+    crate::window_labels::apply_labels(windows);
+}
+
+/// Project an editor showed in the latest listing that had one
+#[derive(Debug, Clone, PartialEq)]
+struct RecentProject {
+    app_name: String,
+    project: String,
+    project_path: Option<String>,
+}
+
+static RECENT_PROJECTS: Mutex<Vec<RecentProject>> = Mutex::new(Vec::new());
+
+/// Records the project of the frontmost project window of each editor
+fn remember_recent_projects(recent: &mut Vec<RecentProject>, windows: &[WindowInfo]) {
+    let mut updated: Vec<&str> = Vec::new();
+    for window in windows
+        .iter()
+        .filter(|window| window.has_windows && !window.auxiliary)
+    {
+        let Some(project) = &window.project else {
+            continue;
+        };
+        if updated.contains(&window.app_name.as_str()) {
+            continue;
+        }
+        updated.push(&window.app_name);
+
+        recent.retain(|entry| entry.app_name != window.app_name);
+        recent.push(RecentProject {
+            app_name: window.app_name.clone(),
+            project: project.clone(),
+            project_path: window.project_path.clone(),
+        });
+    }
+}
+
+/// Entries for running editors that own none of `windows`, carrying the
+/// project each showed last so it can be reopened
+fn windowless_editors(
+    apps: Vec<RunningApp>,
+    windows: &[WindowInfo],
+    recent: &[RecentProject],
+) -> Vec<WindowInfo> {
+    apps.into_iter()
+        .filter(|app| !windows.iter().any(|window| window.pid == app.pid))
+        .map(|app| {
+            let recent = recent.iter().find(|entry| entry.app_name == app.name);
+            WindowInfo::builder(app.name, app.pid, 0)
+                .project(recent.map(|entry| entry.project.clone()))
+                .app_icon(app.icon)
+                .project_path(recent.and_then(|entry| entry.project_path.clone()))
+                .has_windows(false)
+                .build()
+        })
+        .collect()
+}
+
+/// Windows that appeared or disappeared between two listings
+#[derive(Debug, Default)]
+pub struct WindowChanges {
+    pub opened: Vec<WindowInfo>,
+    pub closed: Vec<WindowInfo>,
+}
+
+fn diff_windows(previous: &[WindowInfo], current: &[WindowInfo]) -> WindowChanges {
+    let is_listed = |windows: &[WindowInfo], window: &WindowInfo| {
+        windows
+            .iter()
+            .any(|other| other.pid == window.pid && other.window_number == window.window_number)
+    };
+    WindowChanges {
+        opened: current
+            .iter()
+            .filter(|window| !is_listed(previous, window))
+            .cloned()
+            .collect(),
+        closed: previous
+            .iter()
+            .filter(|window| !is_listed(current, window))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Lists windows again, replacing the latest listing, and reports which
+/// windows were opened or closed since
+pub fn relist_windows() -> Result<WindowChanges, WindowError> {
+    let mut windows = provider().list_windows()?;
+    filter_untitled(&mut windows, include_untitled());
+    enrich_windows(&mut windows);
+    let previous = std::mem::replace(
+        &mut *LAST_LISTED
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        windows.clone(),
+    );
+    mark_listed();
+    Ok(diff_windows(&previous, &windows))
+}
+
+/// Lists windows again and returns the given window as it is now, or `None`
+/// when it's gone
+pub fn relisted_window(pid: i32, window_number: u32) -> Result<Option<WindowInfo>, WindowError> {
+    relist_windows()?;
+    Ok(listed_window(pid, window_number))
+}
+
+/// A window as last listed, or as listed again when the latest listing
+/// doesn't have it yet
+pub fn known_window(pid: i32, window_number: u32) -> Result<Option<WindowInfo>, WindowError> {
+    match listed_window(pid, window_number) {
+        Some(window) => Ok(Some(window)),
+        None => relisted_window(pid, window_number),
+    }
+}
+
+static LAST_LISTED: Mutex<Vec<WindowInfo>> = Mutex::new(Vec::new());
+static LISTED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Keeps the latest listing so follow-up commands can look windows up
+/// without enumerating again
+fn remember_windows(windows: &[WindowInfo]) {
+    *LAST_LISTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = windows.to_vec();
+    mark_listed();
+}
+
+fn mark_listed() {
+    *LISTED_AT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Instant::now());
+}
+
+/// Whether a listing taken at `listed_at` is at most `max_age` old
+fn is_fresh(listed_at: Option<Instant>, max_age: Duration) -> bool {
+    listed_at.is_some_and(|at| at.elapsed() <= max_age)
+}
+
+/// The latest listing when it's at most `max_age` old, otherwise a new one
+pub fn recent_windows(max_age: Duration) -> Result<Vec<WindowInfo>, WindowError> {
+    let listed_at = *LISTED_AT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if !is_fresh(listed_at, max_age) {
+        relist_windows()?;
+    }
+    Ok(listed_windows())
+}
+
+/// Every window of the latest `list_editor_windows` result
+pub fn listed_windows() -> Vec<WindowInfo> {
+    LAST_LISTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// A window from the latest `list_editor_windows` result
+pub fn listed_window(pid: i32, window_number: u32) -> Option<WindowInfo> {
+    LAST_LISTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .find(|window| window.pid == pid && window.window_number == window_number)
+        .cloned()
+}
+
+/// The listed window that came to the front: `window_number` of `pid`, or
+/// the first open window of `pid` when the number isn't known
+pub fn focused_window(pid: i32, window_number: Option<u32>) -> Option<WindowInfo> {
+    match window_number {
+        Some(window_number) => listed_window(pid, window_number),
+        None => listed_windows()
+            .into_iter()
+            .find(|window| window.pid == pid && window.has_windows),
+    }
+}
+
+/// Window numbers of the windows of `pid` in the latest listing
+pub fn listed_window_numbers(pid: i32) -> Vec<u32> {
+    LAST_LISTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .filter(|window| window.pid == pid && window.has_windows)
+        .map(|window| window.window_number)
+        .collect()
+}
+
+/// Applies `update` to a window of the latest listing, relabels the listing
+/// and returns the updated window
+pub fn update_listed_window(
+    pid: i32,
+    window_number: u32,
+    update: impl FnOnce(&mut WindowInfo),
+) -> Option<WindowInfo> {
+    let mut windows = LAST_LISTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let index = windows
+        .iter()
+        .position(|window| window.pid == pid && window.window_number == window_number)?;
+    update(&mut windows[index]);
+    assign_project_labels(&mut windows);
+    Some(windows[index].clone())
+}
+
+/// Fills in `project_label` so windows sharing a project name can be told
+/// apart.
+///
+/// Windows of a duplicated project are first qualified by where they run
+/// (SSH host or dev environment) or by the parent directory of the project
+/// root; any labels still equal after that get an index, e.g. "api",
+/// "api (2)".
+fn assign_project_labels(windows: &mut [WindowInfo]) {
+    let mut project_counts: HashMap<String, usize> = HashMap::new();
+    for project in windows.iter().filter_map(|window| window.project.as_ref()) {
+        *project_counts.entry(project.clone()).or_default() += 1;
+    }
+
+    let mut label_counts: HashMap<String, usize> = HashMap::new();
+    for window in windows.iter_mut() {
+        let Some(project) = window.project.as_deref() else {
+            window.project_label = None;
+            continue;
+        };
+
+        let mut label = match project_qualifier(window) {
+            Some(location) if project_counts[project] > 1 => {
+                format!("{} ({})", project, location)
+            }
+            _ => project.to_string(),
+        };
+
+        let seen = label_counts.entry(label.clone()).or_default();
+        *seen += 1;
+        if *seen > 1 {
+            label = format!("{} ({})", label, seen);
+        }
+        window.project_label = Some(label);
+    }
+}
+
+/// Remote host or dev environment a window is attached to, falling back to
+/// the name of the directory holding the project root
+fn project_qualifier(window: &WindowInfo) -> Option<&str> {
+    window
+        .remote_host
+        .as_deref()
+        .or_else(|| window.environment.as_ref().map(|env| env.name.as_str()))
+        .or_else(|| {
+            Path::new(window.project_path.as_deref()?)
+                .parent()?
+                .file_name()?
+                .to_str()
+        })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::macos_window::is_editor_window;
-    use crate::types::WindowInfo;
+    use swii_title_parser::EditorKind;
+
+    use super::{
+        assign_project_labels, chunk_windows, diff_windows, filter_untitled, is_fresh, paginate,
+        remember_recent_projects, windowless_editors, RecentProject,
+    };
+    use crate::window_provider::provider;
+    use swii_core::editor_config::is_editor_application as is_editor_window;
+    use swii_core::provider::{RunningApp, WindowError};
+    use swii_core::types::WindowInfo;
+
+    fn get_editor_windows() -> Result<Vec<WindowInfo>, WindowError> {
+        provider().list_windows()
+    }
 
     #[test]
     fn test_window_info_creation() {
-        let window_info = WindowInfo {
-            app_name: "Test App".to_string(),
-            window_name: Some("Test Window".to_string()),
-            pid: 1234,
-            window_number: 42,
-            project: None,
-            active_editor_tab: None,
-            app_icon: None,
-        };
+        let window_info = WindowInfo::builder("Test App", 1234, 42)
+            .window_name(Some("Test Window"))
+            .build();
 
         assert_eq!(window_info.app_name, "Test App");
         assert_eq!(window_info.window_name, Some("Test Window".to_string()));
@@ -62,15 +497,7 @@ mod tests {
 
     #[test]
     fn test_window_info_with_no_window_name() {
-        let window_info = WindowInfo {
-            app_name: "App Without Window Name".to_string(),
-            window_name: None,
-            pid: 5678,
-            window_number: 100,
-            project: None,
-            active_editor_tab: None,
-            app_icon: None,
-        };
+        let window_info = WindowInfo::builder("App Without Window Name", 5678, 100).build();
 
         assert_eq!(window_info.app_name, "App Without Window Name");
         assert_eq!(window_info.window_name, None);
@@ -83,15 +510,9 @@ mod tests {
 
     #[test]
     fn test_window_info_serialization() {
-        let window_info = WindowInfo {
-            app_name: "Serialization Test".to_string(),
-            window_name: Some("Test Window".to_string()),
-            pid: 999,
-            window_number: 1,
-            project: None,
-            active_editor_tab: None,
-            app_icon: None,
-        };
+        let window_info = WindowInfo::builder("Serialization Test", 999, 1)
+            .window_name(Some("Test Window"))
+            .build();
 
         let serialized = serde_json::to_string(&window_info).unwrap();
         let expected_fields = [
@@ -112,15 +533,12 @@ mod tests {
 
     #[test]
     fn test_window_info_with_app_icon() {
-        let window_info = WindowInfo {
-            app_name: "Test App".to_string(),
-            window_name: Some("Test Window".to_string()),
-            pid: 1234,
-            window_number: 42,
-            project: Some("test-project".to_string()),
-            active_editor_tab: Some("main.rs".to_string()),
-            app_icon: Some("base64_encoded_icon_data".to_string()),
-        };
+        let window_info = WindowInfo::builder("Test App", 1234, 42)
+            .window_name(Some("Test Window"))
+            .project(Some("test-project"))
+            .active_editor_tab(Some("main.rs"))
+            .app_icon(Some("base64_encoded_icon_data".to_string()))
+            .build();
 
         assert_eq!(window_info.app_name, "Test App");
         assert_eq!(window_info.window_name, Some("Test Window".to_string()));
@@ -136,15 +554,13 @@ mod tests {
 
     #[test]
     fn test_window_info_serialization_with_app_icon() {
-        let window_info = WindowInfo {
-            app_name: "Serialization Test".to_string(),
-            window_name: Some("Test Window".to_string()),
-            pid: 999,
-            window_number: 1,
-            project: Some("test-project".to_string()),
-            active_editor_tab: Some("main.rs".to_string()),
-            app_icon: Some("base64_icon_data".to_string()),
-        };
+        let window_info = WindowInfo::builder("Serialization Test", 999, 1)
+            .window_name(Some("Test Window"))
+            .project(Some("test-project"))
+            .active_editor_tab(Some("main.rs"))
+            .app_icon(Some("base64_icon_data".to_string()))
+            .editor_kind(EditorKind::VsCode)
+            .build();
 
         let serialized = serde_json::to_string(&window_info).unwrap();
         let expected_fields = [
@@ -155,6 +571,7 @@ mod tests {
             "\"project\":\"test-project\"",
             "\"active_editor_tab\":\"main.rs\"",
             "\"app_icon\":\"base64_icon_data\"",
+            "\"editor_kind\":\"vs_code\"",
         ];
 
         for field in expected_fields.iter() {
@@ -248,27 +665,6 @@ mod tests {
         }
     }
 
-    #[test]
-    #[cfg(not(target_os = "macos"))]
-    fn test_non_macos_behavior() {
-        // On non-macOS platforms, the function should return an empty vector
-        // or handle gracefully
-        let windows = get_editor_windows();
-        match windows {
-            Ok(vec) => {
-                assert_eq!(
-                    vec.len(),
-                    0,
-                    "Should return empty vector on non-macOS platforms"
-                );
-            }
-            Err(e) => {
-                // Even on non-macOS, we should get a Result, not an error
-                panic!("Unexpected error on non-macOS platform: {}", e);
-            }
-        }
-    }
-
     #[test]
     fn test_is_editor_window() {
         // Test positive cases
@@ -312,4 +708,143 @@ mod tests {
         assert!(is_editor_window("Vim"));
         assert!(is_editor_window("Xcode"));
     }
+
+    fn window(number: u32, project: Option<&str>, remote_host: Option<&str>) -> WindowInfo {
+        WindowInfo::builder("Visual Studio Code", 1, number)
+            .project(project)
+            .remote_host(remote_host)
+            .build()
+    }
+
+    #[test]
+    fn test_assign_project_labels() {
+        let mut windows = vec![
+            window(1, Some("api"), None),
+            window(2, Some("api"), Some("prod-box")),
+            window(3, Some("api"), None),
+            window(4, Some("web"), Some("prod-box")),
+            window(5, None, None),
+            window(6, Some("api"), None),
+        ];
+        windows[5].project_path = Some("/Users/dev/clients/acme/api".to_string());
+        assign_project_labels(&mut windows);
+
+        let labels: Vec<Option<&str>> =
+            windows.iter().map(|w| w.project_label.as_deref()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                Some("api"),
+                Some("api (prod-box)"),
+                Some("api (2)"),
+                Some("web"),
+                None,
+                Some("api (acme)")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_windows() {
+        let previous = vec![window(1, Some("api"), None), window(2, Some("web"), None)];
+        let current = vec![window(2, Some("web"), None), window(3, Some("cli"), None)];
+
+        let changes = diff_windows(&previous, &current);
+        let numbers = |windows: &[WindowInfo]| -> Vec<u32> {
+            windows.iter().map(|window| window.window_number).collect()
+        };
+        assert_eq!(numbers(&changes.opened), vec![3]);
+        assert_eq!(numbers(&changes.closed), vec![1]);
+    }
+
+    #[test]
+    fn test_windowless_editors() {
+        let mut windows = vec![window(1, Some("api"), None), window(2, Some("web"), None)];
+        windows[0].project_path = Some("/Users/dev/api".to_string());
+        let mut recent = Vec::new();
+        remember_recent_projects(&mut recent, &windows);
+        assert_eq!(
+            recent,
+            vec![RecentProject {
+                app_name: "Visual Studio Code".to_string(),
+                project: "api".to_string(),
+                project_path: Some("/Users/dev/api".to_string()),
+            }]
+        );
+
+        let app = |pid: i32, name: &str| RunningApp {
+            pid,
+            name: name.to_string(),
+            bundle_id: None,
+            icon: None,
+        };
+        let apps = vec![
+            app(1, "Visual Studio Code"),
+            app(2, "Visual Studio Code"),
+            app(3, "Zed"),
+        ];
+        let entries = windowless_editors(apps, &[window(1, None, None)], &recent);
+
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| !entry.has_windows));
+        assert_eq!(entries[0].pid, 2);
+        assert_eq!(entries[0].project.as_deref(), Some("api"));
+        assert_eq!(entries[0].project_path.as_deref(), Some("/Users/dev/api"));
+        assert_eq!(entries[1].editor_kind, EditorKind::Zed);
+        assert_eq!(entries[1].project, None);
+    }
+
+    #[test]
+    fn test_filter_untitled() {
+        let titled = |number: u32, name: Option<&str>| WindowInfo {
+            window_name: name.map(str::to_string),
+            ..window(number, None, None)
+        };
+        let windows = vec![
+            titled(1, Some("main.rs — api")),
+            titled(2, Some("  ")),
+            titled(3, None),
+        ];
+
+        let mut included = windows.clone();
+        filter_untitled(&mut included, true);
+        assert_eq!(included.len(), 3);
+        assert_eq!(included[1].window_name, None);
+
+        let mut excluded = windows;
+        filter_untitled(&mut excluded, false);
+        assert_eq!(excluded.len(), 1);
+        assert_eq!(excluded[0].window_number, 1);
+    }
+
+    #[test]
+    fn test_paginate_and_chunk() {
+        let windows: Vec<WindowInfo> = (1..=25).map(|n| window(n, None, None)).collect();
+
+        let page = paginate(windows.clone(), 5, Some(10));
+        assert_eq!(page.len(), 10);
+        assert_eq!(page[0].window_number, 6);
+        assert_eq!(paginate(windows.clone(), 20, None).len(), 5);
+        assert!(paginate(windows.clone(), 30, Some(10)).is_empty());
+
+        let chunks = chunk_windows(&windows, 10);
+        let sizes: Vec<(usize, usize, bool)> = chunks
+            .iter()
+            .map(|chunk| (chunk.offset, chunk.windows.len(), chunk.done))
+            .collect();
+        assert_eq!(sizes, vec![(0, 10, false), (10, 10, false), (20, 5, true)]);
+
+        let empty = chunk_windows(&[], 10);
+        assert_eq!(empty.len(), 1);
+        assert!(empty[0].done);
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let max_age = std::time::Duration::from_secs(2);
+        assert!(!is_fresh(None, max_age));
+        assert!(is_fresh(Some(std::time::Instant::now()), max_age));
+        let stale = std::time::Instant::now() - std::time::Duration::from_secs(10);
+        assert!(!is_fresh(Some(stale), max_age));
+    }
 }
@@ -0,0 +1,37 @@
+//! Selection of the window provider commands talk to.
+//!
+//! The [`WindowProvider`] interface and the platform backends live in
+//! `swii_core`. Commands call [`provider`] instead of branching on `cfg`
+//! themselves: the platform backend is chosen at compile time, and the mock
+//! provider can be swapped in at runtime through `SWII_MOCK_WINDOWS` or
+//! `--mock-windows`.
+
+use swii_core::provider::{platform_provider, WindowProvider};
+
+/// Returns the provider commands should use
+pub fn provider() -> &'static dyn WindowProvider {
+    if swii_core::mock::is_enabled() {
+        return &swii_core::mock::MockProvider;
+    }
+    platform_provider()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_window_provider_capabilities(
+    _app: tauri::AppHandle,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    crate::command_wrapper::create_command("get_window_provider_capabilities", args, |ctx| {
+        let capabilities = provider().capabilities();
+        ctx.logger.info(&format!(
+            "Window provider '{}' capabilities: list={}, focus={}, observe={}",
+            capabilities.provider,
+            capabilities.list_windows,
+            capabilities.focus_windows,
+            capabilities.observe_windows
+        ));
+        Ok(capabilities)
+    })
+    .await
+}
@@ -0,0 +1,297 @@
+//! Project paths of Zed windows from Zed's workspace database.
+//!
+//! Zed only shows worktree names in its titles. It persists every open
+//! workspace, with the absolute paths of its worktrees and the id of the
+//! window showing it, in a SQLite database under its data dir
+//! (`db/<channel>/db.sqlite`). On macOS that window id is the window number,
+//! so windows can be matched exactly; elsewhere they are matched by name.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rusqlite::{Connection, OpenFlags};
+use swii_title_parser::{EditorKind, ProjectSource};
+use tracing::debug;
+
+use swii_core::types::WindowInfo;
+
+pub mod constants {
+    /// Database file inside each release channel directory
+    pub const DB_FILE: &str = "db.sqlite";
+
+    /// How long workspaces read from the database are reused
+    pub const WORKSPACES_CACHE_TTL_SECS: u64 = 5;
+}
+
+/// One workspace row: the window showing it and its worktree paths
+#[derive(Debug, Clone, PartialEq)]
+struct ZedWorkspace {
+    window_id: Option<u32>,
+    paths: Vec<String>,
+}
+
+impl ZedWorkspace {
+    fn name(&self) -> Option<String> {
+        path_name(self.paths.first()?)
+    }
+}
+
+fn path_name(path: &str) -> Option<String> {
+    Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+}
+
+/// Zed's `db` directory for the current platform
+fn db_dir() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    if cfg!(target_os = "macos") {
+        Some(home?.join("Library/Application Support/Zed/db"))
+    } else if cfg!(target_os = "windows") {
+        Some(PathBuf::from(std::env::var_os("LOCALAPPDATA")?).join("Zed/db"))
+    } else {
+        let data = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| Some(home?.join(".local/share")))?;
+        Some(data.join("zed/db"))
+    }
+}
+
+/// Decodes the bincode `Vec<PathBuf>` older Zed versions store in
+/// `local_paths`: a little-endian u64 count, then length-prefixed strings
+fn decode_local_paths(blob: &[u8]) -> Option<Vec<String>> {
+    fn read_u64(bytes: &mut &[u8]) -> Option<usize> {
+        let (head, rest) = bytes.split_first_chunk::<8>()?;
+        *bytes = rest;
+        usize::try_from(u64::from_le_bytes(*head)).ok()
+    }
+
+    let mut bytes = blob;
+    let count = read_u64(&mut bytes)?;
+    let mut paths = Vec::new();
+    for _ in 0..count {
+        let len = read_u64(&mut bytes)?;
+        if len > bytes.len() {
+            return None;
+        }
+        let (path, rest) = bytes.split_at(len);
+        paths.push(String::from_utf8_lossy(path).to_string());
+        bytes = rest;
+    }
+    Some(paths)
+}
+
+/// Reads the workspaces of one database, supporting both the newer
+/// newline-separated `paths` column and the older `local_paths` blob
+fn read_workspaces(db: &Path) -> rusqlite::Result<Vec<ZedWorkspace>> {
+    let connection = Connection::open_with_flags(
+        db,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+
+    let columns: Vec<String> = connection
+        .prepare("SELECT name FROM pragma_table_info('workspaces')")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    let workspaces = if columns.iter().any(|column| column == "paths") {
+        connection
+            .prepare("SELECT window_id, paths FROM workspaces WHERE paths IS NOT NULL")?
+            .query_map([], |row| {
+                let paths: String = row.get(1)?;
+                Ok(ZedWorkspace {
+                    window_id: row.get(0)?,
+                    paths: paths
+                        .lines()
+                        .filter(|path| !path.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        connection
+            .prepare("SELECT window_id, local_paths FROM workspaces WHERE local_paths IS NOT NULL")?
+            .query_map([], |row| {
+                let blob: Vec<u8> = row.get(1)?;
+                Ok(ZedWorkspace {
+                    window_id: row.get(0)?,
+                    paths: decode_local_paths(&blob).unwrap_or_default(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    Ok(workspaces
+        .into_iter()
+        .filter(|workspace| !workspace.paths.is_empty())
+        .collect())
+}
+
+/// Workspaces of every Zed release channel, cached for
+/// [`constants::WORKSPACES_CACHE_TTL_SECS`]
+fn workspaces() -> Vec<ZedWorkspace> {
+    static CACHE: Mutex<Option<(Instant, Vec<ZedWorkspace>)>> = Mutex::new(None);
+
+    let mut cache = CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let ttl = Duration::from_secs(constants::WORKSPACES_CACHE_TTL_SECS);
+    if let Some((read_at, workspaces)) = cache.as_ref() {
+        if read_at.elapsed() < ttl {
+            return workspaces.clone();
+        }
+    }
+
+    let channels = db_dir()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .flatten();
+    let mut workspaces = Vec::new();
+    for channel in channels {
+        let db = channel.path().join(constants::DB_FILE);
+        if !db.is_file() {
+            continue;
+        }
+        match read_workspaces(&db) {
+            Ok(found) => workspaces.extend(found),
+            Err(e) => debug!("Failed to read Zed workspaces from {}: {}", db.display(), e),
+        }
+    }
+
+    *cache = Some((Instant::now(), workspaces.clone()));
+    workspaces
+}
+
+/// Fills in project paths of Zed windows from Zed's workspace database
+pub fn apply_workspace_paths(windows: &mut [WindowInfo]) {
+    if !windows
+        .iter()
+        .any(|window| window.editor_kind == EditorKind::Zed)
+    {
+        return;
+    }
+    merge_workspaces(windows, &workspaces(), cfg!(target_os = "macos"));
+}
+
+/// Matches windows by window id when the ids are window numbers, otherwise
+/// by project name as long as only one workspace has that name
+fn merge_workspaces(windows: &mut [WindowInfo], workspaces: &[ZedWorkspace], match_ids: bool) {
+    for window in windows.iter_mut() {
+        if window.editor_kind != EditorKind::Zed {
+            continue;
+        }
+
+        let by_id = || {
+            workspaces
+                .iter()
+                .find(|workspace| match_ids && workspace.window_id == Some(window.window_number))
+        };
+        let by_name = || {
+            let mut named = workspaces
+                .iter()
+                .filter(|workspace| window.project.is_some() && workspace.name() == window.project);
+            let first = named.next()?;
+            // The same folder may be recorded once per channel
+            named
+                .all(|other| other.paths == first.paths)
+                .then_some(first)
+        };
+
+        if let Some(workspace) = by_id().or_else(by_name) {
+            window.project = workspace.name();
+            window.project_path = workspace.paths.first().cloned();
+            if workspace.paths.len() > 1 {
+                window.worktrees = workspace
+                    .paths
+                    .iter()
+                    .filter_map(|p| path_name(p))
+                    .collect();
+            }
+            window.project_source = ProjectSource::EditorDatabase;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(number: u32, project: &str) -> WindowInfo {
+        WindowInfo::builder("Zed", 1, number)
+            .project(Some(project))
+            .build()
+    }
+
+    fn workspace(window_id: Option<u32>, paths: &[&str]) -> ZedWorkspace {
+        ZedWorkspace {
+            window_id,
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_decode_local_paths() {
+        let mut blob = 2u64.to_le_bytes().to_vec();
+        for path in ["/Users/dev/api", "/Users/dev/web"] {
+            blob.extend((path.len() as u64).to_le_bytes());
+            blob.extend(path.as_bytes());
+        }
+        assert_eq!(
+            decode_local_paths(&blob),
+            Some(vec![
+                "/Users/dev/api".to_string(),
+                "/Users/dev/web".to_string()
+            ])
+        );
+        assert_eq!(decode_local_paths(&blob[..20]), None);
+        assert_eq!(decode_local_paths(&[]), None);
+    }
+
+    #[test]
+    fn test_read_workspaces() {
+        let temp = tempfile::tempdir().unwrap();
+        let db = temp.path().join("workspaces.sqlite");
+        let connection = Connection::open(&db).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE workspaces (workspace_id INTEGER PRIMARY KEY, paths TEXT, window_id INTEGER);
+                 INSERT INTO workspaces VALUES (1, '/Users/dev/api\n/Users/dev/web', 7);
+                 INSERT INTO workspaces VALUES (2, NULL, 8);",
+            )
+            .unwrap();
+        drop(connection);
+
+        assert_eq!(
+            read_workspaces(&db).unwrap(),
+            vec![workspace(Some(7), &["/Users/dev/api", "/Users/dev/web"])]
+        );
+    }
+
+    #[test]
+    fn test_merge_workspaces() {
+        let workspaces = [
+            workspace(Some(7), &["/Users/dev/api", "/Users/dev/web"]),
+            workspace(Some(9), &["/Users/dev/clients/acme/api"]),
+            workspace(None, &["/Users/dev/notes"]),
+        ];
+
+        let mut windows = vec![window(7, "api"), window(9, "api"), window(3, "notes")];
+        merge_workspaces(&mut windows, &workspaces, true);
+        assert_eq!(windows[0].project_path.as_deref(), Some("/Users/dev/api"));
+        assert_eq!(windows[0].worktrees, vec!["api", "web"]);
+        assert_eq!(
+            windows[1].project_path.as_deref(),
+            Some("/Users/dev/clients/acme/api")
+        );
+        assert_eq!(windows[2].project_path.as_deref(), Some("/Users/dev/notes"));
+        assert_eq!(windows[2].project_source, ProjectSource::EditorDatabase);
+
+        // Ambiguous names are left alone without window ids
+        let mut windows = vec![window(7, "api")];
+        merge_workspaces(&mut windows, &workspaces, false);
+        assert_eq!(windows[0].project_path, None);
+    }
+}
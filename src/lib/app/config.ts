@@ -3,15 +3,26 @@
 
 // Window management constants
 export const WINDOW_VISIBILITY_CHECK_INTERVAL = 100;
-export const GLOBAL_SHORTCUT_KEY = "Command+Y";
 
-// Application types
-export type WindowInfo = {
-    app_name: string;
-    window_name: string | null;
-    pid: number;
-    window_number: number;
-    project: string | null;
-    active_editor_tab: string | null;
-    app_icon: string | null;
-}
\ No newline at end of file
+// Backend event names
+export const IDLE_STATE_CHANGED_EVENT = "idle-state-changed";
+export const WINDOW_TITLE_CHANGED_EVENT = "window-title-changed";
+export const WINDOW_OPENED_EVENT = "window-opened";
+export const WINDOW_CLOSED_EVENT = "window-closed";
+export const FOCUS_RULE_FIRED_EVENT = "focus-rule-fired";
+export const WINDOW_CHUNK_EVENT = "editor-windows-chunk";
+export const SHORTCUT_PRESSED_EVENT = "global-shortcut-pressed";
+export const SHORTCUT_STATUS_EVENT = "shortcut-status-changed";
+export const WATCHED_PROJECT_CHANGED_EVENT = "watched-project-changed";
+export const DND_CHANGED_EVENT = "dnd-changed";
+export const FOCUS_TIMER_EVENT = "focus-timer";
+
+// Application types (generated from the Rust definitions)
+export type { DndStatus, FocusRuleFired, FocusTimerStatus, ShortcutPressed, ShortcutStatus, WatchedProjectChanged, WindowChunk, WindowInfo } from "$lib/tauri/bindings";
+import type { WindowInfo } from "$lib/tauri/bindings";
+
+// Payload of WINDOW_TITLE_CHANGED_EVENT: the fields of a window that follow its title
+export type WindowTitleChanged = Pick<
+  WindowInfo,
+  "pid" | "window_number" | "window_name" | "project" | "project_label" | "active_editor_tab" | "is_modified"
+>;
\ No newline at end of file
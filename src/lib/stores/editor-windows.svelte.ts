@@ -1,17 +1,88 @@
+import { listen } from '@tauri-apps/api/event';
 import commands from '$lib/tauri/commands';
 import { handleCommandError, isCommandError } from '$lib/utils/errorHandler';
-import type { WindowInfo } from '$lib/app/config';
+import {
+  WINDOW_CHUNK_EVENT,
+  WINDOW_CLOSED_EVENT,
+  WINDOW_OPENED_EVENT,
+  WINDOW_TITLE_CHANGED_EVENT,
+  type WindowChunk,
+  type WindowInfo,
+  type WindowTitleChanged,
+} from '$lib/app/config';
 import { logger } from '$lib/utils/logger';
 import { devErrorsStore } from './dev-errors.svelte';
 
+type WindowKey = Pick<WindowInfo, 'pid' | 'window_number'>;
+
 class EditorWindowsStore {
   windows = $state<WindowInfo[]>([]);
   isLoading = $state(false);
+  private prefilled = false;
+
+  constructor() {
+    this.listenForTitleChanges();
+    this.listenForOpenedAndClosed();
+    this.listenForWindowChunks();
+  }
+
+  private isSameWindow(a: WindowKey, b: WindowKey) {
+    return a.pid === b.pid && a.window_number === b.window_number;
+  }
+
+  private async listenForOpenedAndClosed() {
+    await listen<WindowInfo>(WINDOW_OPENED_EVENT, (event) => {
+      const opened = event.payload;
+      if (!this.windows.some((window) => this.isSameWindow(window, opened))) {
+        logger.debug('EDITOR_STORE', `Window ${opened.window_number} opened:`, opened.window_name);
+        this.windows.push(opened);
+      }
+    });
+    await listen<WindowInfo>(WINDOW_CLOSED_EVENT, (event) => {
+      const closed = event.payload;
+      logger.debug('EDITOR_STORE', `Window ${closed.window_number} closed:`, closed.window_name);
+      this.windows = this.windows.filter((window) => !this.isSameWindow(window, closed));
+    });
+  }
+
+  // Shows a long list while it is still arriving; the command result replaces it
+  private async listenForWindowChunks() {
+    await listen<WindowChunk>(WINDOW_CHUNK_EVENT, (event) => {
+      const chunk = event.payload;
+      if (!this.isLoading) {
+        return;
+      }
+      this.windows = chunk.offset === 0 ? chunk.windows : [...this.windows, ...chunk.windows];
+    });
+  }
+
+  private async listenForTitleChanges() {
+    await listen<WindowTitleChanged>(WINDOW_TITLE_CHANGED_EVENT, (event) => {
+      const change = event.payload;
+      const index = this.windows.findIndex((window) => this.isSameWindow(window, change));
+      if (index === -1) {
+        return;
+      }
+      logger.debug('EDITOR_STORE', `Window ${change.window_number} retitled:`, change.window_name);
+      this.windows[index] = { ...this.windows[index], ...change };
+    });
+  }
+
+  // Windows sent along with the shortcut; the next load is skipped
+  prefill(windows: WindowInfo[]) {
+    logger.info('EDITOR_STORE', `Received ${windows.length} windows with the shortcut`);
+    this.windows = windows;
+    this.prefilled = true;
+  }
 
   async loadWindows() {
+    if (this.prefilled) {
+      this.prefilled = false;
+      return;
+    }
     this.isLoading = true;
     logger.info('EDITOR_STORE', 'Loading editor windows...');
-    const result = await commands.listEditorWindows();
+    const result = await commands.listEditorWindows({ stream: true });
 
     logger.info('EDITOR_STORE', 'Command result:', result);
 
@@ -36,6 +107,25 @@ class EditorWindowsStore {
       return false;
     }
 
+    const focused = result.data.window;
+    if (focused) {
+      const index = this.windows.findIndex((window) => this.isSameWindow(window, focused));
+      if (index !== -1) {
+        this.windows[index] = focused;
+      }
+    }
+
+    return true;
+  }
+
+  async openEditorWindow(pid: number, path: string | null) {
+    const result = await commands.openEditorWindow({ pid, path });
+
+    if (isCommandError(result)) {
+      handleCommandError(result.error, "open editor window");
+      return false;
+    }
+
     return true;
   }
 }
@@ -1,5 +1,6 @@
 import { getCurrentWindow } from '@tauri-apps/api/window';
-import { WINDOW_VISIBILITY_CHECK_INTERVAL } from '$lib/app/config';
+import { listen } from '@tauri-apps/api/event';
+import { DND_CHANGED_EVENT, IDLE_STATE_CHANGED_EVENT, WINDOW_VISIBILITY_CHECK_INTERVAL, type DndStatus } from '$lib/app/config';
 import { logger } from '$lib/utils/logger';
 
 class OverlayStore {
@@ -9,11 +10,59 @@ class OverlayStore {
   
   private win = getCurrentWindow();
   private intervalId: number | null = null;
+  private checkVisibility: (() => Promise<void>) | null = null;
+  private idle = false;
+  private dnd = false;
 
   constructor() {
     logger.info('OVERLAY_STORE', 'Initializing overlay store');
     logger.debug('OVERLAY_STORE', 'Window object created');
     this.startVisibilityCheck();
+    this.listenForIdle();
+    this.listenForDnd();
+  }
+
+  private async listenForIdle() {
+    await listen<{ idle: boolean }>(IDLE_STATE_CHANGED_EVENT, (event) => {
+      this.idle = event.payload.idle;
+      if (this.idle) {
+        logger.info('OVERLAY_STORE', 'User idle, pausing visibility check');
+        this.pauseVisibilityCheck();
+      } else {
+        logger.info('OVERLAY_STORE', 'User active, resuming visibility check');
+        this.resumeVisibilityCheck();
+      }
+    });
+  }
+
+  private async listenForDnd() {
+    await listen<DndStatus>(DND_CHANGED_EVENT, (event) => {
+      this.dnd = event.payload.enabled;
+      if (this.dnd) {
+        logger.info('OVERLAY_STORE', 'Do not disturb on, pausing visibility check');
+        this.pauseVisibilityCheck();
+      } else {
+        logger.info('OVERLAY_STORE', 'Do not disturb off, resuming visibility check');
+        this.resumeVisibilityCheck();
+      }
+    });
+  }
+
+  private pauseVisibilityCheck() {
+    if (this.intervalId !== null) {
+      clearInterval(this.intervalId);
+      this.intervalId = null;
+    }
+  }
+
+  private resumeVisibilityCheck() {
+    // Stays paused until the user is neither idle nor in do not disturb
+    if (this.idle || this.dnd) {
+      return;
+    }
+    if (this.intervalId === null && this.checkVisibility) {
+      this.intervalId = window.setInterval(this.checkVisibility, WINDOW_VISIBILITY_CHECK_INTERVAL);
+    }
   }
 
   private async startVisibilityCheck() {
@@ -41,11 +90,12 @@ class OverlayStore {
       }
     };
 
+    this.checkVisibility = checkVisibility;
     await checkVisibility();
     const initialVisibility = this.isVisible;
     logger.info('OVERLAY_STORE', `Initial visibility: ${initialVisibility}`);
     
-    this.intervalId = window.setInterval(checkVisibility, WINDOW_VISIBILITY_CHECK_INTERVAL);
+    this.resumeVisibilityCheck();
     logger.info('OVERLAY_STORE', 'Visibility check interval started');
   }
 
@@ -98,9 +148,7 @@ class OverlayStore {
   }
 
   destroy() {
-    if (this.intervalId !== null) {
-      clearInterval(this.intervalId);
-    }
+    this.pauseVisibilityCheck();
   }
 }
 
@@ -1,26 +1,111 @@
-import { createFuseInstance } from "$lib/fuse/config";
+import commands from "$lib/tauri/commands";
+import type { SearchMatch, SearchSuggestion } from "$lib/tauri/bindings";
 import type { WindowInfo } from "$lib/app/config";
-import type Fuse from "fuse.js";
+import { handleCommandError, isCommandError } from "$lib/utils/errorHandler";
 
 class SearchStore {
   query = $state("");
+  matches = $state<SearchMatch[]>([]);
+  suggestions = $state<SearchSuggestion[]>([]);
+  private latestRequest = 0;
+  private latestSuggestionRequest = 0;
 
   clear() {
     this.query = "";
+    this.matches = [];
+    this.suggestions = [];
   }
 
-  search(windows: WindowInfo[]) {
-    const fuseInstance: Fuse<WindowInfo> = createFuseInstance(windows);
+  // Previous queries starting with the typed text, most used first
+  async suggest(prefix: string) {
+    const request = ++this.latestSuggestionRequest;
+    if (!prefix.trim()) {
+      this.suggestions = [];
+      return;
+    }
+
+    const result = await commands.getSearchSuggestions({ prefix });
+    if (request !== this.latestSuggestionRequest) {
+      return;
+    }
+    if (isCommandError(result)) {
+      handleCommandError(result.error, "load search suggestions");
+      return;
+    }
+    this.suggestions = result.data;
+  }
 
+  // Remembers which result the current query led to, so it ranks higher next time
+  async accept(window: WindowInfo) {
     if (!this.query.trim()) {
-      return windows.map((window, index) => ({
-        item: window,
-        refIndex: index,
-        score: 0,
-      }));
+      return;
+    }
+    const result = await commands.recordSearch({
+      query: this.query,
+      app_name: window.app_name,
+      project: window.project,
+      project_path: window.project_path,
+      window_name: window.window_name,
+    });
+    if (isCommandError(result)) {
+      handleCommandError(result.error, "record search");
     }
+  }
 
-    return fuseInstance.search(this.query);
+  // Ranks the listed windows and recent projects in the backend; answers to older queries are dropped
+  async search(query: string) {
+    const request = ++this.latestRequest;
+    if (!query.trim()) {
+      this.matches = [];
+      return;
+    }
+
+    const result = await commands.searchWindows({ query });
+    if (request !== this.latestRequest) {
+      return;
+    }
+    if (isCommandError(result)) {
+      handleCommandError(result.error, "search windows");
+      return;
+    }
+    this.matches = result.data;
+  }
+
+  // Recent projects show up as windowless entries of an editor that is listed
+  results(windows: WindowInfo[]) {
+    if (!this.query.trim()) {
+      return windows;
+    }
+    return this.matches.flatMap((match): WindowInfo[] => {
+      const { item } = match;
+      if (item.kind === "window") {
+        return [item.window];
+      }
+      const editor = windows.find((window) => window.app_name === item.project.app_name);
+      if (!editor) {
+        return [];
+      }
+      return [{
+        ...editor,
+        window_name: null,
+        window_number: 0,
+        project: item.project.project,
+        project_label: null,
+        project_path: item.project.project_path,
+        active_editor_tab: null,
+        is_modified: false,
+        remote_host: null,
+        environment: null,
+        workspace: false,
+        worktrees: [],
+        auxiliary: false,
+        is_scratch: false,
+        project_source: "title",
+        containerized: false,
+        has_windows: false,
+        label: null,
+      }];
+    });
   }
 }
 
@@ -0,0 +1,934 @@
+// @ts-nocheck
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+async listEditorWindows(args: JsonValue | null) : Promise<CommandResult<WindowInfo[]>> {
+    return await TAURI_INVOKE("list_editor_windows", { args });
+},
+async searchWindows(args: JsonValue | null) : Promise<CommandResult<SearchMatch[]>> {
+    return await TAURI_INVOKE("search_windows", { args });
+},
+async recordSearch(args: JsonValue | null) : Promise<CommandResult<null>> {
+    return await TAURI_INVOKE("record_search", { args });
+},
+async getSearchSuggestions(args: JsonValue | null) : Promise<CommandResult<SearchSuggestion[]>> {
+    return await TAURI_INVOKE("get_search_suggestions", { args });
+},
+async bringWindowToFront(args: JsonValue | null) : Promise<CommandResult<FocusOutcome>> {
+    return await TAURI_INVOKE("bring_window_to_front", { args });
+},
+async listOpenFiles(args: JsonValue | null) : Promise<CommandResult<OpenFile[]>> {
+    return await TAURI_INVOKE("list_open_files", { args });
+},
+async getFocusHistory(args: JsonValue | null) : Promise<CommandResult<FocusHistoryPage>> {
+    return await TAURI_INVOKE("get_focus_history", { args });
+},
+async getRunningEditors(args: JsonValue | null) : Promise<CommandResult<RunningEditor[]>> {
+    return await TAURI_INVOKE("get_running_editors", { args });
+},
+async openEditorWindow(args: JsonValue | null) : Promise<CommandResult<OpenWindowOutcome>> {
+    return await TAURI_INVOKE("open_editor_window", { args });
+},
+async focusBrowserTab(args: JsonValue | null) : Promise<CommandResult<BrowserTabOutcome>> {
+    return await TAURI_INVOKE("focus_browser_tab", { args });
+},
+async logFromFrontend(level: LogLevel, tag: string, message: string) : Promise<void> {
+    await TAURI_INVOKE("log_from_frontend", { level, tag, message });
+},
+async logFromFrontendWithData(level: LogLevel, tag: string, message: string, data: JsonValue) : Promise<void> {
+    await TAURI_INVOKE("log_from_frontend_with_data", { level, tag, message, data });
+},
+async openDevtools(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_devtools", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listWebviewWindows(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_webview_windows", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async runDiagnostics(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_diagnostics", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dumpWindowDebugInfo(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("dump_window_debug_info", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async healthCheck(args: JsonValue | null) : Promise<CommandResult<HealthReport>> {
+    return await TAURI_INVOKE("health_check", { args });
+},
+async runSelfTest(args: JsonValue | null) : Promise<CommandResult<SelfTestReport>> {
+    return await TAURI_INVOKE("run_self_test", { args });
+},
+async startSnapshotRecording(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_snapshot_recording", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async startSnapshotReplay(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_snapshot_replay", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async stopSnapshots(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_snapshots", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSnapshotStatus(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_snapshot_status", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getIdleStatus(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_idle_status", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setIdleThreshold(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_idle_threshold", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getWindowProviderCapabilities(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_window_provider_capabilities", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getApiSchema(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_api_schema", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async reloadSettings(args: JsonValue | null) : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reload_settings", { args }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setActivationPolicy(args: JsonValue | null) : Promise<CommandResult<ActivationPolicy>> {
+    return await TAURI_INVOKE("set_activation_policy", { args });
+},
+async watchProject(args: JsonValue | null) : Promise<CommandResult<string[]>> {
+    return await TAURI_INVOKE("watch_project", { args });
+},
+async unwatchProject(args: JsonValue | null) : Promise<CommandResult<string[]>> {
+    return await TAURI_INVOKE("unwatch_project", { args });
+},
+async setDnd(args: JsonValue | null) : Promise<CommandResult<DndStatus>> {
+    return await TAURI_INVOKE("set_dnd", { args });
+},
+async exportSession(args: JsonValue | null) : Promise<CommandResult<string>> {
+    return await TAURI_INVOKE("export_session", { args });
+},
+async startFocusTimer(args: JsonValue | null) : Promise<CommandResult<FocusTimerStatus>> {
+    return await TAURI_INVOKE("start_focus_timer", { args });
+},
+async stopFocusTimer(args: JsonValue | null) : Promise<CommandResult<FocusTimerStatus | null>> {
+    return await TAURI_INVOKE("stop_focus_timer", { args });
+},
+async copyProjectPath(args: JsonValue | null) : Promise<CommandResult<string | null>> {
+    return await TAURI_INVOKE("copy_project_path", { args });
+},
+async copyFilePath(args: JsonValue | null) : Promise<CommandResult<string | null>> {
+    return await TAURI_INVOKE("copy_file_path", { args });
+},
+async openFile(args: JsonValue | null) : Promise<CommandResult<OpenFileOutcome>> {
+    return await TAURI_INVOKE("open_file", { args });
+},
+async setWindowLabel(args: JsonValue | null) : Promise<CommandResult<WindowInfo>> {
+    return await TAURI_INVOKE("set_window_label", { args });
+},
+async logBatchFromFrontend(entries: LogEntry[]) : Promise<void> {
+    await TAURI_INVOKE("log_batch_from_frontend", { entries });
+},
+async getShortcutStatus(args: JsonValue | null) : Promise<CommandResult<ShortcutStatus>> {
+    return await TAURI_INVOKE("get_shortcut_status", { args });
+},
+async getProjectSummary(args: JsonValue | null) : Promise<CommandResult<ProjectSummary>> {
+    return await TAURI_INVOKE("get_project_summary", { args });
+}
+}
+
+/** user-defined events **/
+
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * How swii presents itself to the system
+ */
+export type ActivationPolicy =
+/**
+ * No Dock icon
+ */
+"accessory" |
+/**
+ * A Dock icon and an entry in the app switcher
+ */
+"regular"
+/**
+ * Payload returned by `get_api_schema`
+ */
+export type ApiSchema = { api_version: number;
+/**
+ * Version of the `WindowInfo` layout returned by the window commands
+ */
+window_schema_version: number; app_version: string; commands: CommandSchema[] }
+export type AppExtractionTiming = { app_name: string; pid: number; elapsed_ms: number }
+/**
+ * Result of a successful `focus_browser_tab`
+ */
+export type BrowserTabOutcome = { browser: string;
+/**
+ * Full URL of the tab that was selected
+ */
+url: string }
+/**
+ * Outcome of a single check
+ */
+export type CheckStatus = "pass" |
+/**
+ * swii works, but with reduced detail
+ */
+"warn" | "fail" |
+/**
+ * The check doesn't apply on this platform
+ */
+"skipped"
+/**
+ * Standardized command result wrapper
+ */
+export type CommandResult<T> = { success: boolean; data: T | null; error: ErrorInfo | null; execution_time_ms: number; timestamp: string }
+/**
+ * How much the parser trusts its result
+ */
+export type Confidence =
+/**
+ * Generic fallback, or nothing could be extracted
+ */
+"low" |
+/**
+ * Heuristic split of an em-dash title
+ */
+"medium" |
+/**
+ * A user rule or an editor-specific title format matched
+ */
+"high"
+/**
+ * Description of one invokable command
+ */
+export type CommandSchema = { name: string; description: string;
+/**
+ * True when parameters are passed inside an `args` object, as with all
+ * commands built on `command_wrapper`; false when they are top-level
+ */
+wrapped_args: boolean; parameters: ParameterSchema[];
+/**
+ * TypeScript name of the response payload
+ */
+returns: string }
+/**
+ * Environment marker such as "[Dev Container: api]" or "[WSL: Ubuntu]"
+ */
+export type DevEnvironment = { kind: DevEnvironmentKind;
+/**
+ * Container, distribution or codespace name
+ */
+name: string }
+/**
+ * Kind of environment a remote window runs in
+ */
+export type DevEnvironmentKind = "dev_container" | "wsl" | "codespaces"
+/**
+ * Timing report returned by `run_diagnostics`
+ */
+export type DiagnosticsReport = { window_count: number; app_count: number; timings: EnumerationTimings; serialization_ms: number; payload_bytes: number; total_ms: number }
+/**
+ * State of the mode, returned by `set_dnd` and sent with `dnd-changed`
+ */
+export type DndStatus = { enabled: boolean;
+/**
+ * Milliseconds since the Unix epoch, `None` until turned off
+ */
+until: number | null }
+/**
+ * Whether a known editor is installed at its usual location
+ */
+export type EditorInstall = { name: string; path: string; installed: boolean }
+/**
+ * Editor family a window title belongs to
+ */
+export type EditorKind = "vs_code" | "cursor" | "zed" | "jet_brains" | "xcode" | "sublime" | "vim" | "helix" | "kakoune" | "tmux" | "shell" | "lapce" | "pulsar" | "nova" | "bb_edit" | "text_mate" | "eclipse" | "net_beans" | "r_studio" | "matlab" | "jupyter" | "unknown"
+/**
+ * Per-phase timing breakdown collected during window enumeration
+ */
+export type EnumerationTimings = { cg_enumeration_ms: number; ax_extraction_ms: number; ax_extraction_per_app: AppExtractionTiming[]; icon_extraction_ms: number; window_info_ms: number }
+/**
+ * Wire format of a [`SwiiError`]
+ */
+export type ErrorInfo = {
+/**
+ * Stable, snake_case identifier of the failure
+ */
+code: string;
+/**
+ * Short description for the UI, in English; translate by `code`
+ */
+user_message: string;
+/**
+ * Detailed description for logs and developers
+ */
+message: string;
+/**
+ * Structured context for some codes, e.g. the offending parameter
+ */
+details: JsonValue | null }
+/**
+ * Characters of a field matched by one query term
+ */
+export type FieldMatch = { field: SearchField;
+/**
+ * Character indices into the field, for highlighting
+ */
+positions: number[] }
+/**
+ * One page of the focus history
+ */
+export type FocusHistoryPage = { records: FocusRecord[];
+/**
+ * Number of records in the whole history
+ */
+total: number;
+/**
+ * Offset of the next page, `None` on the last page
+ */
+next_offset: number | null }
+/**
+ * Result of a successful `bring_window_to_front`
+ */
+export type FocusOutcome = { pid: number; window_number: number;
+/**
+ * Window provider that performed the focus
+ */
+provider: string;
+/**
+ * The focused window as listed right after focusing, or as last listed
+ * when windows couldn't be listed again
+ */
+window: WindowInfo | null;
+/**
+ * Whether the window was found in the listing taken after focusing
+ */
+verified: boolean }
+/**
+ * An editor window that came to the front
+ */
+export type FocusRecord = { pid: number; window_number: number; app_name: string; window_name: string | null; project: string | null; project_path: string | null;
+/**
+ * Milliseconds since the Unix epoch
+ */
+focused_at: number;
+/**
+ * Milliseconds until the next focus or until the user went idle,
+ * `None` for the latest record
+ */
+duration_ms: number | null }
+/**
+ * Outcome of a fired rule, sent with `focus-rule-fired`
+ */
+export type FocusRuleFired = { rule: string; project: string;
+/**
+ * Window that was focused, `None` when no window shows the project
+ */
+pid: number | null; window_number: number | null;
+/**
+ * Why focusing failed
+ */
+error: string | null }
+/**
+ * What happened to the timer
+ */
+export type FocusTimerPhase =
+/**
+ * `start_focus_timer` gave the project a budget
+ */
+"started" |
+/**
+ * The budget is used up; the timer is gone
+ */
+"finished" |
+/**
+ * A window of another project came to the front early
+ */
+"left_project" |
+/**
+ * A window of the project is in front again
+ */
+"returned" |
+/**
+ * `stop_focus_timer` ended the timer early
+ */
+"stopped"
+/**
+ * Payload of `focus-timer`, also returned by the timer commands
+ */
+export type FocusTimerStatus = { project: string; phase: FocusTimerPhase;
+/**
+ * Milliseconds since the Unix epoch
+ */
+started_at: number;
+/**
+ * Milliseconds since the Unix epoch
+ */
+ends_at: number }
+/**
+ * A single check of the health report
+ */
+export type HealthCheck = {
+/**
+ * Stable identifier, e.g. "accessibility"
+ */
+id: string; status: CheckStatus; detail: string;
+/**
+ * What the user can do about a warning or failure
+ */
+remediation: string | null }
+/**
+ * Result of `health_check`
+ */
+export type HealthReport = {
+/**
+ * False when any check failed
+ */
+healthy: boolean;
+/**
+ * Window provider the checks ran against
+ */
+provider: string; checks: HealthCheck[]; editors: EditorInstall[] }
+/**
+ * Idle state snapshot sent to the frontend
+ */
+export type IdleStatus = { idle: boolean; idle_seconds: number | null; threshold_secs: number }
+export type JsonValue = null | boolean | number | string | JsonValue[] | { [key in string]: JsonValue }
+/**
+ * One message of a `log_batch_from_frontend` batch
+ */
+export type LogEntry = { level: LogLevel; tag: string; message: string;
+/**
+ * Structured data attached to the message
+ */
+data: JsonValue | null }
+export type LogLevel = "debug" | "info" | "warn" | "error"
+/**
+ * A document open in an editor window
+ */
+export type OpenFile = {
+/**
+ * File name as shown on its tab
+ */
+name: string;
+/**
+ * Absolute path, when the source knows it
+ */
+path: string | null;
+/**
+ * Whether this is the document the window currently shows
+ */
+is_active: boolean }
+/**
+ * Result of a successful `open_file`
+ */
+export type OpenFileOutcome = { pid: number; window_number: number; path: string; line: number | null; column: number | null;
+/**
+ * The window already showed the file and was only focused
+ */
+already_open: boolean }
+/**
+ * Result of a successful `open_editor_window`
+ */
+export type OpenWindowOutcome = { pid: number;
+/**
+ * Project that was opened, or `None` for a new empty window
+ */
+path: string | null }
+/**
+ * JSON type of a command parameter
+ */
+export type ParameterKind = "string" | "integer" | "boolean" | "any"
+/**
+ * A single command parameter
+ */
+export type ParameterSchema = { name: string; kind: ParameterKind; required: boolean; description: string }
+/**
+ * Everything the parser extracted from a window title
+ */
+export type ParsedTitle = { editor_kind: EditorKind; project: string | null; tab: string | null;
+/**
+ * File or folder path shown in the title, if any
+ */
+path: string | null;
+/**
+ * Whether the title marks unsaved changes
+ */
+is_modified: boolean;
+/**
+ * Host of a remote SSH window, from "[SSH: host]" or an "SSH: host" segment
+ */
+remote_host: string | null;
+/**
+ * Container or WSL distribution the window is attached to
+ */
+environment: DevEnvironment | null;
+/**
+ * Whether the project is a VS Code multi-root workspace
+ */
+workspace: boolean;
+/**
+ * Non-default VS Code profile shown in the title
+ */
+profile: string | null;
+/**
+ * All worktrees of a Zed window when more than one is open, primary first
+ */
+worktrees: string[];
+/**
+ * Dialogs, diff viewers and welcome screens that don't show a project
+ */
+auxiliary: boolean;
+/**
+ * Kind of Xcode container the project was resolved from
+ */
+xcode_container: XcodeContainer | null;
+/**
+ * Window shows only an unnamed buffer such as "Untitled-1"
+ */
+is_scratch: boolean;
+/**
+ * Root directory of the project, when it could be resolved on disk
+ */
+project_path: string | null;
+/**
+ * Whether `project` came from the title or from the open document
+ */
+project_source: ProjectSource; confidence: Confidence }
+/**
+ * A project root and the branch checked out there
+ */
+export type ProjectPath = { path: string;
+/**
+ * Branch name, or the short commit for a detached `HEAD`; `None`
+ * outside a git repository
+ */
+git_branch: string | null }
+/**
+ * Where the project name of a window came from
+ */
+export type ProjectSource =
+/**
+ * Parsed from the window title
+ */
+"title" |
+/**
+ * Resolved from the document the window has open
+ */
+"document" |
+/**
+ * Reported by the editor's companion extension
+ */
+"extension" |
+/**
+ * Reported by a running JetBrains IDE
+ */
+"ide" |
+/**
+ * Read from the editor's own workspace database
+ */
+"editor_database" |
+/**
+ * Read from the editor's saved session
+ */
+"editor_session" |
+/**
+ * Reported by the terminal multiplexer, e.g. tmux
+ */
+"multiplexer" |
+/**
+ * Reported by the terminal emulator, e.g. iTerm2's session path
+ */
+"terminal"
+/**
+ * Result of `get_project_summary`
+ */
+export type ProjectSummary = { project: string;
+/**
+ * Open windows of the project, as last listed
+ */
+windows: WindowInfo[];
+/**
+ * Roots of the open windows, then roots it was focused in before
+ */
+paths: ProjectPath[];
+/**
+ * Milliseconds the project's windows spent in front over the last day
+ */
+focus_time_ms: number;
+/**
+ * Milliseconds since the Unix epoch, `None` if never focused
+ */
+last_focused_at: number | null;
+/**
+ * Whether the project is in `watched_projects`
+ */
+watched: boolean }
+/**
+ * What the active provider can do on this system
+ */
+export type ProviderCapabilities = { provider: string; list_windows: boolean; focus_windows: boolean; observe_windows: boolean;
+/**
+ * Why a capability is missing, when known
+ */
+detail: string | null }
+/**
+ * A project focused before
+ */
+export type RecentProject = { project: string; project_path: string | null;
+/**
+ * Editor the project was last focused in
+ */
+app_name: string;
+/**
+ * Milliseconds since the Unix epoch
+ */
+last_focused_at: number }
+/**
+ * A running editor application
+ */
+export type RunningEditor = { pid: number; name: string;
+/**
+ * Platform identifier of the application, e.g. "dev.zed.Zed"
+ */
+bundle_id: string | null;
+/**
+ * Base64-encoded application icon
+ */
+icon: string | null;
+/**
+ * Number of listed editor windows, zero for an editor without windows
+ */
+window_count: number }
+/**
+ * Field a query term matched in
+ */
+export type SearchField = "project" | "tab" | "path" | "app_name"
+/**
+ * Something a search can find
+ */
+export type SearchItem = { kind: "window"; window: WindowInfo } |
+/**
+ * A recently focused project without an open window
+ */
+{ kind: "recent_project"; project: RecentProject }
+/**
+ * An item matching the query
+ */
+export type SearchMatch = { item: SearchItem;
+/**
+ * Higher is better; 0 for every window of an empty query
+ */
+score: number;
+/**
+ * One entry per query term
+ */
+matches: FieldMatch[] }
+/**
+ * A previous query starting with the typed prefix
+ */
+export type SearchSuggestion = { query: string;
+/**
+ * Number of times a result was accepted for the query
+ */
+uses: number }
+/**
+ * Output format of `export_session`
+ */
+export type SessionFormat = "json" | "markdown"
+/**
+ * Payload of the shortcut event
+ */
+export type ShortcutPressed = { shortcut: string;
+/**
+ * Editor windows to show right away, `None` when listing failed
+ */
+windows: WindowInfo[] | null }
+/**
+ * Whether the panel shortcut is registered, returned by
+ * `get_shortcut_status` and sent with `shortcut-status-changed`
+ */
+export type ShortcutStatus = {
+/**
+ * Shortcut in use, or the one that was tried when none could be
+ * registered
+ */
+shortcut: string; registered: boolean;
+/**
+ * Why the configured shortcut isn't the one in use, if it isn't
+ */
+error: string | null }
+/**
+ * Result of `run_self_test`
+ */
+export type SelfTestReport = {
+/**
+ * True when no stage failed
+ */
+passed: boolean;
+/**
+ * Window provider under test
+ */
+provider: string;
+/**
+ * Window that was focused, as `[pid, window_number]`
+ */
+target: [number, number] | null; stages: SelfTestStage[] }
+/**
+ * One stage of the self test
+ */
+export type SelfTestStage = {
+/**
+ * Stable identifier, e.g. "focus"
+ */
+id: string; status: CheckStatus; detail: string; elapsed_ms: number }
+/**
+ * Result of loading the settings file
+ */
+export type SettingsStatus = { path: string; exists: boolean; title_rule_count: number;
+/**
+ * Problems with individual entries; the rest of the file still applies
+ */
+errors: string[] }
+/**
+ * Status payload returned by the snapshot commands
+ */
+export type SnapshotStatus = { mode: string; path: string | null; snapshot_count: number; cursor: number | null }
+/**
+ * Payload of `watched-project-changed`
+ */
+export type WatchedProjectChanged = {
+/**
+ * The watched name that matched
+ */
+project: string;
+/**
+ * True when the window opened, false when it closed
+ */
+opened: boolean; window: WindowInfo }
+/**
+ * Part of a streamed window listing, sent with `editor-windows-chunk`
+ */
+export type WindowChunk = {
+/**
+ * Position of the first window of the chunk in the requested page
+ */
+offset: number; windows: WindowInfo[];
+/**
+ * Windows in the requested page
+ */
+total: number;
+/**
+ * True for the last chunk
+ */
+done: boolean }
+/**
+ * Window information structure
+ */
+export type WindowInfo = { app_name: string; window_name: string | null; pid: number; window_number: number; project: string | null; active_editor_tab: string | null; app_icon: string | null; editor_kind: EditorKind;
+/**
+ * Whether the window title marks unsaved changes
+ */
+is_modified: boolean;
+/**
+ * Host of a remote SSH window
+ */
+remote_host: string | null;
+/**
+ * Dev container, WSL distribution or codespace of a remote window
+ */
+environment: DevEnvironment | null;
+/**
+ * Whether the project is a VS Code multi-root workspace
+ */
+workspace: boolean;
+/**
+ * All worktrees of a Zed window when more than one is open, primary first
+ */
+worktrees: string[];
+/**
+ * Dialog, diff viewer or welcome screen rather than a project window
+ */
+auxiliary: boolean;
+/**
+ * Window shows only an unnamed buffer such as "Untitled-1"
+ */
+is_scratch: boolean;
+/**
+ * Project name with a disambiguator when several windows share it,
+ * e.g. "api (prod-box)" or "api (2)"
+ */
+project_label: string | null;
+/**
+ * Absolute root directory of the project, when it could be resolved
+ * from the open document
+ */
+project_path: string | null;
+/**
+ * Whether `project` came from the title or from the open document
+ */
+project_source: ProjectSource;
+/**
+ * Whether the project runs in a dev container or Docker container,
+ * from the title or a dev container config in the project root
+ */
+containerized: boolean;
+/**
+ * False for an entry standing in for a running editor without windows;
+ * `project` and `project_path` then name the project it showed last
+ */
+has_windows: boolean;
+/**
+ * Name the user gave the window with `set_window_label`
+ */
+label: string | null;
+/**
+ * Layout version of this payload; 0 for payloads written before
+ * versioning was added
+ */
+schema_version: number }
+/**
+ * What an Xcode window has open
+ */
+export type XcodeContainer =
+/**
+ * A `.xcodeproj` project
+ */
+"project" |
+/**
+ * A `.xcworkspace` grouping one or more projects
+ */
+"workspace" |
+/**
+ * A `.playground`
+ */
+"playground"
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
@@ -1,26 +1,23 @@
 import { invoke } from "@tauri-apps/api/core";
 import type { InvokeArgs } from "@tauri-apps/api/core";
 import { isTauri } from "$lib/utils/guards";
+import type { ErrorInfo, CommandResult as GeneratedCommandResult } from "./bindings";
 
 // Re-export InvokeArgs for use by other files in the tauri layer
 export type { InvokeArgs } from "@tauri-apps/api/core";
 
-// Command types moved from src/lib/commands/command.ts
-export type CommandSuccess<T> = {
-    readonly success: true;
-    readonly data: T;
-    readonly execution_time_ms: number;
-    readonly timestamp: string;
-    readonly error: null;
-};
-
-export type CommandFailure = {
-    readonly success: false;
-    readonly error: string;
-    readonly execution_time_ms: number;
-    readonly timestamp: string;
-    readonly data: null;
-};
+// Discriminated views over the generated CommandResult shape
+export type CommandSuccess<T> = Readonly<GeneratedCommandResult<T> & {
+    success: true;
+    data: T;
+    error: null;
+}>;
+
+export type CommandFailure = Readonly<GeneratedCommandResult<never> & {
+    success: false;
+    error: ErrorInfo;
+    data: null;
+}>;
 
 export type CommandResult<T> = CommandSuccess<T> | CommandFailure;
 
@@ -38,7 +35,7 @@ export type CommandFn<Args extends InvokeArgs | void, Ret> =
 export type FlatCommandResult<T> =
     | { kind: "success"; data: T }
     | { kind: "transport_error"; error: Error }
-    | { kind: "command_error"; error: string };
+    | { kind: "command_error"; error: ErrorInfo };
 
 
 
@@ -2,7 +2,33 @@ import type { InvokeArgs } from "@tauri-apps/api/core";
 import type { Result } from "$lib/utils/tryCatch";
 import { tryCatch } from "$lib/utils/tryCatch";
 import { makeCommandFn, type CommandResult, type FlatCommandResult } from "./client";
-import type { WindowInfo } from "$lib/app/config";
+import type {
+  ActivationPolicy,
+  ApiSchema,
+  BrowserTabOutcome,
+  DiagnosticsReport,
+  DndStatus,
+  FocusHistoryPage,
+  FocusOutcome,
+  FocusTimerStatus,
+  HealthReport,
+  IdleStatus,
+  LogLevel,
+  OpenFile,
+  OpenFileOutcome,
+  OpenWindowOutcome,
+  ProjectSummary,
+  ProviderCapabilities,
+  RunningEditor,
+  SearchMatch,
+  SearchSuggestion,
+  SelfTestReport,
+  SessionFormat,
+  SettingsStatus,
+  ShortcutStatus,
+  SnapshotStatus,
+  WindowInfo,
+} from "./bindings";
 
 // Command utility types imported from client
 
@@ -22,8 +48,6 @@ function defineCommand<Args extends InvokeArgs | void, Ret>(command_name: string
 }
 
 // Command argument types
-type LogLevel = 'debug' | 'info' | 'warn' | 'error';
-
 type LogArgs = {
   level: LogLevel;
   tag: string;
@@ -34,6 +58,7 @@ type LogWithDataArgs = LogArgs & {
   data: any;
 };
 
+
 /**
  * Command registry for Tauri IPC communication.
  * 
@@ -50,9 +75,44 @@ type LogWithDataArgs = LogArgs & {
  * - The backend command names should match the string literals provided to `defineCommand`
  */
 export default {
-    bringWindowToFront: defineCommand<{ pid: number, window_number: number }, void>("bring_window_to_front"),
-    listEditorWindows: defineCommand<void, WindowInfo[]>("list_editor_windows"),
+    bringWindowToFront: defineCommand<{ pid: number, window_number: number, raise_only?: boolean }, FocusOutcome>("bring_window_to_front"),
+    getFocusHistory: defineCommand<{ offset?: number, limit?: number }, FocusHistoryPage>("get_focus_history"),
+    getRunningEditors: defineCommand<void, RunningEditor[]>("get_running_editors"),
+    openEditorWindow: defineCommand<{ pid: number, path?: string | null }, OpenWindowOutcome>("open_editor_window"),
+    focusBrowserTab: defineCommand<{ browser: string, url: string }, BrowserTabOutcome>("focus_browser_tab"),
+    listEditorWindows: defineCommand<{ include_untitled?: boolean, offset?: number, limit?: number, stream?: boolean }, WindowInfo[]>("list_editor_windows"),
+    searchWindows: defineCommand<{ query: string, limit?: number }, SearchMatch[]>("search_windows"),
+    recordSearch: defineCommand<{ query: string, app_name?: string, project?: string | null, project_path?: string | null, window_name?: string | null }, void>("record_search"),
+    getSearchSuggestions: defineCommand<{ prefix: string, limit?: number }, SearchSuggestion[]>("get_search_suggestions"),
+    listOpenFiles: defineCommand<{ pid: number, window_number: number }, OpenFile[]>("list_open_files"),
     logFromFrontend: defineCommand<LogArgs, void>("log_from_frontend"),
     logFromFrontendWithData: defineCommand<LogWithDataArgs, void>("log_from_frontend_with_data"),
-    openDevtools: defineCommand<void, void>("open_devtools"),
+    openDevtools: defineCommand<{ label?: string }, void>("open_devtools"),
+    listWebviewWindows: defineCommand<void, string[]>("list_webview_windows"),
+    runDiagnostics: defineCommand<void, DiagnosticsReport>("run_diagnostics"),
+    dumpWindowDebugInfo: defineCommand<{ include_all_apps?: boolean }, unknown>("dump_window_debug_info"),
+    healthCheck: defineCommand<void, HealthReport>("health_check"),
+    runSelfTest: defineCommand<{ pid?: number, window_number?: number }, SelfTestReport>("run_self_test"),
+    startSnapshotRecording: defineCommand<{ path?: string }, SnapshotStatus>("start_snapshot_recording"),
+    startSnapshotReplay: defineCommand<{ path: string }, SnapshotStatus>("start_snapshot_replay"),
+    stopSnapshots: defineCommand<void, SnapshotStatus>("stop_snapshots"),
+    getSnapshotStatus: defineCommand<void, SnapshotStatus>("get_snapshot_status"),
+    getIdleStatus: defineCommand<void, IdleStatus>("get_idle_status"),
+    setIdleThreshold: defineCommand<{ seconds: number }, IdleStatus>("set_idle_threshold"),
+    getWindowProviderCapabilities: defineCommand<void, ProviderCapabilities>("get_window_provider_capabilities"),
+    getApiSchema: defineCommand<void, ApiSchema>("get_api_schema"),
+    reloadSettings: defineCommand<void, SettingsStatus>("reload_settings"),
+    setActivationPolicy: defineCommand<{ policy: ActivationPolicy }, ActivationPolicy>("set_activation_policy"),
+    watchProject: defineCommand<{ name: string }, string[]>("watch_project"),
+    unwatchProject: defineCommand<{ name: string }, string[]>("unwatch_project"),
+    setDnd: defineCommand<{ enabled: boolean, duration?: number }, DndStatus>("set_dnd"),
+    exportSession: defineCommand<{ format?: SessionFormat }, string>("export_session"),
+    startFocusTimer: defineCommand<{ project: string, minutes: number }, FocusTimerStatus>("start_focus_timer"),
+    stopFocusTimer: defineCommand<void, FocusTimerStatus | null>("stop_focus_timer"),
+    copyProjectPath: defineCommand<{ pid: number, window_number: number }, string | null>("copy_project_path"),
+    copyFilePath: defineCommand<{ pid: number, window_number: number }, string | null>("copy_file_path"),
+    openFile: defineCommand<{ pid: number, window_number: number, path: string, line?: number, column?: number }, OpenFileOutcome>("open_file"),
+    setWindowLabel: defineCommand<{ pid: number, window_number: number, label?: string | null }, WindowInfo>("set_window_label"),
+    getShortcutStatus: defineCommand<void, ShortcutStatus>("get_shortcut_status"),
+    getProjectSummary: defineCommand<{ project: string }, ProjectSummary>("get_project_summary"),
 };
\ No newline at end of file
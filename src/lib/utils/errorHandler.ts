@@ -1,9 +1,15 @@
 import type { FlatCommandResult } from "$lib/tauri/client";
+import type { ErrorInfo } from "$lib/tauri/bindings";
 
 export function handleCommandError(error: unknown, context: string): void {
   console.error(`Failed to ${context}:`, error);
 }
 
-export function isCommandError<T>(result: FlatCommandResult<T>): result is { kind: 'transport_error'; error: Error } | { kind: 'command_error'; error: string } {
+// Text to show users for a failed command; the full message stays in the logs
+export function userMessage(error: Error | ErrorInfo): string {
+  return 'user_message' in error ? error.user_message : 'Something went wrong';
+}
+
+export function isCommandError<T>(result: FlatCommandResult<T>): result is { kind: 'transport_error'; error: Error } | { kind: 'command_error'; error: ErrorInfo } {
   return result.kind === 'transport_error' || result.kind === 'command_error';
 } 
\ No newline at end of file
@@ -1,9 +1,8 @@
-import commands from '$lib/tauri/commands';
+import { invoke } from '@tauri-apps/api/core';
 import { devErrorsStore } from '$lib/stores/dev-errors.svelte';
 import { isTauri } from './guards';
 import { untrack } from 'svelte';
-
-type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+import type { LogEntry, LogLevel } from '$lib/tauri/bindings';
 
 function safeSnapshot(value: any): any {
   return untrack(() => {
@@ -15,9 +14,31 @@ function safeSnapshot(value: any): any {
   });
 }
 
+// Console methods saved by the backend's console capture bridge, if injected
+declare global {
+  interface Window {
+    __swiiConsoleCapture?: Record<'debug' | 'log' | 'info' | 'warn' | 'error', (...args: any[]) => void>;
+  }
+}
+
+// Entries are sent to Rust in batches rather than one IPC call per line
+const FLUSH_INTERVAL_MS = 250;
+const MAX_BATCH_SIZE = 100;
+
 class Logger {
-  private async log(level: LogLevel, tag: string, message: string, data?: any) {
-    const browserLog = console[level === 'debug' ? 'log' : level];
+  private pending: LogEntry[] = [];
+  private flushTimer: ReturnType<typeof setTimeout> | null = null;
+
+  constructor() {
+    if (typeof window !== 'undefined') {
+      window.addEventListener('pagehide', () => this.flush());
+    }
+  }
+
+  private log(level: LogLevel, tag: string, message: string, data?: any) {
+    // Captured console output is forwarded already, so bypass the capture
+    const browserConsole = (isTauri() && window.__swiiConsoleCapture) || console;
+    const browserLog = browserConsole[level === 'debug' ? 'log' : level];
     const safeData = data !== undefined ? safeSnapshot(data) : '';
     browserLog(`[${tag}] ${message}`, safeData);
 
@@ -26,21 +47,39 @@ class Logger {
       return;
     }
 
-    const result = data !== undefined
-      ? await commands.logFromFrontendWithData({
-        level,
-        tag,
-        message,
-        data: JSON.parse(JSON.stringify(safeData))
-      })
-      : await commands.logFromFrontend({ level, tag, message });
-
-    if (result.kind !== 'success') {
-      const errorMsg = result.kind === 'command_error'
-        ? result.error
-        : result.error.message;
-      console.error('Failed to send log to Rust:', errorMsg);
-      devErrorsStore.addError(`[${tag}] Failed to send log to Rust: ${errorMsg}`, { level, message, data });
+    this.pending.push({
+      level,
+      tag,
+      message,
+      data: data !== undefined ? JSON.parse(JSON.stringify(safeData)) : null
+    });
+
+    // Errors go out right away in case the app is about to go down
+    if (level === 'error' || this.pending.length >= MAX_BATCH_SIZE) {
+      this.flush();
+    } else if (this.flushTimer === null) {
+      this.flushTimer = setTimeout(() => this.flush(), FLUSH_INTERVAL_MS);
+    }
+  }
+
+  /** Sends the buffered entries to Rust */
+  async flush() {
+    if (this.flushTimer !== null) {
+      clearTimeout(this.flushTimer);
+      this.flushTimer = null;
+    }
+    if (this.pending.length === 0) {
+      return;
+    }
+
+    const entries = this.pending;
+    this.pending = [];
+    try {
+      await invoke('log_batch_from_frontend', { entries });
+    } catch (error) {
+      const errorMsg = error instanceof Error ? error.message : String(error);
+      console.error('Failed to send logs to Rust:', errorMsg);
+      devErrorsStore.addError(`Failed to send ${entries.length} log entries to Rust: ${errorMsg}`, { entries });
     }
   }
 